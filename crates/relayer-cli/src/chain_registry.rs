@@ -133,11 +133,14 @@ where
         },
         rpc_timeout: default::rpc_timeout(),
         trusted_node: default::trusted_node(),
+        witnesses: Vec::new(),
         genesis_restart: None,
         account_prefix: chain_data.bech32_prefix,
         key_name: String::new(),
+        key_names: Vec::new(),
         key_store_type: Store::default(),
         key_store_folder: None,
+        remote_signer: None,
         store_prefix: "ibc".to_string(),
         default_gas: Some(100000),
         max_gas: Some(400000),
@@ -170,6 +173,10 @@ where
         clear_interval: None,
         excluded_sequences: ExcludedSequences::new(BTreeMap::new()),
         allow_ccq: true,
+        backup_endpoints: Vec::new(),
+        failover: Default::default(),
+        wallet: Default::default(),
+        verified_queries: None,
     }))
 }
 
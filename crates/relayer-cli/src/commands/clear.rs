@@ -165,6 +165,16 @@ impl Runnable for ClearPacketsCmd {
             .map(|chain_config| chain_config.excluded_sequences(&self.channel_id).to_vec())
             .unwrap_or_default();
 
+        let src_ics20_filter = config
+            .find_chain(&chains.src.id())
+            .map(|chain_config| chain_config.packet_filter().ics20.clone())
+            .unwrap_or_default();
+
+        let dst_ics20_filter = config
+            .find_chain(&chains.dst.id())
+            .map(|chain_config| chain_config.packet_filter().ics20.clone())
+            .unwrap_or_default();
+
         let exclude_dst_sequences =
             if let Some(counterparty_channel_id) = channel.counterparty().channel_id() {
                 config
@@ -186,6 +196,8 @@ impl Runnable for ClearPacketsCmd {
             max_memo_size: config.mode.packets.ics20_max_memo_size,
             max_receiver_size: config.mode.packets.ics20_max_receiver_size,
             exclude_src_sequences,
+            ics20_filter: src_ics20_filter,
+            verify_proofs: config.mode.packets.verify_proofs,
         };
 
         let counterparty_channel_id = match channel.counterparty().channel_id() {
@@ -205,6 +217,8 @@ impl Runnable for ClearPacketsCmd {
             max_memo_size: config.mode.packets.ics20_max_memo_size,
             max_receiver_size: config.mode.packets.ics20_max_receiver_size,
             exclude_src_sequences: exclude_dst_sequences,
+            ics20_filter: dst_ics20_filter,
+            verify_proofs: config.mode.packets.verify_proofs,
         };
 
         let fwd_link = match Link::new_from_opts(
@@ -5,6 +5,7 @@ use abscissa_core::{Command, Runnable};
 use crate::commands::create::channel::CreateChannelCommand;
 use crate::commands::create::connection::CreateConnectionCommand;
 use crate::commands::tx::client::TxCreateClientCmd;
+use crate::commands::tx::solomachine::TxCreateSoloMachineClientCmd;
 
 mod channel;
 mod connection;
@@ -15,6 +16,9 @@ pub enum CreateCmds {
     /// Create a new IBC client
     Client(TxCreateClientCmd),
 
+    /// Create a new solo machine client, tracking a key held by Hermes
+    SolomachineClient(TxCreateSoloMachineClientCmd),
+
     /// Create a new connection between two chains
     Connection(CreateConnectionCommand),
 
@@ -7,6 +7,7 @@ use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::chain::requests::{
     IncludeProof, QueryClientStateRequest, QueryConnectionRequest, QueryHeight,
 };
+use ibc_relayer::channel::version::negotiate_transfer_version;
 use ibc_relayer::channel::Channel;
 use ibc_relayer::config::default::connection_delay;
 use ibc_relayer::connection::Connection;
@@ -110,7 +111,8 @@ pub struct CreateChannelCommand {
         long = "channel-version",
         visible_alias = "chan-version",
         value_name = "VERSION",
-        help = "The version for the new channel"
+        help = "The version for the new channel. For transfer channels, defaults to the most \
+                recent ICS20 version supported by both chains"
     )]
     version: Option<Version>,
 
@@ -185,6 +187,8 @@ impl CreateChannelCommand {
             self.order
         );
 
+        let version = self.channel_version(&chains.src, &chains.dst);
+
         let client_a = ForeignClient::new(chains.src.clone(), chains.dst.clone())
             .unwrap_or_else(exit_with_unrecoverable_error);
         let client_b = ForeignClient::new(chains.dst.clone(), chains.src)
@@ -200,7 +204,7 @@ impl CreateChannelCommand {
             self.order,
             self.port_a.clone(),
             self.port_b.clone(),
-            self.version.clone(),
+            version,
         )
         .unwrap_or_else(exit_with_unrecoverable_error);
 
@@ -242,6 +246,8 @@ impl CreateChannelCommand {
         let chain_b =
             spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error);
 
+        let version = self.channel_version(&chain_a, &chain_b);
+
         // Create the foreign client handles.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), conn_end.client_id())
             .unwrap_or_else(exit_with_unrecoverable_error);
@@ -258,12 +264,32 @@ impl CreateChannelCommand {
             self.order,
             self.port_a.clone(),
             self.port_b.clone(),
-            self.version.clone(),
+            version,
         )
         .unwrap_or_else(exit_with_unrecoverable_error);
 
         Output::success(channel).exit();
     }
+
+    /// Returns the version for the new channel: the version given by the user if any,
+    /// otherwise the ICS20 version negotiated between the chains for transfer channels.
+    fn channel_version(
+        &self,
+        chain_a: &impl ChainHandle,
+        chain_b: &impl ChainHandle,
+    ) -> Option<Version> {
+        if let Some(version) = &self.version {
+            return Some(version.clone());
+        }
+
+        let version = negotiate_transfer_version(chain_a, &self.port_a, chain_b, &self.port_b);
+
+        if let Some(version) = &version {
+            info!("Negotiated version {version} for the new transfer channel");
+        }
+
+        version
+    }
 }
 
 #[cfg(test)]
@@ -4,10 +4,12 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{config::Override, Command, Runnable};
 use ibc_relayer::config::Config;
 
+use self::pay_packet::FeePayPacketCmd;
 use self::register_counterparty_payee::RegisterCounterpartyPayeeCmd;
 use self::register_payee::RegisterPayeeCmd;
 use self::transfer::FeeTransferCmd;
 
+pub mod pay_packet;
 pub mod register_counterparty_payee;
 pub mod register_payee;
 pub mod transfer;
@@ -23,6 +25,9 @@ pub enum FeeCmd {
 
     /// Perform a token transfer supported with a fee
     Transfer(FeeTransferCmd),
+
+    /// Escrow fees for the relaying of a packet which has already been sent
+    PayPacket(FeePayPacketCmd),
 }
 
 impl Override<Config> for FeeCmd {
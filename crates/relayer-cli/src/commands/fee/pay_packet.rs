@@ -0,0 +1,250 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryHeight, QueryPacketCommitmentRequest};
+use ibc_relayer::chain::tracking::TrackedMsgs;
+use ibc_relayer_types::applications::ics29_fee::msgs::pay_packet_async::build_pay_packet_fee_async_message;
+use ibc_relayer_types::applications::transfer::{Amount, Coin};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct FeePayPacketCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "FLAGS",
+        help = "Identifier of the chain on which the packet was sent"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "channel",
+        visible_alias = "chan",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "FLAGS",
+        help = "Identifier of the channel on which the packet was sent"
+    )]
+    channel_id: ChannelId,
+
+    #[clap(
+        long = "port",
+        value_name = "PORT_ID",
+        help = "Identifier of the port on which the packet was sent. Default: transfer",
+        default_value = "transfer"
+    )]
+    port_id: PortId,
+
+    #[clap(
+        long = "sequence",
+        visible_alias = "seq",
+        required = true,
+        value_name = "SEQUENCE",
+        help_heading = "FLAGS",
+        help = "Sequence of the packet"
+    )]
+    sequence: Sequence,
+
+    #[clap(
+        long = "recv-fee",
+        value_name = "RECV_FEE",
+        help = "Fee to pay for the Recv message. Default: 0",
+        default_value = "0"
+    )]
+    recv_fee: Amount,
+
+    #[clap(
+        long = "ack-fee",
+        value_name = "ACK_FEE",
+        help = "Fee to pay for the Ack message. Default: 0",
+        default_value = "0"
+    )]
+    ack_fee: Amount,
+
+    #[clap(
+        long = "timeout-fee",
+        value_name = "TIMEOUT_FEE",
+        help = "Fee to pay for the Timeout message. Default: 0",
+        default_value = "0"
+    )]
+    timeout_fee: Amount,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination of the fees. Default: samoleans",
+        default_value = "samoleans"
+    )]
+    denom: String,
+}
+
+impl Runnable for FeePayPacketCmd {
+    fn run(&self) {
+        run_pay_packet_command(self).unwrap_or_else(exit_with_unrecoverable_error);
+
+        Output::success_msg(format!(
+            "Successfully escrowed fees for packet {} on {}/{}",
+            self.sequence, self.port_id, self.channel_id
+        ))
+        .exit()
+    }
+}
+
+fn run_pay_packet_command(cmd: &FeePayPacketCmd) -> Result<(), Error> {
+    let config = app_config();
+
+    let chain_handle = spawn_chain_runtime(&config, &cmd.chain_id)?;
+
+    // Fees can only be escrowed for a packet which is still in flight,
+    // i.e. whose commitment is still stored on the sending chain.
+    let (commitment, _) = chain_handle
+        .query_packet_commitment(
+            QueryPacketCommitmentRequest {
+                port_id: cmd.port_id.clone(),
+                channel_id: cmd.channel_id.clone(),
+                sequence: cmd.sequence,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(Error::relayer)?;
+
+    if commitment.is_empty() {
+        return Err(Error::missing_packet_commitment(
+            cmd.chain_id.clone(),
+            cmd.port_id.clone(),
+            cmd.channel_id.clone(),
+            cmd.sequence,
+        ));
+    }
+
+    let signer = chain_handle.get_signer().map_err(Error::relayer)?;
+
+    let message = build_pay_packet_fee_async_message(
+        &cmd.port_id,
+        &cmd.channel_id,
+        cmd.sequence,
+        &signer,
+        vec![Coin::new(cmd.denom.clone(), cmd.recv_fee)],
+        vec![Coin::new(cmd.denom.clone(), cmd.ack_fee)],
+        vec![Coin::new(cmd.denom.clone(), cmd.timeout_fee)],
+    )
+    .map_err(Error::fee)?;
+
+    let messages = TrackedMsgs::new_static(vec![message], "cli");
+
+    chain_handle
+        .send_messages_and_wait_commit(messages)
+        .map_err(Error::relayer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::FeePayPacketCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::{
+        applications::transfer::Amount,
+        core::{
+            ics04_channel::packet::Sequence,
+            ics24_host::identifier::{ChainId, ChannelId, PortId},
+        },
+    };
+
+    #[test]
+    fn test_pay_packet_required_only() {
+        assert_eq!(
+            FeePayPacketCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                channel_id: ChannelId::from_str("channel-0").unwrap(),
+                port_id: PortId::from_str("transfer").unwrap(),
+                sequence: Sequence::from(42),
+                recv_fee: Amount::from(0u64),
+                ack_fee: Amount::from(0u64),
+                timeout_fee: Amount::from(0u64),
+                denom: "samoleans".to_owned(),
+            },
+            FeePayPacketCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--channel",
+                "channel-0",
+                "--sequence",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_pay_packet_all_options() {
+        assert_eq!(
+            FeePayPacketCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                channel_id: ChannelId::from_str("channel-0").unwrap(),
+                port_id: PortId::from_str("port_a").unwrap(),
+                sequence: Sequence::from(42),
+                recv_fee: Amount::from(100u64),
+                ack_fee: Amount::from(200u64),
+                timeout_fee: Amount::from(300u64),
+                denom: "stake".to_owned(),
+            },
+            FeePayPacketCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--chan",
+                "channel-0",
+                "--port",
+                "port_a",
+                "--seq",
+                "42",
+                "--recv-fee",
+                "100",
+                "--ack-fee",
+                "200",
+                "--timeout-fee",
+                "300",
+                "--denom",
+                "stake"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_pay_packet_no_sequence() {
+        assert!(FeePayPacketCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_a",
+            "--channel",
+            "channel-0"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_pay_packet_no_chain() {
+        assert!(FeePayPacketCmd::try_parse_from([
+            "test",
+            "--channel",
+            "channel-0",
+            "--sequence",
+            "42"
+        ])
+        .is_err())
+    }
+}
@@ -254,6 +254,8 @@ impl From<FeeTransferOptions> for TransferOptions {
             timeout_duration: f.timeout_duration,
             number_msgs: f.number_msgs,
             memo: f.memo,
+            additional_tokens: vec![],
+            forwarding: None,
         }
     }
 }
@@ -14,7 +14,7 @@ use ibc_relayer::{
     config::{ChainConfig, Config},
     keyring::{
         AnySigningKeyPair, KeyRing, NamadaKeyPair, Secp256k1KeyPair, SigningKeyPair,
-        SigningKeyPairSized, Store,
+        SigningKeyPairSized,
     },
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -207,7 +207,7 @@ pub fn add_key(
     let key_pair = match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.on_disk()?,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
@@ -223,8 +223,11 @@ pub fn add_key(
             key_pair.into()
         }
         ChainConfig::Namada(config) => {
-            let mut keyring =
-                KeyRing::new_namada(Store::Test, &config.id, &config.key_store_folder)?;
+            let mut keyring = KeyRing::new_namada(
+                config.key_store_type.on_disk()?,
+                &config.id,
+                &config.key_store_folder,
+            )?;
 
             check_key_exists(&keyring, key_name, overwrite);
 
@@ -253,6 +256,8 @@ pub fn add_key(
             namada_key.into()
         }
         ChainConfig::Penumbra(_) => unimplemented!("no key storage support for penumbra"),
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => unimplemented!("no key storage support for mock chains"),
     };
 
     Ok(key_pair)
@@ -271,7 +276,7 @@ pub fn restore_key(
     let key_pair = match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.on_disk()?,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
@@ -295,6 +300,8 @@ pub fn restore_key(
             ));
         }
         ChainConfig::Penumbra(_) => return Err(eyre!("no key storage support for penumbra")),
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => return Err(eyre!("no key storage support for mock chains")),
     };
 
     Ok(key_pair)
@@ -81,6 +81,8 @@ fn get_balance(chain: impl ChainHandle, key_name: Option<String>, denom: Option<
                         chain_config.key_name
                     }
                     ChainConfig::Penumbra(_) => unimplemented!("not yet supported for penumbra"),
+                    #[cfg(feature = "mock-chain")]
+                    ChainConfig::Mock(chain_config) => chain_config.key_name,
                 }
             });
 
@@ -106,6 +108,8 @@ fn get_balances(chain: impl ChainHandle, key_name: Option<String>) {
                         chain_config.key_name
                     }
                     ChainConfig::Penumbra(_) => unimplemented!("not yet supported for penumbra"),
+                    #[cfg(feature = "mock-chain")]
+                    ChainConfig::Mock(chain_config) => chain_config.key_name,
                 }
             });
 
@@ -4,7 +4,7 @@ use abscissa_core::{Command, Runnable};
 use eyre::eyre;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyRing, Store},
+    keyring::KeyRing,
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -116,7 +116,7 @@ pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
     match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.on_disk()?,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
@@ -124,11 +124,16 @@ pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
             keyring.remove_key(key_name)?;
         }
         ChainConfig::Namada(config) => {
-            let mut keyring =
-                KeyRing::new_namada(Store::Test, &config.id, &config.key_store_folder)?;
+            let mut keyring = KeyRing::new_namada(
+                config.key_store_type.on_disk()?,
+                &config.id,
+                &config.key_store_folder,
+            )?;
             keyring.remove_key(key_name)?;
         }
         ChainConfig::Penumbra(_) => unimplemented!("no key support for penumbra"),
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => unimplemented!("no key support for mock chains"),
     }
     Ok(())
 }
@@ -137,7 +142,7 @@ pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
     match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.on_disk()?,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
@@ -148,14 +153,19 @@ pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
             }
         }
         ChainConfig::Namada(config) => {
-            let mut keyring =
-                KeyRing::new_namada(Store::Test, &config.id, &config.key_store_folder)?;
+            let mut keyring = KeyRing::new_namada(
+                config.key_store_type.on_disk()?,
+                &config.id,
+                &config.key_store_folder,
+            )?;
             let keys = keyring.keys()?;
             for (key_name, _) in keys {
                 keyring.remove_key(&key_name)?;
             }
         }
         ChainConfig::Penumbra(_) => unimplemented!("no key support for penumbra"),
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => unimplemented!("no key support for mock chains"),
     }
     Ok(())
 }
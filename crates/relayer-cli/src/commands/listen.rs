@@ -18,6 +18,7 @@ use ibc_relayer::chain::handle::Subscription;
 use ibc_relayer::config::{ChainConfig, EventSourceMode};
 use ibc_relayer::error::Error;
 use ibc_relayer::event::source::EventSource;
+use ibc_relayer::sink;
 use ibc_relayer::HERMES_VERSION;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::events::IbcEvent;
@@ -85,6 +86,8 @@ impl ListenCmd {
             .find_chain(&self.chain_id)
             .ok_or_else(|| eyre!("chain '{}' not found in configuration", self.chain_id))?;
 
+        sink::init(&config.sinks).map_err(|e| eyre!("failed to set up the event sinks: {e}"))?;
+
         let events = if self.events.is_empty() {
             &[EventFilter::Tx, EventFilter::NewBlock]
         } else {
@@ -119,15 +122,18 @@ pub fn listen(config: &ChainConfig, filters: &[EventFilter]) -> eyre::Result<()>
                     .events
                     .iter()
                     .filter(|e| event_match(&e.event, filters))
+                    .cloned()
                     .collect_vec();
 
                 if matching_events.is_empty() {
                     continue;
                 }
 
-                for event in matching_events {
+                for event in &matching_events {
                     info!("{}", event);
                 }
+
+                sink::ibc_events(&batch.chain_id, batch.height, &matching_events);
             }
             Err(e) => error!("- error: {}", e),
         }
@@ -208,6 +214,8 @@ fn subscribe(
             let subscription = monitor_tx.subscribe()?;
             Ok(subscription)
         }
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => Err(eyre!("cannot listen to the events of a mock chain")),
     }
 }
 
@@ -218,6 +226,8 @@ fn detect_compatibility_mode(
     let rpc_addr = match config {
         ChainConfig::CosmosSdk(config) | ChainConfig::Namada(config) => config.rpc_addr.clone(),
         ChainConfig::Penumbra(config) => config.rpc_addr.clone(),
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => return Ok(CompatMode::latest()),
     };
 
     let client = HttpClient::builder(rpc_addr.try_into()?)
@@ -232,6 +242,8 @@ fn detect_compatibility_mode(
             let status = rt.block_on(client.status())?;
             penumbra::util::compat_mode_from_version(&config.compat_mode, status.node_info.version)?
         }
+        #[cfg(feature = "mock-chain")]
+        ChainConfig::Mock(_) => CompatMode::latest(),
     };
 
     Ok(compat_mode)
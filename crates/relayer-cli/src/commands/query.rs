@@ -16,6 +16,8 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod fee;
+mod ica;
 mod packet;
 mod transfer;
 mod tx;
@@ -55,6 +57,14 @@ pub enum QueryCmd {
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
+
+    /// Query information about interchain accounts (ICS27)
+    #[clap(subcommand)]
+    Ica(ica::QueryIcaCmds),
+
+    /// Query information about fees (ICS29)
+    #[clap(subcommand)]
+    Fee(fee::QueryFeeCmds),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
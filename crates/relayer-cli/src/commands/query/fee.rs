@@ -0,0 +1,120 @@
+//! `query fee` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+
+/// `query fee` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum QueryFeeCmds {
+    /// Query the packets sent on a channel which have fees escrowed for their relaying
+    IncentivizedPackets(QueryIncentivizedPacketsCmd),
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryIncentivizedPacketsCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain to query"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "channel",
+        visible_alias = "chan",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the channel to query"
+    )]
+    channel_id: ChannelId,
+
+    #[clap(
+        long = "port",
+        value_name = "PORT_ID",
+        default_value = "transfer",
+        help = "Identifier of the port to query. Default: transfer"
+    )]
+    port_id: PortId,
+}
+
+impl Runnable for QueryIncentivizedPacketsCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        match chain.query_incentivized_packets(self.channel_id.clone(), self.port_id.clone()) {
+            Ok(packets) => Output::success(packets).exit(),
+            Err(e) => Output::error(format!(
+                "there was a problem querying the incentivized packets: {e}"
+            ))
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryIncentivizedPacketsCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+    #[test]
+    fn test_query_incentivized_packets() {
+        assert_eq!(
+            QueryIncentivizedPacketsCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                channel_id: ChannelId::from_str("channel-0").unwrap(),
+                port_id: PortId::from_str("transfer").unwrap(),
+            },
+            QueryIncentivizedPacketsCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--channel",
+                "channel-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_incentivized_packets_port() {
+        assert_eq!(
+            QueryIncentivizedPacketsCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                channel_id: ChannelId::from_str("channel-0").unwrap(),
+                port_id: PortId::from_str("port_a").unwrap(),
+            },
+            QueryIncentivizedPacketsCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--chan",
+                "channel-0",
+                "--port",
+                "port_a"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_incentivized_packets_no_channel() {
+        assert!(
+            QueryIncentivizedPacketsCmd::try_parse_from(["test", "--chain", "chain_a"]).is_err()
+        )
+    }
+}
@@ -0,0 +1,114 @@
+//! `query ica` subcommand
+
+use core::str::FromStr;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+
+/// `query ica` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum QueryIcaCmds {
+    /// Query the address of the interchain account controlled by an owner
+    Address(QueryIcaAddressCmd),
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryIcaAddressCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain, on which the owner account lives"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain, on the controller chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "owner",
+        required = true,
+        value_name = "OWNER",
+        help_heading = "REQUIRED",
+        help = "Address of the owner of the interchain account"
+    )]
+    owner: String,
+}
+
+impl Runnable for QueryIcaAddressCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let owner = Signer::from_str(&self.owner).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let chain = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        match chain.query_interchain_account(owner, self.connection_id.clone()) {
+            Ok(address) if json() => Output::success(address).exit(),
+            Ok(address) => Output::success_msg(format!("address: {address}")).exit(),
+            Err(e) => Output::error(format!(
+                "there was a problem querying the interchain account: {e}"
+            ))
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryIcaAddressCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_query_ica_address() {
+        assert_eq!(
+            QueryIcaAddressCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                owner: "cosmos1owner".to_owned(),
+            },
+            QueryIcaAddressCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--connection",
+                "connection-0",
+                "--owner",
+                "cosmos1owner"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_ica_address_no_owner() {
+        assert!(QueryIcaAddressCmd::try_parse_from([
+            "test",
+            "--controller-chain",
+            "chain_a",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
@@ -1,16 +1,17 @@
 use ibc_relayer::supervisor::SupervisorOptions;
 use ibc_relayer::util::debug_section::DebugSection;
 use std::error::Error;
-use std::io;
+use std::{fs, io};
 
 use abscissa_core::clap::Parser;
 use crossbeam_channel::Sender;
 
 use ibc_relayer::chain::handle::{CachingChainHandle, ChainHandle};
-use ibc_relayer::config::Config;
+use ibc_relayer::config::{AuthConfig, Config, TlsConfig};
 use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::rest;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
+use ibc_telemetry::secure::{AuthOptions, ServerOptions, TlsOptions};
 
 use crate::conclude::json;
 use crate::conclude::Output;
@@ -57,6 +58,7 @@ impl Runnable for StartCmd {
         let options = SupervisorOptions {
             force_full_scan: self.full_scan,
             health_check: true,
+            config_path: crate::config::config_path(),
         };
 
         let supervisor_handle = make_supervisor::<CachingChainHandle>(config, options)
@@ -82,13 +84,13 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Reload the configuration file and apply the changes to the chains.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
 fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -97,10 +99,28 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!("reloading configuration (triggered by SIGHUP)");
+
+                    let (tx, rx) = crossbeam_channel::bounded(1);
+
+                    if let Err(e) = tx_cmd.try_send(SupervisorCmd::Reload(tx)) {
+                        error!("failed to request a reload of the configuration: {}", e);
+                        continue;
+                    }
+
+                    std::thread::spawn(move || match rx.recv() {
+                        Ok(Ok(diff)) => info!(
+                            added = ?diff.added,
+                            updated = ?diff.updated,
+                            reconfigured = ?diff.reconfigured,
+                            removed = ?diff.removed,
+                            "configuration reloaded"
+                        ),
+                        Ok(Err(e)) => error!("failed to reload configuration: {}", e),
+                        Err(_) => {}
+                    });
+                }
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -143,15 +163,34 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
         return None;
     }
 
+    let options = match server_options(rest.tls.as_ref(), rest.auth.as_ref()) {
+        Ok(options) => options,
+        Err(e) => {
+            error!("REST service failed to start: {e}");
+            return None;
+        }
+    };
+
     let (tx, rx) = crossbeam_channel::unbounded();
 
     spawn_blocking(async move {
-        let result = ibc_relayer_rest::spawn((rest.host.as_str(), rest.port), tx);
+        let scheme = if options.tls.is_some() {
+            "https"
+        } else {
+            "http"
+        };
+
+        let result = ibc_relayer_rest::spawn_with_options(
+            (rest.host.as_str(), rest.port),
+            tx,
+            options,
+            rest.protect_reads,
+        );
 
         match result {
             Ok(handle) => {
                 info!(
-                    "REST service running, exposing REST API at http://{}:{}",
+                    "REST service running, exposing REST API at {scheme}://{}:{}",
                     rest.host, rest.port
                 );
 
@@ -187,12 +226,30 @@ fn spawn_telemetry_server(config: &Config) {
         return;
     }
 
+    let options = match server_options(telemetry.tls.as_ref(), telemetry.auth.as_ref()) {
+        Ok(options) => options,
+        Err(e) => {
+            error!("telemetry service failed to start: {e}");
+            return;
+        }
+    };
+
     spawn_blocking(async move {
-        let result = ibc_telemetry::spawn((telemetry.host, telemetry.port), state.clone());
+        let scheme = if options.tls.is_some() {
+            "https"
+        } else {
+            "http"
+        };
+
+        let result = ibc_telemetry::spawn_with_options(
+            (telemetry.host, telemetry.port),
+            state.clone(),
+            options,
+        );
 
         match result {
             Ok((addr, handle)) => {
-                info!("telemetry service running, exposing metrics at http://{addr}/metrics");
+                info!("telemetry service running, exposing metrics at {scheme}://{addr}/metrics");
 
                 if let Err(e) = handle.await {
                     error!("telemetry service crashed with error: {e}");
@@ -203,6 +260,43 @@ fn spawn_telemetry_server(config: &Config) {
     });
 }
 
+/// Set up TLS and the authentication of clients with the given settings.
+/// Clients presenting a certificate verified against the client CA are authenticated.
+fn server_options(
+    tls: Option<&TlsConfig>,
+    auth: Option<&AuthConfig>,
+) -> Result<ServerOptions, Box<dyn Error + Send + Sync>> {
+    let token = match auth {
+        Some(auth) => {
+            let token = fs::read_to_string(&auth.token_file).map_err(|e| {
+                format!(
+                    "failed to read the token file {}: {e}",
+                    auth.token_file.display()
+                )
+            })?;
+
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(format!("token file {} is empty", auth.token_file.display()).into());
+            }
+
+            Some(token.to_string())
+        }
+        None => None,
+    };
+
+    let client_cert = tls.is_some_and(|tls| tls.client_ca.is_some());
+
+    Ok(ServerOptions {
+        tls: tls.map(|tls| TlsOptions {
+            cert: tls.cert.clone(),
+            key: tls.key.clone(),
+            client_ca: tls.client_ca.clone(),
+        }),
+        auth: AuthOptions { token, client_cert },
+    })
+}
+
 fn make_supervisor<Chain: ChainHandle>(
     config: Config,
     options: SupervisorOptions,
@@ -6,7 +6,10 @@ use ibc_relayer::config::Config;
 mod channel;
 pub(crate) mod client;
 mod connection;
+mod ica;
 mod packet;
+mod recover_client;
+pub(crate) mod solomachine;
 mod transfer;
 mod upgrade;
 
@@ -71,8 +74,21 @@ pub enum TxCmd {
     /// Relay acknowledgment packets
     PacketAck(packet::TxPacketAckCmd),
 
+    /// Relay timeout packets
+    PacketTimeout(packet::TxPacketTimeoutCmd),
+
+    /// Relay timeout on close packets
+    PacketTimeoutOnClose(packet::TxPacketTimeoutOnCloseCmd),
+
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
+
+    /// Submit a governance proposal recovering an expired or frozen client
+    RecoverClient(recover_client::TxRecoverClientCmd),
+
+    /// Register and operate interchain accounts (ICS27)
+    #[clap(subcommand)]
+    Ica(ica::TxIcaCmds),
 }
 
 impl Override<Config> for TxCmd {
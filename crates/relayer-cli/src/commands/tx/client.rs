@@ -75,6 +75,14 @@ pub struct TxCreateClientCmd {
     /// and trusted validator set is sufficient for a commit to be accepted going forward.
     #[clap(long = "trust-threshold", value_name = "TRUST_THRESHOLD", parse(try_from_str = parse_trust_threshold))]
     trust_threshold: Option<TrustThreshold>,
+
+    /// Create a 08-wasm client running the Wasm light client contract with the given checksum.
+    ///
+    /// The hex-encoded checksum identifies a Tendermint light client contract stored on
+    /// the host chain. The created client wraps the Tendermint client state and consensus
+    /// state, and is updated and checked for misbehaviour like a Tendermint client.
+    #[clap(long = "wasm-checksum", value_name = "WASM_CHECKSUM")]
+    wasm_checksum: Option<String>,
 }
 
 /// Sample to run this tx:
@@ -92,12 +100,19 @@ impl Runnable for TxCreateClientCmd {
             Err(e) => Output::error(e).exit(),
         };
 
+        let wasm_checksum = self.wasm_checksum.as_ref().map(|checksum| {
+            subtle_encoding::hex::decode(checksum).unwrap_or_else(|e| {
+                Output::error(format!("invalid Wasm checksum '{checksum}': {e}")).exit()
+            })
+        });
+
         let client = ForeignClient::restore(ClientId::default(), chains.dst, chains.src);
 
         let options = CreateOptions {
             max_clock_drift: self.clock_drift.map(Into::into),
             trusting_period: self.trusting_period.map(Into::into),
             trust_threshold: self.trust_threshold.map(Into::into),
+            wasm_checksum,
         };
 
         // Trigger client creation via the "build" interface, so that we obtain the resulting event
@@ -212,6 +227,8 @@ impl Runnable for TxUpdateClientCmd {
                     ChainConfig::Penumbra(chain_config) => {
                         chain_config.genesis_restart = Some(restart_params)
                     }
+                    #[cfg(feature = "mock-chain")]
+                    ChainConfig::Mock(_) => { /* no-op */ }
                 },
                 None => {
                     Output::error(format!(
@@ -646,7 +663,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -666,7 +684,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -684,7 +703,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("3s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -706,7 +726,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("5s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -724,7 +745,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -746,7 +768,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -760,6 +783,29 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_create_client_wasm_checksum() {
+        assert_eq!(
+            TxCreateClientCmd {
+                dst_chain_id: ChainId::from_string("host_chain"),
+                src_chain_id: ChainId::from_string("reference_chain"),
+                clock_drift: None,
+                trusting_period: None,
+                trust_threshold: None,
+                wasm_checksum: Some("a1b2c3".to_owned())
+            },
+            TxCreateClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--reference-chain",
+                "reference_chain",
+                "--wasm-checksum",
+                "a1b2c3"
+            ])
+        )
+    }
+
     #[test]
     fn test_create_client_all_options() {
         assert_eq!(
@@ -768,7 +814,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -0,0 +1,300 @@
+use core::str::FromStr;
+use core::time::Duration;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::interchain_account::{
+    build_and_send_interchain_account_tx, build_and_send_register_interchain_account,
+    parse_messages, RegisterInterchainAccountOptions, SendInterchainAccountTxOptions,
+};
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+/// `tx ica` subcommands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum TxIcaCmds {
+    /// Register an interchain account, opening a channel for it (MsgRegisterInterchainAccount)
+    Register(TxIcaRegisterCmd),
+
+    /// Send a transaction to be executed by an interchain account (MsgSendTx)
+    Send(TxIcaSendCmd),
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaRegisterCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain, on which the owner account lives"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain, on the controller chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "owner",
+        value_name = "OWNER",
+        help = "Address of the owner of the interchain account, which must be the address of the \
+                key used by Hermes on the controller chain. Defaults to that address"
+    )]
+    owner: Option<String>,
+
+    #[clap(
+        long = "ordering",
+        value_name = "ORDERING",
+        default_value = "ordered",
+        help = "Ordering of the channel opened for the interchain account, either 'ordered' or \
+                'unordered'. Ignored by controller chains with ibc-go versions below v8.0.0"
+    )]
+    ordering: Ordering,
+
+    #[clap(
+        long = "version",
+        value_name = "VERSION",
+        help = "Version of the channel opened for the interchain account. \
+                Defaults to the ICS27 metadata chosen by the controller chain"
+    )]
+    version: Option<Version>,
+}
+
+impl Runnable for TxIcaRegisterCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let owner =
+            parse_owner(self.owner.as_deref()).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let controller_chain = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let opts = RegisterInterchainAccountOptions {
+            connection_id: self.connection_id.clone(),
+            owner,
+            version: self.version.clone().unwrap_or_else(Version::empty),
+            ordering: self.ordering,
+        };
+
+        let res = build_and_send_register_interchain_account(&controller_chain, &opts)
+            .map_err(Error::interchain_account);
+
+        match res {
+            Ok(event) => Output::success(event.event).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaSendCmd {
+    #[clap(
+        long = "controller-chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain, on which the owner account lives"
+    )]
+    controller_chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain, on the controller chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "msgs",
+        required = true,
+        value_name = "MSGS_JSON",
+        help_heading = "REQUIRED",
+        help = "JSON array of the protobuf messages executed by the interchain account, \
+                e.g. '[{\"type_url\": \"/cosmos.bank.v1beta1.MsgSend\", \"value\": \"<base64>\"}]'"
+    )]
+    msgs: String,
+
+    #[clap(
+        long = "owner",
+        value_name = "OWNER",
+        help = "Address of the owner of the interchain account, which must be the address of the \
+                key used by Hermes on the controller chain. Defaults to that address"
+    )]
+    owner: Option<String>,
+
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        help = "Memo included in the packet data"
+    )]
+    memo: Option<String>,
+
+    #[clap(
+        long = "timeout-seconds",
+        default_value = "600",
+        value_name = "TIMEOUT_SECONDS",
+        help = "Timeout of the packet in seconds, relative to the time of the controller chain"
+    )]
+    timeout_seconds: u64,
+}
+
+impl Runnable for TxIcaSendCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let messages = parse_messages(&self.msgs)
+            .map_err(Error::interchain_account)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let owner =
+            parse_owner(self.owner.as_deref()).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let controller_chain = spawn_chain_runtime(&config, &self.controller_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let opts = SendInterchainAccountTxOptions {
+            connection_id: self.connection_id.clone(),
+            owner,
+            messages,
+            memo: self.memo.clone().unwrap_or_default(),
+            timeout: Duration::from_secs(self.timeout_seconds),
+        };
+
+        let res = build_and_send_interchain_account_tx(&controller_chain, &opts)
+            .map_err(Error::interchain_account);
+
+        match res {
+            Ok(event) => Output::success(event.event).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+fn parse_owner(owner: Option<&str>) -> Result<Option<Signer>, Error> {
+    owner
+        .map(|owner| Signer::from_str(owner).map_err(Error::signer))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TxIcaRegisterCmd, TxIcaSendCmd};
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_register_required_only() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                owner: None,
+                ordering: Ordering::Ordered,
+                version: None,
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--connection",
+                "connection-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_all_options() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                owner: Some("cosmos1owner".to_owned()),
+                ordering: Ordering::Unordered,
+                version: Some(Version::new("ics27-1".to_owned())),
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--conn",
+                "connection-0",
+                "--owner",
+                "cosmos1owner",
+                "--ordering",
+                "unordered",
+                "--version",
+                "ics27-1"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_no_connection() {
+        assert!(
+            TxIcaRegisterCmd::try_parse_from(["test", "--controller-chain", "chain_a"]).is_err()
+        )
+    }
+
+    #[test]
+    fn test_ica_send() {
+        assert_eq!(
+            TxIcaSendCmd {
+                controller_chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                msgs: "[]".to_owned(),
+                owner: None,
+                memo: Some("memo".to_owned()),
+                timeout_seconds: 60,
+            },
+            TxIcaSendCmd::parse_from([
+                "test",
+                "--controller-chain",
+                "chain_a",
+                "--connection",
+                "connection-0",
+                "--msgs",
+                "[]",
+                "--memo",
+                "memo",
+                "--timeout-seconds",
+                "60"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_send_no_msgs() {
+        assert!(TxIcaSendCmd::try_parse_from([
+            "test",
+            "--controller-chain",
+            "chain_a",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
@@ -90,6 +90,11 @@ impl Runnable for TxPacketRecvCmd {
 
             // Packets are only excluded when clearing
             exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| chain_config.packet_filter().ics20.clone())
+                .unwrap_or_default(),
+            verify_proofs: config.mode.packets.verify_proofs,
         };
 
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
@@ -191,6 +196,11 @@ impl Runnable for TxPacketAckCmd {
 
             // Packets are only excluded when clearing
             exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| chain_config.packet_filter().ics20.clone())
+                .unwrap_or_default(),
+            verify_proofs: config.mode.packets.verify_proofs,
         };
 
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
@@ -216,13 +226,202 @@ impl Runnable for TxPacketAckCmd {
     }
 }
 
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxPacketTimeoutCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel"
+    )]
+    src_channel_id: ChannelId,
+
+    #[clap(
+        long = "packet-sequences",
+        visible_alias = "sequence",
+        help = "Sequences of packets to be timed out on `src-chain`. \
+                Either a single sequence or a range of sequences can be specified. \
+                If not provided, all timed out packets will be timed out on `src-chain`. \
+                Each element of the comma-separated list must be either a single \
+                sequence or a range of sequences. \
+                Example: `1,10..20` will time out packets with sequences 1, 10, 11, ..., 20",
+        value_delimiter = ',',
+        value_parser = parse_seq_range
+    )]
+    packet_sequences: Vec<RangeInclusive<Sequence>>,
+}
+
+impl Runnable for TxPacketTimeoutCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let opts = LinkParameters {
+            src_port_id: self.src_port_id.clone(),
+            src_channel_id: self.src_channel_id.clone(),
+            max_memo_size: config.mode.packets.ics20_max_memo_size,
+            max_receiver_size: config.mode.packets.ics20_max_receiver_size,
+
+            // Packets are only excluded when clearing
+            exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| chain_config.packet_filter().ics20.clone())
+                .unwrap_or_default(),
+            verify_proofs: config.mode.packets.verify_proofs,
+        };
+
+        let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
+            Ok(link) => link,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let res: Result<Vec<IbcEvent>, Error> = link
+            .relay_timeout_packet_messages(self.packet_sequences.clone())
+            .map_err(Error::link);
+
+        match res {
+            Ok(ev) => Output::success(ev).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxPacketTimeoutOnCloseCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel"
+    )]
+    src_channel_id: ChannelId,
+
+    #[clap(
+        long = "packet-sequences",
+        visible_alias = "sequence",
+        help = "Sequences of packets to be timed out on `src-chain`. \
+                Either a single sequence or a range of sequences can be specified. \
+                If not provided, all unreceived packets will be timed out on `src-chain`. \
+                Each element of the comma-separated list must be either a single \
+                sequence or a range of sequences. \
+                Example: `1,10..20` will time out packets with sequences 1, 10, 11, ..., 20",
+        value_delimiter = ',',
+        value_parser = parse_seq_range
+    )]
+    packet_sequences: Vec<RangeInclusive<Sequence>>,
+}
+
+impl Runnable for TxPacketTimeoutOnCloseCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let opts = LinkParameters {
+            src_port_id: self.src_port_id.clone(),
+            src_channel_id: self.src_channel_id.clone(),
+            max_memo_size: config.mode.packets.ics20_max_memo_size,
+            max_receiver_size: config.mode.packets.ics20_max_receiver_size,
+
+            // Packets are only excluded when clearing
+            exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| chain_config.packet_filter().ics20.clone())
+                .unwrap_or_default(),
+            verify_proofs: config.mode.packets.verify_proofs,
+        };
+
+        let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
+            Ok(link) => link,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let res: Result<Vec<IbcEvent>, Error> = link
+            .relay_timeout_on_close_packet_messages(self.packet_sequences.clone())
+            .map_err(Error::link);
+
+        match res {
+            Ok(ev) => Output::success(ev).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{TxPacketAckCmd, TxPacketRecvCmd};
+    use super::{TxPacketAckCmd, TxPacketRecvCmd, TxPacketTimeoutCmd, TxPacketTimeoutOnCloseCmd};
 
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
     #[test]
@@ -462,4 +661,111 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_packet_timeout() {
+        assert_eq!(
+            TxPacketTimeoutCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                packet_sequences: vec![],
+            },
+            TxPacketTimeoutCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_packet_timeout_sequences() {
+        assert_eq!(
+            TxPacketTimeoutCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                packet_sequences: vec![
+                    Sequence::from(1)..=Sequence::from(1),
+                    Sequence::from(10)..=Sequence::from(20)
+                ],
+            },
+            TxPacketTimeoutCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--sequence",
+                "1,10..20"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_packet_timeout_no_sender_channel() {
+        assert!(TxPacketTimeoutCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_packet_timeout_on_close() {
+        assert_eq!(
+            TxPacketTimeoutOnCloseCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                packet_sequences: vec![Sequence::from(3)..=Sequence::from(5)],
+            },
+            TxPacketTimeoutOnCloseCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-chan",
+                "channel_sender",
+                "--packet-sequences",
+                "3..5"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_packet_timeout_on_close_no_receiver_chain() {
+        assert!(TxPacketTimeoutOnCloseCmd::try_parse_from([
+            "test",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender"
+        ])
+        .is_err())
+    }
 }
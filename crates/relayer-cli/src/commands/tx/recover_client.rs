@@ -0,0 +1,205 @@
+use abscissa_core::clap::Parser;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use ibc_relayer::recover_client::{build_and_send_recover_client_proposal, RecoverClientOptions};
+use ibc_relayer::upgrade_chain::requires_legacy_upgrade_proposal;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+use crate::prelude::*;
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxRecoverClientCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client to recover"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "subject",
+        required = true,
+        value_name = "SUBJECT_CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to recover"
+    )]
+    subject_client_id: ClientId,
+
+    #[clap(
+        long = "amount",
+        required = true,
+        value_name = "AMOUNT",
+        help_heading = "REQUIRED",
+        help = "Amount of stake deposited with the proposal"
+    )]
+    amount: u64,
+
+    #[clap(
+        long = "substitute",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of an active client tracking the same chain as the subject client, \
+                whose state the subject client is recovered from. \
+                If not specified, a new client with the same parameters as the subject client is created"
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "gov-account",
+        value_name = "GOV_ACCOUNT",
+        help = "Authority account used to sign the recovery proposal. Note: This is only used for chains with ibc-go version v8.0.0 or higher"
+    )]
+    gov_account: Option<String>,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination for the deposit (default: 'stake')"
+    )]
+    denom: Option<String>,
+}
+
+impl Runnable for TxRecoverClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let reference_chain_id = match host_chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.subject_client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) => cs.chain_id(),
+            Err(e) => {
+                Output::error(format!(
+                    "Query of client '{}' on chain '{}' failed with error: {}",
+                    self.subject_client_id, self.host_chain_id, e
+                ))
+                .exit();
+            }
+        };
+
+        let reference_chain = spawn_chain_runtime(&config, &reference_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let gov_account = if requires_legacy_upgrade_proposal(host_chain.clone())
+            .unwrap_or_else(exit_with_unrecoverable_error)
+        {
+            "".to_string()
+        } else if let Some(gov_account) = &self.gov_account {
+            gov_account.clone()
+        } else {
+            Output::error("The host chain uses an ibc-go version v8.0.0 or higher, which requires the governance module account to be specified using the flag `--gov-account`".to_owned()).exit();
+        };
+
+        let opts = RecoverClientOptions {
+            subject_client_id: self.subject_client_id.clone(),
+            substitute_client_id: self.substitute_client_id.clone(),
+            amount: self.amount,
+            denom: self.denom.as_deref().unwrap_or("stake").into(),
+            gov_account,
+        };
+
+        let res = build_and_send_recover_client_proposal(host_chain, reference_chain, &opts)
+            .map_err(Error::recover_client);
+
+        match res {
+            Ok(proposal) => Output::success(proposal).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TxRecoverClientCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_recover_client_required_only() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                host_chain_id: ChainId::from_string("host_chain"),
+                subject_client_id: ClientId::from_str("client_subject").unwrap(),
+                amount: 42,
+                substitute_client_id: None,
+                gov_account: None,
+                denom: None
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--subject",
+                "client_subject",
+                "--amount",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_substitute() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                host_chain_id: ChainId::from_string("host_chain"),
+                subject_client_id: ClientId::from_str("client_subject").unwrap(),
+                amount: 42,
+                substitute_client_id: Some(ClientId::from_str("client_substitute").unwrap()),
+                gov_account: Some("gov_account".to_owned()),
+                denom: Some("my_denom".to_owned())
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--subject",
+                "client_subject",
+                "--substitute",
+                "client_substitute",
+                "--amount",
+                "42",
+                "--gov-account",
+                "gov_account",
+                "--denom",
+                "my_denom"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_no_subject() {
+        assert!(TxRecoverClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--amount",
+            "42"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_recover_client_no_amount() {
+        assert!(TxRecoverClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--subject",
+            "client_subject"
+        ])
+        .is_err())
+    }
+}
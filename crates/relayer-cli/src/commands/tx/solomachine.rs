@@ -0,0 +1,274 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::config::{ChainConfig, Config};
+use ibc_relayer::keyring::{KeyRing, Secp256k1KeyPair};
+use ibc_relayer::solomachine::{SoloMachine, SoloMachineClient};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxCreateSoloMachineClientCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "key-name",
+        required = true,
+        value_name = "KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "Name of the key of the solo machine, in the keyring of the host chain"
+    )]
+    key_name: String,
+
+    #[clap(
+        long = "diversifier",
+        required = true,
+        value_name = "DIVERSIFIER",
+        help_heading = "REQUIRED",
+        help = "Diversifier of the solo machine, which prevents its signatures from being \
+                replayed on another client tracking the same key"
+    )]
+    diversifier: String,
+}
+
+impl Runnable for TxCreateSoloMachineClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let key = load_key(&config, &self.host_chain_id, &self.key_name)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let client = SoloMachineClient::restore(
+            ClientId::default(),
+            host_chain,
+            SoloMachine::new(key, self.diversifier.clone()),
+        );
+
+        match client
+            .build_create_client_and_send()
+            .map_err(Error::solo_machine)
+        {
+            Ok(receipt) => Output::success(receipt.event).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxUpdateSoloMachineClientCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the solo machine client to update"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "key-name",
+        required = true,
+        value_name = "KEY_NAME",
+        help_heading = "REQUIRED",
+        help = "Name of the key of the solo machine, in the keyring of the host chain"
+    )]
+    key_name: String,
+
+    #[clap(
+        long = "diversifier",
+        value_name = "DIVERSIFIER",
+        help = "New diversifier of the solo machine. If not specified, the diversifier of the client is kept"
+    )]
+    diversifier: Option<String>,
+}
+
+impl Runnable for TxUpdateSoloMachineClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let key = load_key(&config, &self.host_chain_id, &self.key_name)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let diversifier = match &self.diversifier {
+            Some(diversifier) => diversifier.clone(),
+            None => {
+                let client = SoloMachineClient::restore(
+                    self.client_id.clone(),
+                    host_chain.clone(),
+                    SoloMachine::new(key.clone(), String::new()),
+                );
+
+                match client.client_state().map_err(Error::solo_machine) {
+                    Ok(client_state) => client_state.consensus_state.diversifier,
+                    Err(e) => Output::error(e).exit(),
+                }
+            }
+        };
+
+        let client = SoloMachineClient::restore(
+            self.client_id.clone(),
+            host_chain,
+            SoloMachine::new(key, diversifier),
+        );
+
+        match client
+            .build_update_client_and_send()
+            .map_err(Error::solo_machine)
+        {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+/// Load the key of the solo machine from the keyring of the host chain
+fn load_key(
+    config: &Config,
+    host_chain_id: &ChainId,
+    key_name: &str,
+) -> Result<Secp256k1KeyPair, Error> {
+    let chain_config = config
+        .find_chain(host_chain_id)
+        .ok_or_else(|| Error::missing_chain_config(host_chain_id.clone()))?;
+
+    match chain_config {
+        ChainConfig::CosmosSdk(chain_config) => {
+            let keyring = KeyRing::new_secp256k1(
+                chain_config
+                    .key_store_type
+                    .on_disk()
+                    .map_err(Error::key_ring)?,
+                &chain_config.account_prefix,
+                &chain_config.id,
+                &chain_config.key_store_folder,
+            )
+            .map_err(Error::key_ring)?;
+
+            keyring.get_key(key_name).map_err(Error::key_ring)
+        }
+        _ => Err(Error::unsupported_solo_machine_key(host_chain_id.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TxCreateSoloMachineClientCmd, TxUpdateSoloMachineClientCmd};
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_create_solomachine_client() {
+        assert_eq!(
+            TxCreateSoloMachineClientCmd {
+                host_chain_id: ChainId::from_string("host_chain"),
+                key_name: "solo".to_owned(),
+                diversifier: "solo-1".to_owned(),
+            },
+            TxCreateSoloMachineClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--key-name",
+                "solo",
+                "--diversifier",
+                "solo-1"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_create_solomachine_client_no_diversifier() {
+        assert!(TxCreateSoloMachineClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--key-name",
+            "solo"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_update_solomachine_client() {
+        assert_eq!(
+            TxUpdateSoloMachineClientCmd {
+                host_chain_id: ChainId::from_string("host_chain"),
+                client_id: ClientId::from_str("06-solomachine-0").unwrap(),
+                key_name: "solo".to_owned(),
+                diversifier: None,
+            },
+            TxUpdateSoloMachineClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--client",
+                "06-solomachine-0",
+                "--key-name",
+                "solo"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_update_solomachine_client_diversifier() {
+        assert_eq!(
+            TxUpdateSoloMachineClientCmd {
+                host_chain_id: ChainId::from_string("host_chain"),
+                client_id: ClientId::from_str("06-solomachine-0").unwrap(),
+                key_name: "solo".to_owned(),
+                diversifier: Some("solo-2".to_owned()),
+            },
+            TxUpdateSoloMachineClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "host_chain",
+                "--client",
+                "06-solomachine-0",
+                "--key-name",
+                "solo",
+                "--diversifier",
+                "solo-2"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_update_solomachine_client_no_client() {
+        assert!(TxUpdateSoloMachineClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--key-name",
+            "solo"
+        ])
+        .is_err())
+    }
+}
@@ -1,21 +1,32 @@
 use core::time::Duration;
+use std::thread;
+use std::time::Instant;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{config::Override, FrameworkErrorKind};
 use eyre::eyre;
+use serde::Serialize;
 
 use ibc_relayer::{
-    chain::handle::ChainHandle,
+    chain::handle::{BaseChainHandle, ChainHandle},
     config::Config,
     event::IbcEventWithHeight,
-    transfer::{build_and_send_transfer_messages, TransferOptions},
+    transfer::{
+        build_and_send_transfer_messages,
+        forward::{
+            build_forward_transfer_messages, track_forward_transfer, ForwardRoute,
+            ForwardTransferOptions, ForwardTransferProgress, TransferLeg,
+        },
+        send_messages, TransferOptions,
+    },
 };
 use ibc_relayer_types::{
-    applications::transfer::Amount,
+    applications::transfer::{msgs::transfer::Forwarding, Amount, RawCoin, TracePath},
     core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    events::IbcEvent,
 };
 
-use crate::cli_utils::{check_can_send_on_channel, ChainHandlePair};
+use crate::cli_utils::{check_can_send_on_channel, spawn_chain_runtime_generic, ChainHandlePair};
 use crate::conclude::{exit_with_unrecoverable_error, Output};
 use crate::error::Error;
 use crate::prelude::*;
@@ -116,9 +127,72 @@ pub struct TxIcs20MsgTransferCmd {
     #[clap(
         long = "memo",
         value_name = "MEMO",
-        help = "Optional memo included in the transfer"
+        help = "Optional memo included in the transfer. With a route, it must be a JSON object, \
+                which is included in the transfer to the destination chain"
     )]
     memo: Option<String>,
+
+    #[clap(
+        long = "route",
+        value_name = "ROUTE",
+        help = "Comma-separated list of the chains through which the tokens are forwarded to the \
+                destination chain by the packet-forward-middleware, each along with the channel \
+                it forwards them over, e.g. `chain-b:channel-1,chain-c:channel-7`. \
+                The source channel then leads to the first chain of the route"
+    )]
+    route: Option<ForwardRoute>,
+
+    #[clap(
+        long = "hop-timeout-seconds",
+        value_name = "HOP_TIMEOUT_SECONDS",
+        requires = "route",
+        help = "Timeout in seconds of the packets forwarded by the chains of the route. \
+                Defaults to the timeout of the packet-forward-middleware"
+    )]
+    hop_timeout_seconds: Option<u64>,
+
+    #[clap(
+        long = "hop-retries",
+        value_name = "HOP_RETRIES",
+        requires = "route",
+        help = "Number of times the chains of the route retry forwarding a packet which timed out. \
+                Defaults to the retries of the packet-forward-middleware"
+    )]
+    hop_retries: Option<u8>,
+
+    #[clap(
+        long = "track",
+        requires = "route",
+        help = "Wait for the forwarded transfer to reach the destination chain, \
+                or to fail along the route, for up to 10 minutes, and output its progress on each hop"
+    )]
+    track: bool,
+
+    #[clap(
+        long = "additional-tokens",
+        value_name = "TOKENS",
+        conflicts_with = "route",
+        help = "Comma-separated list of tokens transferred along with the amount of the \
+                denomination, e.g. `100uatom,20uosmo`. Requires an ICS20 v2 channel"
+    )]
+    additional_tokens: Option<String>,
+
+    #[clap(
+        long = "forwarding-hops",
+        value_name = "HOPS",
+        conflicts_with = "route",
+        help = "Ports and channels through which the destination chain forwards the tokens, \
+                e.g. `transfer/channel-1/transfer/channel-7`. Requires an ICS20 v2 channel"
+    )]
+    forwarding_hops: Option<TracePath>,
+
+    #[clap(
+        long = "unwind",
+        conflicts_with = "route",
+        help = "Send the tokens back along their trace to their origin chain before forwarding \
+                them through the forwarding hops. Requires an ICS20 v2 channel"
+    )]
+    unwind: bool,
 }
 
 impl Override<Config> for TxIcs20MsgTransferCmd {
@@ -161,6 +235,45 @@ impl TxIcs20MsgTransferCmd {
             return Err(eyre!("number of messages should be greater than zero"));
         }
 
+        if self.track && number_msgs > 1 {
+            return Err(eyre!(
+                "only a single message can be tracked along the route"
+            ));
+        }
+
+        if let Some(route) = &self.route {
+            for hop in route.hops() {
+                config.find_chain(&hop.chain_id).ok_or_else(|| {
+                    eyre!(
+                        "missing configuration for chain '{}' of the route",
+                        hop.chain_id
+                    )
+                })?;
+            }
+        }
+
+        let additional_tokens = match &self.additional_tokens {
+            Some(tokens) => RawCoin::from_string_list(tokens)
+                .map_err(|e| eyre!("invalid additional tokens '{tokens}': {e}"))?,
+            None => vec![],
+        };
+
+        let forwarding = (self.forwarding_hops.is_some() || self.unwind).then(|| Forwarding {
+            unwind: self.unwind,
+            hops: self
+                .forwarding_hops
+                .iter()
+                .flat_map(|hops| hops.prefixes().cloned())
+                .collect(),
+        });
+
+        // Forwarded ICS20 v2 transfers can only time out at a given time
+        if forwarding.is_some() && self.timeout_height_offset != 0 {
+            return Err(eyre!(
+                "forwarded transfers cannot have a timeout height offset, use a timeout in seconds"
+            ));
+        }
+
         let opts = TransferOptions {
             src_port_id: self.src_port_id.clone(),
             src_channel_id: self.src_channel_id.clone(),
@@ -171,6 +284,8 @@ impl TxIcs20MsgTransferCmd {
             timeout_duration: Duration::from_secs(self.timeout_seconds),
             number_msgs,
             memo: self.memo.clone(),
+            additional_tokens,
+            forwarding,
         };
 
         Ok(opts)
@@ -189,6 +304,20 @@ impl Runnable for TxIcs20MsgTransferCmd {
         let chains = ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
+        if let Some(route) = &self.route {
+            let opts = ForwardTransferOptions {
+                transfer: opts,
+                route: route.clone(),
+                hop_timeout: self.hop_timeout_seconds.map(Duration::from_secs),
+                hop_retries: self.hop_retries,
+            };
+
+            match forward_transfer(&config, chains, &opts, self.track) {
+                Ok(output) => Output::success(output).exit(),
+                Err(e) => Output::error(e).exit(),
+            }
+        }
+
         check_can_send_on_channel(
             &chains.src,
             &opts.src_channel_id,
@@ -209,16 +338,92 @@ impl Runnable for TxIcs20MsgTransferCmd {
     }
 }
 
+/// How often, and for how long at most, the progress of a forwarded transfer is queried
+const TRACK_INTERVAL: Duration = Duration::from_secs(5);
+const TRACK_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Serialize)]
+struct ForwardTransferOutput {
+    events: Vec<IbcEventWithHeight>,
+    legs: Vec<TransferLeg>,
+    final_denom: String,
+    final_ibc_denom: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    progress: Option<ForwardTransferProgress>,
+}
+
+fn forward_transfer(
+    config: &Config,
+    chains: ChainHandlePair,
+    opts: &ForwardTransferOptions,
+    track: bool,
+) -> eyre::Result<ForwardTransferOutput> {
+    let hop_chains = opts
+        .route
+        .hops()
+        .iter()
+        .map(|hop| spawn_chain_runtime_generic::<BaseChainHandle>(config, &hop.chain_id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let transfer = build_forward_transfer_messages(&chains.src, &hop_chains, &chains.dst, opts)?;
+
+    // Check every leg of the route before sending anything
+    let sending_chains = core::iter::once(&chains.src).chain(hop_chains.iter());
+    for (chain, leg) in sending_chains.zip(&transfer.legs) {
+        check_can_send_on_channel(
+            chain,
+            &leg.path.counterparty_channel_id,
+            &leg.path.counterparty_port_id,
+            &leg.chain_id,
+        )?;
+    }
+
+    let events = send_messages(&chains.src, transfer.messages.clone())?;
+
+    let progress = if track {
+        let sequence = events
+            .iter()
+            .find_map(|event| match &event.event {
+                IbcEvent::SendPacket(send_packet) => Some(send_packet.packet.sequence),
+                _ => None,
+            })
+            .ok_or_else(|| eyre!("no SendPacket event was emitted by the transfer"))?;
+
+        let start = Instant::now();
+
+        loop {
+            let progress = track_forward_transfer(&hop_chains, &chains.dst, &transfer, sequence)?;
+
+            if progress.is_complete(&transfer) || start.elapsed() >= TRACK_TIMEOUT {
+                break Some(progress);
+            }
+
+            thread::sleep(TRACK_INTERVAL);
+        }
+    } else {
+        None
+    };
+
+    Ok(ForwardTransferOutput {
+        events,
+        final_denom: transfer.final_denom.to_string(),
+        final_ibc_denom: transfer.final_ibc_denom(),
+        legs: transfer.legs,
+        progress,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use ibc_relayer_types::{
-        applications::transfer::Amount,
+        applications::transfer::{Amount, TracePath},
         core::ics24_host::identifier::{ChainId, ChannelId, PortId},
     };
 
     use super::TxIcs20MsgTransferCmd;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer::transfer::forward::ForwardRoute;
     use std::str::FromStr;
 
     #[test]
@@ -237,6 +442,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -270,6 +482,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -303,6 +522,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -338,6 +564,13 @@ mod tests {
                 number_msgs: None,
                 key_name: Some("key_name".to_owned()),
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -373,6 +606,13 @@ mod tests {
                 number_msgs: Some(21),
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -408,6 +648,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -443,6 +690,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -478,6 +732,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -513,6 +774,13 @@ mod tests {
                 number_msgs: None,
                 key_name: None,
                 memo: Some("test memo".to_owned()),
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
             },
             TxIcs20MsgTransferCmd::parse_from([
                 "test",
@@ -532,6 +800,162 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_ft_transfer_route() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                receiver: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None,
+                memo: None,
+                route: Some(ForwardRoute::from_str("chain_b:channel-1,chain_c:channel-7").unwrap()),
+                hop_timeout_seconds: Some(600),
+                hop_retries: Some(2),
+                track: true,
+                additional_tokens: None,
+                forwarding_hops: None,
+                unwind: false,
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--route",
+                "chain_b:channel-1,chain_c:channel-7",
+                "--hop-timeout-seconds",
+                "600",
+                "--hop-retries",
+                "2",
+                "--track"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_ics20_v2() {
+        assert_eq!(
+            TxIcs20MsgTransferCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                amount: Amount::from(42u64),
+                timeout_height_offset: 0,
+                timeout_seconds: 0,
+                receiver: None,
+                denom: "samoleans".to_owned(),
+                number_msgs: None,
+                key_name: None,
+                memo: None,
+                route: None,
+                hop_timeout_seconds: None,
+                hop_retries: None,
+                track: false,
+                additional_tokens: Some("100uatom,20uosmo".to_owned()),
+                forwarding_hops: Some(
+                    TracePath::from_str("transfer/channel-1/transfer/channel-7").unwrap()
+                ),
+                unwind: true,
+            },
+            TxIcs20MsgTransferCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender",
+                "--amount",
+                "42",
+                "--additional-tokens",
+                "100uatom,20uosmo",
+                "--forwarding-hops",
+                "transfer/channel-1/transfer/channel-7",
+                "--unwind"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ft_transfer_forwarding_hops_with_route() {
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--amount",
+            "42",
+            "--route",
+            "chain_b:channel-1",
+            "--forwarding-hops",
+            "transfer/channel-1"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_ft_transfer_invalid_route() {
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--amount",
+            "42",
+            "--route",
+            "chain_b"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_ft_transfer_hop_retries_without_route() {
+        assert!(TxIcs20MsgTransferCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_receiver",
+            "--src-chain",
+            "chain_sender",
+            "--src-port",
+            "port_sender",
+            "--src-channel",
+            "channel_sender",
+            "--amount",
+            "42",
+            "--hop-retries",
+            "2"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_ft_transfer_no_amount() {
         assert!(TxIcs20MsgTransferCmd::try_parse_from([
@@ -4,9 +4,13 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
 use crate::commands::tx::client::TxUpdateClientCmd;
+use crate::commands::tx::solomachine::TxUpdateSoloMachineClientCmd;
 
 #[derive(Command, Debug, Parser, Runnable)]
 pub enum UpdateCmds {
     /// Update an IBC client
     Client(TxUpdateClientCmd),
+
+    /// Update a solo machine client with a header signed by Hermes
+    SolomachineClient(TxUpdateSoloMachineClientCmd),
 }
@@ -7,15 +7,19 @@ use tendermint::Error as TendermintError;
 
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::signer::SignerError;
 
 use ibc_relayer::channel::ChannelError;
 use ibc_relayer::connection::ConnectionError;
 use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
+use ibc_relayer::interchain_account::InterchainAccountError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
+use ibc_relayer::recover_client::RecoverClientError;
+use ibc_relayer::solomachine::SoloMachineError;
 use ibc_relayer::spawn::SpawnError;
 use ibc_relayer::supervisor::Error as SupervisorError;
 use ibc_relayer::transfer::TransferError;
@@ -72,6 +76,19 @@ define_error! {
                     e.channel_end)
             },
 
+        MissingPacketCommitment
+            {
+                chain_id: ChainId,
+                port_id: PortId,
+                channel_id: ChannelId,
+                sequence: Sequence,
+            }
+            | e | {
+                format_args!("no commitment found on chain '{}' for packet {} sent on {}/{}, \
+                    the packet was either never sent or already acknowledged or timed out",
+                    e.chain_id, e.sequence, e.port_id, e.channel_id)
+            },
+
         Relayer
             [ RelayerError ]
             |_| { "relayer error" },
@@ -112,6 +129,25 @@ define_error! {
             [ UpgradeChainError ]
             |_| { "upgrade chain error" },
 
+        RecoverClient
+            [ RecoverClientError ]
+            |_| { "recover client error" },
+
+        InterchainAccount
+            [ InterchainAccountError ]
+            |_| { "interchain account error" },
+
+        SoloMachine
+            [ SoloMachineError ]
+            |_| { "solo machine client error" },
+
+        UnsupportedSoloMachineKey
+            { chain_id: ChainId }
+            | e | {
+                format_args!("solo machine keys are only supported in the keyring of Cosmos SDK chains, chain '{}' is not one",
+                    e.chain_id)
+            },
+
         Signer
             [ SignerError ]
             |_| { "signer error" },
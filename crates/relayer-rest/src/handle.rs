@@ -4,15 +4,23 @@ use tracing::error;
 
 use crossbeam_channel as channel;
 
-use ibc_relayer::supervisor::dump_state::SupervisorState;
+use ibc_relayer::supervisor::{
+    dump_state::{SupervisorState, WorkerDesc},
+    reload::ConfigDiff,
+};
 use ibc_relayer::{
+    client_expiry::ClientExpiry,
     config::ChainConfig,
+    lifecycle::PacketLifecycle,
     rest::{
         request::{reply_channel, ReplySender, Request, VersionInfo},
         RestApiError,
     },
+    worker::WorkerId,
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::events::IbcEvent;
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -76,6 +84,100 @@ pub fn trigger_clear_packets(
     })
 }
 
+/// Submit a request to reload the configuration from the configuration file.
+pub fn trigger_reload(sender: &channel::Sender<Request>) -> Result<ConfigDiff, RestApiError> {
+    submit_request(sender, |reply_to| Request::Reload { reply_to })
+}
+
+pub fn all_workers(sender: &channel::Sender<Request>) -> Result<Vec<WorkerDesc>, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetWorkers { reply_to })
+}
+
+/// Submit a request to stop the worker with the given `worker_id`.
+pub fn stop_worker(
+    sender: &channel::Sender<Request>,
+    worker_id: WorkerId,
+) -> Result<WorkerDesc, RestApiError> {
+    submit_request(sender, |reply_to| Request::StopWorker {
+        worker_id,
+        reply_to,
+    })
+}
+
+/// Submit a request to pause the worker with the given `worker_id`.
+pub fn pause_worker(
+    sender: &channel::Sender<Request>,
+    worker_id: WorkerId,
+) -> Result<WorkerDesc, RestApiError> {
+    submit_request(sender, |reply_to| Request::PauseWorker {
+        worker_id,
+        reply_to,
+    })
+}
+
+/// Submit a request to resume the worker with the given `worker_id`.
+pub fn resume_worker(
+    sender: &channel::Sender<Request>,
+    worker_id: WorkerId,
+) -> Result<WorkerDesc, RestApiError> {
+    submit_request(sender, |reply_to| Request::ResumeWorker {
+        worker_id,
+        reply_to,
+    })
+}
+
+/// Submit a request to spawn a packet worker for the channel
+/// with the given `port_id` and `channel_id` on chain `chain_id`.
+pub fn spawn_packet_worker(
+    sender: &channel::Sender<Request>,
+    chain_id: ChainId,
+    port_id: PortId,
+    channel_id: ChannelId,
+) -> Result<WorkerDesc, RestApiError> {
+    submit_request(sender, |reply_to| Request::SpawnPacketWorker {
+        chain_id,
+        port_id,
+        channel_id,
+        reply_to,
+    })
+}
+
+/// Submit a request to update the client with the given `client_id` on chain `chain_id`.
+pub fn trigger_update_client(
+    sender: &channel::Sender<Request>,
+    chain_id: ChainId,
+    client_id: ClientId,
+) -> Result<Vec<IbcEvent>, RestApiError> {
+    submit_request(sender, |reply_to| Request::UpdateClient {
+        chain_id,
+        client_id,
+        reply_to,
+    })
+}
+
+/// Fetch the lifecycle of the packet with the given `sequence`,
+/// sent on channel `channel_id` of chain `chain_id`.
+pub fn packet_lifecycle(
+    sender: &channel::Sender<Request>,
+    chain_id: ChainId,
+    channel_id: ChannelId,
+    sequence: Sequence,
+) -> Result<PacketLifecycle, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetPacketLifecycle {
+        chain_id,
+        channel_id,
+        sequence,
+        reply_to,
+    })
+}
+
+/// Fetch the time left before each client refreshed by Hermes expires.
+pub fn client_expiries(
+    sender: &channel::Sender<Request>,
+) -> Result<Vec<ClientExpiry>, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetClients { reply_to })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
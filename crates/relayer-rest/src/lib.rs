@@ -1,3 +1,3 @@
 mod handle;
 mod server;
-pub use server::spawn;
+pub use server::{spawn, spawn_with_options};
@@ -7,17 +7,26 @@ use axum::{
     extract::{Path, Query},
     response::IntoResponse,
     routing::{get, post},
-    Extension, Json, Router, Server,
+    Extension, Json, Router,
 };
 use crossbeam_channel as channel;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
 use serde::{Deserialize, Serialize};
 use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, warn};
 
-use ibc_relayer::rest::{request::Request, RestApiError};
+use ibc_relayer::{
+    rest::{request::Request, RestApiError},
+    worker::WorkerId,
+};
+use ibc_telemetry::secure::{self, AuthOptions, ServerOptions};
 
 use crate::handle::{
-    all_chain_ids, assemble_version_info, chain_config, supervisor_state, trigger_clear_packets,
+    all_chain_ids, all_workers, assemble_version_info, chain_config, client_expiries,
+    packet_lifecycle, pause_worker, resume_worker, spawn_packet_worker, stop_worker,
+    supervisor_state, trigger_clear_packets, trigger_reload, trigger_update_client,
 };
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
@@ -25,9 +34,24 @@ pub type BoxError = Box<dyn Error + Send + Sync>;
 pub fn spawn(
     addr: impl ToSocketAddrs,
     sender: channel::Sender<Request>,
+) -> Result<JoinHandle<()>, BoxError> {
+    spawn_with_options(addr, sender, ServerOptions::default(), false)
+}
+
+/// Spawn the REST server, serving the API over TLS if the given options say so.
+///
+/// If authentication is enabled, the endpoints acting on the relayer
+/// are only available to authenticated clients, as well as the read-only
+/// endpoints if `protect_reads` is set.
+pub fn spawn_with_options(
+    addr: impl ToSocketAddrs,
+    sender: channel::Sender<Request>,
+    options: ServerOptions,
+    protect_reads: bool,
 ) -> Result<JoinHandle<()>, BoxError> {
     let addr = addr.to_socket_addrs()?.next().unwrap();
-    let handle = tokio::spawn(run(addr, sender));
+    let tls = options.tls.as_ref().map(secure::tls_acceptor).transpose()?;
+    let handle = tokio::spawn(run(addr, sender, tls, options.auth, protect_reads));
     Ok(handle)
 }
 
@@ -84,19 +108,122 @@ async fn clear_packets(
     Json(JsonResult::from(result))
 }
 
+async fn reload(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = trigger_reload(&sender);
+    Json(JsonResult::from(result))
+}
+
+async fn get_workers(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let workers = all_workers(&sender);
+    Json(JsonResult::from(workers))
+}
+
+async fn stop(Path(id): Path<u64>, Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = stop_worker(&sender, WorkerId::new(id));
+    Json(JsonResult::from(result))
+}
+
+async fn pause(Path(id): Path<u64>, Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = pause_worker(&sender, WorkerId::new(id));
+    Json(JsonResult::from(result))
+}
+
+async fn resume(Path(id): Path<u64>, Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = resume_worker(&sender, WorkerId::new(id));
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct PacketWorkerParams {
+    chain: ChainId,
+    port: PortId,
+    channel: ChannelId,
+}
+
+async fn packet_worker(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<PacketWorkerParams>,
+) -> impl IntoResponse {
+    let result = spawn_packet_worker(&sender, params.chain, params.port, params.channel);
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateClientParams {
+    chain: ChainId,
+    client: ClientId,
+}
+
+async fn update_client(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<UpdateClientParams>,
+) -> impl IntoResponse {
+    let result = trigger_update_client(&sender, params.chain, params.client);
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct PacketParams {
+    chain: ChainId,
+    channel: ChannelId,
+    sequence: Sequence,
+}
+
+async fn get_packet(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<PacketParams>,
+) -> impl IntoResponse {
+    let result = packet_lifecycle(&sender, params.chain, params.channel, params.sequence);
+    Json(JsonResult::from(result))
+}
+
+async fn get_clients(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let clients = client_expiries(&sender);
+    Json(JsonResult::from(clients))
+}
+
 type Sender = channel::Sender<Request>;
 
-async fn run(addr: SocketAddr, sender: Sender) {
-    let app = Router::new()
+async fn run(
+    addr: SocketAddr,
+    sender: Sender,
+    tls: Option<TlsAcceptor>,
+    auth: AuthOptions,
+    protect_reads: bool,
+) {
+    let read_only = Router::new()
         .route("/version", get(get_version))
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
         .route("/state", get(get_state))
+        .route("/workers", get(get_workers))
+        .route("/packet", get(get_packet))
+        .route("/clients", get(get_clients));
+
+    let admin = Router::new()
         .route("/clear_packets", post(clear_packets))
+        .route("/reload", post(reload))
+        .route("/worker/:id/stop", post(stop))
+        .route("/worker/:id/pause", post(pause))
+        .route("/worker/:id/resume", post(resume))
+        .route("/packet_worker", post(packet_worker))
+        .route("/update_client", post(update_client));
+
+    let read_only = if protect_reads {
+        secure::require_auth(read_only, &auth)
+    } else {
+        read_only
+    };
+
+    if !auth.is_enabled() && !addr.ip().is_loopback() {
+        warn!("REST API is exposed on {addr} without authentication, anyone reaching it can act on the relayer");
+    }
+
+    let app = read_only
+        .merge(secure::require_auth(admin, &auth))
         .layer(Extension(sender));
 
-    Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await
-        .unwrap();
+    if let Err(e) = secure::serve(addr, app, tls).await {
+        error!("REST server failed: {e}");
+    }
 }
@@ -1,13 +1,22 @@
-use std::{fmt::Debug, str::FromStr, time::Duration};
+use std::{
+    fmt::Debug,
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
+    client_expiry::{ClientExpiry, ClientStatus},
     config::ChainConfig,
+    lifecycle::{PacketLifecycle, Relayer, Stage},
+    object::{Object, Packet},
     rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    supervisor::dump_state::{SupervisorState, WorkerDesc},
+    worker::WorkerId,
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::Height;
 
 use ibc_relayer_rest::spawn;
 
@@ -146,3 +155,102 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn get_workers() {
+    let object = Object::Packet(Packet {
+        dst_chain_id: "mock-1".parse().unwrap(),
+        src_chain_id: "mock-0".parse().unwrap(),
+        src_channel_id: "channel-0".parse().unwrap(),
+        src_port_id: "transfer".parse().unwrap(),
+    });
+
+    let workers = vec![WorkerDesc::new(WorkerId::new(1), object, None)];
+    let result: JsonResult<_, ()> = JsonResult::Success(workers.clone());
+
+    run_test(19105, "/workers", result, |req| match req {
+        Request::GetWorkers { reply_to } => {
+            reply_to.send(Ok(workers)).unwrap();
+            TestResult::Success
+        }
+        req => TestResult::WrongRequest(req),
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn get_packet() {
+    let stage = |secs, relayed_by| Stage {
+        height: Some(Height::new(0, secs).unwrap()),
+        observed_at: UNIX_EPOCH + Duration::from_secs(secs),
+        tracking_id: None,
+        relayed_by,
+    };
+
+    let lifecycle = PacketLifecycle {
+        src_chain_id: "mock-0".parse().unwrap(),
+        src_port_id: "transfer".parse().unwrap(),
+        src_channel_id: "channel-0".parse().unwrap(),
+        dst_chain_id: Some("mock-1".parse().unwrap()),
+        dst_port_id: "transfer".parse().unwrap(),
+        dst_channel_id: "channel-1".parse().unwrap(),
+        sequence: 7.into(),
+        sent: Some(stage(10, None)),
+        received: Some(stage(
+            20,
+            Some(Relayer::Hermes {
+                tx_hashes: vec!["ABCD".to_string()],
+            }),
+        )),
+        acknowledged: Some(stage(30, Some(Relayer::Other))),
+        timed_out: None,
+    };
+
+    let result: JsonResult<_, ()> = JsonResult::Success(lifecycle.clone());
+
+    run_test(
+        19106,
+        "/packet?chain=mock-0&channel=channel-0&sequence=7",
+        result,
+        |req| match req {
+            Request::GetPacketLifecycle {
+                chain_id,
+                channel_id,
+                sequence,
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && channel_id.as_str() == "channel-0"
+                && u64::from(sequence) == 7 =>
+            {
+                reply_to.send(Ok(lifecycle)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn get_clients() {
+    let clients = vec![ClientExpiry {
+        chain_id: "mock-1".parse().unwrap(),
+        client_id: ClientId::default(),
+        counterparty_chain_id: "mock-0".parse().unwrap(),
+        status: ClientStatus::ExpiringSoon,
+        trusting_period: Some(Duration::from_secs(14 * 24 * 60 * 60)),
+        time_to_expiry: Some(Duration::from_secs(60 * 60)),
+        observed_at: UNIX_EPOCH + Duration::from_secs(10),
+    }];
+
+    let result: JsonResult<_, ()> = JsonResult::Success(clients.clone());
+
+    run_test(19107, "/clients", result, |req| match req {
+        Request::GetClients { reply_to } => {
+            reply_to.send(Ok(clients)).unwrap();
+            TestResult::Success
+        }
+        req => TestResult::WrongRequest(req),
+    })
+    .await;
+}
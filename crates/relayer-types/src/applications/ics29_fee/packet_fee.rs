@@ -2,6 +2,7 @@ use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
 use ibc_proto::ibc::applications::fee::v1::{
     Fee as ProtoFee, IdentifiedPacketFees as ProtoIdentifiedPacketFees, PacketFee as ProtoPacketFee,
 };
+use serde_derive::Serialize;
 use std::str::FromStr;
 
 use super::error::Error;
@@ -13,7 +14,7 @@ use crate::signer::Signer;
 
 /// The core type that encodes the different fees that are redeemable by relayers for relaying
 /// different types of packets.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Fee {
     /// The amount that the forward relayer redeems for submitting a recv packet.
     /// This fee is refunded to the payer in the case that the recv packet is not successfully relayed, i.e.,
@@ -27,14 +28,14 @@ pub struct Fee {
     pub timeout_fee: Vec<RawCoin>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PacketFee {
     pub fee: Fee,
     pub refund_address: Signer,
     // do not expose relayer field as it is currently a reserved field
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IdentifiedPacketFees {
     pub packet_id: PacketId,
     pub packet_fees: Vec<PacketFee>,
@@ -39,6 +39,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -76,6 +84,21 @@ impl TracePath {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the prefixes of the path in the order they appear in its string representation,
+    /// i.e. starting with the most recently added prefix.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
+}
+
+impl FromIterator<TracePrefix> for TracePath {
+    /// Collects the prefixes of a path, given in the order they appear in its string representation.
+    fn from_iter<I: IntoIterator<Item = TracePrefix>>(iter: I) -> Self {
+        let mut prefixes: Vec<TracePrefix> = iter.into_iter().collect();
+        prefixes.reverse();
+        Self(prefixes)
+    }
 }
 
 impl<'a> TryFrom<Vec<&'a str>> for TracePath {
@@ -143,6 +166,21 @@ pub struct PrefixedDenom {
 }
 
 impl PrefixedDenom {
+    pub fn new(trace_path: TracePath, base_denom: BaseDenom) -> Self {
+        Self {
+            trace_path,
+            base_denom,
+        }
+    }
+
+    pub fn trace_path(&self) -> &TracePath {
+        &self.trace_path
+    }
+
+    pub fn base_denom(&self) -> &BaseDenom {
+        &self.base_denom
+    }
+
     /// Removes the specified prefix from the trace path if there is a match, otherwise does nothing.
     pub fn remove_trace_prefix(&mut self, prefix: &TracePrefix) {
         self.trace_path.remove_prefix(prefix)
@@ -383,6 +421,11 @@ mod tests {
         trace_path.remove_prefix(&prefix_1);
         assert!(trace_path.is_empty());
 
+        let trace_path = TracePath::from_str("transfer/channel-0/transfer/channel-1")?;
+        let prefixes: Vec<TracePrefix> = trace_path.prefixes().cloned().collect();
+        assert_eq!(prefixes, vec![prefix_2, prefix_1]);
+        assert_eq!(prefixes.into_iter().collect::<TracePath>(), trace_path);
+
         Ok(())
     }
 }
@@ -24,3 +24,7 @@ pub const PORT_ID_STR: &str = "transfer";
 
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
+
+/// ICS20 application version supporting the transfer of several tokens
+/// in a single packet, and their forwarding through intermediate chains.
+pub const VERSION_V2: &str = "ics20-2";
@@ -3,9 +3,12 @@
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::Protobuf;
 
 use crate::applications::transfer::error::Error;
+use crate::applications::transfer::packet::RawHop;
+use crate::applications::transfer::TracePrefix;
 use crate::core::ics04_channel::timeout::TimeoutHeight;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
 use crate::signer::Signer;
@@ -127,6 +130,231 @@ impl From<MsgTransfer> for Any {
     }
 }
 
+/// Message used to build an ICS20 v2 token transfer packet, which can carry
+/// several tokens at once and have them forwarded through other chains.
+///
+/// It shares its type URL with [`MsgTransfer`], but can only be sent over
+/// ICS20 v2 channels unless it transfers a single token without forwarding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTransferV2<C = Coin> {
+    /// the port on which the packet will be sent
+    pub source_port: PortId,
+    /// the channel by which the packet will be sent
+    pub source_channel: ChannelId,
+    /// the tokens to be transferred
+    pub tokens: Vec<C>,
+    /// the sender address
+    pub sender: Signer,
+    /// the recipient address on the destination chain
+    pub receiver: Signer,
+    /// Timeout height relative to the current block height.
+    /// The timeout is disabled when set to None.
+    pub timeout_height: TimeoutHeight,
+    /// Timeout timestamp relative to the current block timestamp.
+    /// The timeout is disabled when set to 0.
+    pub timeout_timestamp: Timestamp,
+    /// optional memo
+    pub memo: Option<String>,
+    /// optional forwarding of the tokens through other chains
+    pub forwarding: Option<Forwarding>,
+}
+
+/// The hops through which the tokens of a transfer are forwarded
+/// after being received by the destination chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Forwarding {
+    /// whether the tokens are first sent back along their trace to their origin chain
+    pub unwind: bool,
+    /// the port and channel through which the tokens are sent on each intermediate chain
+    pub hops: Vec<TracePrefix>,
+}
+
+impl<C> From<MsgTransfer<C>> for MsgTransferV2<C> {
+    fn from(msg: MsgTransfer<C>) -> Self {
+        MsgTransferV2 {
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            tokens: vec![msg.token],
+            sender: msg.sender,
+            receiver: msg.receiver,
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+            memo: msg.memo,
+            forwarding: None,
+        }
+    }
+}
+
+impl Msg for MsgTransferV2 {
+    type ValidationError = Error;
+    type Raw = RawMsgTransferV2;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+}
+
+impl TryFrom<RawMsgTransferV2> for MsgTransferV2 {
+    type Error = Error;
+
+    fn try_from(raw_msg: RawMsgTransferV2) -> Result<Self, Self::Error> {
+        let timeout_timestamp = Timestamp::from_nanoseconds(raw_msg.timeout_timestamp)
+            .map_err(|_| Error::invalid_packet_timeout_timestamp(raw_msg.timeout_timestamp))?;
+
+        let timeout_height: TimeoutHeight = raw_msg.timeout_height.try_into().map_err(|e| {
+            Error::invalid_packet_timeout_height(format!("invalid timeout height {e}"))
+        })?;
+
+        let memo = Some(raw_msg.memo).filter(|m| !m.is_empty());
+
+        let tokens: Vec<Coin> = raw_msg.token.into_iter().chain(raw_msg.tokens).collect();
+        if tokens.is_empty() {
+            return Err(Error::invalid_token());
+        }
+
+        let forwarding = raw_msg
+            .forwarding
+            .filter(|forwarding| forwarding.unwind || !forwarding.hops.is_empty())
+            .map(|forwarding| {
+                let hops = forwarding
+                    .hops
+                    .into_iter()
+                    .map(|hop| {
+                        let port_id = hop
+                            .port_id
+                            .parse()
+                            .map_err(|e| Error::invalid_port_id(hop.port_id.clone(), e))?;
+                        let channel_id = hop
+                            .channel_id
+                            .parse()
+                            .map_err(|e| Error::invalid_channel_id(hop.channel_id.clone(), e))?;
+
+                        Ok(TracePrefix::new(port_id, channel_id))
+                    })
+                    .collect::<Result<_, Error>>()?;
+
+                Ok::<_, Error>(Forwarding {
+                    unwind: forwarding.unwind,
+                    hops,
+                })
+            })
+            .transpose()?;
+
+        Ok(MsgTransferV2 {
+            source_port: raw_msg
+                .source_port
+                .parse()
+                .map_err(|e| Error::invalid_port_id(raw_msg.source_port.clone(), e))?,
+            source_channel: raw_msg
+                .source_channel
+                .parse()
+                .map_err(|e| Error::invalid_channel_id(raw_msg.source_channel.clone(), e))?,
+            tokens,
+            sender: raw_msg.sender.parse().map_err(Error::signer)?,
+            receiver: raw_msg.receiver.parse().map_err(Error::signer)?,
+            timeout_height,
+            timeout_timestamp,
+            memo,
+            forwarding,
+        })
+    }
+}
+
+impl From<MsgTransferV2> for RawMsgTransferV2 {
+    fn from(domain_msg: MsgTransferV2) -> Self {
+        let memo = domain_msg.memo.unwrap_or_default();
+
+        // A single token is set in the `token` field, which is the only
+        // one known to chains which do not support ICS20 v2.
+        let (token, tokens) = if domain_msg.tokens.len() == 1 {
+            (domain_msg.tokens.into_iter().next(), Vec::new())
+        } else {
+            (None, domain_msg.tokens)
+        };
+
+        let forwarding = domain_msg.forwarding.map(|forwarding| RawForwarding {
+            unwind: forwarding.unwind,
+            hops: forwarding.hops.into_iter().map(RawHop::from).collect(),
+        });
+
+        RawMsgTransferV2 {
+            source_port: domain_msg.source_port.to_string(),
+            source_channel: domain_msg.source_channel.to_string(),
+            token,
+            sender: domain_msg.sender.to_string(),
+            receiver: domain_msg.receiver.to_string(),
+            timeout_height: domain_msg.timeout_height.into(),
+            timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
+            memo,
+            tokens,
+            forwarding,
+        }
+    }
+}
+
+impl Protobuf<RawMsgTransferV2> for MsgTransferV2 {}
+
+impl TryFrom<Any> for MsgTransferV2 {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => MsgTransferV2::decode_vec(&raw.value).map_err(Error::decode_raw_msg),
+            _ => Err(Error::unknown_msg_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<MsgTransferV2> for Any {
+    fn from(msg: MsgTransferV2) -> Self {
+        Self {
+            type_url: TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+// The `MsgTransfer` of ibc-go v9, whose `tokens` and `forwarding`
+// fields are not part of the `MsgTransfer` of `ibc-proto`.
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgTransferV2 {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<Coin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<RawHeight>,
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+    #[prost(message, repeated, tag = "9")]
+    pub tokens: Vec<Coin>,
+    #[prost(message, optional, tag = "10")]
+    pub forwarding: Option<RawForwarding>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawForwarding {
+    #[prost(bool, tag = "1")]
+    pub unwind: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub hops: Vec<RawHop>,
+}
+
 #[cfg(test)]
 pub mod test_util {
     use core::ops::Add;
@@ -1,10 +1,12 @@
 use std::str::FromStr;
 
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
-use super::{Amount, PrefixedCoin, PrefixedDenom};
+use super::{Amount, BaseDenom, PrefixedCoin, PrefixedDenom, TracePath, TracePrefix};
+use crate::core::ics04_channel::version::Version;
 use crate::signer::Signer;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -45,3 +47,503 @@ impl From<PacketData> for RawPacketData {
         }
     }
 }
+
+/// The data of an ICS20 v2 packet, which can carry several tokens, and the hops
+/// through which the receiving chain forwards them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataV2 {
+    pub tokens: Vec<PrefixedCoin>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Option<String>,
+    pub forwarding: Option<ForwardingPacketData>,
+}
+
+/// The remaining hops through which the tokens of an ICS20 v2 packet are forwarded
+/// once received, and the memo delivered with them to their final destination.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardingPacketData {
+    pub destination_memo: Option<String>,
+    pub hops: Vec<TracePrefix>,
+}
+
+impl Protobuf<RawPacketDataV2> for PacketDataV2 {}
+
+impl TryFrom<RawPacketDataV2> for PacketDataV2 {
+    type Error = Error;
+
+    fn try_from(raw_pkt_data: RawPacketDataV2) -> Result<Self, Self::Error> {
+        if raw_pkt_data.tokens.is_empty() {
+            return Err(Error::invalid_token());
+        }
+
+        let tokens = raw_pkt_data
+            .tokens
+            .into_iter()
+            .map(PrefixedCoin::try_from)
+            .collect::<Result<_, _>>()?;
+
+        let forwarding = raw_pkt_data
+            .forwarding
+            .filter(|forwarding| !forwarding.hops.is_empty())
+            .map(ForwardingPacketData::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            tokens,
+            sender: raw_pkt_data.sender.parse().map_err(Error::signer)?,
+            receiver: raw_pkt_data.receiver.parse().map_err(Error::signer)?,
+            memo: Some(raw_pkt_data.memo).filter(|m| !m.is_empty()),
+            forwarding,
+        })
+    }
+}
+
+impl From<PacketDataV2> for RawPacketDataV2 {
+    fn from(pkt_data: PacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data.tokens.into_iter().map(Into::into).collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.unwrap_or_default(),
+            forwarding: pkt_data.forwarding.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<RawForwardingPacketData> for ForwardingPacketData {
+    type Error = Error;
+
+    fn try_from(raw_forwarding: RawForwardingPacketData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            destination_memo: Some(raw_forwarding.destination_memo).filter(|m| !m.is_empty()),
+            hops: hops_from_raw(raw_forwarding.hops)?,
+        })
+    }
+}
+
+impl From<ForwardingPacketData> for RawForwardingPacketData {
+    fn from(forwarding: ForwardingPacketData) -> Self {
+        Self {
+            destination_memo: forwarding.destination_memo.unwrap_or_default(),
+            hops: forwarding.hops.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<RawToken> for PrefixedCoin {
+    type Error = Error;
+
+    fn try_from(raw_token: RawToken) -> Result<Self, Self::Error> {
+        let raw_denom = raw_token.denom.ok_or_else(Error::invalid_token)?;
+
+        // The trace of the denomination lists its hops starting with the most recent one,
+        // in the same order as in the string representation of the denomination.
+        let trace_path: TracePath = hops_from_raw(raw_denom.trace)?.into_iter().collect();
+        let base_denom = BaseDenom::from_str(&raw_denom.base)?;
+
+        Ok(Self {
+            denom: PrefixedDenom::new(trace_path, base_denom),
+            amount: Amount::from_str(&raw_token.amount)?,
+        })
+    }
+}
+
+impl From<PrefixedCoin> for RawToken {
+    fn from(coin: PrefixedCoin) -> Self {
+        Self {
+            denom: Some(RawDenom {
+                base: coin.denom.base_denom().to_string(),
+                trace: coin
+                    .denom
+                    .trace_path()
+                    .prefixes()
+                    .cloned()
+                    .map(Into::into)
+                    .collect(),
+            }),
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+impl From<TracePrefix> for RawHop {
+    fn from(hop: TracePrefix) -> Self {
+        Self {
+            port_id: hop.port_id().to_string(),
+            channel_id: hop.channel_id().to_string(),
+        }
+    }
+}
+
+fn hops_from_raw(raw_hops: Vec<RawHop>) -> Result<Vec<TracePrefix>, Error> {
+    raw_hops
+        .into_iter()
+        .enumerate()
+        .map(|(pos, hop)| {
+            let port_id = hop
+                .port_id
+                .parse()
+                .map_err(|e| Error::invalid_trace_port_id(pos, e))?;
+            let channel_id = hop
+                .channel_id
+                .parse()
+                .map_err(|e| Error::invalid_trace_channel_id(pos, e))?;
+
+            Ok(TracePrefix::new(port_id, channel_id))
+        })
+        .collect()
+}
+
+/// The data of a packet sent over an ICS20 channel, in either version of the application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferPacketData {
+    V1(PacketData),
+    V2(PacketDataV2),
+}
+
+impl TransferPacketData {
+    /// Decodes the data of a packet sent over an ICS20 channel of the given version, which
+    /// is JSON-encoded in ICS20 v1 packets and protobuf-encoded in ICS20 v2 packets.
+    pub fn decode(data: &[u8], version: &Version) -> Result<Self, Error> {
+        match RawTransferPacketData::decode(data, version)? {
+            RawTransferPacketData::V1(raw) => raw.try_into().map(Self::V1),
+            RawTransferPacketData::V2(raw) => raw.try_into().map(Self::V2),
+        }
+    }
+
+    /// Encodes the data of an ICS20 packet, according to its version.
+    pub fn encode(self) -> Vec<u8> {
+        match self {
+            Self::V1(packet_data) => serde_json::to_vec(&packet_data)
+                .expect("PacketData's infallible Serialize impl failed"),
+            Self::V2(packet_data) => packet_data.encode_vec(),
+        }
+    }
+
+    pub fn tokens(&self) -> &[PrefixedCoin] {
+        match self {
+            Self::V1(packet_data) => std::slice::from_ref(&packet_data.token),
+            Self::V2(packet_data) => &packet_data.tokens,
+        }
+    }
+
+    pub fn sender(&self) -> &Signer {
+        match self {
+            Self::V1(packet_data) => &packet_data.sender,
+            Self::V2(packet_data) => &packet_data.sender,
+        }
+    }
+
+    pub fn receiver(&self) -> &Signer {
+        match self {
+            Self::V1(packet_data) => &packet_data.receiver,
+            Self::V2(packet_data) => &packet_data.receiver,
+        }
+    }
+
+    pub fn memo(&self) -> Option<&str> {
+        match self {
+            Self::V1(packet_data) => packet_data.memo.as_deref(),
+            Self::V2(packet_data) => packet_data.memo.as_deref(),
+        }
+    }
+
+    pub fn forwarding(&self) -> Option<&ForwardingPacketData> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(packet_data) => packet_data.forwarding.as_ref(),
+        }
+    }
+}
+
+/// The raw data of a packet sent over an ICS20 channel, in either version of the
+/// application, whose fields are not validated. Unlike [`TransferPacketData`], it can
+/// be decoded from packets whose denominations are not valid ICS20 denominations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RawTransferPacketData {
+    V1(RawPacketData),
+    V2(RawPacketDataV2),
+}
+
+impl RawTransferPacketData {
+    /// Decodes the data of a packet sent over an ICS20 channel of the given version, which
+    /// is JSON-encoded in ICS20 v1 packets and protobuf-encoded in ICS20 v2 packets.
+    pub fn decode(data: &[u8], version: &Version) -> Result<Self, Error> {
+        if version.is_ics20_v2() {
+            match <RawPacketDataV2 as prost::Message>::decode(data) {
+                Ok(raw) if !raw.tokens.is_empty() => Ok(Self::V2(raw)),
+                _ => Err(Error::packet_data_deserialization()),
+            }
+        } else {
+            serde_json::from_slice::<RawPacketData>(data)
+                .map(Self::V1)
+                .map_err(|_| Error::packet_data_deserialization())
+        }
+    }
+
+    pub fn sender(&self) -> &str {
+        match self {
+            Self::V1(raw) => &raw.sender,
+            Self::V2(raw) => &raw.sender,
+        }
+    }
+
+    pub fn receiver(&self) -> &str {
+        match self {
+            Self::V1(raw) => &raw.receiver,
+            Self::V2(raw) => &raw.receiver,
+        }
+    }
+
+    /// Returns the denomination, prefixed with its trace path, and the amount
+    /// of each token transferred by the packet.
+    pub fn tokens(&self) -> Vec<(String, &str)> {
+        match self {
+            Self::V1(raw) => vec![(raw.denom.clone(), raw.amount.as_str())],
+            Self::V2(raw) => raw
+                .tokens
+                .iter()
+                .map(|token| {
+                    let denom = token
+                        .denom
+                        .as_ref()
+                        .map(|denom| {
+                            denom
+                                .trace
+                                .iter()
+                                .map(|hop| format!("{}/{}", hop.port_id, hop.channel_id))
+                                .chain(core::iter::once(denom.base.clone()))
+                                .collect::<Vec<_>>()
+                                .join("/")
+                        })
+                        .unwrap_or_default();
+
+                    (denom, token.amount.as_str())
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the memo of the packet, and the memo delivered to the
+    /// final destination of the tokens if they are forwarded.
+    pub fn memos(&self) -> Vec<&str> {
+        match self {
+            Self::V1(raw) => vec![raw.memo.as_str()],
+            Self::V2(raw) => core::iter::once(raw.memo.as_str())
+                .chain(
+                    raw.forwarding
+                        .iter()
+                        .map(|forwarding| forwarding.destination_memo.as_str()),
+                )
+                .collect(),
+        }
+    }
+}
+
+// The ICS20 v2 packet data types of ibc-go v9, which are not part of `ibc-proto`.
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawPacketDataV2 {
+    #[prost(message, repeated, tag = "1")]
+    pub tokens: Vec<RawToken>,
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    #[prost(string, tag = "3")]
+    pub receiver: String,
+    #[prost(string, tag = "4")]
+    pub memo: String,
+    #[prost(message, optional, tag = "5")]
+    pub forwarding: Option<RawForwardingPacketData>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawToken {
+    #[prost(message, optional, tag = "1")]
+    pub denom: Option<RawDenom>,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawDenom {
+    #[prost(string, tag = "1")]
+    pub base: String,
+    #[prost(message, repeated, tag = "3")]
+    pub trace: Vec<RawHop>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawHop {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawForwardingPacketData {
+    #[prost(string, tag = "1")]
+    pub destination_memo: String,
+    #[prost(message, repeated, tag = "2")]
+    pub hops: Vec<RawHop>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(denom: &str, amount: u64) -> PrefixedCoin {
+        PrefixedCoin {
+            denom: denom.parse().unwrap(),
+            amount: amount.into(),
+        }
+    }
+
+    fn signer(address: &str) -> Signer {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn decode_v1_packet_data() {
+        let data = br#"{"denom":"transfer/channel-1/uatom","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver","memo":"hello"}"#;
+
+        let packet_data = TransferPacketData::decode(data, &Version::ics20()).unwrap();
+
+        assert!(matches!(packet_data, TransferPacketData::V1(_)));
+        assert_eq!(
+            packet_data.tokens(),
+            &[coin("transfer/channel-1/uatom", 100)]
+        );
+        assert_eq!(packet_data.receiver(), &signer("cosmos1receiver"));
+        assert_eq!(packet_data.memo(), Some("hello"));
+        assert_eq!(packet_data.forwarding(), None);
+    }
+
+    #[test]
+    fn decode_v2_packet_data() {
+        let packet_data = PacketDataV2 {
+            tokens: vec![
+                coin("uatom", 100),
+                coin("transfer/channel-1/transfer/channel-2/uosmo", 200),
+            ],
+            sender: signer("cosmos1sender"),
+            receiver: signer("cosmos1receiver"),
+            memo: None,
+            forwarding: Some(ForwardingPacketData {
+                destination_memo: Some("hello".to_string()),
+                hops: vec![TracePrefix::new(
+                    "transfer".parse().unwrap(),
+                    "channel-3".parse().unwrap(),
+                )],
+            }),
+        };
+
+        let data = TransferPacketData::V2(packet_data.clone()).encode();
+        let decoded = TransferPacketData::decode(&data, &Version::ics20_v2()).unwrap();
+
+        assert_eq!(decoded, TransferPacketData::V2(packet_data.clone()));
+        assert_eq!(decoded.tokens(), packet_data.tokens.as_slice());
+        assert_eq!(decoded.memo(), None);
+        assert_eq!(decoded.forwarding(), packet_data.forwarding.as_ref());
+    }
+
+    #[test]
+    fn encode_v2_denom_trace() {
+        let raw = RawToken::from(coin("transfer/channel-1/transfer/channel-2/uosmo", 1));
+        let raw_denom = raw.denom.unwrap();
+
+        assert_eq!(raw_denom.base, "uosmo");
+        assert_eq!(
+            raw_denom
+                .trace
+                .iter()
+                .map(|hop| hop.channel_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["channel-1", "channel-2"]
+        );
+    }
+
+    #[test]
+    fn decode_raw_packet_data() {
+        // Some denominations of tokens created on a chain are not valid ICS20 v1 denominations
+        let data = br#"{"denom":"gamm/pool/1","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver","memo":"hello"}"#;
+        assert!(TransferPacketData::decode(data, &Version::ics20()).is_err());
+
+        let raw = RawTransferPacketData::decode(data, &Version::ics20()).unwrap();
+        assert_eq!(raw.sender(), "cosmos1sender");
+        assert_eq!(raw.receiver(), "cosmos1receiver");
+        assert_eq!(raw.tokens(), vec![("gamm/pool/1".to_string(), "100")]);
+        assert_eq!(raw.memos(), vec!["hello"]);
+
+        let raw = RawPacketDataV2 {
+            tokens: vec![
+                RawToken::from(coin("uatom", 1)),
+                RawToken::from(coin("transfer/channel-1/uosmo", 2)),
+            ],
+            sender: "cosmos1sender".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            memo: String::new(),
+            forwarding: Some(RawForwardingPacketData {
+                destination_memo: "hello".to_string(),
+                hops: vec![],
+            }),
+        };
+        let raw = RawTransferPacketData::decode(
+            &prost::Message::encode_to_vec(&raw),
+            &Version::ics20_v2(),
+        )
+        .unwrap();
+        assert_eq!(raw.receiver(), "cosmos1receiver");
+        assert_eq!(
+            raw.tokens(),
+            vec![
+                ("uatom".to_string(), "1"),
+                ("transfer/channel-1/uosmo".to_string(), "2")
+            ]
+        );
+        assert_eq!(raw.memos(), vec!["", "hello"]);
+    }
+
+    #[test]
+    fn decode_by_channel_version() {
+        let v1_data = br#"{"denom":"uatom","amount":"100","sender":"cosmos1sender","receiver":"cosmos1receiver"}"#;
+        assert!(TransferPacketData::decode(v1_data, &Version::ics20_with_fee()).is_ok());
+        assert!(TransferPacketData::decode(v1_data, &Version::ics20_v2()).is_err());
+
+        let v2_data = TransferPacketData::V2(PacketDataV2 {
+            tokens: vec![coin("uatom", 100)],
+            sender: signer("cosmos1sender"),
+            receiver: signer("cosmos1receiver"),
+            memo: None,
+            forwarding: None,
+        })
+        .encode();
+        assert!(
+            TransferPacketData::decode(&v2_data, &Version::app_version_with_fee("ics20-2")).is_ok()
+        );
+        assert!(TransferPacketData::decode(&v2_data, &Version::ics20()).is_err());
+    }
+
+    #[test]
+    fn decode_invalid_packet_data() {
+        assert!(TransferPacketData::decode(b"not a transfer packet", &Version::ics20()).is_err());
+        assert!(
+            TransferPacketData::decode(b"not a transfer packet", &Version::ics20_v2()).is_err()
+        );
+
+        let no_tokens = RawPacketDataV2 {
+            tokens: vec![],
+            sender: "cosmos1sender".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            memo: String::new(),
+            forwarding: None,
+        };
+        assert!(PacketDataV2::try_from(no_tokens).is_err());
+    }
+}
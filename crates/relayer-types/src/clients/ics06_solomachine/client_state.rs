@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The sequence of the next header, the latest height of the client is `0-{sequence}`
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::zero_sequence());
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// A solo machine is not a chain, its diversifier stands for its chain identifier
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        // SAFETY: the sequence is checked to be non-zero on construction
+        Height::new(0, self.sequence).unwrap()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        // Frozen solo machine clients report the frozen height `0-1`, as in ibc-go
+        self.is_frozen.then(|| Height::new(0, 1).unwrap())
+    }
+
+    /// Solo machine clients do not expire, the solo machine can always sign a new header
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(Error::zero_sequence());
+        }
+
+        Ok(Self {
+            sequence: raw.sequence,
+            is_frozen: raw.is_frozen,
+            consensus_state: raw
+                .consensus_state
+                .ok_or_else(Error::missing_consensus_state)?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => RawClientState::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use ibc_proto::google::protobuf::Any;
+
+    use crate::clients::ics06_solomachine::client_state::ClientState;
+    use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::clients::ics06_solomachine::PublicKey;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    fn consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            PublicKey::secp256k1(vec![0x02; 33]),
+            "solo".to_string(),
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+        )
+    }
+
+    #[test]
+    fn any_roundtrip() {
+        let client_state = ClientState::new(3, consensus_state()).unwrap();
+
+        assert_eq!(client_state.latest_height(), Height::new(0, 3).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::from_string("solo"));
+        assert_eq!(client_state.frozen_height(), None);
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn zero_sequence() {
+        assert!(ClientState::new(0, consensus_state()).is_err());
+    }
+}
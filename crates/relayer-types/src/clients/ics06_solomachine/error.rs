@@ -0,0 +1,36 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    Error {
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            |_| { "decode error" },
+
+        UnsupportedPublicKey
+            { type_url: String }
+            |e| { format_args!("unsupported public key type {}, only secp256k1 keys are supported", e.type_url) },
+
+        MissingPublicKey
+            |_| { "missing public key" },
+
+        MissingConsensusState
+            |_| { "missing consensus state" },
+
+        MissingSignatureAndData
+            |_| { "missing signature and data" },
+
+        ZeroSequence
+            |_| { "sequence cannot be zero" },
+
+        ZeroTimestamp
+            |_| { "timestamp cannot be zero" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
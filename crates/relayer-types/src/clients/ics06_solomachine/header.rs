@@ -0,0 +1,134 @@
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    Header as RawHeader, HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+};
+use ibc_proto::Protobuf;
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::{PublicKey, SENTINEL_HEADER_PATH};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// A header of a solo machine, setting the public key and diversifier the solo machine
+/// signs with from then on, signed with its current key
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The sequence of the client state this header applies to. It is not part of the
+    /// encoded header, and is zero for headers decoded from their protobuf encoding.
+    #[serde(skip)]
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    /// The encoded `SignatureDescriptor.Data` over the [`sign_bytes`](Header::sign_bytes)
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// The bytes the solo machine signs to produce a header updating a client
+    /// at the given sequence, whose consensus state has the given diversifier
+    pub fn sign_bytes(
+        sequence: u64,
+        timestamp: Timestamp,
+        diversifier: &str,
+        new_public_key: &PublicKey,
+        new_diversifier: &str,
+    ) -> Vec<u8> {
+        let data = RawHeaderData {
+            new_pub_key: Some(new_public_key.clone().into()),
+            new_diversifier: new_diversifier.to_string(),
+        };
+
+        RawSignBytes {
+            sequence,
+            timestamp: timestamp.nanoseconds(),
+            diversifier: diversifier.to_string(),
+            path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+            data: data.encode_to_vec(),
+        }
+        .encode_to_vec()
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    /// The latest height of the client once updated with this header
+    fn height(&self) -> Height {
+        // SAFETY: the height is non-zero
+        Height::new(0, self.sequence + 1).unwrap()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.timestamp == 0 {
+            return Err(Error::zero_timestamp());
+        }
+
+        Ok(Self {
+            sequence: 0,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(|_| Error::zero_timestamp())?,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or_else(Error::missing_public_key)?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => RawHeader::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
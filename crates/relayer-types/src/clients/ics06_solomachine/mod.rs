@@ -0,0 +1,77 @@
+//! ICS 06: Solo Machine Client tracks the public key of a single signer, such as
+//! an off-chain process holding a key, instead of the consensus of a chain.
+//!
+//! The solo machine proves its state by signing it: a header is a signature over the
+//! new public key and diversifier of the solo machine, produced with its current key.
+//! Every header advances the `sequence` of the client, which stands for its height.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::cosmos::crypto::secp256k1::PubKey as RawSecp256k1PubKey;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use error::Error;
+
+/// The path signed over by the solo machine in its headers
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+pub const SECP256K1_PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// The public key of a solo machine, only single secp256k1 keys are supported
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKey {
+    /// The compressed secp256k1 public key
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub key: Vec<u8>,
+}
+
+impl PublicKey {
+    pub fn secp256k1(key: Vec<u8>) -> Self {
+        Self { key }
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if raw.type_url != SECP256K1_PUB_KEY_TYPE_URL {
+            return Err(Error::unsupported_public_key(raw.type_url));
+        }
+
+        let pub_key = RawSecp256k1PubKey::decode(raw.value.as_slice()).map_err(Error::decode)?;
+
+        Ok(Self::secp256k1(pub_key.key))
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(value: PublicKey) -> Self {
+        Any {
+            type_url: SECP256K1_PUB_KEY_TYPE_URL.to_string(),
+            value: RawSecp256k1PubKey { key: value.key }.encode_to_vec(),
+        }
+    }
+}
+
+/// Encode a signature produced by a single key in the format expected by the solo machine client,
+/// i.e. the protobuf encoding of the `SignatureDescriptor.Data` holding it
+pub fn encode_signature(signature: Vec<u8>) -> Vec<u8> {
+    RawSignatureData {
+        sum: Some(Sum::Single(Single {
+            mode: SignMode::Direct as i32,
+            signature,
+        })),
+    }
+    .encode_to_vec()
+}
@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawWasmClientState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_wrapped, encode_wrapped};
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+/// The client state of a Wasm client wrapping a Tendermint client
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The checksum of the Wasm contract implementing the client
+    pub checksum: Vec<u8>,
+    pub latest_height: Height,
+    pub tendermint: TmClientState,
+}
+
+impl ClientState {
+    /// Wrap a Tendermint client state in a client state for the given Wasm contract
+    pub fn new(checksum: Vec<u8>, tendermint: TmClientState) -> Result<Self, Error> {
+        if checksum.is_empty() {
+            return Err(Error::empty_checksum());
+        }
+
+        Ok(Self {
+            checksum,
+            latest_height: tendermint.latest_height,
+            tendermint,
+        })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.tendermint.chain_id()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.tendermint.frozen_height()
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.tendermint.expired(elapsed)
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        let tendermint =
+            TmClientState::try_from(decode_wrapped(&raw.data)?).map_err(Error::wrapped)?;
+
+        Ok(Self {
+            checksum: raw.checksum,
+            latest_height: raw
+                .latest_height
+                .ok_or_else(Error::missing_latest_height)?
+                .try_into()
+                .map_err(|_| Error::missing_latest_height())?,
+            tendermint,
+        })
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            data: encode_wrapped(value.tendermint.into()),
+            checksum: value.checksum,
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => RawWasmClientState::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use test_log::test;
+
+    use ibc_proto::google::protobuf::Any;
+
+    use crate::clients::ics07_tendermint::client_state::{
+        AllowUpdate, ClientState as TmClientState,
+    };
+    use crate::clients::ics08_wasm::client_state::ClientState;
+    use crate::core::ics02_client::client_state::ClientState as _;
+    use crate::core::ics02_client::client_type::ClientType;
+    use crate::core::ics02_client::trust_threshold::TrustThreshold;
+    use crate::core::ics23_commitment::specs::ProofSpecs;
+    use crate::core::ics24_host::identifier::ChainId;
+    use crate::Height;
+
+    fn tendermint_client_state() -> TmClientState {
+        TmClientState::new(
+            ChainId::from_string("ibc-1"),
+            TrustThreshold::TWO_THIRDS,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).unwrap(),
+            ProofSpecs::default(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn any_roundtrip() {
+        let client_state = ClientState::new(vec![0xab; 32], tendermint_client_state()).unwrap();
+
+        assert_eq!(client_state.client_type(), ClientType::Wasm);
+        assert_eq!(client_state.chain_id(), ChainId::from_string("ibc-1"));
+        assert_eq!(client_state.latest_height(), Height::new(1, 10).unwrap());
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn empty_checksum() {
+        assert!(ClientState::new(vec![], tendermint_client_state()).is_err());
+    }
+}
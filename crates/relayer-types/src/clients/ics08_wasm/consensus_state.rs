@@ -0,0 +1,89 @@
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ConsensusState as RawWasmConsensusState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_wrapped, encode_wrapped};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::consensus_state::ConsensusState as Ics2ConsensusState;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+/// The consensus state of a Wasm client wrapping a Tendermint client
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub tendermint: TmConsensusState,
+}
+
+impl Ics2ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        self.tendermint.root()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.tendermint.timestamp()
+    }
+}
+
+impl From<TmConsensusState> for ConsensusState {
+    fn from(tendermint: TmConsensusState) -> Self {
+        Self { tendermint }
+    }
+}
+
+impl Protobuf<RawWasmConsensusState> for ConsensusState {}
+
+impl TryFrom<RawWasmConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmConsensusState) -> Result<Self, Self::Error> {
+        let tendermint =
+            TmConsensusState::try_from(decode_wrapped(&raw.data)?).map_err(Error::wrapped)?;
+
+        Ok(Self { tendermint })
+    }
+}
+
+impl From<ConsensusState> for RawWasmConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            data: encode_wrapped(value.tendermint.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => RawWasmConsensusState::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    Error {
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            |_| { "decode error" },
+
+        MissingLatestHeight
+            |_| { "missing latest height" },
+
+        EmptyChecksum
+            |_| { "empty checksum of the Wasm contract" },
+
+        Wrapped
+            [ Ics02Error ]
+            |_| { "invalid data of the wrapped Tendermint client" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
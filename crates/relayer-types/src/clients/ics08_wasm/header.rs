@@ -0,0 +1,88 @@
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_wrapped, encode_wrapped};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The type URL of the messages updating a Wasm client, i.e. both its headers and misbehaviours
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// A header updating a Wasm client wrapping a Tendermint client
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub tendermint: TmHeader,
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn height(&self) -> Height {
+        self.tendermint.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.tendermint.timestamp()
+    }
+}
+
+impl From<TmHeader> for Header {
+    fn from(tendermint: TmHeader) -> Self {
+        Self { tendermint }
+    }
+}
+
+impl Protobuf<RawClientMessage> for Header {}
+
+impl TryFrom<RawClientMessage> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let tendermint = TmHeader::try_from(decode_wrapped(&raw.data)?).map_err(Error::wrapped)?;
+
+        Ok(Self { tendermint })
+    }
+}
+
+impl From<Header> for RawClientMessage {
+    fn from(value: Header) -> Self {
+        Self {
+            data: encode_wrapped(value.tendermint.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => RawClientMessage::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(header),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawTmMisbehaviour;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::misbehaviour::{
+    Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::header::WASM_CLIENT_MESSAGE_TYPE_URL;
+use crate::clients::ics08_wasm::{decode_wrapped, encode_wrapped};
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::Height;
+
+/// A misbehaviour of a Wasm client wrapping a Tendermint client
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub tendermint: TmMisbehaviour,
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        self.tendermint.client_id()
+    }
+
+    fn height(&self) -> Height {
+        self.tendermint.height()
+    }
+}
+
+impl From<TmMisbehaviour> for Misbehaviour {
+    fn from(tendermint: TmMisbehaviour) -> Self {
+        Self { tendermint }
+    }
+}
+
+impl Protobuf<RawClientMessage> for Misbehaviour {}
+
+impl TryFrom<RawClientMessage> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let wrapped = decode_wrapped(&raw.data)?;
+
+        if wrapped.type_url != TENDERMINT_MISBEHAVIOR_TYPE_URL {
+            return Err(Error::wrapped(Ics02Error::unknown_misbehaviour_type(
+                wrapped.type_url,
+            )));
+        }
+
+        let tendermint = Protobuf::<RawTmMisbehaviour>::decode_vec(&wrapped.value)
+            .map_err(|e| Error::wrapped(Ics02Error::decode_raw_misbehaviour(e)))?;
+
+        Ok(Self { tendermint })
+    }
+}
+
+impl From<Misbehaviour> for RawClientMessage {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            data: encode_wrapped(Any {
+                type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
+                value: Protobuf::<RawTmMisbehaviour>::encode_vec(value.tendermint),
+            }),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => RawClientMessage::decode(raw.value.as_slice())
+                .map_err(Error::decode)?
+                .try_into()
+                .map_err(Into::into),
+            _ => Err(Ics02Error::unknown_misbehaviour_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.tendermint)
+    }
+}
@@ -0,0 +1,27 @@
+//! ICS 08: Wasm Client wraps a client verification algorithm implemented as a Wasm contract.
+//!
+//! Hermes supports the Wasm clients whose contract implements the Tendermint client:
+//! the client and consensus states, headers and misbehaviours of such clients hold
+//! the data of their Tendermint counterpart, encoded as the protobuf encoding of
+//! the `Any` message holding it.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use error::Error;
+
+/// Encode the wrapped Tendermint data into the `data` field of a Wasm message
+pub(crate) fn encode_wrapped(wrapped: Any) -> Vec<u8> {
+    wrapped.encode_to_vec()
+}
+
+/// Decode the wrapped Tendermint data from the `data` field of a Wasm message
+pub(crate) fn decode_wrapped(data: &[u8]) -> Result<Any, Error> {
+    Any::decode(data).map_err(Error::decode)
+}
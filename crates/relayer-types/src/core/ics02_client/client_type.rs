@@ -7,15 +7,21 @@ use super::error::Error;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ClientType {
     Tendermint = 1,
+    Wasm = 2,
+    SoloMachine = 3,
 }
 
 impl ClientType {
     const TENDERMINT_STR: &'static str = "07-tendermint";
+    const WASM_STR: &'static str = "08-wasm";
+    const SOLOMACHINE_STR: &'static str = "06-solomachine";
 
     /// Yields the identifier of this client type as a string
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Tendermint => Self::TENDERMINT_STR,
+            Self::Wasm => Self::WASM_STR,
+            Self::SoloMachine => Self::SOLOMACHINE_STR,
         }
     }
 }
@@ -32,6 +38,8 @@ impl core::str::FromStr for ClientType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             Self::TENDERMINT_STR => Ok(Self::Tendermint),
+            Self::WASM_STR => Ok(Self::Wasm),
+            Self::SOLOMACHINE_STR => Ok(Self::SoloMachine),
 
             _ => Err(Error::unknown_client_type(s.to_string())),
         }
@@ -56,6 +64,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_wasm_client_type() {
+        let client_type = ClientType::from_str("08-wasm");
+
+        match client_type {
+            Ok(ClientType::Wasm) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_solomachine_client_type() {
+        let client_type = ClientType::from_str("06-solomachine");
+
+        match client_type {
+            Ok(ClientType::SoloMachine) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
     #[test]
     fn parse_unknown_client_type() {
         let client_type_str = "some-random-client-type";
@@ -1,11 +1,12 @@
 use ibc_proto::ibc::core::channel::v1::PacketId as ProtoPacketId;
+use serde_derive::Serialize;
 use std::str::FromStr;
 
 use crate::core::ics04_channel::error::Error;
 use crate::core::ics04_channel::packet::Sequence;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PacketId {
     pub channel_id: ChannelId,
     pub port_id: PortId,
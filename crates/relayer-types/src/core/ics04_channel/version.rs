@@ -27,6 +27,10 @@ impl Version {
         Self::new(transfer::VERSION.to_string())
     }
 
+    pub fn ics20_v2() -> Self {
+        Self::new(transfer::VERSION_V2.to_string())
+    }
+
     pub fn ics20_with_fee() -> Self {
         let val = json::json!({
             "fee_version": "ics29-1",
@@ -61,6 +65,27 @@ impl Version {
             })
             .unwrap_or(false)
     }
+
+    /// Returns the version of the application, unwrapping it from the
+    /// fee middleware metadata if the channel supports fees.
+    pub fn app_version(&self) -> String {
+        json::from_str::<json::Value>(&self.0)
+            .ok()
+            .and_then(|val| Some(val.get("app_version")?.as_str()?.to_string()))
+            .unwrap_or_else(|| self.0.clone())
+    }
+
+    /// Returns true if the application version is one of the ICS20 versions.
+    pub fn is_ics20(&self) -> bool {
+        let app_version = self.app_version();
+        app_version == transfer::VERSION || app_version == transfer::VERSION_V2
+    }
+
+    /// Returns true if the application version is ICS20 v2,
+    /// whose packets can carry several tokens and be forwarded.
+    pub fn is_ics20_v2(&self) -> bool {
+        self.app_version() == transfer::VERSION_V2
+    }
 }
 
 impl PartialEq for Version {
@@ -128,4 +153,19 @@ mod test {
             assert!(version.supports_fee());
         }
     }
+
+    #[test]
+    fn test_ics20_v2_version() {
+        assert!(!Version::ics20().is_ics20_v2());
+        assert!(!Version::ics20_with_fee().is_ics20_v2());
+        assert!(Version::ics20_v2().is_ics20_v2());
+        assert!(Version::app_version_with_fee("ics20-2").is_ics20_v2());
+        assert!(Version::ics20_with_fee().is_ics20());
+        assert!(Version::ics20_v2().is_ics20());
+        assert!(!Version::new("ics27-1".to_string()).is_ics20());
+        assert_eq!(
+            Version::app_version_with_fee("ics20-2").app_version(),
+            "ics20-2"
+        );
+    }
 }
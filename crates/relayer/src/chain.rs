@@ -4,6 +4,8 @@ pub mod cosmos;
 pub mod counterparty;
 pub mod endpoint;
 pub mod handle;
+#[cfg(any(test, feature = "mock-chain"))]
+pub mod mock;
 pub mod namada;
 pub mod penumbra;
 pub mod requests;
@@ -1,7 +1,7 @@
 use alloc::sync::Arc;
 use bytes::Buf;
 use bytes::Bytes;
-use config::CosmosSdkConfig;
+use config::{CosmosSdkConfig, VerifiedQueriesConfig};
 use core::{future::Future, str::FromStr, time::Duration};
 use futures::future::join_all;
 use ibc_proto::interchain_security::ccv::provider::v1::QueryConsumerIdFromClientIdRequest;
@@ -10,6 +10,7 @@ use num_bigint::BigInt;
 use prost::Message;
 use std::cmp::Ordering;
 use std::thread;
+use std::time::Instant;
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
 use tonic::metadata::AsciiMetadataValue;
@@ -24,13 +25,14 @@ use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRe
 use ibc_proto::interchain_security::ccv::v1::ConsumerParams as CcvConsumerParams;
 use ibc_proto::Protobuf;
 use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
 };
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
 use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
-use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::{
@@ -39,8 +41,8 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::channel::{State, UpgradeState};
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
-use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
-use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
@@ -62,6 +64,7 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint::block::Height as TmHeight;
 use tendermint::node::{self, info::TxIndexStatus};
 use tendermint::time::Time as TmTime;
+use tendermint_light_client::verifier::options::Options as LightClientOptions;
 use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::client::CompatMode;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
@@ -70,11 +73,15 @@ use tendermint_rpc::{Client, HttpClient, Order};
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::account_pool::AccountPool;
 use crate::chain::cosmos::batch::{
     send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
+    send_batched_messages_from_accounts_and_wait_check_tx,
+    send_batched_messages_from_accounts_and_wait_commit,
     sequential_send_batched_messages_and_wait_commit,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::estimate::estimate_tx_fees;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
 use crate::chain::cosmos::query::account::get_or_fetch_account;
@@ -83,18 +90,18 @@ use crate::chain::cosmos::query::connection::query_connection_params;
 use crate::chain::cosmos::query::consensus_state::query_consensus_state_heights;
 use crate::chain::cosmos::query::custom::cross_chain_query_via_rpc;
 use crate::chain::cosmos::query::denom_trace::query_denom_trace;
-use crate::chain::cosmos::query::fee::query_incentivized_packet;
+use crate::chain::cosmos::query::fee::{query_incentivized_packet, query_incentivized_packets};
+use crate::chain::cosmos::query::interchain_account::query_interchain_account;
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
     filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
-use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
 };
-use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck, NodeStatus};
 use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -105,9 +112,9 @@ use crate::config::{parse_gas_prices, ChainConfig, GasPrice};
 use crate::consensus_state::AnyConsensusState;
 use crate::denom::DenomTrace;
 use crate::error::Error;
-use crate::event::source::{EventSource, TxEventSourceCmd};
+use crate::event::source::{EventSource, EventSourceNode, TxEventSourceCmd};
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::{KeyRing, Secp256k1KeyPair, Secp256k1Signer, Store};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
@@ -118,9 +125,11 @@ use crate::util::pretty::{
 };
 use crate::HERMES_VERSION;
 
+use self::failover::{build_rpc_client, EndpointPool};
 use self::gas::dynamic_gas_price;
 use self::types::gas::GasConfig;
 
+pub mod account_pool;
 pub mod batch;
 pub mod client;
 pub mod compatibility;
@@ -128,6 +137,7 @@ pub mod config;
 pub mod eip_base_fee;
 pub mod encode;
 pub mod estimate;
+pub mod failover;
 pub mod fee;
 pub mod gas;
 pub mod query;
@@ -164,10 +174,17 @@ pub struct CosmosSdkChain {
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
 
-    /// A cached copy of the account information
-    account: Option<Account>,
+    /// The accounts transactions are submitted from,
+    /// with a cached copy of their account information
+    accounts: AccountPool,
 
     tx_monitor_cmd: Option<TxEventSourceCmd>,
+
+    /// The full nodes this chain can fail over between
+    endpoints: EndpointPool,
+
+    /// The prefix of the IBC store, under which the paths of verified queries are proven
+    commitment_prefix: CommitmentPrefix,
 }
 
 impl CosmosSdkChain {
@@ -181,9 +198,10 @@ impl CosmosSdkChain {
         self.config.max_tx_size.into()
     }
 
-    fn key(&self) -> Result<Secp256k1KeyPair, Error> {
+    /// The signer of the transactions submitted from the account of `key_name`
+    fn key(&self) -> Result<Arc<dyn Secp256k1Signer>, Error> {
         self.keybase()
-            .get_key(&self.config.key_name)
+            .get_signer(&self.config.key_name)
             .map_err(Error::key_base)
     }
 
@@ -332,6 +350,76 @@ impl CosmosSdkChain {
         Ok(())
     }
 
+    /// Probe every configured full node and switch to the preferred
+    /// healthy node if it is not the one currently in use.
+    fn maintain_endpoints(&mut self) -> Result<(), Error> {
+        let probes = self.block_on(self.endpoints.probe());
+        self.endpoints.record(probes);
+
+        match self.endpoints.select() {
+            Some(index) => self.switch_endpoint(index),
+            None => Ok(()),
+        }
+    }
+
+    /// Switch every client of this chain, including the event source,
+    /// over to the full node at the given index in the endpoint pool.
+    fn switch_endpoint(&mut self, index: usize) -> Result<(), Error> {
+        let from = self.endpoints.active().clone();
+        let to = self.endpoints.endpoint(index).clone();
+
+        warn!(
+            "switching from full node at {} to full node at {}",
+            from.rpc_addr, to.rpc_addr
+        );
+
+        let mut config = self.config.clone();
+        config.rpc_addr = to.rpc_addr.clone();
+        config.grpc_addr = to.grpc_addr.clone();
+
+        let rpc_client = build_rpc_client(&to, self.compat_mode)?;
+        let node_info = self.block_on(fetch_node_info(&rpc_client, &config))?;
+        let light_client = TmLightClient::from_cosmos_sdk_config(&config, node_info.id)?;
+
+        let grpc_addr = Uri::from_str(&to.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(to.grpc_addr.to_string(), e))?;
+
+        let tx_config = TxConfig::try_from(&config)?;
+
+        let event_source_node = match &mut config.event_source {
+            crate::config::EventSourceMode::Push { url, .. } => {
+                *url = to
+                    .websocket_addr()
+                    .map_err(|e| Error::rpc(to.rpc_addr.clone(), e))?;
+
+                EventSourceNode::WebSocket(url.clone())
+            }
+            crate::config::EventSourceMode::Pull { .. } => EventSourceNode::Rpc(rpc_client.clone()),
+        };
+
+        if let Some(tx_monitor_cmd) = &self.tx_monitor_cmd {
+            tx_monitor_cmd
+                .switch_node(event_source_node)
+                .map_err(Error::event_source)?;
+        }
+
+        self.config = config;
+        self.tx_config = tx_config;
+        self.rpc_client = rpc_client;
+        self.grpc_addr = grpc_addr;
+        self.light_client = light_client;
+        self.endpoints.set_active(index);
+
+        crate::telemetry!(
+            endpoint_switch,
+            self.id(),
+            &from.rpc_addr.to_string(),
+            &to.rpc_addr.to_string()
+        );
+
+        Ok(())
+    }
+
     fn init_event_source(&mut self) -> Result<TxEventSourceCmd, Error> {
         crate::time!(
             "init_event_source",
@@ -556,20 +644,213 @@ impl CosmosSdkChain {
             return Err(Error::private_store());
         }
 
+        let verify = self.config.verified_queries.is_some() && data.is_provable();
+
         let response = self.block_on(abci_query(
             &self.rpc_client,
             &self.config.rpc_addr,
             IBC_QUERY_PATH.to_string(),
             data.to_string(),
             height_query.into(),
-            prove,
+            prove || verify,
         ))?;
 
-        // TODO: Verify response proof, if requested.
+        if verify {
+            self.verify_query_response(&data, &response)?;
+        }
 
         Ok(response)
     }
 
+    /// Verify the proof included in the response to a query of `path` against the app hash
+    /// of the header following the one the query was performed at, as verified by the light
+    /// client starting from the trusted header configured in `verified_queries`.
+    fn verify_query_response(&self, path: &Path, response: &QueryResponse) -> Result<(), Error> {
+        let Some(verified_queries) = &self.config.verified_queries else {
+            return Ok(());
+        };
+
+        crate::time!(
+            "verify_query_response",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+
+        let root = self.verified_query_root(verified_queries, response.height.value())?;
+
+        self.verify_query_proof(path, response, &root)
+    }
+
+    /// The app hash of the state at `query_height`, taken from the next header, as verified
+    /// by the light client starting from the trusted header configured in `verified_queries`.
+    fn verified_query_root(
+        &self,
+        verified_queries: &VerifiedQueriesConfig,
+        query_height: u64,
+    ) -> Result<CommitmentRoot, Error> {
+        let header_height = ICSHeight::new(self.id().version(), query_height + 1)
+            .map_err(|_| Error::invalid_height_no_source())?;
+
+        self.wait_for_height(header_height)?;
+
+        let light_block = self.light_client.verify_for_query(
+            verified_queries,
+            header_height,
+            self.verified_query_options()?,
+            TmTime::now(),
+        )?;
+
+        Ok(CommitmentRoot::from_bytes(
+            light_block.signed_header.header.app_hash.as_bytes(),
+        ))
+    }
+
+    /// Verify the proof included in the response to a query of `path` against `root`.
+    fn verify_query_proof(
+        &self,
+        path: &Path,
+        response: &QueryResponse,
+        root: &CommitmentRoot,
+    ) -> Result<(), Error> {
+        let proof = response
+            .proof
+            .as_ref()
+            .ok_or_else(Error::empty_response_proof)?;
+
+        let merkle_path = apply_prefix(&self.commitment_prefix, vec![path.to_string()]);
+        let proof_specs = self.config.proof_specs.clone().unwrap_or_default();
+
+        // An empty value means that nothing is stored at the path
+        let result = if response.value.is_empty() {
+            proof.verify_non_membership(&proof_specs, root.clone().into(), merkle_path)
+        } else {
+            proof.verify_membership(
+                &proof_specs,
+                root.clone().into(),
+                merkle_path,
+                response.value.clone(),
+                0,
+            )
+        };
+
+        result.map_err(|e| {
+            Error::query_proof_verification(
+                self.id().clone(),
+                path.to_string(),
+                response.height.value(),
+                e,
+            )
+        })
+    }
+
+    /// The options of the light client verifying the headers used by verified queries
+    fn verified_query_options(&self) -> Result<LightClientOptions, Error> {
+        let trusting_period = match self.config.trusting_period {
+            Some(trusting_period) => trusting_period,
+            None => self.trusting_period(self.unbonding_period()?),
+        };
+
+        Ok(LightClientOptions {
+            trust_threshold: self.config.trust_threshold.into(),
+            trusting_period,
+            clock_drift: self.config.clock_drift,
+        })
+    }
+
+    /// With verified queries, keep only the sequences among `sequences` whose packet commitment
+    /// is proven to be stored at `height`, as the responses to gRPC queries carry no proof.
+    ///
+    /// The header at `height + 1` is verified once, and the proofs of the commitments are then
+    /// queried concurrently, at most `query_packets_chunk_size` at a time.
+    ///
+    /// Note that a full node can still hide packet commitments by omitting their sequence.
+    fn retain_proven_commitments(
+        &self,
+        request: &QueryPacketCommitmentsRequest,
+        sequences: Vec<Sequence>,
+        height: ICSHeight,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let Some(verified_queries) = &self.config.verified_queries else {
+            return Ok((sequences, height));
+        };
+
+        if sequences.is_empty() {
+            return Ok((sequences, height));
+        }
+
+        crate::time!(
+            "retain_proven_commitments",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+
+        let root = self.verified_query_root(verified_queries, height.revision_height())?;
+
+        let mut proven = Vec::with_capacity(sequences.len());
+
+        for chunk in sequences.chunks(self.config.query_packets_chunk_size.max(1)) {
+            let paths = chunk
+                .iter()
+                .map(|&sequence| {
+                    Path::from(CommitmentsPath {
+                        port_id: request.port_id.clone(),
+                        channel_id: request.channel_id.clone(),
+                        sequence,
+                    })
+                })
+                .collect_vec();
+
+            let responses = self.block_on(join_all(paths.iter().map(|path| {
+                abci_query(
+                    &self.rpc_client,
+                    &self.config.rpc_addr,
+                    IBC_QUERY_PATH.to_string(),
+                    path.to_string(),
+                    QueryHeight::Specific(height).into(),
+                    true,
+                )
+            })));
+
+            for ((&sequence, path), response) in chunk.iter().zip(&paths).zip(responses) {
+                let response = response?;
+
+                self.verify_query_proof(path, &response, &root)?;
+
+                if response.value.is_empty() {
+                    warn!(
+                        chain = %self.id(), port = %request.port_id, channel = %request.channel_id, %sequence, %height,
+                        "ignoring packet commitment reported by the full node but proven absent"
+                    );
+                } else {
+                    proven.push(sequence);
+                }
+            }
+        }
+
+        Ok((proven, height))
+    }
+
+    /// Wait for the chain to reach `height`, for at most `max_block_time`.
+    fn wait_for_height(&self, height: ICSHeight) -> Result<(), Error> {
+        let deadline = Instant::now() + self.config.max_block_time;
+
+        while self.query_chain_latest_height()? < height {
+            if Instant::now() >= deadline {
+                return Err(Error::query_header_timeout(
+                    self.id().clone(),
+                    height.revision_height(),
+                    self.config.max_block_time,
+                ));
+            }
+
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        Ok(())
+    }
+
     /// Perform an ABCI query against the client upgrade sub-store.
     ///
     /// The data is returned in its raw format `Vec<u8>`, and is either the
@@ -700,34 +981,53 @@ impl CosmosSdkChain {
 
         let proto_msgs = tracked_msgs.msgs;
 
-        let key_pair = self.key()?;
-        let key_account = key_pair.account();
-
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
-
         let memo_prefix = if let Some(memo_overwrite) = &self.config.memo_overwrite {
             memo_overwrite.clone()
         } else {
             self.config.memo_prefix.clone()
         };
 
-        if self.config.sequential_batch_tx {
-            sequential_send_batched_messages_and_wait_commit(
-                &self.rpc_client,
-                &self.tx_config,
-                &key_pair,
-                account,
-                &memo_prefix,
-                proto_msgs,
-            )
-            .await
+        // Transactions must be committed in order when `sequential_batch_tx` is set,
+        // so only the account of `key_name` is used in that case
+        if self.config.sequential_batch_tx || self.accounts.len() == 1 {
+            let key_pair = self.key()?;
+            let key_account = key_pair.account();
+
+            let account =
+                get_or_fetch_account(&self.grpc_addr, &key_account, self.accounts.primary_mut())
+                    .await?;
+
+            if self.config.sequential_batch_tx {
+                sequential_send_batched_messages_and_wait_commit(
+                    &self.rpc_client,
+                    &self.tx_config,
+                    &key_pair,
+                    account,
+                    &memo_prefix,
+                    proto_msgs,
+                )
+                .await
+            } else {
+                send_batched_messages_and_wait_commit(
+                    &self.rpc_client,
+                    &self.tx_config,
+                    &key_pair,
+                    account,
+                    &memo_prefix,
+                    proto_msgs,
+                )
+                .await
+            }
         } else {
-            send_batched_messages_and_wait_commit(
+            let mut senders = self
+                .accounts
+                .senders(&self.grpc_addr, &self.keybase)
+                .await?;
+
+            send_batched_messages_from_accounts_and_wait_commit(
                 &self.rpc_client,
                 &self.tx_config,
-                &key_pair,
-                account,
+                &mut senders,
                 &memo_prefix,
                 proto_msgs,
             )
@@ -757,27 +1057,76 @@ impl CosmosSdkChain {
 
         let proto_msgs = tracked_msgs.msgs;
 
-        let key_pair = self.key()?;
-        let key_account = key_pair.account();
+        let memo_prefix = if let Some(memo_overwrite) = &self.config.memo_overwrite {
+            memo_overwrite.clone()
+        } else {
+            self.config.memo_prefix.clone()
+        };
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        if self.accounts.len() == 1 {
+            let key_pair = self.key()?;
+            let key_account = key_pair.account();
+
+            let account =
+                get_or_fetch_account(&self.grpc_addr, &key_account, self.accounts.primary_mut())
+                    .await?;
+
+            return send_batched_messages_and_wait_check_tx(
+                &self.rpc_client,
+                &self.tx_config,
+                &key_pair,
+                account,
+                &memo_prefix,
+                proto_msgs,
+            )
+            .await;
+        }
 
+        let mut senders = self
+            .accounts
+            .senders(&self.grpc_addr, &self.keybase)
+            .await?;
+
+        send_batched_messages_from_accounts_and_wait_check_tx(
+            &self.rpc_client,
+            &self.tx_config,
+            &mut senders,
+            &memo_prefix,
+            proto_msgs,
+        )
+        .await
+    }
+
+    /// Estimate the fees of a transaction with the given messages,
+    /// as submitted from the account of `key_name`
+    async fn do_estimate_fees(&mut self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
         let memo_prefix = if let Some(memo_overwrite) = &self.config.memo_overwrite {
             memo_overwrite.clone()
         } else {
             self.config.memo_prefix.clone()
         };
 
-        send_batched_messages_and_wait_check_tx(
-            &self.rpc_client,
+        let key_pair = self.key()?;
+        let key_account = key_pair.account();
+
+        let account =
+            get_or_fetch_account(&self.grpc_addr, &key_account, self.accounts.primary_mut())
+                .await?;
+
+        let (fee, _) = estimate_tx_fees(
             &self.tx_config,
             &key_pair,
             account,
             &memo_prefix,
-            proto_msgs,
+            &tracked_msgs.msgs,
         )
-        .await
+        .await?;
+
+        fee.amount
+            .into_iter()
+            .map(RawCoin::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|e| Error::invalid_fee(self.config.id.clone(), e))
     }
 
     fn query_packet_from_block(
@@ -928,12 +1277,19 @@ impl ChainEndpoint for CosmosSdkChain {
         let light_client = TmLightClient::from_cosmos_sdk_config(&config, node_info.id)?;
 
         // Initialize key store and load key
-        let keybase = KeyRing::new_secp256k1(
-            config.key_store_type,
-            &config.account_prefix,
-            &config.id,
-            &config.key_store_folder,
-        )
+        let keybase = match (config.key_store_type, &config.remote_signer) {
+            (Store::Remote, Some(signer)) => Ok(KeyRing::new_remote(
+                signer,
+                &config.account_prefix,
+                &config.address_type,
+            )),
+            (key_store_type, _) => KeyRing::new_secp256k1(
+                key_store_type,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+            ),
+        }
         .map_err(Error::key_base)?;
 
         let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
@@ -941,6 +1297,13 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let tx_config = TxConfig::try_from(&config)?;
 
+        let endpoints = EndpointPool::from_config(&config, compat_mode)?;
+
+        let accounts = AccountPool::new(&config.key_name, &config.key_names);
+
+        let commitment_prefix = CommitmentPrefix::try_from(config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))?;
+
         // Retrieve the version specification of this chain
 
         let chain = Self {
@@ -952,8 +1315,10 @@ impl ChainEndpoint for CosmosSdkChain {
             rt,
             keybase,
             tx_config,
-            account: None,
+            accounts,
             tx_monitor_cmd: None,
+            endpoints,
+            commitment_prefix,
         };
 
         Ok(chain)
@@ -1030,6 +1395,20 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(HealthCheck::Healthy)
     }
 
+    fn maintenance_interval(&self) -> Option<Duration> {
+        self.endpoints
+            .has_backups()
+            .then_some(self.endpoints.check_interval())
+    }
+
+    fn maintain(&mut self) -> Result<(), Error> {
+        self.maintain_endpoints()
+    }
+
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error> {
+        Ok(self.endpoints.status())
+    }
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -1098,6 +1477,12 @@ impl ChainEndpoint for CosmosSdkChain {
         runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs))
     }
 
+    fn estimate_fees(&mut self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        let runtime = self.rt.clone();
+
+        runtime.block_on(self.do_estimate_fees(tracked_msgs))
+    }
+
     /// Get the account for the signer
     fn get_signer(&self) -> Result<Signer, Error> {
         // Get the key from key seed file
@@ -1132,7 +1517,10 @@ impl ChainEndpoint for CosmosSdkChain {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
         let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
+            Some(key_name) => self
+                .keybase()
+                .get_signer(key_name)
+                .map_err(Error::key_base)?,
             None => self.key()?,
         };
         let account = key.account();
@@ -1153,7 +1541,10 @@ impl ChainEndpoint for CosmosSdkChain {
         // If a key_name is given, extract the account hash.
         // Else retrieve the account from the configuration file.
         let key = match key_name {
-            Some(key_name) => self.keybase().get_key(key_name).map_err(Error::key_base)?,
+            Some(key_name) => self
+                .keybase()
+                .get_signer(key_name)
+                .map_err(Error::key_base)?,
             None => self.key()?,
         };
         let account = key.account();
@@ -1169,6 +1560,20 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(denom_trace)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        crate::telemetry!(query, self.id(), "query_interchain_account");
+
+        self.block_on(query_interchain_account(
+            &self.grpc_addr,
+            &owner,
+            &connection_id,
+        ))
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!(
             "query_commitment_prefix",
@@ -1179,8 +1584,7 @@ impl ChainEndpoint for CosmosSdkChain {
         crate::telemetry!(query, self.id(), "query_commitment_prefix");
 
         // TODO - do a real chain query
-        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
-            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+        Ok(self.commitment_prefix.clone())
     }
 
     /// Query the application status
@@ -1387,13 +1791,6 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
-        if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
-            return Err(Error::consensus_state_type_mismatch(
-                ClientType::Tendermint,
-                consensus_state.client_type(),
-            ));
-        }
-
         match include_proof {
             IncludeProof::Yes => {
                 let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
@@ -1573,6 +1970,23 @@ impl ChainEndpoint for CosmosSdkChain {
                     Some(res.proof.ok_or_else(Error::empty_response_proof)?),
                 ))
             }
+            // The responses to gRPC queries carry no proof, so verified queries go through ABCI
+            IncludeProof::No if self.config.verified_queries.is_some() => {
+                let res = self.query(
+                    ConnectionsPath(request.connection_id.clone()),
+                    request.height,
+                    false,
+                )?;
+
+                if res.value.is_empty() {
+                    return Err(Error::connection_not_found(request.connection_id));
+                }
+
+                let connection_end =
+                    ConnectionEnd::decode_vec(&res.value).map_err(Error::decode)?;
+
+                Ok((connection_end, None))
+            }
             IncludeProof::No => self
                 .block_on(async {
                     do_query_connection(self, &request.connection_id, request.height).await
@@ -1950,7 +2364,7 @@ impl ChainEndpoint for CosmosSdkChain {
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
 
-            Ok((commitment_sequences, height))
+            self.retain_proven_commitments(&request, commitment_sequences, height)
         } else {
             let mut tonic_request = tonic::Request::new(request.clone().into());
 
@@ -1975,7 +2389,7 @@ impl ChainEndpoint for CosmosSdkChain {
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or_else(|| Error::grpc_response_param("height".to_string()))?;
 
-            Ok((commitment_sequences, height))
+            self.retain_proven_commitments(&request, commitment_sequences, height)
         }
     }
 
@@ -2507,7 +2921,7 @@ impl ChainEndpoint for CosmosSdkChain {
             &self.rpc_client,
             &self.tx_config,
             &key_pair,
-            &mut self.account,
+            self.accounts.primary_mut(),
             &memo_prefix,
             channel_id,
             port_id,
@@ -2544,6 +2958,20 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(incentivized_response)
     }
 
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        crate::telemetry!(query, self.id(), "query_incentivized_packets");
+
+        self.block_on(query_incentivized_packets(
+            &self.grpc_addr,
+            &channel_id,
+            &port_id,
+        ))
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         use ibc_proto::interchain_security::ccv::provider::v1::ConsumerPhase;
         use ibc_proto::interchain_security::ccv::provider::v1::QueryConsumerChainsRequest;
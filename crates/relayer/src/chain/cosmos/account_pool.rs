@@ -0,0 +1,303 @@
+//! Submitting transactions from several accounts of the same chain.
+//!
+//! IBC messages are always built with the account of `key_name` as their signer.
+//! The accounts of the additional keys listed in `key_names` submit messages on its
+//! behalf by wrapping them in an authz `MsgExec`. Each of these accounts must therefore
+//! have been granted an authorization for the relevant IBC messages by the account
+//! of `key_name`, eg. with `<chain-binary> tx authz grant <grantee> generic --msg-type ...`.
+//!
+//! Every account tracks its own sequence number, so that transactions submitted from
+//! different accounts can be in flight at the same time.
+//!
+//! The keys and accounts are loaded once and then cached. The additional accounts whose
+//! key or account cannot be loaded are skipped for a while, rather than failing the
+//! submission of the transactions, which then only fails if the account of `key_name`
+//! cannot be loaded.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use http::Uri;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+use tracing::warn;
+
+use crate::chain::cosmos::query::account::get_or_fetch_account;
+use crate::chain::cosmos::types::account::Account;
+use crate::error::Error;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, Secp256k1Signer};
+
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+
+/// The maximum length of the encoding of a varint
+const MAX_VARINT_LEN: usize = 10;
+
+/// How long to skip an additional account for after failing to load it
+const FAILED_ACCOUNT_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// `MsgExec` attempts to execute the provided messages on behalf of the
+/// granter, using the authorizations granted to the grantee.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: ::prost::alloc::vec::Vec<Any>,
+}
+
+/// An account to submit transactions from
+pub struct TxSender<'a> {
+    pub signer: Arc<dyn Secp256k1Signer>,
+    pub account: &'a mut Account,
+
+    /// Whether messages must be executed on behalf of the account
+    /// of `key_name` through authz, rather than submitted as is
+    pub via_authz: bool,
+}
+
+impl<'a> TxSender<'a> {
+    pub fn new(signer: Arc<dyn Secp256k1Signer>, account: &'a mut Account) -> Self {
+        Self {
+            signer,
+            account,
+            via_authz: false,
+        }
+    }
+
+    /// The messages to put in a transaction submitted by this account
+    pub fn wrap_messages(&self, messages: Vec<Any>) -> Vec<Any> {
+        if !self.via_authz {
+            return messages;
+        }
+
+        let exec = MsgExec {
+            grantee: self.signer.account(),
+            msgs: messages,
+        };
+
+        vec![Any {
+            type_url: MSG_EXEC_TYPE_URL.to_string(),
+            value: exec.encode_to_vec(),
+        }]
+    }
+
+    /// An upper bound on the number of bytes [`TxSender::wrap_messages`] adds to a transaction.
+    ///
+    /// The messages keep the same encoding inside the `MsgExec`, which only adds its
+    /// own envelope: the grantee, the type URL and the length delimiters.
+    pub fn wrapping_overhead(&self) -> usize {
+        if !self.via_authz {
+            return 0;
+        }
+
+        let envelope = Any {
+            type_url: MSG_EXEC_TYPE_URL.to_string(),
+            value: MsgExec {
+                grantee: self.signer.account(),
+                msgs: Vec::new(),
+            }
+            .encode_to_vec(),
+        };
+
+        1 + envelope.encoded_len() + 2 * MAX_VARINT_LEN
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PoolAccount {
+    key_name: String,
+    signer: Option<Arc<dyn Secp256k1Signer>>,
+    account: Option<Account>,
+    failed_at: Option<Instant>,
+}
+
+impl PoolAccount {
+    /// Load the signer and account information, unless they are already cached
+    async fn load(
+        &mut self,
+        grpc_address: &Uri,
+        keybase: &KeyRing<Secp256k1KeyPair>,
+    ) -> Result<(), Error> {
+        if self.signer.is_none() {
+            let signer = keybase
+                .get_signer(&self.key_name)
+                .map_err(|e| Error::key_not_found(self.key_name.clone(), e))?;
+
+            self.signer = Some(signer);
+        }
+
+        if let Some(signer) = &self.signer {
+            get_or_fetch_account(grpc_address, &signer.account(), &mut self.account).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The sender for this account, if it is loaded
+    fn sender(&mut self, via_authz: bool) -> Option<TxSender<'_>> {
+        match (&self.signer, &mut self.account) {
+            (Some(signer), Some(account)) => Some(TxSender {
+                signer: signer.clone(),
+                account,
+                via_authz,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The accounts of a chain which transactions are submitted from, in round-robin order.
+/// The first account is the one of `key_name`, followed by the ones of `key_names`.
+#[derive(Clone, Debug)]
+pub struct AccountPool {
+    accounts: Vec<PoolAccount>,
+    next: usize,
+}
+
+impl AccountPool {
+    pub fn new(key_name: &str, key_names: &[String]) -> Self {
+        let accounts = core::iter::once(key_name)
+            .chain(
+                key_names
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|name| *name != key_name),
+            )
+            .map(|key_name| PoolAccount {
+                key_name: key_name.to_string(),
+                signer: None,
+                account: None,
+                failed_at: None,
+            })
+            .collect();
+
+        Self { accounts, next: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// The cached account information of `key_name`
+    pub fn primary_mut(&mut self) -> &mut Option<Account> {
+        &mut self.accounts[0].account
+    }
+
+    /// The accounts to submit the next transactions from, starting from the account
+    /// following the one which the previous transactions started from.
+    ///
+    /// Fails if the account of `key_name` cannot be loaded, while the additional
+    /// accounts which cannot be loaded are left out.
+    pub async fn senders(
+        &mut self,
+        grpc_address: &Uri,
+        keybase: &KeyRing<Secp256k1KeyPair>,
+    ) -> Result<Vec<TxSender<'_>>, Error> {
+        let start = self.next;
+        self.next = (self.next + 1) % self.accounts.len();
+
+        let mut senders = Vec::with_capacity(self.accounts.len());
+
+        for (index, entry) in self.accounts.iter_mut().enumerate() {
+            let is_primary = index == 0;
+
+            if !is_primary
+                && entry
+                    .failed_at
+                    .is_some_and(|failed_at| failed_at.elapsed() < FAILED_ACCOUNT_RETRY_DELAY)
+            {
+                continue;
+            }
+
+            match entry.load(grpc_address, keybase).await {
+                Ok(()) => entry.failed_at = None,
+                Err(e) if is_primary => return Err(e),
+                Err(e) => {
+                    warn!(
+                        "skipping the account of key '{}' for the next {}s, as it failed to load: {e}",
+                        entry.key_name,
+                        FAILED_ACCOUNT_RETRY_DELAY.as_secs()
+                    );
+
+                    entry.failed_at = Some(Instant::now());
+                    continue;
+                }
+            }
+
+            senders.extend(entry.sender(!is_primary));
+        }
+
+        senders.rotate_left(start % senders.len());
+
+        Ok(senders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    use crate::chain::cosmos::types::account::{AccountAddress, AccountNumber, AccountSequence};
+    use crate::config::AddressType;
+    use crate::keyring::{SigningKeyPair, Store};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn deduplicates_primary_key() {
+        let pool = AccountPool::new("relayer", &["relayer".to_string(), "relayer-2".to_string()]);
+
+        assert_eq!(pool.len(), 2);
+        assert_eq!(pool.accounts[0].key_name, "relayer");
+        assert_eq!(pool.accounts[1].key_name, "relayer-2");
+    }
+
+    #[tokio::test]
+    async fn skips_accounts_which_fail_to_load() {
+        let key_pair = Secp256k1KeyPair::from_mnemonic(
+            MNEMONIC,
+            &"m/44'/118'/0'/0/0".parse().unwrap(),
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap();
+
+        let mut keybase = KeyRing::new_secp256k1(
+            Store::Memory,
+            "cosmos",
+            &ChainId::from_string("chain"),
+            &None,
+        )
+        .unwrap();
+        let account = SigningKeyPair::account(&key_pair);
+        keybase.add_key("relayer", key_pair).unwrap();
+
+        let mut pool = AccountPool::new("relayer", &["missing".to_string()]);
+
+        // Only the account of `key_name` is known, so that no account needs to be fetched
+        *pool.primary_mut() = Some(Account {
+            address: AccountAddress::new(account.clone()),
+            number: AccountNumber::new(0),
+            sequence: AccountSequence::new(0),
+        });
+
+        let grpc_address: Uri = "http://127.0.0.1:9090".parse().unwrap();
+
+        for _ in 0..2 {
+            let senders = pool.senders(&grpc_address, &keybase).await.unwrap();
+
+            assert_eq!(senders.len(), 1);
+            assert_eq!(senders[0].signer.account(), account);
+            assert!(!senders[0].via_authz);
+        }
+
+        assert!(pool.accounts[1].failed_at.is_some());
+        assert!(pool.accounts[0].signer.is_some());
+    }
+}
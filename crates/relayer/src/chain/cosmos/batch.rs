@@ -1,5 +1,6 @@
 use core::mem;
 
+use futures::future::join_all;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::events::IbcEvent;
@@ -7,8 +8,9 @@ use ibc_relayer_types::Height;
 use prost::Message;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::HttpClient;
-use tracing::debug;
+use tracing::{debug, warn};
 
+use crate::chain::cosmos::account_pool::TxSender;
 use crate::chain::cosmos::encode::encoded_tx_metrics;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
@@ -19,7 +21,7 @@ use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::Secp256k1KeyPair;
+use crate::keyring::Secp256k1Signer;
 
 /**
    Broadcast messages as multiple batched transactions to the chain all at once,
@@ -31,7 +33,7 @@ use crate::keyring::Secp256k1KeyPair;
 pub async fn send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -60,6 +62,45 @@ pub async fn send_batched_messages_and_wait_commit(
     Ok(events)
 }
 
+/**
+   Same as [`send_batched_messages_and_wait_commit`], but with the batched
+   transactions spread in round-robin order across the given accounts,
+   starting with the first one. Each account submits its own transactions
+   one after another, while the accounts submit theirs concurrently.
+   Fails if any of the accounts fails to send one of its transactions.
+*/
+pub async fn send_batched_messages_from_accounts_and_wait_commit(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    senders: &mut [TxSender<'_>],
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut tx_sync_results =
+        send_messages_as_batches_from_accounts(rpc_client, config, senders, tx_memo, messages)
+            .await?;
+
+    wait_for_block_commits(
+        &config.chain_id,
+        rpc_client,
+        &config.rpc_address,
+        &config.rpc_timeout,
+        &mut tx_sync_results,
+    )
+    .await?;
+
+    let events = tx_sync_results
+        .into_iter()
+        .flat_map(|el| el.events)
+        .collect();
+
+    Ok(events)
+}
+
 /**
    Send batched messages one after another, only after the previous one
    has been committed. This is only used in case if parallel transactions
@@ -68,7 +109,7 @@ pub async fn send_batched_messages_and_wait_commit(
 pub async fn sequential_send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -93,7 +134,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
 pub async fn send_batched_messages_and_wait_check_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -118,10 +159,34 @@ pub async fn send_batched_messages_and_wait_check_tx(
     Ok(responses)
 }
 
+/**
+   Same as [`send_batched_messages_and_wait_check_tx`], but with the batched
+   transactions spread in round-robin order across the given accounts.
+   Fails if any of the accounts fails to send one of its transactions.
+*/
+pub async fn send_batched_messages_from_accounts_and_wait_check_tx(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    senders: &mut [TxSender<'_>],
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<Response>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let batches = batch_messages_for_senders(config, senders, tx_memo, messages).await?;
+
+    let results = send_batches_from_accounts(rpc_client, config, senders, tx_memo, batches).await;
+    let sent = merge_account_results(&config.chain_id, results)?;
+
+    Ok(sent.into_iter().map(|(_, _, response)| response).collect())
+}
+
 async fn send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -159,10 +224,134 @@ async fn send_messages_as_batches(
     Ok(tx_sync_results)
 }
 
+async fn send_messages_as_batches_from_accounts(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    senders: &mut [TxSender<'_>],
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<TxSyncResult>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let message_count = messages.len();
+
+    let batches = batch_messages_for_senders(config, senders, tx_memo, messages).await?;
+
+    debug!(
+        "sending {} messages as {} batches to chain {} in parallel from {} account(s)",
+        message_count,
+        batches.len(),
+        config.chain_id,
+        senders.len().min(batches.len()),
+    );
+
+    let results = send_batches_from_accounts(rpc_client, config, senders, tx_memo, batches).await;
+    let sent = merge_account_results(&config.chain_id, results)?;
+
+    let tx_sync_results = sent
+        .into_iter()
+        .map(|(_, message_count, response)| {
+            response_to_tx_sync_result(&config.chain_id, message_count, response)
+        })
+        .collect();
+
+    Ok(tx_sync_results)
+}
+
+/// The index, number of messages and response of a batch which was broadcast
+type SentBatch = (usize, usize, Response);
+
+/// Send the batches in round-robin order across the given accounts, and return
+/// the batches sent by each account. An account stops at its first failing batch,
+/// which does not stop the other accounts from sending theirs.
+async fn send_batches_from_accounts(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    senders: &mut [TxSender<'_>],
+    tx_memo: &Memo,
+    batches: Vec<Vec<Any>>,
+) -> Vec<Result<Vec<SentBatch>, Error>> {
+    let sender_count = senders.len();
+    assert!(
+        sender_count > 0,
+        "at least one account is needed to send messages"
+    );
+
+    let mut assigned_batches = vec![Vec::new(); sender_count];
+
+    for (index, batch) in batches.into_iter().enumerate() {
+        assigned_batches[index % sender_count].push((index, batch));
+    }
+
+    let tasks = senders
+        .iter_mut()
+        .zip(assigned_batches)
+        .map(|(sender, batches)| async move {
+            let mut sent = Vec::with_capacity(batches.len());
+
+            for (index, batch) in batches {
+                let message_count = batch.len();
+                let messages = sender.wrap_messages(batch);
+
+                let response = send_tx_with_account_sequence_retry(
+                    rpc_client,
+                    config,
+                    &*sender.signer,
+                    sender.account,
+                    tx_memo,
+                    &messages,
+                )
+                .await?;
+
+                sent.push((index, message_count, response));
+            }
+
+            Ok(sent)
+        });
+
+    join_all(tasks).await
+}
+
+/// Merge the batches sent by the accounts, in the order of the batches.
+/// Fails with the first error if any of the accounts failed to send one of its batches.
+fn merge_account_results(
+    chain_id: &ChainId,
+    results: Vec<Result<Vec<SentBatch>, Error>>,
+) -> Result<Vec<SentBatch>, Error> {
+    let mut sent = Vec::new();
+    let mut errors = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(batches) => sent.extend(batches),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if !errors.is_empty() {
+        if !sent.is_empty() {
+            warn!(
+                "{} of the batches were sent to chain {}, but {} account(s) failed to send theirs",
+                sent.len(),
+                chain_id,
+                errors.len()
+            );
+        }
+
+        return Err(errors.swap_remove(0));
+    }
+
+    sent.sort_by_key(|(index, _, _)| *index);
+
+    Ok(sent)
+}
+
 async fn sequential_send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -238,15 +427,56 @@ pub fn response_to_tx_sync_result(
     }
 }
 
+/// Split the messages into batches which fit in a transaction sent from any of the given accounts
+async fn batch_messages_for_senders(
+    config: &TxConfig,
+    senders: &[TxSender<'_>],
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<Vec<Any>>, Error> {
+    let sender = senders
+        .first()
+        .expect("at least one account is needed to send messages");
+
+    let reserved_len = senders
+        .iter()
+        .map(TxSender::wrapping_overhead)
+        .max()
+        .unwrap_or(0);
+
+    batch_messages_with_reserved_len(
+        config,
+        &*sender.signer,
+        &*sender.account,
+        tx_memo,
+        messages,
+        reserved_len,
+    )
+    .await
+}
+
 async fn batch_messages(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<Vec<Any>>, Error> {
+    batch_messages_with_reserved_len(config, key_pair, account, tx_memo, messages, 0).await
+}
+
+/// Split the messages into batches, leaving `reserved_len` bytes
+/// of every transaction free for wrapping the messages.
+async fn batch_messages_with_reserved_len(
+    config: &TxConfig,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
+    reserved_len: usize,
 ) -> Result<Vec<Vec<Any>>, Error> {
     let max_message_count = config.max_msg_num.to_usize();
-    let max_tx_size = config.max_tx_size.into();
+    let max_tx_size = usize::from(config.max_tx_size).saturating_sub(reserved_len);
 
     let mut batches = vec![];
 
@@ -316,7 +546,7 @@ async fn batch_messages(
 #[allow(clippy::redundant_clone)]
 #[cfg(test)]
 mod tests {
-    use super::batch_messages;
+    use super::{batch_messages, merge_account_results};
     use crate::chain::cosmos::encode::sign_and_encode_tx;
     use crate::chain::cosmos::gas::gas_amount_to_fee;
     use crate::chain::cosmos::types::account::{
@@ -325,10 +555,12 @@ mod tests {
     use crate::chain::cosmos::types::config::TxConfig;
     use crate::config;
     use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
+    use crate::error::Error;
     use crate::keyring::{self, KeyRing, Secp256k1KeyPair, SigningKeyPair};
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::core::ics24_host::identifier::ChainId;
     use std::fs;
+    use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 
     const COSMOS_HD_PATH: &str = "m/44'/118'/0'/0/0";
 
@@ -640,4 +872,39 @@ mod tests {
         )
         .await;
     }
+
+    #[test]
+    fn merge_fails_if_one_account_fails() {
+        let chain_id = ChainId::from_string("chain_A");
+        let response = |hash: u8| Response {
+            codespace: String::new(),
+            code: Default::default(),
+            data: Default::default(),
+            log: String::new(),
+            hash: tendermint::Hash::Sha256([hash; 32]),
+        };
+
+        let sent = merge_account_results(
+            &chain_id,
+            vec![
+                Ok(vec![(0, 2, response(0)), (2, 1, response(2))]),
+                Ok(vec![(1, 3, response(1))]),
+            ],
+        )
+        .unwrap();
+
+        let indices: Vec<_> = sent.iter().map(|(index, _, _)| *index).collect();
+        assert_eq!(indices, vec![0, 1, 2]);
+        assert_eq!(sent[1].2.hash, response(1).hash);
+
+        let result = merge_account_results(
+            &chain_id,
+            vec![
+                Ok(vec![(0, 2, response(0))]),
+                Err(Error::send_tx("account sequence mismatch".to_string())),
+            ],
+        );
+
+        assert!(result.is_err());
+    }
 }
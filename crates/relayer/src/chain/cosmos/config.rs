@@ -3,7 +3,8 @@ use std::path::PathBuf;
 
 use byte_unit::Byte;
 use serde_derive::{Deserialize, Serialize};
-use tendermint_rpc::Url;
+use tendermint::Hash;
+use tendermint_rpc::{Scheme, Url, WebSocketClientUrl};
 
 use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -12,11 +13,12 @@ use crate::chain::cosmos::config::error::Error as ConfigError;
 use crate::config::compat_mode::CompatMode;
 use crate::config::dynamic_gas::DynamicGasPrice;
 use crate::config::gas_multiplier::GasMultiplier;
-use crate::config::types::{MaxMsgNum, MaxTxSize, Memo, TrustThreshold};
+use crate::config::types::{MaxMsgNum, MaxTxSize, Memo, TokenAmount, TrustThreshold};
 use crate::config::{
     self, AddressType, EventSourceMode, ExtensionOption, GasPrice, GenesisRestart, PacketFilter,
 };
 use crate::config::{default, RefreshRate};
+use crate::keyring::remote::RemoteSignerConfig;
 use crate::keyring::Store;
 use crate::util::excluded_sequences::ExcludedSequences;
 
@@ -45,11 +47,27 @@ pub struct CosmosSdkConfig {
     #[serde(default = "default::trusted_node")]
     pub trusted_node: bool,
 
+    /// Additional full nodes against which to cross-check the headers of client
+    /// updates when detecting misbehaviour, on top of the node at `rpc_addr`
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub witnesses: Vec<Url>,
+
     pub account_prefix: String,
     pub key_name: String,
+
+    /// Additional keys whose accounts submit transactions alongside the one of `key_name`,
+    /// on behalf of the latter through authz grants
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_names: Vec<String>,
+
     #[serde(default)]
     pub key_store_type: Store,
     pub key_store_folder: Option<PathBuf>,
+
+    /// The signer holding the key named `key_name`, when `key_store_type` is `Remote`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<RemoteSignerConfig>,
+
     pub store_prefix: String,
     pub default_gas: Option<u64>,
     pub max_gas: Option<u64>,
@@ -152,12 +170,169 @@ pub struct CosmosSdkConfig {
 
     #[serde(default = "default::allow_ccq")]
     pub allow_ccq: bool,
+
+    /// Additional full nodes to fail over to when the node configured via
+    /// `rpc_addr`, `grpc_addr` and `event_source` becomes unhealthy,
+    /// listed in order of preference.
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub backup_endpoints: Vec<NodeEndpoint>,
+
+    /// Settings for detecting unhealthy full nodes and switching between them
+    #[serde(default)]
+    pub failover: FailoverConfig,
+
+    /// Balance thresholds of the wallet of `key_name`, and settings for topping it up
+    #[serde(default)]
+    pub wallet: WalletConfig,
+
+    /// Settings for verifying the results of the queries Hermes acts on against
+    /// headers verified by the light client, when the full node is not trusted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified_queries: Option<VerifiedQueriesConfig>,
+}
+
+/// The addresses of a single full node
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeEndpoint {
+    /// The RPC URL to connect to
+    pub rpc_addr: Url,
+
+    /// The gRPC URL to connect to
+    pub grpc_addr: Url,
+
+    /// The WebSocket URL to collect events from when using a push event source.
+    /// Defaults to the `/websocket` endpoint of `rpc_addr`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websocket_addr: Option<WebSocketClientUrl>,
+}
+
+impl NodeEndpoint {
+    /// The WebSocket URL of this node, either as configured or derived from its RPC URL.
+    pub fn websocket_addr(&self) -> Result<WebSocketClientUrl, tendermint_rpc::Error> {
+        if let Some(url) = &self.websocket_addr {
+            return Ok(url.clone());
+        }
+
+        let scheme = match self.rpc_addr.scheme() {
+            Scheme::Https | Scheme::SecureWebSocket => "wss",
+            Scheme::Http | Scheme::WebSocket => "ws",
+        };
+
+        format!(
+            "{scheme}://{}:{}/websocket",
+            self.rpc_addr.host(),
+            self.rpc_addr.port()
+        )
+        .parse()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FailoverConfig {
+    /// How often the health of every configured full node is probed
+    #[serde(default = "default::failover_check_interval", with = "humantime_serde")]
+    pub check_interval: Duration,
+
+    /// How many blocks a node may lag behind the highest known
+    /// height before it is considered unhealthy
+    #[serde(default = "default::failover_max_height_lag")]
+    pub max_height_lag: u64,
+
+    /// How many consecutive failed or lagging probes mark a node as unhealthy,
+    /// and how many consecutive successful probes mark it as healthy again
+    #[serde(default = "default::failover_threshold")]
+    pub threshold: u32,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: default::failover_check_interval(),
+            max_height_lag: default::failover_max_height_lag(),
+            threshold: default::failover_threshold(),
+        }
+    }
+}
+
+/// Balance thresholds of the wallet Hermes relays with, expressed
+/// in the denomination of `gas_price`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WalletConfig {
+    /// Below this balance, the balance is reported as low
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_balance: Option<TokenAmount>,
+
+    /// Below this balance, the balance is reported as critical
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_balance: Option<TokenAmount>,
+
+    /// Whether to stop relaying packets to the chain while the balance is critical,
+    /// rather than spending the last funds on transactions which may fail
+    #[serde(default)]
+    pub pause_on_critical: bool,
+
+    /// Settings for topping up the wallet when its balance is low
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_up: Option<TopUpConfig>,
+}
+
+impl WalletConfig {
+    /// The balance below which the wallet is topped up
+    pub fn top_up_threshold(&self) -> Option<TokenAmount> {
+        self.min_balance.or(self.critical_balance)
+    }
+}
+
+/// Settings for topping up the wallet Hermes relays with,
+/// with funds transferred from another wallet on the same chain
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TopUpConfig {
+    /// The key of the wallet the funds are transferred from
+    pub key_name: String,
+
+    /// The amount transferred on every top-up
+    pub amount: TokenAmount,
+
+    /// The minimum time between two top-ups, so that the wallet is
+    /// not topped up again before the previous top-up is reflected
+    /// in its balance
+    #[serde(default = "default::top_up_cooldown", with = "humantime_serde")]
+    pub cooldown: Duration,
+}
+
+/// The header from which the light client verifies the headers whose app hashes
+/// the proofs of verified queries are checked against.
+///
+/// It must be obtained from a trusted source, and be within the trusting period
+/// of the chain when Hermes starts.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct VerifiedQueriesConfig {
+    /// The height of the trusted header
+    pub trusted_height: u64,
+
+    /// The hash of the trusted header
+    pub trusted_hash: Hash,
 }
 
 impl CosmosSdkConfig {
     pub fn validate(&self) -> Result<(), Diagnostic<ConfigError>> {
         validate_trust_threshold(&self.id, self.trust_threshold)?;
         validate_gas_settings(&self.id, self.gas_adjustment)?;
+        validate_failover(&self.id, &self.failover)?;
+        validate_remote_signer(&self.id, self.key_store_type, &self.remote_signer)?;
+        validate_key_names(
+            &self.id,
+            &self.key_name,
+            &self.key_names,
+            self.sequential_batch_tx,
+        )?;
+        validate_wallet(&self.id, &self.key_name, &self.wallet)?;
+        validate_verified_queries(&self.id, self.trusted_node, &self.verified_queries)?;
         Ok(())
     }
 }
@@ -215,6 +390,154 @@ fn validate_gas_settings(
 
     Ok(())
 }
+
+fn validate_failover(
+    id: &ChainId,
+    failover: &FailoverConfig,
+) -> Result<(), Diagnostic<ConfigError>> {
+    if failover.threshold == 0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_failover_config(
+            id.clone(),
+            "`failover.threshold` must be greater than zero".to_string(),
+        )));
+    }
+
+    if failover.check_interval.is_zero() {
+        return Err(Diagnostic::Error(ConfigError::invalid_failover_config(
+            id.clone(),
+            "`failover.check_interval` must be greater than zero".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_key_names(
+    id: &ChainId,
+    key_name: &str,
+    key_names: &[String],
+    sequential_batch_tx: bool,
+) -> Result<(), Diagnostic<ConfigError>> {
+    let mut seen = std::collections::BTreeSet::new();
+
+    if let Some(duplicate) = key_names.iter().find(|name| !seen.insert(name.as_str())) {
+        return Err(Diagnostic::Error(ConfigError::invalid_key_names(
+            id.clone(),
+            format!("key `{duplicate}` is listed more than once"),
+        )));
+    }
+
+    if key_names.iter().any(|name| name == key_name) {
+        return Err(Diagnostic::Warning(ConfigError::invalid_key_names(
+            id.clone(),
+            format!("key `{key_name}` is already used as `key_name`"),
+        )));
+    }
+
+    if sequential_batch_tx && !key_names.is_empty() {
+        return Err(Diagnostic::Warning(ConfigError::invalid_key_names(
+            id.clone(),
+            "only `key_name` is used when `sequential_batch_tx` is enabled".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_wallet(
+    id: &ChainId,
+    key_name: &str,
+    wallet: &WalletConfig,
+) -> Result<(), Diagnostic<ConfigError>> {
+    if let Some(top_up) = &wallet.top_up {
+        if top_up.key_name == key_name {
+            return Err(Diagnostic::Error(ConfigError::invalid_wallet_config(
+                id.clone(),
+                format!("`wallet.top_up.key_name` must differ from `key_name`, found `{key_name}`"),
+            )));
+        }
+    }
+
+    if let (Some(min_balance), Some(critical_balance)) =
+        (wallet.min_balance, wallet.critical_balance)
+    {
+        if critical_balance > min_balance {
+            return Err(Diagnostic::Warning(ConfigError::invalid_wallet_config(
+                id.clone(),
+                format!("`wallet.critical_balance` ({critical_balance}) is greater than `wallet.min_balance` ({min_balance})"),
+            )));
+        }
+    }
+
+    if wallet.pause_on_critical && wallet.critical_balance.is_none() {
+        return Err(Diagnostic::Warning(ConfigError::invalid_wallet_config(
+            id.clone(),
+            "`wallet.pause_on_critical` is ignored unless `wallet.critical_balance` is set"
+                .to_string(),
+        )));
+    }
+
+    if wallet.top_up.is_some() && wallet.top_up_threshold().is_none() {
+        return Err(Diagnostic::Warning(ConfigError::invalid_wallet_config(
+            id.clone(),
+            "`wallet.top_up` is ignored unless `wallet.min_balance` or `wallet.critical_balance` is set"
+                .to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_verified_queries(
+    id: &ChainId,
+    trusted_node: bool,
+    verified_queries: &Option<VerifiedQueriesConfig>,
+) -> Result<(), Diagnostic<ConfigError>> {
+    match verified_queries {
+        Some(_) if trusted_node => Err(Diagnostic::Error(
+            ConfigError::invalid_verified_queries_config(
+                id.clone(),
+                "`verified_queries` requires `trusted_node = false`".to_string(),
+            ),
+        )),
+        Some(config) if config.trusted_height == 0 => Err(Diagnostic::Error(
+            ConfigError::invalid_verified_queries_config(
+                id.clone(),
+                "`verified_queries.trusted_height` must be greater than zero".to_string(),
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_remote_signer(
+    id: &ChainId,
+    key_store_type: Store,
+    remote_signer: &Option<RemoteSignerConfig>,
+) -> Result<(), Diagnostic<ConfigError>> {
+    match (key_store_type, remote_signer) {
+        (Store::Remote, None) => Err(Diagnostic::Error(
+            ConfigError::invalid_remote_signer_config(
+                id.clone(),
+                "`remote_signer` must be set when `key_store_type` is `Remote`".to_string(),
+            ),
+        )),
+        (Store::Remote, Some(signer)) if signer.timeout.is_zero() => Err(Diagnostic::Error(
+            ConfigError::invalid_remote_signer_config(
+                id.clone(),
+                "`remote_signer.timeout` must be greater than zero".to_string(),
+            ),
+        )),
+        (Store::Memory | Store::Test | Store::Encrypted, Some(_)) => Err(Diagnostic::Warning(
+            ConfigError::invalid_remote_signer_config(
+                id.clone(),
+                "`remote_signer` is ignored unless `key_store_type` is `Remote`".to_string(),
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
@@ -31,6 +31,56 @@ define_error! {
             )
         },
 
+        InvalidFailoverConfig
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `failover` configuration for the chain '{0}', caused by: {1}",
+                e.chain_id, e.reason)
+        },
+
+        InvalidWalletConfig
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `wallet` configuration for the chain '{0}', caused by: {1}",
+                e.chain_id, e.reason)
+        },
+
+        InvalidRemoteSignerConfig
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `remote_signer` configuration for the chain '{0}', caused by: {1}",
+                e.chain_id, e.reason)
+        },
+
+        InvalidVerifiedQueriesConfig
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `verified_queries` configuration for the chain '{0}', caused by: {1}",
+                e.chain_id, e.reason)
+        },
+
+        InvalidKeyNames
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies invalid `key_names` for the chain '{0}', caused by: {1}",
+                e.chain_id, e.reason)
+        },
+
         ExpectedExcludedSequencesArray
         |_| { "expected excluded_sequences to be an array of values" },
 
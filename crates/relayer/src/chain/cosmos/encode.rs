@@ -15,11 +15,11 @@ use crate::chain::cosmos::types::tx::SignedTx;
 use crate::config::types::Memo;
 use crate::config::AddressType;
 use crate::error::Error;
-use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::Secp256k1Signer;
 
 pub fn sign_and_encode_tx(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -46,7 +46,7 @@ pub struct EncodedTxMetrics {
 
 pub fn encoded_tx_metrics(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -76,7 +76,7 @@ pub fn encoded_tx_metrics(
 
 pub fn sign_tx(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -108,10 +108,10 @@ pub fn sign_tx(
     })
 }
 
-fn encode_key_bytes(key_pair: &Secp256k1KeyPair) -> Result<Vec<u8>, Error> {
+fn encode_key_bytes(key_pair: &dyn Secp256k1Signer) -> Result<Vec<u8>, Error> {
     let mut pk_buf = Vec::new();
 
-    Message::encode(&key_pair.public_key.serialize().to_vec(), &mut pk_buf)
+    Message::encode(&key_pair.public_key().serialize().to_vec(), &mut pk_buf)
         .map_err(|e| Error::protobuf_encode("PublicKey".into(), e))?;
 
     Ok(pk_buf)
@@ -119,7 +119,7 @@ fn encode_key_bytes(key_pair: &Secp256k1KeyPair) -> Result<Vec<u8>, Error> {
 
 fn encode_sign_doc(
     chain_id: &ChainId,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account_number: AccountNumber,
     auth_info_bytes: Vec<u8>,
     body_bytes: Vec<u8>,
@@ -226,7 +226,7 @@ fn tx_body_and_bytes(
     Ok((body, body_buf))
 }
 
-pub fn key_pair_to_signer(key_pair: &Secp256k1KeyPair) -> Result<Signer, Error> {
+pub fn key_pair_to_signer(key_pair: &dyn Secp256k1Signer) -> Result<Signer, Error> {
     let signer = key_pair
         .account()
         .parse()
@@ -0,0 +1,327 @@
+//! Health-scored failover between the full nodes configured for a Cosmos SDK chain.
+//!
+//! Every node is periodically probed for its latest application height.
+//! A node which fails to answer, or whose height lags too far behind
+//! the highest height reported by any node, accumulates failures and is
+//! eventually marked as unhealthy. The chain always uses the first healthy
+//! node in the configured order of preference, which means that it moves
+//! back to the preferred node as soon as the latter has recovered.
+
+use core::time::Duration;
+
+use futures::future::join_all;
+use tendermint_rpc::{client::CompatMode, Client, HttpClient};
+
+use crate::chain::cosmos::config::{CosmosSdkConfig, FailoverConfig, NodeEndpoint};
+use crate::chain::endpoint::NodeStatus;
+use crate::error::Error;
+use crate::HERMES_VERSION;
+
+/// The outcome of probing a single node for its latest height
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Probe {
+    Height(u64),
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+struct NodeState {
+    endpoint: NodeEndpoint,
+    rpc_client: HttpClient,
+    healthy: bool,
+    consecutive_failures: u32,
+    consecutive_successes: u32,
+    latest_height: Option<u64>,
+    last_error: Option<String>,
+}
+
+/// The set of full nodes a chain can fail over between, in order of preference.
+#[derive(Clone, Debug)]
+pub struct EndpointPool {
+    nodes: Vec<NodeState>,
+    active: usize,
+    config: FailoverConfig,
+    rpc_timeout: Duration,
+}
+
+impl EndpointPool {
+    /// Build the pool of nodes from the given chain configuration, with the node
+    /// configured through `rpc_addr` and `grpc_addr` as the preferred one.
+    pub fn from_config(config: &CosmosSdkConfig, compat_mode: CompatMode) -> Result<Self, Error> {
+        let primary = NodeEndpoint {
+            rpc_addr: config.rpc_addr.clone(),
+            grpc_addr: config.grpc_addr.clone(),
+            websocket_addr: match &config.event_source {
+                crate::config::EventSourceMode::Push { url, .. } => Some(url.clone()),
+                crate::config::EventSourceMode::Pull { .. } => None,
+            },
+        };
+
+        let nodes = core::iter::once(primary)
+            .chain(config.backup_endpoints.iter().cloned())
+            .map(|endpoint| {
+                let rpc_client = build_rpc_client(&endpoint, compat_mode)?;
+
+                Ok(NodeState {
+                    endpoint,
+                    rpc_client,
+                    healthy: true,
+                    consecutive_failures: 0,
+                    consecutive_successes: 0,
+                    latest_height: None,
+                    last_error: None,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self {
+            nodes,
+            active: 0,
+            config: config.failover,
+            rpc_timeout: config.rpc_timeout,
+        })
+    }
+
+    /// Whether there is more than one node to choose from
+    pub fn has_backups(&self) -> bool {
+        self.nodes.len() > 1
+    }
+
+    pub fn check_interval(&self) -> Duration {
+        self.config.check_interval
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    pub fn active(&self) -> &NodeEndpoint {
+        &self.nodes[self.active].endpoint
+    }
+
+    pub fn endpoint(&self, index: usize) -> &NodeEndpoint {
+        &self.nodes[index].endpoint
+    }
+
+    pub fn set_active(&mut self, index: usize) {
+        assert!(index < self.nodes.len(), "node index out of bounds");
+        self.active = index;
+    }
+
+    /// Probe every node concurrently for its latest application height.
+    pub async fn probe(&self) -> Vec<Probe> {
+        let probes = self.nodes.iter().map(|node| async move {
+            let result = tokio::time::timeout(self.rpc_timeout, node.rpc_client.abci_info()).await;
+
+            match result {
+                Ok(Ok(info)) => Probe::Height(info.last_block_height.value()),
+                Ok(Err(e)) => Probe::Failed(e.to_string()),
+                Err(_) => Probe::Failed(format!("timed out after {:?}", self.rpc_timeout)),
+            }
+        });
+
+        join_all(probes).await
+    }
+
+    /// Update the health of every node with the outcome of a round of probes,
+    /// given in the same order as the nodes in the pool.
+    pub fn record(&mut self, probes: Vec<Probe>) {
+        let best_height = probes
+            .iter()
+            .filter_map(|probe| match probe {
+                Probe::Height(height) => Some(*height),
+                Probe::Failed(_) => None,
+            })
+            .max();
+
+        let threshold = self.config.threshold;
+        let max_height_lag = self.config.max_height_lag;
+
+        for (node, probe) in self.nodes.iter_mut().zip(probes) {
+            let outcome = match probe {
+                Probe::Height(height) => {
+                    node.latest_height = Some(height);
+
+                    let lag = best_height.unwrap_or(height).saturating_sub(height);
+
+                    if lag > max_height_lag {
+                        Err(format!(
+                            "lagging {lag} blocks behind the highest known height"
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                }
+                Probe::Failed(reason) => Err(reason),
+            };
+
+            match outcome {
+                Ok(()) => {
+                    node.consecutive_failures = 0;
+                    node.consecutive_successes = node.consecutive_successes.saturating_add(1);
+                    node.last_error = None;
+
+                    if node.consecutive_successes >= threshold {
+                        node.healthy = true;
+                    }
+                }
+                Err(reason) => {
+                    node.consecutive_successes = 0;
+                    node.consecutive_failures = node.consecutive_failures.saturating_add(1);
+                    node.last_error = Some(reason);
+
+                    if node.consecutive_failures >= threshold {
+                        node.healthy = false;
+                    }
+                }
+            }
+        }
+    }
+
+    /// The index of the node the chain should switch to, if any.
+    ///
+    /// This is the first healthy node in order of preference, provided it is not
+    /// already the active one. If no node is healthy, the chain stays where it is.
+    pub fn select(&self) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.healthy)
+            .filter(|index| *index != self.active)
+    }
+
+    pub fn status(&self) -> Vec<NodeStatus> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| NodeStatus {
+                rpc_addr: node.endpoint.rpc_addr.to_string(),
+                grpc_addr: node.endpoint.grpc_addr.to_string(),
+                active: index == self.active,
+                healthy: node.healthy,
+                latest_height: node.latest_height,
+                last_error: node.last_error.clone(),
+            })
+            .collect()
+    }
+}
+
+pub fn build_rpc_client(
+    endpoint: &NodeEndpoint,
+    compat_mode: CompatMode,
+) -> Result<HttpClient, Error> {
+    let mut rpc_client = HttpClient::builder(endpoint.rpc_addr.clone().try_into().unwrap())
+        .user_agent(format!("hermes/{}", HERMES_VERSION))
+        .build()
+        .map_err(|e| Error::rpc(endpoint.rpc_addr.clone(), e))?;
+
+    rpc_client.set_compat_mode(compat_mode);
+
+    Ok(rpc_client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use tendermint_rpc::Url;
+
+    fn pool(nodes: usize, threshold: u32) -> EndpointPool {
+        let nodes = (0..nodes)
+            .map(|i| {
+                let endpoint = NodeEndpoint {
+                    rpc_addr: Url::from_str(&format!("http://127.0.0.1:{}", 26657 + i)).unwrap(),
+                    grpc_addr: Url::from_str(&format!("http://127.0.0.1:{}", 9090 + i)).unwrap(),
+                    websocket_addr: None,
+                };
+
+                NodeState {
+                    rpc_client: build_rpc_client(&endpoint, CompatMode::V0_37).unwrap(),
+                    endpoint,
+                    healthy: true,
+                    consecutive_failures: 0,
+                    consecutive_successes: 0,
+                    latest_height: None,
+                    last_error: None,
+                }
+            })
+            .collect();
+
+        EndpointPool {
+            nodes,
+            active: 0,
+            config: FailoverConfig {
+                check_interval: Duration::from_secs(1),
+                max_height_lag: 5,
+                threshold,
+            },
+            rpc_timeout: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn stays_on_healthy_primary() {
+        let mut pool = pool(2, 1);
+
+        pool.record(vec![Probe::Height(100), Probe::Height(101)]);
+
+        assert_eq!(pool.select(), None);
+    }
+
+    #[test]
+    fn fails_over_after_threshold() {
+        let mut pool = pool(2, 2);
+
+        pool.record(vec![
+            Probe::Failed("timeout".to_string()),
+            Probe::Height(100),
+        ]);
+        assert_eq!(pool.select(), None);
+
+        pool.record(vec![
+            Probe::Failed("timeout".to_string()),
+            Probe::Height(101),
+        ]);
+        assert_eq!(pool.select(), Some(1));
+    }
+
+    #[test]
+    fn fails_over_when_lagging() {
+        let mut pool = pool(3, 1);
+
+        pool.record(vec![
+            Probe::Height(90),
+            Probe::Height(100),
+            Probe::Height(100),
+        ]);
+
+        assert_eq!(pool.select(), Some(1));
+    }
+
+    #[test]
+    fn moves_back_to_recovered_primary() {
+        let mut pool = pool(2, 2);
+
+        pool.record(vec![Probe::Failed("down".to_string()), Probe::Height(100)]);
+        pool.record(vec![Probe::Failed("down".to_string()), Probe::Height(101)]);
+        pool.set_active(pool.select().unwrap());
+
+        pool.record(vec![Probe::Height(102), Probe::Height(102)]);
+        assert_eq!(pool.select(), None);
+
+        pool.record(vec![Probe::Height(103), Probe::Height(103)]);
+        assert_eq!(pool.select(), Some(0));
+    }
+
+    #[test]
+    fn stays_put_when_all_nodes_are_unhealthy() {
+        let mut pool = pool(2, 1);
+
+        pool.record(vec![
+            Probe::Failed("down".to_string()),
+            Probe::Failed("down".to_string()),
+        ]);
+
+        assert_eq!(pool.select(), None);
+    }
+}
@@ -21,6 +21,7 @@ pub mod consensus_state;
 pub mod custom;
 pub mod denom_trace;
 pub mod fee;
+pub mod interchain_account;
 pub mod status;
 pub mod tx;
 
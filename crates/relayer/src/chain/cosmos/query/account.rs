@@ -26,8 +26,8 @@ pub struct EthAccount {
 /// Otherwise query for the account information, update the `Option` to `Some`,
 /// and return the underlying `&mut` reference.
 pub async fn get_or_fetch_account<'a>(
-    grpc_address: &'a Uri,
-    account_address: &'a str,
+    grpc_address: &Uri,
+    account_address: &str,
     m_account: &'a mut Option<Account>,
 ) -> Result<&'a mut Account, Error> {
     match m_account {
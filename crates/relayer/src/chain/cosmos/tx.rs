@@ -14,7 +14,7 @@ use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::{Secp256k1KeyPair, Secp256k1Signer};
 
 use super::batch::send_batched_messages_and_wait_commit;
 use super::estimate::EstimatedGas;
@@ -22,7 +22,7 @@ use super::estimate::EstimatedGas;
 pub async fn estimate_fee_and_send_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -41,7 +41,7 @@ pub async fn estimate_fee_and_send_tx(
 async fn send_tx_with_fee(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &dyn Secp256k1Signer,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -1,4 +1,7 @@
 use alloc::sync::Arc;
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
 
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
@@ -8,7 +11,9 @@ use tokio::runtime::Runtime as TokioRuntime;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -64,6 +69,18 @@ pub struct ChainStatus {
     pub timestamp: Timestamp,
 }
 
+/// The status of one of the full nodes a chain is configured with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeStatus {
+    pub rpc_addr: String,
+    pub grpc_addr: String,
+    /// Whether the relayer is currently using this node
+    pub active: bool,
+    pub healthy: bool,
+    pub latest_height: Option<u64>,
+    pub last_error: Option<String>,
+}
+
 /// Defines a blockchain as understood by the relayer
 pub trait ChainEndpoint: Sized {
     /// Type of light blocks for this chain
@@ -101,6 +118,22 @@ pub trait ChainEndpoint: Sized {
     /// Perform a health check
     fn health_check(&mut self) -> Result<HealthCheck, Error>;
 
+    /// How often the chain runtime should call [`ChainEndpoint::maintain`], if at all
+    fn maintenance_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Perform periodic housekeeping in between requests,
+    /// eg. switching to a healthier full node
+    fn maintain(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Returns the status of the full nodes this chain is configured with
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error> {
+        Ok(Vec::new())
+    }
+
     // Events
     fn subscribe(&mut self) -> Result<Subscription, Error>;
 
@@ -146,6 +179,10 @@ pub trait ChainEndpoint: Sized {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<TxResponse>, Error>;
 
+    /// Estimates the fees of a single transaction with `msgs`, by simulating
+    /// it on the chain, without submitting it.
+    fn estimate_fees(&mut self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error>;
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -176,6 +213,14 @@ pub trait ChainEndpoint: Sized {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address of the interchain account controlled by the given owner
+    /// over the given connection.
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
@@ -689,6 +734,13 @@ pub trait ChainEndpoint: Sized {
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
 
+    /// Query the packets sent on the given channel which have fees escrowed for their relaying
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error>;
 
     fn query_upgrade(
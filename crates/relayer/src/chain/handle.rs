@@ -9,7 +9,9 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::{
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
@@ -48,7 +50,7 @@ use crate::{
 
 use super::{
     client::ClientSettings,
-    endpoint::{ChainStatus, HealthCheck},
+    endpoint::{ChainStatus, HealthCheck, NodeStatus},
     requests::*,
     tracking::TrackedMsgs,
     version::Specs,
@@ -112,6 +114,10 @@ pub enum ChainRequest {
         reply_to: ReplyTo<HealthCheck>,
     },
 
+    QueryNodeStatus {
+        reply_to: ReplyTo<Vec<NodeStatus>>,
+    },
+
     Subscribe {
         reply_to: ReplyTo<Subscription>,
     },
@@ -126,6 +132,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>>,
     },
 
+    EstimateFees {
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Vec<RawCoin>>,
+    },
+
     Config {
         reply_to: ReplyTo<ChainConfig>,
     },
@@ -164,6 +175,12 @@ pub enum ChainRequest {
         reply_to: ReplyTo<DenomTrace>,
     },
 
+    QueryInterchainAccount {
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Signer>,
+    },
+
     QueryApplicationStatus {
         reply_to: ReplyTo<ChainStatus>,
     },
@@ -371,6 +388,12 @@ pub enum ChainRequest {
         reply_to: ReplyTo<QueryIncentivizedPacketResponse>,
     },
 
+    QueryIncentivizedPackets {
+        channel_id: ChannelId,
+        port_id: PortId,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    },
+
     QueryConsumerChains {
         reply_to: ReplyTo<Vec<ConsumerChain>>,
     },
@@ -407,6 +430,9 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     /// Perform a health check
     fn health_check(&self) -> Result<HealthCheck, Error>;
 
+    /// Returns the status of the full nodes the chain is configured with.
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error>;
+
     /// Subscribe to the events emitted by the chain.
     fn subscribe(&self) -> Result<Subscription, Error>;
 
@@ -426,6 +452,10 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         tracked_msgs: TrackedMsgs,
     ) -> Result<Vec<tendermint_rpc::endpoint::broadcast::tx_sync::Response>, Error>;
 
+    /// Estimate the fees of a single transaction with the given messages,
+    /// without submitting it.
+    fn estimate_fees(&self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error>;
+
     fn get_signer(&self) -> Result<Signer, Error>;
 
     fn config(&self) -> Result<ChainConfig, Error>;
@@ -453,6 +483,14 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address of the interchain account controlled by the given owner
+    /// over the given connection.
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error>;
+
     /// Query the latest height and timestamp the application is at
     fn query_application_status(&self) -> Result<ChainStatus, Error>;
 
@@ -705,6 +743,13 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
 
+    /// Query the packets sent on the given channel which have fees escrowed for their relaying
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error>;
 
     fn query_upgrade(
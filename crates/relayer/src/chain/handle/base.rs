@@ -10,6 +10,7 @@ use ibc_proto::ibc::{
 use ibc_relayer_types::{
     applications::{
         ics28_ccv::msgs::{ConsumerChain, ConsumerId},
+        ics29_fee::packet_fee::IdentifiedPacketFees,
         ics31_icq::response::CrossChainQueryResponse,
     },
     core::{
@@ -34,7 +35,10 @@ use ibc_relayer_types::{
 use crate::{
     account::Balance,
     chain::{
-        client::ClientSettings, endpoint::ChainStatus, requests::*, tracking::TrackedMsgs,
+        client::ClientSettings,
+        endpoint::{ChainStatus, NodeStatus},
+        requests::*,
+        tracking::TrackedMsgs,
         version::Specs,
     },
     client_state::{AnyClientState, IdentifiedAnyClientState},
@@ -106,6 +110,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::HealthCheck { reply_to })
     }
 
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error> {
+        self.send(|reply_to| ChainRequest::QueryNodeStatus { reply_to })
+    }
+
     fn shutdown(&self) -> Result<(), Error> {
         self.send(|reply_to| ChainRequest::Shutdown { reply_to })
     }
@@ -134,6 +142,13 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn estimate_fees(&self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        self.send(|reply_to| ChainRequest::EstimateFees {
+            tracked_msgs,
+            reply_to,
+        })
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.send(|reply_to| ChainRequest::Signer { reply_to })
     }
@@ -178,6 +193,18 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryDenomTrace { hash, reply_to })
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::QueryInterchainAccount {
+            owner,
+            connection_id,
+            reply_to,
+        })
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.send(|reply_to| ChainRequest::QueryApplicationStatus { reply_to })
     }
@@ -525,6 +552,18 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryIncentivizedPacket { request, reply_to })
     }
 
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.send(|reply_to| ChainRequest::QueryIncentivizedPackets {
+            channel_id,
+            port_id,
+            reply_to,
+        })
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         self.send(|reply_to| ChainRequest::QueryConsumerChains { reply_to })
     }
@@ -10,7 +10,9 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -31,7 +33,7 @@ use ibc_relayer_types::Height;
 
 use crate::account::Balance;
 use crate::chain::client::ClientSettings;
-use crate::chain::endpoint::{ChainStatus, HealthCheck};
+use crate::chain::endpoint::{ChainStatus, HealthCheck, NodeStatus};
 use crate::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
@@ -113,6 +115,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().health_check()
     }
 
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error> {
+        self.inc_metric("query_node_status");
+        self.inner().query_node_status()
+    }
+
     fn subscribe(&self) -> Result<Subscription, Error> {
         self.inc_metric("subscribe");
         self.inner().subscribe()
@@ -134,6 +141,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn estimate_fees(&self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        self.inc_metric("estimate_fees");
+        self.inner().estimate_fees(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.inc_metric("get_signer");
         self.inner().get_signer()
@@ -178,6 +190,15 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_denom_trace(hash)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.inc_metric("query_interchain_account");
+        self.inner().query_interchain_account(owner, connection_id)
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.inc_metric("query_application_status");
         self.inner().query_application_status()
@@ -508,6 +529,15 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner.query_incentivized_packet(request)
     }
 
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.inc_metric("query_incentivized_packets");
+        self.inner.query_incentivized_packets(channel_id, port_id)
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         self.inc_metric("query_consumer_chains");
         self.inner.query_consumer_chains()
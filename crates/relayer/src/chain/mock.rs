@@ -0,0 +1,1565 @@
+//! An in-process chain, which keeps its state in memory.
+//!
+//! [`MockChain`] executes IBC messages itself, instead of submitting them to a full node,
+//! while producing the same ICS-23 proofs and Tendermint headers as a Cosmos SDK chain.
+//! This allows exercising the relayer end-to-end without running any chain binary,
+//! eg. in plain `cargo test`.
+//!
+//! Every runtime spawned for the same chain identifier shares the state of the chain,
+//! so that the chain can be relayed to and queried from several places at once,
+//! as for a real chain.
+
+pub mod config;
+pub mod host;
+pub mod store;
+
+use alloc::sync::Arc;
+use core::str::FromStr;
+
+use hdpath::StandardHDPath;
+use tendermint::abci::Code;
+use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
+use ibc_proto::ibc::core::connection::v1::ClientPaths;
+use ibc_proto::Protobuf;
+use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+    AllowUpdate, ClientState as TmClientState,
+};
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConnectionsPath, ClientStatePath, ConnectionsPath,
+    ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::version::Specs as CosmosSpecs;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::chain::version::Specs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::{AddressType, ChainConfig, Error as ConfigError};
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair, Store as KeyStore};
+use crate::misbehaviour::MisbehaviourEvidence;
+use crate::util::lock::{LockExt, RwArc};
+
+use self::config::MockChainConfig;
+use self::host::{commitment_prefix, consensus_state_path, MockHost};
+
+/// The mnemonic every key of a mock chain is derived from
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// The HD path every key of a mock chain is derived with
+const HD_PATH: &str = "m/44'/118'/0'/0/0";
+
+pub struct MockChain {
+    config: MockChainConfig,
+    host: RwArc<MockHost>,
+    keybase: KeyRing<Secp256k1KeyPair>,
+}
+
+impl MockChain {
+    /// The key the relayer signs with, derived from a fixed mnemonic
+    pub fn key_pair(account_prefix: &str) -> Result<Secp256k1KeyPair, Error> {
+        let hd_path = StandardHDPath::from_str(HD_PATH)
+            .map_err(|_| Error::other(format!("invalid HD path {HD_PATH}")))?;
+
+        Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, account_prefix)
+            .map_err(Error::key_base)
+    }
+
+    fn key(&self) -> Result<Secp256k1KeyPair, Error> {
+        self.keybase
+            .get_key(&self.config.key_name)
+            .map_err(Error::key_base)
+    }
+
+    fn account(&self, key_name: Option<&str>) -> Result<String, Error> {
+        let key = match key_name {
+            Some(key_name) => self.keybase.get_key(key_name).map_err(Error::key_base)?,
+            None => self.key()?,
+        };
+
+        Ok(key.account())
+    }
+
+    /// Query the value stored at the given path of the IBC store at the given height,
+    /// along with the proof of its value if requested.
+    fn query(
+        &self,
+        path: String,
+        height: QueryHeight,
+        include_proof: IncludeProof,
+    ) -> Result<(Option<Vec<u8>>, Option<MerkleProof>), Error> {
+        let host = self.host.acquire_read();
+        let (state, _) = host
+            .state_at(height)
+            .ok_or_else(|| Error::query(format!("no block at height {height}")))?;
+
+        let value = state.store().get(&path).cloned();
+
+        let proof = match include_proof {
+            IncludeProof::Yes => Some(
+                state
+                    .store()
+                    .prove(&path)
+                    .ok_or_else(Error::queried_proof_not_found)?,
+            ),
+            IncludeProof::No => None,
+        };
+
+        Ok((value, proof))
+    }
+
+    /// Query the value stored at the given path, failing if there is none
+    fn query_existing(
+        &self,
+        path: String,
+        height: QueryHeight,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query(path.clone(), height, include_proof)?;
+        let value = value.ok_or_else(|| Error::query(format!("no value at `{path}`")))?;
+
+        Ok((value, proof))
+    }
+
+    /// The identifiers of the objects stored under the given path prefix,
+    /// taken from the path segment following the prefix.
+    fn query_identifiers(&self, prefix: &str) -> Vec<(String, Vec<u8>)> {
+        let host = self.host.acquire_read();
+        let state = &host.latest_block().state;
+
+        state
+            .store()
+            .scan(prefix)
+            .map(|(key, value)| (key[prefix.len()..].to_string(), value.to_vec()))
+            .collect()
+    }
+
+    fn query_sequences(
+        &self,
+        prefix: String,
+        height: QueryHeight,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let host = self.host.acquire_read();
+        let (state, height) = host
+            .state_at(height)
+            .ok_or_else(|| Error::query(format!("no block at height {height}")))?;
+
+        let sequences = state
+            .store()
+            .scan(&prefix)
+            .filter_map(|(key, _)| Sequence::from_str(&key[prefix.len()..]).ok())
+            .collect();
+
+        Ok((sequences, height))
+    }
+}
+
+impl ChainEndpoint for MockChain {
+    type LightBlock = TmLightBlock;
+    type Header = TmHeader;
+    type ConsensusState = TmConsensusState;
+    type ClientState = TmClientState;
+    type Time = tendermint::Time;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn id(&self) -> &ChainId {
+        &self.config.id
+    }
+
+    fn config(&self) -> ChainConfig {
+        ChainConfig::Mock(self.config.clone())
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let ChainConfig::Mock(config) = config else {
+            return Err(Error::config(ConfigError::wrong_type()));
+        };
+
+        let mut keybase =
+            KeyRing::new_secp256k1(KeyStore::Memory, &config.account_prefix, &config.id, &None)
+                .map_err(Error::key_base)?;
+
+        let key = Self::key_pair(&config.account_prefix)?;
+        let host = host::host(&config, &key.account());
+
+        keybase
+            .add_key(&config.key_name, key)
+            .map_err(Error::key_base)?;
+
+        Ok(Self {
+            config,
+            host,
+            keybase,
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.host.acquire_write().subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        let account = self.key()?.account();
+        Signer::from_str(&account).map_err(|e| Error::other(e.to_string()))
+    }
+
+    fn get_key(&self) -> Result<Self::SigningKeyPair, Error> {
+        self.key()
+    }
+
+    fn version_specs(&self) -> Result<Specs, Error> {
+        Ok(Specs::Cosmos(CosmosSpecs {
+            cosmos_sdk: None,
+            ibc_go: None,
+            consensus: None,
+        }))
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let mut host = self.host.acquire_write();
+
+        match host.submit(&tracked_msgs.msgs) {
+            Ok((_, events)) => Ok(events),
+            Err(log) => Ok(vec![IbcEventWithHeight::new(
+                IbcEvent::ChainError(log),
+                host.latest_height(),
+            )]),
+        }
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let mut host = self.host.acquire_write();
+
+        // Transactions are executed right away, so that failing to execute one fails its check
+        let response = match host.submit(&tracked_msgs.msgs) {
+            Ok((hash, _)) => TxResponse {
+                codespace: String::new(),
+                code: Code::Ok,
+                data: Default::default(),
+                log: String::new(),
+                hash,
+            },
+            Err(log) => TxResponse {
+                codespace: "ibc".to_string(),
+                code: Code::from(1),
+                data: Default::default(),
+                log,
+                hash: tendermint::Hash::None,
+            },
+        };
+
+        Ok(vec![response])
+    }
+
+    /// Transactions are free on a mock chain.
+    fn estimate_fees(&mut self, _tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        Ok(Vec::new())
+    }
+
+    /// The headers of a mock chain are trusted: they are returned without being verified.
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        let host = self.host.acquire_read();
+        let block = host
+            .block(target)
+            .ok_or_else(|| Error::query(format!("no block at height {target}")))?;
+
+        Ok(block.light_block.clone())
+    }
+
+    /// Mock chains have a single honest validator, and therefore never misbehave.
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        Ok(None)
+    }
+
+    fn query_balance(&self, key_name: Option<&str>, denom: Option<&str>) -> Result<Balance, Error> {
+        let account = self.account(key_name)?;
+        let denom = denom.unwrap_or(&self.config.denom);
+
+        let host = self.host.acquire_read();
+        let amount = host.latest_block().state.balance(&account, denom);
+
+        Ok(Balance {
+            amount: amount.to_string(),
+            denom: denom.to_string(),
+        })
+    }
+
+    fn query_all_balances(&self, key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        let account = self.account(key_name)?;
+
+        let host = self.host.acquire_read();
+        let balances = host
+            .latest_block()
+            .state
+            .balances(&account)
+            .into_iter()
+            .map(|(denom, amount)| Balance {
+                amount: amount.to_string(),
+                denom,
+            })
+            .collect();
+
+        Ok(balances)
+    }
+
+    fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error> {
+        let host = self.host.acquire_read();
+
+        host.latest_block()
+            .state
+            .denom_trace(&hash)
+            .cloned()
+            .ok_or_else(|| Error::empty_denom_trace(hash))
+    }
+
+    fn query_interchain_account(
+        &self,
+        _owner: Signer,
+        _connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        Err(Error::query(
+            "mock chains do not support interchain accounts".to_string(),
+        ))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        Ok(commitment_prefix())
+    }
+
+    /// Query the latest height and time of the chain, producing a new block
+    /// if none was produced during the last block time.
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        let mut host = self.host.acquire_write();
+        host.tick();
+
+        Ok(ChainStatus {
+            height: host.latest_height(),
+            timestamp: host.latest_block().time().into(),
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        self.query_identifiers("clients/")
+            .into_iter()
+            .filter_map(|(suffix, value)| {
+                let client_id = suffix.strip_suffix("/clientState")?;
+                Some((ClientId::from_str(client_id).ok()?, value))
+            })
+            .map(|(client_id, value)| {
+                let client_state = AnyClientState::decode_vec(&value).map_err(Error::decode)?;
+                Ok(IdentifiedAnyClientState::new(client_id, client_state))
+            })
+            .collect()
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query_existing(
+            ClientStatePath(request.client_id).to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        let client_state = AnyClientState::decode_vec(&value).map_err(Error::decode)?;
+        Ok((client_state, proof))
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query_existing(
+            consensus_state_path(&request.client_id, request.consensus_height),
+            request.query_height,
+            include_proof,
+        )?;
+
+        let consensus_state = AnyConsensusState::decode_vec(&value).map_err(Error::decode)?;
+        Ok((consensus_state, proof))
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        let prefix = format!("clients/{}/consensusStates/", request.client_id);
+
+        let mut heights: Vec<ICSHeight> = self
+            .query_identifiers(&prefix)
+            .into_iter()
+            .filter_map(|(height, _)| {
+                let (revision_number, revision_height) = height.split_once('-')?;
+                ICSHeight::new(revision_number.parse().ok()?, revision_height.parse().ok()?).ok()
+            })
+            .collect();
+
+        heights.sort();
+        Ok(heights)
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(Error::empty_upgraded_client_state())
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(Error::empty_upgraded_client_state())
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        self.query_identifiers("connections/")
+            .into_iter()
+            .map(|(connection_id, value)| {
+                let connection_id = ConnectionId::from_str(&connection_id)
+                    .map_err(|e| Error::query(e.to_string()))?;
+                let connection_end = ConnectionEnd::decode_vec(&value).map_err(Error::decode)?;
+
+                Ok(IdentifiedConnectionEnd::new(connection_id, connection_end))
+            })
+            .collect()
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        let (value, _) = self.query(
+            ClientConnectionsPath(request.client_id).to_string(),
+            QueryHeight::Latest,
+            IncludeProof::No,
+        )?;
+
+        let Some(value) = value else {
+            return Ok(Vec::new());
+        };
+
+        let client_paths = <ClientPaths as prost::Message>::decode(value.as_slice())
+            .map_err(|e| Error::query(e.to_string()))?;
+
+        client_paths
+            .paths
+            .iter()
+            .map(|path| ConnectionId::from_str(path).map_err(|e| Error::query(e.to_string())))
+            .collect()
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query(
+            ConnectionsPath(request.connection_id.clone()).to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        let value = value.ok_or_else(|| Error::connection_not_found(request.connection_id))?;
+        let connection_end = ConnectionEnd::decode_vec(&value).map_err(Error::decode)?;
+
+        Ok((connection_end, proof))
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        let channels = self.query_channels(QueryChannelsRequest { pagination: None })?;
+
+        Ok(channels
+            .into_iter()
+            .filter(|channel| {
+                channel.channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.query_identifiers("channelEnds/ports/")
+            .into_iter()
+            .filter_map(|(suffix, value)| {
+                let (port_id, channel_id) = suffix.split_once("/channels/")?;
+                Some((
+                    PortId::from_str(port_id).ok()?,
+                    ChannelId::from_str(channel_id).ok()?,
+                    value,
+                ))
+            })
+            .map(|(port_id, channel_id, value)| {
+                let channel_end = ChannelEnd::decode_vec(&value).map_err(Error::decode)?;
+                Ok(IdentifiedChannelEnd::new(port_id, channel_id, channel_end))
+            })
+            .collect()
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query_existing(
+            ChannelEndsPath(request.port_id, request.channel_id).to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        let channel_end = ChannelEnd::decode_vec(&value).map_err(Error::decode)?;
+        Ok((channel_end, proof))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let (channel_end, _) = self.query_channel(
+            QueryChannelRequest {
+                port_id: request.port_id,
+                channel_id: request.channel_id,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        let Some(connection_id) = channel_end.connection_hops().first() else {
+            return Ok(None);
+        };
+
+        let (connection_end, _) = self.query_connection(
+            QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        let (client_state, _) = self.query_client_state(
+            QueryClientStateRequest {
+                client_id: connection_end.client_id().clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        Ok(Some(IdentifiedAnyClientState::new(
+            connection_end.client_id().clone(),
+            client_state,
+        )))
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query(
+            host::commitment_path(&request.port_id, &request.channel_id, request.sequence),
+            request.height,
+            include_proof,
+        )?;
+
+        Ok((value.unwrap_or_default(), proof))
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        self.query_sequences(
+            format!(
+                "commitments/ports/{}/channels/{}/sequences/",
+                request.port_id, request.channel_id
+            ),
+            request.query_height,
+        )
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query(
+            ReceiptsPath {
+                port_id: request.port_id,
+                channel_id: request.channel_id,
+                sequence: request.sequence,
+            }
+            .to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        Ok((value.unwrap_or_default(), proof))
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let (channel_end, _) = self.query_channel(
+            QueryChannelRequest {
+                port_id: request.port_id.clone(),
+                channel_id: request.channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        if channel_end.order_matches(&Ordering::Ordered) {
+            let (next_sequence, _) = self.query_next_sequence_receive(
+                QueryNextSequenceReceiveRequest {
+                    port_id: request.port_id,
+                    channel_id: request.channel_id,
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )?;
+
+            return Ok(request
+                .packet_commitment_sequences
+                .into_iter()
+                .filter(|sequence| *sequence >= next_sequence)
+                .collect());
+        }
+
+        let host = self.host.acquire_read();
+        let store = host.latest_block().state.store();
+
+        Ok(request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| {
+                let path = ReceiptsPath {
+                    port_id: request.port_id.clone(),
+                    channel_id: request.channel_id.clone(),
+                    sequence: *sequence,
+                };
+                store.get(&path.to_string()).is_none()
+            })
+            .collect())
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query(
+            AcksPath {
+                port_id: request.port_id,
+                channel_id: request.channel_id,
+                sequence: request.sequence,
+            }
+            .to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        Ok((value.unwrap_or_default(), proof))
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let (mut sequences, height) = self.query_sequences(
+            format!(
+                "acks/ports/{}/channels/{}/sequences/",
+                request.port_id, request.channel_id
+            ),
+            QueryHeight::Latest,
+        )?;
+
+        if !request.packet_commitment_sequences.is_empty() {
+            sequences.retain(|sequence| request.packet_commitment_sequences.contains(sequence));
+        }
+
+        Ok((sequences, height))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let host = self.host.acquire_read();
+        let store = host.latest_block().state.store();
+
+        Ok(request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                let path = host::commitment_path(&request.port_id, &request.channel_id, *sequence);
+                store.get(&path).is_some()
+            })
+            .collect())
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        let (value, proof) = self.query_existing(
+            SeqRecvsPath(request.port_id, request.channel_id).to_string(),
+            request.height,
+            include_proof,
+        )?;
+
+        let bytes: [u8; 8] = value
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::query("malformed next sequence receive".to_string()))?;
+
+        Ok((Sequence::from(u64::from_be_bytes(bytes)), proof))
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let host = self.host.acquire_read();
+
+        match request {
+            QueryTxRequest::Transaction(tx) => {
+                Ok(host.tx_events(&tx.0).cloned().unwrap_or_default())
+            }
+
+            QueryTxRequest::Client(request) => {
+                let max_height = match request.query_height {
+                    QueryHeight::Latest => host.latest_height(),
+                    QueryHeight::Specific(height) => height,
+                };
+
+                let event = host
+                    .blocks()
+                    .iter()
+                    .flat_map(|block| block.events.iter())
+                    .filter(|event| event.height <= max_height)
+                    .rfind(|event| match (&request.event_id, &event.event) {
+                        (WithBlockDataType::CreateClient, IbcEvent::CreateClient(create)) => {
+                            create.client_id() == &request.client_id
+                                && create.0.consensus_height == request.consensus_height
+                        }
+                        (WithBlockDataType::UpdateClient, IbcEvent::UpdateClient(update)) => {
+                            update.client_id() == &request.client_id
+                                && update.consensus_height() == request.consensus_height
+                        }
+                        _ => false,
+                    })
+                    .cloned();
+
+                Ok(event.into_iter().collect())
+            }
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let host = self.host.acquire_read();
+
+        let matches_height = |height: ICSHeight| match request.height {
+            Qualified::SmallerEqual(QueryHeight::Latest) => true,
+            Qualified::SmallerEqual(QueryHeight::Specific(max)) => height <= max,
+            Qualified::Equal(QueryHeight::Latest) => height == host.latest_height(),
+            Qualified::Equal(QueryHeight::Specific(expected)) => height == expected,
+        };
+
+        let events = host
+            .blocks()
+            .iter()
+            .flat_map(|block| block.events.iter())
+            .filter(|event| matches_height(event.height))
+            .filter(|event| {
+                matches!(
+                    (&request.event_id, &event.event),
+                    (WithBlockDataType::SendPacket, IbcEvent::SendPacket(_))
+                        | (
+                            WithBlockDataType::WriteAck,
+                            IbcEvent::WriteAcknowledgement(_)
+                        )
+                )
+            });
+
+        // Keep the latest event of each requested packet
+        let mut result: Vec<IbcEventWithHeight> = Vec::new();
+
+        for event in events {
+            let Some(packet) = event.event.packet() else {
+                continue;
+            };
+
+            if packet.source_port != request.source_port_id
+                || packet.source_channel != request.source_channel_id
+                || packet.destination_port != request.destination_port_id
+                || packet.destination_channel != request.destination_channel_id
+                || !request.sequences.contains(&packet.sequence)
+            {
+                continue;
+            }
+
+            result
+                .retain(|known| known.event.packet().map(|p| p.sequence) != Some(packet.sequence));
+            result.push(event.clone());
+        }
+
+        Ok(result)
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        let host = self.host.acquire_read();
+
+        let block = match request.height {
+            QueryHeight::Latest => host.latest_block(),
+            QueryHeight::Specific(height) => host
+                .block(height)
+                .ok_or_else(|| Error::query(format!("no block at height {height}")))?,
+        };
+
+        Ok(TmConsensusState::from(
+            block.light_block.signed_header.header.clone(),
+        ))
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let ClientSettings::Tendermint(settings) = settings;
+
+        TmClientState::new(
+            self.id().clone(),
+            settings.trust_threshold,
+            settings
+                .trusting_period
+                .unwrap_or(self.config.trusting_period),
+            self.config.unbonding_period,
+            settings.max_clock_drift,
+            height,
+            store::proof_specs(),
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .map_err(Error::ics07)
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(TmConsensusState::from(light_block.signed_header.header))
+    }
+
+    fn build_header(
+        &mut self,
+        trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        let host = self.host.acquire_read();
+
+        let block = |height: ICSHeight| {
+            host.block(height)
+                .ok_or_else(|| Error::query(format!("no block at height {height}")))
+        };
+
+        let target = block(target_height)?;
+        let trusted = block(trusted_height)?;
+
+        let header = TmHeader {
+            signed_header: target.light_block.signed_header.clone(),
+            validator_set: target.light_block.validators.clone(),
+            trusted_height,
+            trusted_validator_set: trusted.light_block.next_validators.clone(),
+        };
+
+        Ok((header, Vec::new()))
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(Error::query(
+            "mock chains do not support fee middleware".to_string(),
+        ))
+    }
+
+    fn query_incentivized_packets(
+        &self,
+        _channel_id: ChannelId,
+        _port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        Err(Error::query(
+            "mock chains do not support fee middleware".to_string(),
+        ))
+    }
+
+    fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_upgrade(
+        &self,
+        _request: QueryUpgradeRequest,
+        _height: ICSHeight,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::query(
+            "mock chains do not support channel upgrades".to_string(),
+        ))
+    }
+
+    fn query_upgrade_error(
+        &self,
+        _request: QueryUpgradeErrorRequest,
+        _height: ICSHeight,
+        _include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        Err(Error::query(
+            "mock chains do not support channel upgrades".to_string(),
+        ))
+    }
+
+    fn query_ccv_consumer_id(&self, _client_id: ClientId) -> Result<ConsumerId, Error> {
+        Err(Error::query(
+            "mock chains are not consumer chains".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
+    use ibc_relayer_types::applications::transfer::packet::TransferPacketData;
+    use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
+    use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+    use ibc_relayer_types::core::ics24_host::identifier::PortId;
+    use ibc_relayer_types::core::ics24_host::path::CommitmentsPath;
+
+    use crate::chain::handle::{BaseChainHandle, ChainHandle};
+    use crate::chain::runtime::ChainRuntime;
+    use crate::channel::Channel;
+    use crate::config::filter::{Ics20Filter, Ics20Rule};
+    use crate::config::{default, Config};
+    use crate::connection::Connection;
+    use crate::foreign_client::ForeignClient;
+    use crate::link::error::LinkErrorDetail;
+    use crate::link::{verify, Link, LinkParameters};
+    use crate::transfer::{build_and_send_transfer_messages, TransferOptions};
+
+    fn rt() -> Arc<TokioRuntime> {
+        Arc::new(TokioRuntime::new().unwrap())
+    }
+
+    fn spawn(chain_id: &str) -> BaseChainHandle {
+        let config = MockChainConfig::new(ChainId::from_string(chain_id));
+        ChainRuntime::<MockChain>::spawn::<BaseChainHandle>(ChainConfig::Mock(config), rt())
+            .unwrap()
+    }
+
+    /// Create clients, a connection and a transfer channel between two new chains
+    fn open_channel(
+        a_id: &str,
+        b_id: &str,
+    ) -> (
+        BaseChainHandle,
+        BaseChainHandle,
+        Channel<BaseChainHandle, BaseChainHandle>,
+    ) {
+        open_channel_with_version(a_id, b_id, Version::ics20())
+    }
+
+    fn open_channel_with_version(
+        a_id: &str,
+        b_id: &str,
+        version: Version,
+    ) -> (
+        BaseChainHandle,
+        BaseChainHandle,
+        Channel<BaseChainHandle, BaseChainHandle>,
+    ) {
+        let a = spawn(a_id);
+        let b = spawn(b_id);
+
+        let client_on_a = ForeignClient::new(a.clone(), b.clone()).unwrap();
+        let client_on_b = ForeignClient::new(b.clone(), a.clone()).unwrap();
+
+        let connection = Connection::new(client_on_b, client_on_a, Duration::ZERO).unwrap();
+
+        let channel = Channel::new(
+            connection,
+            Ordering::Unordered,
+            PortId::transfer(),
+            PortId::transfer(),
+            Some(version),
+        )
+        .unwrap();
+
+        (a, b, channel)
+    }
+
+    fn transfer(
+        a: &BaseChainHandle,
+        b: &BaseChainHandle,
+        channel_id: &ChannelId,
+        amount: u64,
+    ) -> Vec<IbcEventWithHeight> {
+        let opts = TransferOptions {
+            src_port_id: PortId::transfer(),
+            src_channel_id: channel_id.clone(),
+            amount: Amount::from(amount),
+            denom: "stake".to_string(),
+            receiver: None,
+            timeout_height_offset: 1000,
+            timeout_duration: Duration::from_secs(600),
+            number_msgs: 1,
+            memo: None,
+            additional_tokens: vec![],
+            forwarding: None,
+        };
+
+        build_and_send_transfer_messages(a, b, &opts).unwrap()
+    }
+
+    /// Wait for the given condition to hold, for at most the given duration
+    fn wait_until(timeout: Duration, mut condition: impl FnMut() -> bool) -> bool {
+        let start = std::time::Instant::now();
+
+        while start.elapsed() < timeout {
+            if condition() {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        false
+    }
+
+    fn balance(chain: &BaseChainHandle, denom: &str) -> u64 {
+        let balance = chain.query_balance(None, Some(denom.to_string())).unwrap();
+        balance.amount.parse().unwrap()
+    }
+
+    #[test]
+    fn proofs_verify_against_signed_headers() {
+        let a = spawn("mock-proofs-a");
+        let b = spawn("mock-proofs-b");
+
+        let client = ForeignClient::new(b.clone(), a.clone()).unwrap();
+
+        // Wait for a new block, so that the client has to be updated to a new header
+        let height = a.query_latest_height().unwrap();
+        assert!(wait_until(Duration::from_secs(5), || {
+            a.query_latest_height().unwrap() > height
+        }));
+        client.update().unwrap();
+
+        let (client_state, _) = b
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: client.id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        assert!(client_state.latest_height() > height);
+    }
+
+    #[test]
+    fn handshakes_open_channel() {
+        let (a, b, channel) = open_channel("mock-handshake-a", "mock-handshake-b");
+
+        let a_channel_id = channel.src_channel_id().unwrap();
+        let b_channel_id = channel.dst_channel_id().unwrap();
+
+        for (chain, channel_id) in [(&a, a_channel_id), (&b, b_channel_id)] {
+            let (channel_end, _) = chain
+                .query_channel(
+                    QueryChannelRequest {
+                        port_id: PortId::transfer(),
+                        channel_id: channel_id.clone(),
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )
+                .unwrap();
+
+            assert!(channel_end.is_open());
+        }
+    }
+
+    #[test]
+    fn link_relays_transfer() {
+        let (a, b, channel) = open_channel("mock-link-a", "mock-link-b");
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+
+        let events = transfer(&a, &b, &a_channel_id, 1000);
+        assert!(matches!(events[0].event, IbcEvent::SendPacket(_)));
+        assert_eq!(balance(&a, "stake"), 1_000_000_000 - 1000);
+
+        let params = LinkParameters {
+            src_port_id: PortId::transfer(),
+            src_channel_id: a_channel_id.clone(),
+            max_memo_size: default::ics20_max_memo_size(),
+            max_receiver_size: default::ics20_max_receiver_size(),
+            exclude_src_sequences: Vec::new(),
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: true,
+        };
+
+        let link = Link::new_from_opts(a.clone(), b.clone(), params, false, false).unwrap();
+
+        let events = link.relay_recv_packet_and_timeout_messages(vec![]).unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        let voucher = b.query_all_balances(None).unwrap();
+        let voucher = voucher
+            .iter()
+            .find(|balance| balance.denom.starts_with("ibc/"))
+            .unwrap();
+        assert_eq!(voucher.amount, "1000");
+
+        let trace = b
+            .query_denom_trace(voucher.denom.trim_start_matches("ibc/").to_string())
+            .unwrap();
+        assert_eq!(trace.base_denom, "stake");
+
+        let b_channel_id = channel.dst_channel_id().unwrap().clone();
+        let reverse = LinkParameters {
+            src_port_id: PortId::transfer(),
+            src_channel_id: b_channel_id,
+            max_memo_size: default::ics20_max_memo_size(),
+            max_receiver_size: default::ics20_max_receiver_size(),
+            exclude_src_sequences: Vec::new(),
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: true,
+        };
+
+        let link = Link::new_from_opts(b.clone(), a.clone(), reverse, false, false).unwrap();
+        let events = link.relay_ack_packet_messages(vec![]).unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+
+        let (commitments, _) = a
+            .query_packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: PortId::transfer(),
+                channel_id: a_channel_id,
+                pagination: Paginate::All,
+                query_height: QueryHeight::Latest,
+            })
+            .unwrap();
+        assert!(commitments.is_empty());
+    }
+
+    #[test]
+    fn link_skips_transfer_rejected_by_ics20_filter() {
+        let (a, b, channel) = open_channel("mock-filter-a", "mock-filter-b");
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+
+        let link = |ics20_filter: Ics20Filter| {
+            let params = LinkParameters {
+                src_port_id: PortId::transfer(),
+                src_channel_id: a_channel_id.clone(),
+                max_memo_size: default::ics20_max_memo_size(),
+                max_receiver_size: default::ics20_max_receiver_size(),
+                exclude_src_sequences: Vec::new(),
+                ics20_filter,
+                verify_proofs: true,
+            };
+
+            Link::new_from_opts(a.clone(), b.clone(), params, false, false).unwrap()
+        };
+
+        transfer(&a, &b, &a_channel_id, 1000);
+
+        // Only amounts of at least 5000 stake are allowed
+        let allow_large_stake = Ics20Filter::new(
+            vec![Ics20Rule {
+                base_denom: Some("stake".parse().unwrap()),
+                min_amount: Some(Amount::from(5000u64)),
+                ..Default::default()
+            }],
+            vec![],
+        );
+
+        let events = link(allow_large_stake)
+            .relay_recv_packet_and_timeout_messages(vec![])
+            .unwrap();
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        let vouchers = b.query_all_balances(None).unwrap();
+        assert!(!vouchers
+            .iter()
+            .any(|balance| balance.denom.starts_with("ibc/")));
+
+        let deny_other_senders = Ics20Filter::new(
+            vec![],
+            vec![Ics20Rule {
+                sender: Some("not-the-sender*".parse().unwrap()),
+                ..Default::default()
+            }],
+        );
+
+        let events = link(deny_other_senders)
+            .relay_recv_packet_and_timeout_messages(vec![])
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+    }
+
+    #[test]
+    fn link_relays_ics20_v2_transfer() {
+        let (a, b, channel) =
+            open_channel_with_version("mock-v2-a", "mock-v2-b", Version::ics20_v2());
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+        let b_channel_id = channel.dst_channel_id().unwrap().clone();
+
+        let link = |src: &BaseChainHandle, dst: &BaseChainHandle, channel_id: &ChannelId| {
+            let params = LinkParameters {
+                src_port_id: PortId::transfer(),
+                src_channel_id: channel_id.clone(),
+                max_memo_size: default::ics20_max_memo_size(),
+                max_receiver_size: default::ics20_max_receiver_size(),
+                exclude_src_sequences: Vec::new(),
+                ics20_filter: Ics20Filter::default(),
+                verify_proofs: true,
+            };
+
+            Link::new_from_opts(src.clone(), dst.clone(), params, false, false).unwrap()
+        };
+
+        transfer(&a, &b, &a_channel_id, 1000);
+        link(&a, &b, &a_channel_id)
+            .relay_recv_packet_and_timeout_messages(vec![])
+            .unwrap();
+
+        let voucher = b
+            .query_all_balances(None)
+            .unwrap()
+            .into_iter()
+            .find(|balance| balance.denom.starts_with("ibc/"))
+            .unwrap();
+
+        // Send back part of the vouchers, along with some of the native tokens of `b`
+        let opts = TransferOptions {
+            src_port_id: PortId::transfer(),
+            src_channel_id: b_channel_id.clone(),
+            amount: Amount::from(300u64),
+            denom: "stake".to_string(),
+            receiver: None,
+            timeout_height_offset: 1000,
+            timeout_duration: Duration::from_secs(600),
+            number_msgs: 1,
+            memo: None,
+            additional_tokens: vec![RawCoin::new(voucher.denom, 400u64)],
+            forwarding: None,
+        };
+
+        let events = build_and_send_transfer_messages(&b, &a, &opts).unwrap();
+        let IbcEvent::SendPacket(event) = &events[0].event else {
+            panic!("expected a SendPacket event, got {:?}", events[0].event);
+        };
+
+        let data = TransferPacketData::decode(&event.packet.data, &Version::ics20_v2()).unwrap();
+        assert!(matches!(data, TransferPacketData::V2(_)));
+        assert_eq!(data.tokens().len(), 2);
+
+        let events = link(&b, &a, &b_channel_id)
+            .relay_recv_packet_and_timeout_messages(vec![])
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        assert_eq!(balance(&a, "stake"), 1_000_000_000 - 1000 + 400);
+        assert!(a
+            .query_all_balances(None)
+            .unwrap()
+            .iter()
+            .any(|balance| balance.denom.starts_with("ibc/") && balance.amount == "300"));
+    }
+
+    #[test]
+    fn ics20_v2_transfer_requires_ics20_v2_channel() {
+        let (a, b, channel) = open_channel("mock-v1-a", "mock-v1-b");
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+
+        let opts = TransferOptions {
+            src_port_id: PortId::transfer(),
+            src_channel_id: a_channel_id,
+            amount: Amount::from(300u64),
+            denom: "stake".to_string(),
+            receiver: None,
+            timeout_height_offset: 1000,
+            timeout_duration: Duration::from_secs(600),
+            number_msgs: 1,
+            memo: None,
+            additional_tokens: vec![RawCoin::new("stake".to_string(), 400u64)],
+            forwarding: None,
+        };
+
+        assert!(build_and_send_transfer_messages(&a, &b, &opts).is_err());
+    }
+
+    #[test]
+    fn supervisor_relays_transfer() {
+        let (a, b, channel) = open_channel("mock-supervisor-a", "mock-supervisor-b");
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+
+        let mut config = Config::default();
+        config.mode.packets.enabled = true;
+        config.mode.clients.misbehaviour = false;
+        config.chains = vec![a.config().unwrap(), b.config().unwrap()];
+
+        let registry = crate::registry::SharedRegistry::<BaseChainHandle>::new(config.clone());
+        registry.get_or_spawn(&a.id()).unwrap();
+        registry.get_or_spawn(&b.id()).unwrap();
+
+        let _supervisor = crate::supervisor::spawn_supervisor(
+            config,
+            registry,
+            None,
+            crate::supervisor::SupervisorOptions {
+                health_check: false,
+                force_full_scan: false,
+                config_path: None,
+            },
+        )
+        .unwrap();
+
+        transfer(&a, &b, &a_channel_id, 500);
+
+        let relayed = wait_until(Duration::from_secs(60), || {
+            a.query_packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: PortId::transfer(),
+                channel_id: a_channel_id.clone(),
+                pagination: Paginate::All,
+                query_height: QueryHeight::Latest,
+            })
+            .map(|(commitments, _)| commitments.is_empty())
+            .unwrap_or(false)
+        });
+
+        assert!(relayed, "the transfer was not relayed and acknowledged");
+        assert!(b
+            .query_all_balances(None)
+            .unwrap()
+            .iter()
+            .any(|balance| balance.denom.starts_with("ibc/") && balance.amount == "500"));
+    }
+
+    #[test]
+    fn invalid_proofs_are_rejected() {
+        let (a, b, channel) = open_channel("mock-verify-a", "mock-verify-b");
+        let a_channel_id = channel.src_channel_id().unwrap().clone();
+
+        let events = transfer(&a, &b, &a_channel_id, 1000);
+        let IbcEvent::SendPacket(event) = &events[0].event else {
+            panic!("expected a SendPacket event, got {:?}", events[0].event);
+        };
+        let packet = event.packet.clone();
+
+        let proofs = a
+            .build_packet_proofs(
+                PacketMsgType::Recv,
+                &packet.source_port,
+                &packet.source_channel,
+                packet.sequence,
+                a.query_latest_height().unwrap(),
+            )
+            .unwrap();
+
+        let client = ForeignClient::restore(channel.dst_client_id().clone(), b, a);
+        let path = CommitmentsPath {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            sequence: packet.sequence,
+        };
+
+        // The client does not hold a consensus state at the proof height yet, so the proofs
+        // are verified against the header of the client update prepended to the batch
+        let client_updates = client
+            .wait_and_build_update_client(proofs.height())
+            .unwrap();
+        assert!(!client_updates.is_empty());
+
+        let verify = |packet: &Packet| {
+            let proof = verify::PendingProof::new(
+                proofs.object_proof().clone(),
+                proofs.height(),
+                &path,
+                Some(verify::packet_commitment(packet)),
+            );
+
+            verify::verify_proofs(&client, [&proof], &client_updates)
+        };
+
+        verify(&packet).unwrap();
+
+        // Without the client update, there is no root to verify the proofs against
+        let proof = verify::PendingProof::new(
+            proofs.object_proof().clone(),
+            proofs.height(),
+            &path,
+            Some(verify::packet_commitment(&packet)),
+        );
+        assert!(verify::verify_proofs(&client, [&proof], &[]).is_err());
+
+        // A proof of the commitment to another packet is rejected
+        let mut tampered = packet;
+        tampered.data = b"tampered".to_vec();
+        assert!(matches!(
+            verify(&tampered).unwrap_err().detail(),
+            LinkErrorDetail::InvalidProof(_)
+        ));
+    }
+
+    #[test]
+    fn failed_transactions_leave_state_untouched() {
+        let a = spawn("mock-failure-a");
+        let _b = spawn("mock-failure-b");
+
+        let key = MockChain::key_pair("cosmos").unwrap();
+        let before = balance(&a, "stake");
+
+        let msg = ibc_proto::cosmos::bank::v1beta1::MsgSend {
+            from_address: key.account(),
+            to_address: "cosmos1receiver".to_string(),
+            amount: vec![ibc_proto::cosmos::base::v1beta1::Coin {
+                denom: "stake".to_string(),
+                amount: (before + 1).to_string(),
+            }],
+        };
+
+        let events = a
+            .send_messages_and_wait_commit(TrackedMsgs::new_static(
+                vec![ibc_proto::google::protobuf::Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: prost::Message::encode_to_vec(&msg),
+                }],
+                "failing-send",
+            ))
+            .unwrap();
+
+        assert!(matches!(events[0].event, IbcEvent::ChainError(_)));
+        assert_eq!(balance(&a, "stake"), before);
+    }
+}
@@ -0,0 +1,122 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{types::TrustThreshold, PacketFilter, RefreshRate};
+
+/// Configuration of an in-process mock chain, which keeps its state in memory.
+///
+/// Every runtime spawned for the same chain identifier within a process
+/// shares the state of the chain.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockChainConfig {
+    pub id: ChainId,
+
+    /// The name of the key the relayer signs with.
+    /// Keys are derived from a fixed mnemonic, so that every key name maps to the same account.
+    #[serde(default = "default::key_name")]
+    pub key_name: String,
+
+    #[serde(default = "default::account_prefix")]
+    pub account_prefix: String,
+
+    /// The denomination of the native token of the chain
+    #[serde(default = "default::denom")]
+    pub denom: String,
+
+    /// The amount of native tokens the account of the relayer holds at genesis
+    #[serde(default = "default::genesis_balance")]
+    pub genesis_balance: u64,
+
+    /// The minimum time between two blocks. Blocks are produced on demand,
+    /// when a transaction is submitted or when the latest height is queried.
+    #[serde(default = "default::block_time", with = "humantime_serde")]
+    pub block_time: Duration,
+
+    #[serde(default = "default::trusting_period", with = "humantime_serde")]
+    pub trusting_period: Duration,
+
+    #[serde(default = "default::unbonding_period", with = "humantime_serde")]
+    pub unbonding_period: Duration,
+
+    #[serde(default = "default::max_block_time", with = "humantime_serde")]
+    pub max_block_time: Duration,
+
+    #[serde(default = "default::clock_drift", with = "humantime_serde")]
+    pub clock_drift: Duration,
+
+    #[serde(default)]
+    pub trust_threshold: TrustThreshold,
+
+    #[serde(default = "default::client_refresh_rate")]
+    pub client_refresh_rate: RefreshRate,
+
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+
+    pub clear_interval: Option<u64>,
+
+    #[serde(default = "default::query_packets_chunk_size")]
+    pub query_packets_chunk_size: usize,
+}
+
+impl MockChainConfig {
+    pub fn new(id: ChainId) -> Self {
+        Self {
+            id,
+            key_name: default::key_name(),
+            account_prefix: default::account_prefix(),
+            denom: default::denom(),
+            genesis_balance: default::genesis_balance(),
+            block_time: default::block_time(),
+            trusting_period: default::trusting_period(),
+            unbonding_period: default::unbonding_period(),
+            max_block_time: default::max_block_time(),
+            clock_drift: default::clock_drift(),
+            trust_threshold: TrustThreshold::default(),
+            client_refresh_rate: default::client_refresh_rate(),
+            packet_filter: PacketFilter::default(),
+            clear_interval: None,
+            query_packets_chunk_size: default::query_packets_chunk_size(),
+        }
+    }
+}
+
+pub mod default {
+    use super::*;
+
+    pub use crate::config::default::{
+        client_refresh_rate, clock_drift, max_block_time, query_packets_chunk_size,
+    };
+
+    pub fn key_name() -> String {
+        "relayer".to_string()
+    }
+
+    pub fn account_prefix() -> String {
+        "cosmos".to_string()
+    }
+
+    pub fn denom() -> String {
+        "stake".to_string()
+    }
+
+    pub fn genesis_balance() -> u64 {
+        1_000_000_000
+    }
+
+    pub fn block_time() -> Duration {
+        Duration::from_millis(100)
+    }
+
+    pub fn trusting_period() -> Duration {
+        Duration::from_secs(14 * 24 * 3600)
+    }
+
+    pub fn unbonding_period() -> Duration {
+        Duration::from_secs(21 * 24 * 3600)
+    }
+}
@@ -0,0 +1,1848 @@
+//! The state machine of a mock chain.
+//!
+//! The host executes the transactions submitted to the chain against its in-memory
+//! [`Store`], and commits to the resulting state in a block signed by a single validator.
+//! The state after every block is kept around, so that the chain can be queried,
+//! and can prove its state, at any past height.
+//!
+//! Only the subset of IBC needed to relay between two mock chains is implemented:
+//! Tendermint clients, connection and channel handshakes, ICS-20 transfers,
+//! and bank transfers. Headers submitted in client updates are not verified,
+//! but the proofs of the counterparty state are.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::str::FromStr;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use ed25519_dalek::{Signer as _, SigningKey};
+use once_cell::sync::Lazy;
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::commitment::v1::{MerkleProof as RawMerkleProof, MerkleRoot};
+use ibc_proto::ibc::core::connection::v1::ClientPaths;
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::Protobuf;
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{
+    MsgTransferV2, TYPE_URL as TRANSFER_TYPE_URL,
+};
+use ibc_relayer_types::applications::transfer::packet::{
+    PacketData, PacketDataV2, TransferPacketData,
+};
+use ibc_relayer_types::applications::transfer::{
+    is_receiver_chain_source, is_sender_chain_source, Amount, PrefixedCoin, PrefixedDenom,
+    TracePrefix,
+};
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
+use ibc_relayer_types::core::ics02_client::events::{
+    self as client_events, Attributes as ClientAttributes, NewBlock,
+};
+use ibc_relayer_types::core::ics02_client::header::AnyHeader;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::{
+    MsgCreateClient, TYPE_URL as CREATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{
+    MsgUpdateClient, TYPE_URL as UPDATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events::{
+    self as connection_events, Attributes as ConnectionAttributes,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_ack::{
+    MsgConnectionOpenAck, TYPE_URL as CONN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_confirm::{
+    MsgConnectionOpenConfirm, TYPE_URL as CONN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_init::{
+    MsgConnectionOpenInit, TYPE_URL as CONN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_try::{
+    MsgConnectionOpenTry, TYPE_URL as CONN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, pick_version};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, Ordering, State as ChannelState, UpgradeState,
+};
+use ibc_relayer_types::core::ics04_channel::events as channel_events;
+use ibc_relayer_types::core::ics04_channel::msgs::acknowledgement::{
+    MsgAcknowledgement, TYPE_URL as ACKNOWLEDGEMENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_confirm::{
+    MsgChannelCloseConfirm, TYPE_URL as CHAN_CLOSE_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_init::{
+    MsgChannelCloseInit, TYPE_URL as CHAN_CLOSE_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::{
+    MsgChannelOpenAck, TYPE_URL as CHAN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::{
+    MsgChannelOpenConfirm, TYPE_URL as CHAN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::{
+    MsgChannelOpenInit, TYPE_URL as CHAN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::{
+    MsgChannelOpenTry, TYPE_URL as CHAN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::recv_packet::{
+    MsgRecvPacket, TYPE_URL as RECV_PACKET_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout::{
+    MsgTimeout, TYPE_URL as TIMEOUT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::timeout_on_close::{
+    MsgTimeoutOnClose, TYPE_URL as TIMEOUT_ON_CLOSE_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics04_channel::version::Version as ChannelVersion;
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConnectionsPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentsPath, ConnectionsPath, ReceiptsPath, SeqAcksPath, SeqRecvsPath, SeqSendsPath,
+};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+use tendermint::block::signed_header::SignedHeader;
+use tendermint::block::{self, parts::Header as PartSetHeader, Commit, CommitSig};
+use tendermint::{validator, vote, AppHash, Hash, PublicKey, Time};
+use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
+
+use crate::chain::handle::Subscription;
+use crate::chain::mock::config::MockChainConfig;
+use crate::chain::mock::store::{Store, IBC_STORE_NAME};
+use crate::chain::requests::QueryHeight;
+use crate::chain::tracking::TrackingId;
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::{ibc_denom, DenomTrace};
+use crate::event::bus::EventBus;
+use crate::event::source::{EventBatch, Result as EventResult};
+use crate::event::IbcEventWithHeight;
+use crate::link::verify::{ack_commitment, packet_commitment};
+use crate::util::lock::{LockExt, RwArc};
+
+const BANK_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// The hosts of all the mock chains of the process, by chain identifier
+static HOSTS: Lazy<RwLock<HashMap<ChainId, RwArc<MockHost>>>> = Lazy::new(Default::default);
+
+/// Get the host of the chain with the given configuration, creating it if needed.
+///
+/// At genesis, the given account holds `config.genesis_balance` of the native token.
+pub fn host(config: &MockChainConfig, genesis_account: &str) -> RwArc<MockHost> {
+    let mut hosts = HOSTS.write().expect("poisoned lock");
+
+    hosts
+        .entry(config.id.clone())
+        .or_insert_with(|| RwArc::new_lock(MockHost::new(config.clone(), genesis_account)))
+        .clone()
+}
+
+/// The account holding the tokens escrowed for transfers over the given channel
+fn escrow_account(port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("escrow/{port_id}/{channel_id}")
+}
+
+/// A block of the chain, along with the state of the application after it
+#[derive(Clone, Debug)]
+pub struct Block {
+    pub light_block: TmLightBlock,
+    pub state: State,
+    pub events: Vec<IbcEventWithHeight>,
+}
+
+impl Block {
+    pub fn time(&self) -> Time {
+        self.light_block.signed_header.header.time
+    }
+}
+
+/// The height and time of the block a transaction is executed in
+struct BlockContext {
+    height: Height,
+    timestamp: Timestamp,
+}
+
+/// The state of the application
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    store: Store,
+    balances: BTreeMap<(String, String), Amount>,
+    denom_traces: BTreeMap<String, DenomTrace>,
+    client_counter: u64,
+    connection_counter: u64,
+    channel_counter: u64,
+}
+
+pub struct MockHost {
+    config: MockChainConfig,
+    validator: Validator,
+    state: State,
+    blocks: Vec<Block>,
+    txs: HashMap<Hash, Vec<IbcEventWithHeight>>,
+    event_bus: EventBus<Arc<EventResult<EventBatch>>>,
+}
+
+impl MockHost {
+    fn new(config: MockChainConfig, genesis_account: &str) -> Self {
+        let mut state = State::default();
+        state.mint(
+            genesis_account,
+            &config.denom,
+            Amount::from(config.genesis_balance),
+        );
+
+        let mut host = Self {
+            validator: Validator::new(&config.id),
+            config,
+            state,
+            blocks: Vec::new(),
+            txs: HashMap::new(),
+            event_bus: EventBus::new(),
+        };
+
+        host.produce_block(host.next_time(), Vec::new());
+        host
+    }
+
+    pub fn config(&self) -> &MockChainConfig {
+        &self.config
+    }
+
+    pub fn subscribe(&mut self) -> Subscription {
+        self.event_bus.subscribe()
+    }
+
+    /// Produce an empty block if none was produced during the last block time
+    pub fn tick(&mut self) {
+        let elapsed = Time::now()
+            .duration_since(self.latest_block().time())
+            .unwrap_or_default();
+
+        if elapsed >= self.config.block_time {
+            self.produce_block(self.next_time(), Vec::new());
+        }
+    }
+
+    pub fn latest_block(&self) -> &Block {
+        self.blocks
+            .last()
+            .expect("the genesis block is produced on creation")
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.height(self.blocks.len() as u64)
+    }
+
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    pub fn block(&self, height: Height) -> Option<&Block> {
+        let index = height.revision_height().checked_sub(1)?;
+        self.blocks.get(index as usize)
+    }
+
+    /// The state of the application after the block at the given height, along with that height
+    pub fn state_at(&self, height: QueryHeight) -> Option<(&State, Height)> {
+        match height {
+            QueryHeight::Latest => Some((&self.latest_block().state, self.latest_height())),
+            QueryHeight::Specific(height) => {
+                let block = self.block(height)?;
+                Some((&block.state, height))
+            }
+        }
+    }
+
+    pub fn tx_events(&self, hash: &Hash) -> Option<&Vec<IbcEventWithHeight>> {
+        self.txs.get(hash)
+    }
+
+    /// Execute the given messages atomically, in a new block.
+    ///
+    /// If any message fails, the state is left untouched and the error of the message is returned.
+    pub fn submit(&mut self, msgs: &[Any]) -> Result<(Hash, Vec<IbcEventWithHeight>), String> {
+        let time = self.next_time();
+        let height = self.height(self.blocks.len() as u64 + 1);
+        let context = BlockContext {
+            height,
+            timestamp: time.into(),
+        };
+
+        let mut state = self.state.clone();
+        let mut events = Vec::new();
+
+        for (index, msg) in msgs.iter().enumerate() {
+            let msg_events = state.deliver(&context, msg).map_err(|e| {
+                format!("failed to execute message {index} ({}): {e}", msg.type_url)
+            })?;
+
+            events.extend(msg_events);
+        }
+
+        self.state = state;
+
+        let mut preimage = height.revision_height().to_be_bytes().to_vec();
+        for msg in msgs {
+            preimage.extend(msg.encode_to_vec());
+        }
+        let hash = Hash::Sha256(Sha256::digest(preimage).into());
+
+        let events = self.produce_block(time, events);
+        self.txs.insert(hash, events.clone());
+
+        Ok((hash, events))
+    }
+
+    fn height(&self, revision_height: u64) -> Height {
+        Height::new(self.config.id.version(), revision_height).expect("block heights are positive")
+    }
+
+    fn next_time(&self) -> Time {
+        let now = Time::now();
+
+        match self.blocks.last() {
+            Some(block) => {
+                let min = (block.time() + Duration::from_millis(1)).expect("valid block time");
+                if now > min {
+                    now
+                } else {
+                    min
+                }
+            }
+            None => now,
+        }
+    }
+
+    /// Commit to the current state in a new block and broadcast its events
+    fn produce_block(&mut self, time: Time, events: Vec<IbcEvent>) -> Vec<IbcEventWithHeight> {
+        let height = self.height(self.blocks.len() as u64 + 1);
+
+        // As in Tendermint, the header of a block commits to the state after the previous block
+        let (app_hash, last_block_id) = match self.blocks.last() {
+            Some(block) => (
+                block.state.store.app_hash(),
+                Some(block.light_block.signed_header.commit.block_id),
+            ),
+            None => (Store::default().app_hash(), None),
+        };
+
+        let light_block = self.validator.sign_block(
+            &self.config.id,
+            height.revision_height(),
+            time,
+            app_hash,
+            last_block_id,
+        );
+
+        let events: Vec<_> = events
+            .into_iter()
+            .map(|event| IbcEventWithHeight::new(event, height))
+            .collect();
+
+        self.blocks.push(Block {
+            light_block,
+            state: self.state.clone(),
+            events: events.clone(),
+        });
+
+        let mut batch_events = vec![IbcEventWithHeight::new(
+            IbcEvent::NewBlock(NewBlock::new(height)),
+            height,
+        )];
+        batch_events.extend(events.iter().cloned());
+
+        self.event_bus.broadcast(Arc::new(Ok(EventBatch {
+            chain_id: self.config.id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events: batch_events,
+        })));
+
+        events
+    }
+}
+
+/// The single validator of a mock chain, whose key is derived from the chain identifier
+struct Validator {
+    signing_key: SigningKey,
+    info: validator::Info,
+}
+
+impl Validator {
+    fn new(chain_id: &ChainId) -> Self {
+        let seed: [u8; 32] = Sha256::digest(chain_id.as_str()).into();
+        let signing_key = SigningKey::from_bytes(&seed);
+
+        let public_key = PublicKey::from_raw_ed25519(signing_key.verifying_key().as_bytes())
+            .expect("valid ed25519 public key");
+
+        Self {
+            signing_key,
+            info: validator::Info::new(public_key, vote::Power::from(1_u32)),
+        }
+    }
+
+    fn validator_set(&self) -> validator::Set {
+        validator::Set::new(vec![self.info.clone()], Some(self.info.clone()))
+    }
+
+    fn sign_block(
+        &self,
+        chain_id: &ChainId,
+        height: u64,
+        time: Time,
+        app_hash: Vec<u8>,
+        last_block_id: Option<block::Id>,
+    ) -> TmLightBlock {
+        let chain_id =
+            tendermint::chain::Id::try_from(chain_id.to_string()).expect("valid chain identifier");
+        let height = block::Height::try_from(height).expect("valid block height");
+        let validators = self.validator_set();
+
+        let header = block::Header {
+            version: block::header::Version { block: 11, app: 0 },
+            chain_id: chain_id.clone(),
+            height,
+            time,
+            last_block_id,
+            last_commit_hash: None,
+            data_hash: None,
+            validators_hash: validators.hash(),
+            next_validators_hash: validators.hash(),
+            consensus_hash: Hash::Sha256(Sha256::digest(b"consensus").into()),
+            app_hash: AppHash::try_from(app_hash).expect("valid app hash"),
+            last_results_hash: None,
+            evidence_hash: None,
+            proposer_address: self.info.address,
+        };
+
+        let header_hash = header.hash();
+        let block_id = block::Id {
+            hash: header_hash,
+            part_set_header: PartSetHeader::new(
+                1,
+                Hash::Sha256(Sha256::digest(header_hash.as_bytes()).into()),
+            )
+            .expect("valid part set header"),
+        };
+
+        let vote = vote::Vote {
+            vote_type: vote::Type::Precommit,
+            height,
+            round: block::Round::default(),
+            block_id: Some(block_id),
+            timestamp: Some(time),
+            validator_address: self.info.address,
+            validator_index: vote::ValidatorIndex::try_from(0_u32).expect("valid validator index"),
+            signature: None,
+            extension: Vec::new(),
+            extension_signature: None,
+        };
+
+        let signature = self.signing_key.sign(&vote.into_signable_vec(chain_id));
+
+        let commit = Commit {
+            height,
+            round: block::Round::default(),
+            block_id,
+            signatures: vec![CommitSig::BlockIdFlagCommit {
+                validator_address: self.info.address,
+                timestamp: time,
+                signature: Some(signature.into()),
+            }],
+        };
+
+        let signed_header = SignedHeader::new(header, commit).expect("valid signed header");
+
+        TmLightBlock::new(
+            signed_header,
+            validators.clone(),
+            validators,
+            tendermint::node::Id::new([0; 20]),
+        )
+    }
+}
+
+impl State {
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn balance(&self, account: &str, denom: &str) -> Amount {
+        self.balances
+            .get(&(account.to_string(), denom.to_string()))
+            .copied()
+            .unwrap_or_else(|| Amount::from(0_u64))
+    }
+
+    pub fn balances(&self, account: &str) -> Vec<(String, Amount)> {
+        self.balances
+            .iter()
+            .filter(|((holder, _), _)| holder == account)
+            .map(|((_, denom), amount)| (denom.clone(), *amount))
+            .collect()
+    }
+
+    pub fn denom_trace(&self, hash: &str) -> Option<&DenomTrace> {
+        self.denom_traces.get(hash)
+    }
+
+    pub fn client_state(&self, client_id: &ClientId) -> Result<AnyClientState, String> {
+        let value = self
+            .store
+            .get(&ClientStatePath(client_id.clone()).to_string())
+            .ok_or_else(|| format!("client {client_id} not found"))?;
+
+        AnyClientState::decode_vec(value).map_err(|e| e.to_string())
+    }
+
+    pub fn consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<AnyConsensusState, String> {
+        let value = self
+            .store
+            .get(&consensus_state_path(client_id, height))
+            .ok_or_else(|| {
+                format!("consensus state of client {client_id} at {height} not found")
+            })?;
+
+        AnyConsensusState::decode_vec(value).map_err(|e| e.to_string())
+    }
+
+    fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, String> {
+        let value = self
+            .store
+            .get(&ConnectionsPath(connection_id.clone()).to_string())
+            .ok_or_else(|| format!("connection {connection_id} not found"))?;
+
+        ConnectionEnd::decode_vec(value).map_err(|e| e.to_string())
+    }
+
+    fn channel_end(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<ChannelEnd, String> {
+        let value = self
+            .store
+            .get(&ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string())
+            .ok_or_else(|| format!("channel {channel_id} on port {port_id} not found"))?;
+
+        ChannelEnd::decode_vec(value).map_err(|e| e.to_string())
+    }
+
+    fn sequence(&self, path: String) -> Result<Sequence, String> {
+        let value = self
+            .store
+            .get(&path)
+            .ok_or_else(|| format!("sequence `{path}` not found"))?;
+
+        let bytes: [u8; 8] = value
+            .as_slice()
+            .try_into()
+            .map_err(|_| format!("malformed sequence `{path}`"))?;
+
+        Ok(Sequence::from(u64::from_be_bytes(bytes)))
+    }
+
+    fn set_sequence(&mut self, path: String, sequence: Sequence) {
+        self.store
+            .set(path, sequence.as_u64().to_be_bytes().to_vec());
+    }
+
+    fn mint(&mut self, account: &str, denom: &str, amount: Amount) {
+        let balance = self.balance(account, denom);
+        self.balances.insert(
+            (account.to_string(), denom.to_string()),
+            balance.checked_add(amount).unwrap_or(balance),
+        );
+    }
+
+    fn burn(&mut self, account: &str, denom: &str, amount: Amount) -> Result<(), String> {
+        let balance = self
+            .balance(account, denom)
+            .checked_sub(amount)
+            .ok_or_else(|| {
+                format!("insufficient funds: {account} holds less than {amount}{denom}")
+            })?;
+
+        self.balances
+            .insert((account.to_string(), denom.to_string()), balance);
+
+        Ok(())
+    }
+
+    fn send(&mut self, from: &str, to: &str, denom: &str, amount: Amount) -> Result<(), String> {
+        self.burn(from, denom, amount)?;
+        self.mint(to, denom, amount);
+        Ok(())
+    }
+
+    fn deliver(&mut self, context: &BlockContext, msg: &Any) -> Result<Vec<IbcEvent>, String> {
+        fn decode<T, R>(msg: &Any) -> Result<T, String>
+        where
+            T: Protobuf<R>,
+            R: Message + Default + From<T>,
+            T: TryFrom<R>,
+            <T as TryFrom<R>>::Error: core::fmt::Display,
+        {
+            T::decode_vec(&msg.value).map_err(|e| e.to_string())
+        }
+
+        match msg.type_url.as_str() {
+            CREATE_CLIENT_TYPE_URL => self.create_client(decode(msg)?),
+            UPDATE_CLIENT_TYPE_URL => self.update_client(decode(msg)?),
+            CONN_OPEN_INIT_TYPE_URL => self.conn_open_init(decode(msg)?),
+            CONN_OPEN_TRY_TYPE_URL => self.conn_open_try(decode(msg)?),
+            CONN_OPEN_ACK_TYPE_URL => self.conn_open_ack(decode(msg)?),
+            CONN_OPEN_CONFIRM_TYPE_URL => self.conn_open_confirm(decode(msg)?),
+            CHAN_OPEN_INIT_TYPE_URL => self.chan_open_init(decode(msg)?),
+            CHAN_OPEN_TRY_TYPE_URL => self.chan_open_try(decode(msg)?),
+            CHAN_OPEN_ACK_TYPE_URL => self.chan_open_ack(decode(msg)?),
+            CHAN_OPEN_CONFIRM_TYPE_URL => self.chan_open_confirm(decode(msg)?),
+            CHAN_CLOSE_INIT_TYPE_URL => self.chan_close_init(decode(msg)?),
+            CHAN_CLOSE_CONFIRM_TYPE_URL => self.chan_close_confirm(decode(msg)?),
+            TRANSFER_TYPE_URL => self.transfer(context, decode(msg)?),
+            RECV_PACKET_TYPE_URL => self.recv_packet(context, decode(msg)?),
+            ACKNOWLEDGEMENT_TYPE_URL => self.acknowledge_packet(decode(msg)?),
+            TIMEOUT_TYPE_URL => self.timeout_packet(decode(msg)?),
+            TIMEOUT_ON_CLOSE_TYPE_URL => self.timeout_on_close_packet(decode(msg)?),
+            BANK_SEND_TYPE_URL => {
+                let msg = MsgSend::decode(msg.value.as_slice()).map_err(|e| e.to_string())?;
+                self.bank_send(msg)
+            }
+            type_url => Err(format!("unsupported message type {type_url}")),
+        }
+    }
+
+    /// Verify that the counterparty chain of the given client stores the given value at the given path,
+    /// or that it stores nothing at that path if no value is given.
+    fn verify(
+        &self,
+        client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        proof_height: Height,
+        path: String,
+        value: Option<Vec<u8>>,
+    ) -> Result<(), String> {
+        let proof_specs = match self.client_state(client_id)? {
+            AnyClientState::Tendermint(client_state) => {
+                if client_state.frozen_height.is_some() {
+                    return Err(format!("client {client_id} is frozen"));
+                }
+                client_state.proof_specs
+            }
+            AnyClientState::Wasm(client_state) => {
+                if client_state.tendermint.frozen_height.is_some() {
+                    return Err(format!("client {client_id} is frozen"));
+                }
+                client_state.tendermint.proof_specs
+            }
+            AnyClientState::SoloMachine(_) => {
+                return Err(format!(
+                    "client {client_id} is a solo machine client, which the mock chain does not support"
+                ))
+            }
+        };
+
+        let consensus_state = self.consensus_state(client_id, proof_height)?;
+        let root = MerkleRoot::from(consensus_state.root().clone());
+
+        let proof: MerkleProof = RawMerkleProof::try_from(proof.clone())
+            .map_err(|e| e.to_string())?
+            .into();
+
+        let merkle_path = apply_prefix(prefix, vec![path.clone()]);
+
+        match value {
+            Some(value) => proof.verify_membership(&proof_specs, root, merkle_path, value, 0),
+            None => proof.verify_non_membership(&proof_specs, root, merkle_path),
+        }
+        .map_err(|e| format!("failed to verify the proof of `{path}` at {proof_height}: {e}"))
+    }
+
+    /// Verify the proof that the counterparty chain stores a value at the given path,
+    /// and return that value.
+    fn verify_proven<T, R>(
+        &self,
+        client_id: &ClientId,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        proof_height: Height,
+        path: String,
+    ) -> Result<T, String>
+    where
+        T: Protobuf<R>,
+        R: Message + Default + From<T>,
+        T: TryFrom<R>,
+        <T as TryFrom<R>>::Error: core::fmt::Display,
+    {
+        let raw = RawMerkleProof::try_from(proof.clone()).map_err(|e| e.to_string())?;
+
+        let value = match raw.proofs.first().and_then(|proof| proof.proof.as_ref()) {
+            Some(Proof::Exist(existence_proof)) => existence_proof.value.clone(),
+            _ => return Err(format!("expected a proof of membership of `{path}`")),
+        };
+
+        self.verify(
+            client_id,
+            prefix,
+            proof,
+            proof_height,
+            path,
+            Some(value.clone()),
+        )?;
+
+        T::decode_vec(&value).map_err(|e| e.to_string())
+    }
+
+    fn open_connection_of(
+        &self,
+        channel_end: &ChannelEnd,
+    ) -> Result<(ConnectionId, ConnectionEnd), String> {
+        let connection_id = channel_end
+            .connection_hops()
+            .first()
+            .ok_or_else(|| "channel has no connection hops".to_string())?
+            .clone();
+
+        let connection_end = self.connection_end(&connection_id)?;
+        if !connection_end.is_open() {
+            return Err(format!("connection {connection_id} is not open"));
+        }
+
+        Ok((connection_id, connection_end))
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, String> {
+        let client_state =
+            AnyClientState::try_from(msg.client_state.clone()).map_err(|e| e.to_string())?;
+        AnyConsensusState::try_from(msg.consensus_state.clone()).map_err(|e| e.to_string())?;
+
+        let client_type = client_state.client_type();
+        let client_id =
+            ClientId::new(client_type, self.client_counter).map_err(|e| e.to_string())?;
+        self.client_counter += 1;
+
+        let height = client_state.latest_height();
+
+        self.store.set(
+            ClientStatePath(client_id.clone()).to_string(),
+            msg.client_state.encode_to_vec(),
+        );
+        self.store.set(
+            consensus_state_path(&client_id, height),
+            msg.consensus_state.encode_to_vec(),
+        );
+
+        Ok(vec![IbcEvent::CreateClient(client_events::CreateClient(
+            ClientAttributes {
+                client_id,
+                client_type,
+                consensus_height: height,
+            },
+        ))])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, String> {
+        let AnyClientState::Tendermint(client_state) = self.client_state(&msg.client_id)? else {
+            return Err(format!(
+                "client {} cannot be updated, only Tendermint clients are supported",
+                msg.client_id
+            ));
+        };
+
+        let header = TmHeader::try_from(msg.header).map_err(|e| e.to_string())?;
+
+        if header.signed_header.header.chain_id.as_str() != client_state.chain_id.as_str() {
+            return Err(format!(
+                "header of chain {} cannot update a client of chain {}",
+                header.signed_header.header.chain_id, client_state.chain_id
+            ));
+        }
+
+        // Headers are not verified: it is enough for them to build on a trusted consensus state
+        self.consensus_state(&msg.client_id, header.trusted_height)?;
+
+        let height = header.height();
+        let client_type = client_state.client_type();
+
+        if height > client_state.latest_height() {
+            let client_state = client_state
+                .with_header(header.clone())
+                .map_err(|e| e.to_string())?;
+
+            self.store.set(
+                ClientStatePath(msg.client_id.clone()).to_string(),
+                Protobuf::<Any>::encode_vec(client_state),
+            );
+        }
+
+        self.store.set(
+            consensus_state_path(&msg.client_id, height),
+            Protobuf::<Any>::encode_vec(TmConsensusState::from(header.clone())),
+        );
+
+        Ok(vec![IbcEvent::UpdateClient(client_events::UpdateClient {
+            common: ClientAttributes {
+                client_id: msg.client_id,
+                client_type,
+                consensus_height: height,
+            },
+            header: Some(AnyHeader::Tendermint(header)),
+        })])
+    }
+
+    fn add_client_connection(&mut self, client_id: &ClientId, connection_id: &ConnectionId) {
+        let path = ClientConnectionsPath(client_id.clone()).to_string();
+
+        let mut client_paths = self
+            .store
+            .get(&path)
+            .and_then(|value| ClientPaths::decode(value.as_slice()).ok())
+            .unwrap_or_default();
+
+        client_paths.paths.push(connection_id.to_string());
+        self.store.set(path, client_paths.encode_to_vec());
+    }
+
+    fn conn_open_init(&mut self, msg: MsgConnectionOpenInit) -> Result<Vec<IbcEvent>, String> {
+        self.client_state(&msg.client_id)?;
+
+        let versions = match msg.version {
+            Some(version) => vec![version],
+            None => get_compatible_versions(),
+        };
+
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        let counterparty_client_id = msg.counterparty.client_id().clone();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            msg.client_id.clone(),
+            msg.counterparty,
+            versions,
+            msg.delay_period,
+        );
+
+        self.store.set(
+            ConnectionsPath(connection_id.clone()).to_string(),
+            connection_end.encode_vec(),
+        );
+        self.add_client_connection(&msg.client_id, &connection_id);
+
+        Ok(vec![IbcEvent::OpenInitConnection(
+            connection_events::OpenInit(ConnectionAttributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: None,
+                counterparty_client_id,
+            }),
+        )])
+    }
+
+    fn conn_open_try(&mut self, msg: MsgConnectionOpenTry) -> Result<Vec<IbcEvent>, String> {
+        let counterparty_connection_id = msg
+            .counterparty
+            .connection_id()
+            .ok_or_else(|| "missing counterparty connection identifier".to_string())?
+            .clone();
+
+        let counterparty_end: ConnectionEnd = self.verify_proven(
+            &msg.client_id,
+            msg.counterparty.prefix(),
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+            ConnectionsPath(counterparty_connection_id.clone()).to_string(),
+        )?;
+
+        if !counterparty_end.state_matches(&ConnectionState::Init)
+            || counterparty_end.client_id() != msg.counterparty.client_id()
+            || counterparty_end.counterparty().client_id() != &msg.client_id
+        {
+            return Err(format!(
+                "counterparty connection {counterparty_connection_id} does not match"
+            ));
+        }
+
+        let version = pick_version(get_compatible_versions(), msg.counterparty_versions)
+            .map_err(|e| e.to_string())?;
+
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+
+        let counterparty_client_id = msg.counterparty.client_id().clone();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::TryOpen,
+            msg.client_id.clone(),
+            msg.counterparty,
+            vec![version],
+            msg.delay_period,
+        );
+
+        self.store.set(
+            ConnectionsPath(connection_id.clone()).to_string(),
+            connection_end.encode_vec(),
+        );
+        self.add_client_connection(&msg.client_id, &connection_id);
+
+        Ok(vec![IbcEvent::OpenTryConnection(
+            connection_events::OpenTry(ConnectionAttributes {
+                connection_id: Some(connection_id),
+                client_id: msg.client_id,
+                counterparty_connection_id: Some(counterparty_connection_id),
+                counterparty_client_id,
+            }),
+        )])
+    }
+
+    fn conn_open_ack(&mut self, msg: MsgConnectionOpenAck) -> Result<Vec<IbcEvent>, String> {
+        let mut connection_end = self.connection_end(&msg.connection_id)?;
+
+        if !connection_end.state_matches(&ConnectionState::Init) {
+            return Err(format!(
+                "connection {} is not in state Init",
+                msg.connection_id
+            ));
+        }
+
+        let counterparty_end: ConnectionEnd = self.verify_proven(
+            connection_end.client_id(),
+            connection_end.counterparty().prefix(),
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+            ConnectionsPath(msg.counterparty_connection_id.clone()).to_string(),
+        )?;
+
+        if !counterparty_end.state_matches(&ConnectionState::TryOpen)
+            || counterparty_end.client_id() != connection_end.counterparty().client_id()
+            || counterparty_end.counterparty().connection_id() != Some(&msg.connection_id)
+        {
+            return Err(format!(
+                "counterparty connection {} does not match",
+                msg.counterparty_connection_id
+            ));
+        }
+
+        let counterparty = ConnectionCounterparty::new(
+            connection_end.counterparty().client_id().clone(),
+            Some(msg.counterparty_connection_id.clone()),
+            connection_end.counterparty().prefix().clone(),
+        );
+
+        connection_end.set_state(ConnectionState::Open);
+        connection_end.set_counterparty(counterparty);
+        connection_end.set_version(msg.version);
+
+        self.store.set(
+            ConnectionsPath(msg.connection_id.clone()).to_string(),
+            connection_end.clone().encode_vec(),
+        );
+
+        Ok(vec![IbcEvent::OpenAckConnection(
+            connection_events::OpenAck(ConnectionAttributes {
+                connection_id: Some(msg.connection_id),
+                client_id: connection_end.client_id().clone(),
+                counterparty_connection_id: Some(msg.counterparty_connection_id),
+                counterparty_client_id: connection_end.counterparty().client_id().clone(),
+            }),
+        )])
+    }
+
+    fn conn_open_confirm(
+        &mut self,
+        msg: MsgConnectionOpenConfirm,
+    ) -> Result<Vec<IbcEvent>, String> {
+        let mut connection_end = self.connection_end(&msg.connection_id)?;
+
+        if !connection_end.state_matches(&ConnectionState::TryOpen) {
+            return Err(format!(
+                "connection {} is not in state TryOpen",
+                msg.connection_id
+            ));
+        }
+
+        let counterparty_connection_id = connection_end
+            .counterparty()
+            .connection_id()
+            .ok_or_else(|| "missing counterparty connection identifier".to_string())?
+            .clone();
+
+        let counterparty_end: ConnectionEnd = self.verify_proven(
+            connection_end.client_id(),
+            connection_end.counterparty().prefix(),
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+            ConnectionsPath(counterparty_connection_id.clone()).to_string(),
+        )?;
+
+        if !counterparty_end.state_matches(&ConnectionState::Open)
+            || counterparty_end.counterparty().connection_id() != Some(&msg.connection_id)
+        {
+            return Err(format!(
+                "counterparty connection {counterparty_connection_id} does not match"
+            ));
+        }
+
+        connection_end.set_state(ConnectionState::Open);
+
+        self.store.set(
+            ConnectionsPath(msg.connection_id.clone()).to_string(),
+            connection_end.clone().encode_vec(),
+        );
+
+        Ok(vec![IbcEvent::OpenConfirmConnection(
+            connection_events::OpenConfirm(ConnectionAttributes {
+                connection_id: Some(msg.connection_id),
+                client_id: connection_end.client_id().clone(),
+                counterparty_connection_id: Some(counterparty_connection_id),
+                counterparty_client_id: connection_end.counterparty().client_id().clone(),
+            }),
+        )])
+    }
+
+    fn new_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_end: ChannelEnd,
+    ) -> Result<(ChannelId, ConnectionId), String> {
+        let (connection_id, _) = self.open_connection_of(&channel_end)?;
+
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+
+        self.store.set(
+            ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string(),
+            channel_end.encode_vec(),
+        );
+
+        for path in [
+            SeqSendsPath(port_id.clone(), channel_id.clone()).to_string(),
+            SeqRecvsPath(port_id.clone(), channel_id.clone()).to_string(),
+            SeqAcksPath(port_id.clone(), channel_id.clone()).to_string(),
+        ] {
+            self.set_sequence(path, Sequence::from(1));
+        }
+
+        Ok((channel_id, connection_id))
+    }
+
+    fn set_channel_end(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_end: ChannelEnd,
+    ) {
+        self.store.set(
+            ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string(),
+            channel_end.encode_vec(),
+        );
+    }
+
+    /// Verify the proof of the channel end at the other end of the given channel
+    fn verify_counterparty_channel(
+        &self,
+        channel_end: &ChannelEnd,
+        counterparty_channel_id: &ChannelId,
+        proof: &CommitmentProofBytes,
+        proof_height: Height,
+    ) -> Result<ChannelEnd, String> {
+        let (_, connection_end) = self.open_connection_of(channel_end)?;
+
+        self.verify_proven(
+            connection_end.client_id(),
+            connection_end.counterparty().prefix(),
+            proof,
+            proof_height,
+            ChannelEndsPath(
+                channel_end.counterparty().port_id().clone(),
+                counterparty_channel_id.clone(),
+            )
+            .to_string(),
+        )
+    }
+
+    fn chan_open_init(&mut self, msg: MsgChannelOpenInit) -> Result<Vec<IbcEvent>, String> {
+        let mut channel_end = msg.channel;
+
+        if channel_end.version().0.is_empty() && msg.port_id == PortId::transfer() {
+            channel_end.set_version(ChannelVersion::ics20());
+        }
+
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+        let (channel_id, connection_id) = self.new_channel(&msg.port_id, channel_end)?;
+
+        Ok(vec![IbcEvent::OpenInitChannel(channel_events::OpenInit {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id,
+            counterparty_channel_id: None,
+        })])
+    }
+
+    fn chan_open_try(&mut self, msg: MsgChannelOpenTry) -> Result<Vec<IbcEvent>, String> {
+        let counterparty_channel_id = msg
+            .channel
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| "missing counterparty channel identifier".to_string())?
+            .clone();
+
+        let counterparty_end = self.verify_counterparty_channel(
+            &msg.channel,
+            &counterparty_channel_id,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        if !counterparty_end.state_matches(&ChannelState::Init)
+            || counterparty_end.counterparty().port_id() != &msg.port_id
+            || counterparty_end.ordering() != msg.channel.ordering()
+        {
+            return Err(format!(
+                "counterparty channel {counterparty_channel_id} does not match"
+            ));
+        }
+
+        let channel_end = ChannelEnd::new(
+            ChannelState::TryOpen,
+            *msg.channel.ordering(),
+            msg.channel.counterparty().clone(),
+            msg.channel.connection_hops().clone(),
+            msg.counterparty_version,
+            Sequence::from(0),
+        );
+
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+        let (channel_id, connection_id) = self.new_channel(&msg.port_id, channel_end)?;
+
+        Ok(vec![IbcEvent::OpenTryChannel(channel_events::OpenTry {
+            port_id: msg.port_id,
+            channel_id: Some(channel_id),
+            connection_id,
+            counterparty_port_id,
+            counterparty_channel_id: Some(counterparty_channel_id),
+        })])
+    }
+
+    fn chan_open_ack(&mut self, msg: MsgChannelOpenAck) -> Result<Vec<IbcEvent>, String> {
+        let mut channel_end = self.channel_end(&msg.port_id, &msg.channel_id)?;
+
+        if !channel_end.state_matches(&ChannelState::Init) {
+            return Err(format!("channel {} is not in state Init", msg.channel_id));
+        }
+
+        let counterparty_end = self.verify_counterparty_channel(
+            &channel_end,
+            &msg.counterparty_channel_id,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        if !counterparty_end.state_matches(&ChannelState::TryOpen)
+            || counterparty_end.counterparty().channel_id() != Some(&msg.channel_id)
+        {
+            return Err(format!(
+                "counterparty channel {} does not match",
+                msg.counterparty_channel_id
+            ));
+        }
+
+        channel_end.set_state(ChannelState::Open(UpgradeState::NotUpgrading));
+        channel_end.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+        channel_end.set_version(msg.counterparty_version);
+
+        let (connection_id, _) = self.open_connection_of(&channel_end)?;
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+        self.set_channel_end(&msg.port_id, &msg.channel_id, channel_end);
+
+        Ok(vec![IbcEvent::OpenAckChannel(channel_events::OpenAck {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            counterparty_channel_id: Some(msg.counterparty_channel_id),
+            connection_id,
+            counterparty_port_id,
+        })])
+    }
+
+    fn chan_open_confirm(&mut self, msg: MsgChannelOpenConfirm) -> Result<Vec<IbcEvent>, String> {
+        let mut channel_end = self.channel_end(&msg.port_id, &msg.channel_id)?;
+
+        if !channel_end.state_matches(&ChannelState::TryOpen) {
+            return Err(format!(
+                "channel {} is not in state TryOpen",
+                msg.channel_id
+            ));
+        }
+
+        let counterparty_channel_id = channel_end
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| "missing counterparty channel identifier".to_string())?
+            .clone();
+
+        let counterparty_end = self.verify_counterparty_channel(
+            &channel_end,
+            &counterparty_channel_id,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        if !counterparty_end.is_open()
+            || counterparty_end.counterparty().channel_id() != Some(&msg.channel_id)
+        {
+            return Err(format!(
+                "counterparty channel {counterparty_channel_id} does not match"
+            ));
+        }
+
+        channel_end.set_state(ChannelState::Open(UpgradeState::NotUpgrading));
+
+        let (connection_id, _) = self.open_connection_of(&channel_end)?;
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+        self.set_channel_end(&msg.port_id, &msg.channel_id, channel_end);
+
+        Ok(vec![IbcEvent::OpenConfirmChannel(
+            channel_events::OpenConfirm {
+                port_id: msg.port_id,
+                channel_id: Some(msg.channel_id),
+                connection_id,
+                counterparty_port_id,
+                counterparty_channel_id: Some(counterparty_channel_id),
+            },
+        )])
+    }
+
+    fn chan_close_init(&mut self, msg: MsgChannelCloseInit) -> Result<Vec<IbcEvent>, String> {
+        let mut channel_end = self.channel_end(&msg.port_id, &msg.channel_id)?;
+
+        if channel_end.state_matches(&ChannelState::Closed) {
+            return Err(format!("channel {} is already closed", msg.channel_id));
+        }
+
+        channel_end.set_state(ChannelState::Closed);
+
+        let (connection_id, _) = self.open_connection_of(&channel_end)?;
+        let counterparty = channel_end.counterparty().clone();
+        self.set_channel_end(&msg.port_id, &msg.channel_id, channel_end);
+
+        Ok(vec![IbcEvent::CloseInitChannel(
+            channel_events::CloseInit {
+                port_id: msg.port_id,
+                channel_id: msg.channel_id,
+                connection_id,
+                counterparty_port_id: counterparty.port_id().clone(),
+                counterparty_channel_id: counterparty.channel_id().cloned(),
+            },
+        )])
+    }
+
+    fn chan_close_confirm(&mut self, msg: MsgChannelCloseConfirm) -> Result<Vec<IbcEvent>, String> {
+        let mut channel_end = self.channel_end(&msg.port_id, &msg.channel_id)?;
+
+        if channel_end.state_matches(&ChannelState::Closed) {
+            return Err(format!("channel {} is already closed", msg.channel_id));
+        }
+
+        let counterparty_channel_id = channel_end
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| "missing counterparty channel identifier".to_string())?
+            .clone();
+
+        let counterparty_end = self.verify_counterparty_channel(
+            &channel_end,
+            &counterparty_channel_id,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        if !counterparty_end.state_matches(&ChannelState::Closed) {
+            return Err(format!(
+                "counterparty channel {counterparty_channel_id} is not closed"
+            ));
+        }
+
+        channel_end.set_state(ChannelState::Closed);
+
+        let (connection_id, _) = self.open_connection_of(&channel_end)?;
+        let counterparty_port_id = channel_end.counterparty().port_id().clone();
+        self.set_channel_end(&msg.port_id, &msg.channel_id, channel_end);
+
+        Ok(vec![IbcEvent::CloseConfirmChannel(
+            channel_events::CloseConfirm {
+                channel_id: Some(msg.channel_id),
+                port_id: msg.port_id,
+                connection_id,
+                counterparty_port_id,
+                counterparty_channel_id: Some(counterparty_channel_id),
+            },
+        )])
+    }
+
+    fn transfer(
+        &mut self,
+        context: &BlockContext,
+        msg: MsgTransferV2,
+    ) -> Result<Vec<IbcEvent>, String> {
+        let channel_end = self.channel_end(&msg.source_port, &msg.source_channel)?;
+
+        if !channel_end.is_open() {
+            return Err(format!("channel {} is not open", msg.source_channel));
+        }
+
+        let is_ics20_v2 = channel_end.version().is_ics20_v2();
+
+        if !is_ics20_v2 && (msg.tokens.len() > 1 || msg.forwarding.is_some()) {
+            return Err(format!(
+                "channel {} does not support ICS20 v2 transfers",
+                msg.source_channel
+            ));
+        }
+
+        if msg.forwarding.is_some() {
+            return Err("forwarding is not supported by mock chains".to_string());
+        }
+
+        let sender = msg.sender.to_string();
+        let mut tokens = Vec::with_capacity(msg.tokens.len());
+
+        for token in &msg.tokens {
+            let amount = Amount::from_str(&token.amount).map_err(|e| e.to_string())?;
+
+            // Vouchers are sent under their full trace, and the other tokens under their own denomination
+            let full_denom = match token.denom.strip_prefix("ibc/") {
+                Some(hash) => {
+                    let trace = self
+                        .denom_trace(hash)
+                        .ok_or_else(|| format!("unknown denomination {}", token.denom))?;
+                    format!("{}/{}", trace.path, trace.base_denom)
+                }
+                None => token.denom.clone(),
+            };
+
+            let denom = PrefixedDenom::from_str(&full_denom).map_err(|e| e.to_string())?;
+
+            if is_sender_chain_source(msg.source_port.clone(), msg.source_channel.clone(), &denom) {
+                let escrow = escrow_account(&msg.source_port, &msg.source_channel);
+                self.send(&sender, &escrow, &token.denom, amount)?;
+            } else {
+                self.burn(&sender, &token.denom, amount)?;
+            }
+
+            tokens.push(PrefixedCoin { denom, amount });
+        }
+
+        // Packets sent over ICS20 v2 channels carry ICS20 v2 packet data, even for a single token
+        let data = if is_ics20_v2 {
+            TransferPacketData::V2(PacketDataV2 {
+                tokens,
+                sender: msg.sender,
+                receiver: msg.receiver,
+                memo: msg.memo,
+                forwarding: None,
+            })
+        } else {
+            TransferPacketData::V1(PacketData {
+                token: tokens.remove(0),
+                sender: msg.sender,
+                receiver: msg.receiver,
+                memo: msg.memo,
+            })
+        };
+
+        let sequence_path = SeqSendsPath(msg.source_port.clone(), msg.source_channel.clone());
+        let sequence = self.sequence(sequence_path.to_string())?;
+        self.set_sequence(sequence_path.to_string(), sequence.increment());
+
+        let counterparty_channel_id = channel_end
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| "missing counterparty channel identifier".to_string())?
+            .clone();
+
+        let packet = Packet {
+            sequence,
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            destination_port: channel_end.counterparty().port_id().clone(),
+            destination_channel: counterparty_channel_id,
+            data: data.encode(),
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        };
+
+        if packet.timed_out(&context.timestamp, context.height) {
+            return Err("packet would time out before being sent".to_string());
+        }
+
+        self.store.set(
+            commitment_path(&packet.source_port, &packet.source_channel, sequence),
+            packet_commitment(&packet),
+        );
+
+        Ok(vec![IbcEvent::SendPacket(channel_events::SendPacket {
+            packet,
+        })])
+    }
+
+    /// Verify that the given packet was sent over an open channel of this chain
+    fn verify_packet_commitment(
+        &self,
+        packet: &Packet,
+        proof: &CommitmentProofBytes,
+        proof_height: Height,
+    ) -> Result<ChannelEnd, String> {
+        let channel_end =
+            self.channel_end(&packet.destination_port, &packet.destination_channel)?;
+
+        if !channel_end.is_open() {
+            return Err(format!(
+                "channel {} is not open",
+                packet.destination_channel
+            ));
+        }
+
+        if channel_end.counterparty().port_id() != &packet.source_port
+            || channel_end.counterparty().channel_id() != Some(&packet.source_channel)
+        {
+            return Err(format!(
+                "packet source {}/{} is not the counterparty of channel {}",
+                packet.source_port, packet.source_channel, packet.destination_channel
+            ));
+        }
+
+        let (_, connection_end) = self.open_connection_of(&channel_end)?;
+
+        self.verify(
+            connection_end.client_id(),
+            connection_end.counterparty().prefix(),
+            proof,
+            proof_height,
+            commitment_path(&packet.source_port, &packet.source_channel, packet.sequence),
+            Some(packet_commitment(packet)),
+        )?;
+
+        Ok(channel_end)
+    }
+
+    fn recv_packet(
+        &mut self,
+        context: &BlockContext,
+        msg: MsgRecvPacket,
+    ) -> Result<Vec<IbcEvent>, String> {
+        let packet = msg.packet;
+        let channel_end =
+            self.verify_packet_commitment(&packet, msg.proofs.object_proof(), msg.proofs.height())?;
+
+        if packet.timed_out(&context.timestamp, context.height) {
+            return Err(format!("packet {} has timed out", packet.sequence));
+        }
+
+        let port_id = &packet.destination_port;
+        let channel_id = &packet.destination_channel;
+
+        match channel_end.ordering() {
+            Ordering::Ordered => {
+                let path = SeqRecvsPath(port_id.clone(), channel_id.clone()).to_string();
+                let next_sequence = self.sequence(path.clone())?;
+
+                if packet.sequence < next_sequence {
+                    return Ok(Vec::new());
+                }
+                if packet.sequence > next_sequence {
+                    return Err(format!(
+                        "packet {} received out of order, expected {next_sequence}",
+                        packet.sequence
+                    ));
+                }
+
+                self.set_sequence(path, next_sequence.increment());
+            }
+            _ => {
+                let path = ReceiptsPath {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    sequence: packet.sequence,
+                }
+                .to_string();
+
+                if self.store.get(&path).is_some() {
+                    return Ok(Vec::new());
+                }
+
+                self.store.set(path, vec![1]);
+            }
+        }
+
+        let ack = match self.on_recv_transfer(&packet) {
+            Ok(()) => Acknowledgement::success(),
+            Err(e) => Acknowledgement::Error(e),
+        };
+        let ack = serde_json::to_vec(&ack).map_err(|e| e.to_string())?;
+
+        self.store.set(
+            AcksPath {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: packet.sequence,
+            }
+            .to_string(),
+            ack_commitment(&ack),
+        );
+
+        Ok(vec![
+            IbcEvent::ReceivePacket(channel_events::ReceivePacket {
+                packet: packet.clone(),
+            }),
+            IbcEvent::WriteAcknowledgement(channel_events::WriteAcknowledgement { packet, ack }),
+        ])
+    }
+
+    /// Credit the receiver of a transfer, atomically
+    fn on_recv_transfer(&mut self, packet: &Packet) -> Result<(), String> {
+        let channel_end =
+            self.channel_end(&packet.destination_port, &packet.destination_channel)?;
+
+        let data = TransferPacketData::decode(&packet.data, channel_end.version())
+            .map_err(|e| format!("cannot unmarshal ICS-20 transfer packet data: {e}"))?;
+
+        if data.forwarding().is_some() {
+            return Err("forwarding is not supported by mock chains".to_string());
+        }
+
+        let receiver = data.receiver().to_string();
+        let mut state = self.clone();
+
+        for token in data.tokens() {
+            let mut denom = token.denom.clone();
+
+            if is_receiver_chain_source(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                &denom,
+            ) {
+                denom.remove_trace_prefix(&TracePrefix::new(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                ));
+
+                let escrow = escrow_account(&packet.destination_port, &packet.destination_channel);
+                state.send(&escrow, &receiver, &ibc_denom(&denom), token.amount)?;
+            } else {
+                denom.add_trace_prefix(TracePrefix::new(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                ));
+
+                let local = ibc_denom(&denom);
+                let full_denom = denom.to_string();
+                if let (Some(hash), Some((path, base_denom))) =
+                    (local.strip_prefix("ibc/"), full_denom.rsplit_once('/'))
+                {
+                    state.denom_traces.insert(
+                        hash.to_string(),
+                        DenomTrace {
+                            path: path.to_string(),
+                            base_denom: base_denom.to_string(),
+                        },
+                    );
+                }
+
+                state.mint(&receiver, &local, token.amount);
+            }
+        }
+
+        *self = state;
+        Ok(())
+    }
+
+    /// Give back the tokens of a transfer which failed, or timed out
+    fn refund_transfer(&mut self, packet: &Packet) -> Result<(), String> {
+        let channel_end = self.channel_end(&packet.source_port, &packet.source_channel)?;
+
+        let data = TransferPacketData::decode(&packet.data, channel_end.version())
+            .map_err(|e| e.to_string())?;
+        let sender = data.sender().to_string();
+
+        for token in data.tokens() {
+            let denom = ibc_denom(&token.denom);
+
+            if is_sender_chain_source(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                &token.denom,
+            ) {
+                let escrow = escrow_account(&packet.source_port, &packet.source_channel);
+                self.send(&escrow, &sender, &denom, token.amount)?;
+            } else {
+                self.mint(&sender, &denom, token.amount);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that the given packet was sent by this chain and is not yet acknowledged or timed out,
+    /// in which case the message is a no-op.
+    fn is_packet_pending(&self, packet: &Packet) -> Result<bool, String> {
+        let commitment = self.store.get(&commitment_path(
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+        ));
+
+        match commitment {
+            None => Ok(false),
+            Some(commitment) if commitment == &packet_commitment(packet) => Ok(true),
+            Some(_) => Err(format!(
+                "packet {} does not match its commitment",
+                packet.sequence
+            )),
+        }
+    }
+
+    fn acknowledge_packet(&mut self, msg: MsgAcknowledgement) -> Result<Vec<IbcEvent>, String> {
+        let packet = msg.packet;
+
+        if !self.is_packet_pending(&packet)? {
+            return Ok(Vec::new());
+        }
+
+        let channel_end = self.channel_end(&packet.source_port, &packet.source_channel)?;
+        if !channel_end.is_open() {
+            return Err(format!("channel {} is not open", packet.source_channel));
+        }
+
+        let (_, connection_end) = self.open_connection_of(&channel_end)?;
+        let ack = msg.acknowledgement.as_ref().to_vec();
+
+        self.verify(
+            connection_end.client_id(),
+            connection_end.counterparty().prefix(),
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+            AcksPath {
+                port_id: packet.destination_port.clone(),
+                channel_id: packet.destination_channel.clone(),
+                sequence: packet.sequence,
+            }
+            .to_string(),
+            Some(ack_commitment(&ack)),
+        )?;
+
+        if channel_end.order_matches(&Ordering::Ordered) {
+            let path = SeqAcksPath(packet.source_port.clone(), packet.source_channel.clone());
+            let next_sequence = self.sequence(path.to_string())?;
+
+            if packet.sequence != next_sequence {
+                return Err(format!(
+                    "packet {} acknowledged out of order, expected {next_sequence}",
+                    packet.sequence
+                ));
+            }
+
+            self.set_sequence(path.to_string(), next_sequence.increment());
+        }
+
+        self.store.remove(&commitment_path(
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+        ));
+
+        if let Ok(Acknowledgement::Error(_)) = serde_json::from_slice(&ack) {
+            self.refund_transfer(&packet)?;
+        }
+
+        Ok(vec![IbcEvent::AcknowledgePacket(
+            channel_events::AcknowledgePacket { packet },
+        )])
+    }
+
+    /// Verify that the given packet was not, and will never be, received by the counterparty chain,
+    /// and give back the tokens it carried.
+    fn verify_not_received(
+        &mut self,
+        packet: &Packet,
+        next_sequence_recv: Sequence,
+        proof: &CommitmentProofBytes,
+        proof_height: Height,
+    ) -> Result<(), String> {
+        let mut channel_end = self.channel_end(&packet.source_port, &packet.source_channel)?;
+        let (_, connection_end) = self.open_connection_of(&channel_end)?;
+        let client_id = connection_end.client_id();
+        let prefix = connection_end.counterparty().prefix();
+
+        if channel_end.order_matches(&Ordering::Ordered) {
+            if packet.sequence < next_sequence_recv {
+                return Err(format!("packet {} was already received", packet.sequence));
+            }
+
+            self.verify(
+                client_id,
+                prefix,
+                proof,
+                proof_height,
+                SeqRecvsPath(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                )
+                .to_string(),
+                Some(next_sequence_recv.as_u64().to_be_bytes().to_vec()),
+            )?;
+
+            // Ordered channels are closed when one of their packets times out
+            channel_end.set_state(ChannelState::Closed);
+            self.set_channel_end(&packet.source_port, &packet.source_channel, channel_end);
+        } else {
+            self.verify(
+                client_id,
+                prefix,
+                proof,
+                proof_height,
+                ReceiptsPath {
+                    port_id: packet.destination_port.clone(),
+                    channel_id: packet.destination_channel.clone(),
+                    sequence: packet.sequence,
+                }
+                .to_string(),
+                None,
+            )?;
+        }
+
+        self.store.remove(&commitment_path(
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+        ));
+
+        self.refund_transfer(packet)
+    }
+
+    fn timeout_packet(&mut self, msg: MsgTimeout) -> Result<Vec<IbcEvent>, String> {
+        let packet = msg.packet;
+
+        if !self.is_packet_pending(&packet)? {
+            return Ok(Vec::new());
+        }
+
+        let channel_end = self.channel_end(&packet.source_port, &packet.source_channel)?;
+        if !channel_end.is_open() {
+            return Err(format!("channel {} is not open", packet.source_channel));
+        }
+
+        let (_, connection_end) = self.open_connection_of(&channel_end)?;
+        let consensus_state =
+            self.consensus_state(connection_end.client_id(), msg.proofs.height())?;
+
+        if !packet.timed_out(&consensus_state.timestamp(), msg.proofs.height()) {
+            return Err(format!("packet {} has not timed out yet", packet.sequence));
+        }
+
+        self.verify_not_received(
+            &packet,
+            msg.next_sequence_recv,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        Ok(vec![IbcEvent::TimeoutPacket(
+            channel_events::TimeoutPacket { packet },
+        )])
+    }
+
+    fn timeout_on_close_packet(&mut self, msg: MsgTimeoutOnClose) -> Result<Vec<IbcEvent>, String> {
+        let packet = msg.packet;
+
+        if !self.is_packet_pending(&packet)? {
+            return Ok(Vec::new());
+        }
+
+        let channel_end = self.channel_end(&packet.source_port, &packet.source_channel)?;
+        let channel_proof = msg
+            .proofs
+            .other_proof()
+            .ok_or_else(|| "missing proof of the counterparty channel".to_string())?;
+
+        let counterparty_end = self.verify_counterparty_channel(
+            &channel_end,
+            &packet.destination_channel,
+            channel_proof,
+            msg.proofs.height(),
+        )?;
+
+        if !counterparty_end.state_matches(&ChannelState::Closed) {
+            return Err(format!(
+                "counterparty channel {} is not closed",
+                packet.destination_channel
+            ));
+        }
+
+        self.verify_not_received(
+            &packet,
+            msg.next_sequence_recv,
+            msg.proofs.object_proof(),
+            msg.proofs.height(),
+        )?;
+
+        Ok(vec![IbcEvent::TimeoutOnClosePacket(
+            channel_events::TimeoutOnClosePacket { packet },
+        )])
+    }
+
+    fn bank_send(&mut self, msg: MsgSend) -> Result<Vec<IbcEvent>, String> {
+        for coin in msg.amount {
+            let amount = Amount::from_str(&coin.amount).map_err(|e| e.to_string())?;
+            self.send(&msg.from_address, &msg.to_address, &coin.denom, amount)?;
+        }
+
+        Ok(Vec::new())
+    }
+}
+
+pub fn consensus_state_path(client_id: &ClientId, height: Height) -> String {
+    ClientConsensusStatePath {
+        client_id: client_id.clone(),
+        epoch: height.revision_number(),
+        height: height.revision_height(),
+    }
+    .to_string()
+}
+
+pub fn commitment_path(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> String {
+    CommitmentsPath {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence,
+    }
+    .to_string()
+}
+
+/// The commitment prefix of the IBC store of a mock chain
+pub fn commitment_prefix() -> CommitmentPrefix {
+    CommitmentPrefix::try_from(IBC_STORE_NAME.as_bytes().to_vec())
+        .expect("the IBC store name is a valid commitment prefix")
+}
@@ -0,0 +1,304 @@
+//! An in-memory key-value store committing to its entries with a Tendermint-style
+//! simple Merkle tree, and producing the ICS-23 proofs of (non-)membership of its keys.
+//!
+//! As in the Cosmos SDK, the IBC store is itself committed to by a multistore,
+//! whose root is the application hash of the chain. Proofs are therefore made of
+//! two ICS-23 proofs, which are verified with the Tendermint proof spec at both levels.
+
+use std::collections::BTreeMap;
+
+use ibc_proto::ics23::{
+    self, commitment_proof::Proof, CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp,
+    LengthOp, NonExistenceProof,
+};
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+
+/// The name under which the IBC store is committed to in the multistore
+pub const IBC_STORE_NAME: &str = "ibc";
+
+/// The proof specs of the two levels of the proofs produced by the store
+pub fn proof_specs() -> ProofSpecs {
+    vec![ics23::tendermint_spec(), ics23::tendermint_spec()].into()
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Store {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl Store {
+    pub fn get(&self, key: &str) -> Option<&Vec<u8>> {
+        self.entries.get(key.as_bytes())
+    }
+
+    pub fn set(&mut self, key: String, value: Vec<u8>) {
+        self.entries.insert(key.into_bytes(), value);
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Vec<u8>> {
+        self.entries.remove(key.as_bytes())
+    }
+
+    /// The entries whose key starts with the given prefix, in the order of their keys
+    pub fn scan<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a str, &'a [u8])> + 'a {
+        self.entries
+            .range(prefix.as_bytes().to_vec()..)
+            .take_while(move |(key, _)| key.starts_with(prefix.as_bytes()))
+            .filter_map(|(key, value)| Some((core::str::from_utf8(key).ok()?, value.as_slice())))
+    }
+
+    /// The root of the IBC store
+    pub fn root(&self) -> Vec<u8> {
+        let leaves = self.leaves();
+        simple_root(&leaves)
+    }
+
+    /// The root of the multistore committing to the IBC store, ie. the application hash
+    pub fn app_hash(&self) -> Vec<u8> {
+        leaf_hash(IBC_STORE_NAME.as_bytes(), &self.root())
+    }
+
+    /// Prove that the given key holds its current value in the store,
+    /// or that it is absent from it.
+    pub fn prove(&self, key: &str) -> Option<MerkleProof> {
+        let leaves = self.leaves();
+        let keys: Vec<&Vec<u8>> = self.entries.keys().collect();
+
+        let proof = match keys.binary_search(&&key.as_bytes().to_vec()) {
+            Ok(index) => Proof::Exist(self.existence_proof(&leaves, index)),
+            Err(index) => {
+                if keys.is_empty() {
+                    return None;
+                }
+
+                Proof::Nonexist(NonExistenceProof {
+                    key: key.as_bytes().to_vec(),
+                    left: index
+                        .checked_sub(1)
+                        .map(|left| self.existence_proof(&leaves, left)),
+                    right: (index < keys.len()).then(|| self.existence_proof(&leaves, index)),
+                })
+            }
+        };
+
+        let store_proof = ExistenceProof {
+            key: IBC_STORE_NAME.as_bytes().to_vec(),
+            value: simple_root(&leaves),
+            leaf: Some(leaf_op()),
+            path: vec![],
+        };
+
+        Some(MerkleProof {
+            proofs: vec![
+                CommitmentProof { proof: Some(proof) },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(store_proof)),
+                },
+            ],
+        })
+    }
+
+    fn leaves(&self) -> Vec<Vec<u8>> {
+        self.entries
+            .iter()
+            .map(|(key, value)| leaf_hash(key, value))
+            .collect()
+    }
+
+    fn existence_proof(&self, leaves: &[Vec<u8>], index: usize) -> ExistenceProof {
+        let (key, value) = self
+            .entries
+            .iter()
+            .nth(index)
+            .expect("index of an existing entry");
+
+        let mut path = Vec::new();
+        inner_path(leaves, index, &mut path);
+
+        ExistenceProof {
+            key: key.clone(),
+            value: value.clone(),
+            leaf: Some(leaf_op()),
+            path,
+        }
+    }
+}
+
+fn leaf_op() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: vec![0],
+    }
+}
+
+fn leaf_hash(key: &[u8], value: &[u8]) -> Vec<u8> {
+    let value_hash = Sha256::digest(value);
+
+    let mut preimage = vec![0];
+    encode_varint(key.len(), &mut preimage);
+    preimage.extend_from_slice(key);
+    encode_varint(value_hash.len(), &mut preimage);
+    preimage.extend_from_slice(&value_hash);
+
+    Sha256::digest(preimage).to_vec()
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update([1]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().to_vec()
+}
+
+/// The root of the simple Merkle tree over the given leaves,
+/// which is split at the largest power of two smaller than the number of leaves.
+fn simple_root(leaves: &[Vec<u8>]) -> Vec<u8> {
+    match leaves {
+        [] => Sha256::digest([]).to_vec(),
+        [leaf] => leaf.clone(),
+        _ => {
+            let split = split_point(leaves.len());
+            inner_hash(
+                &simple_root(&leaves[..split]),
+                &simple_root(&leaves[split..]),
+            )
+        }
+    }
+}
+
+/// Push the inner nodes on the path from the leaf at the given index to the root,
+/// from the leaf upwards.
+fn inner_path(leaves: &[Vec<u8>], index: usize, path: &mut Vec<InnerOp>) {
+    if leaves.len() <= 1 {
+        return;
+    }
+
+    let split = split_point(leaves.len());
+
+    let step = if index < split {
+        inner_path(&leaves[..split], index, path);
+        InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: vec![1],
+            suffix: simple_root(&leaves[split..]),
+        }
+    } else {
+        inner_path(&leaves[split..], index - split, path);
+        let mut prefix = vec![1];
+        prefix.extend(simple_root(&leaves[..split]));
+        InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix,
+            suffix: vec![],
+        }
+    };
+
+    path.push(step);
+}
+
+fn split_point(len: usize) -> usize {
+    let mut split = 1;
+    while split * 2 < len {
+        split *= 2;
+    }
+    split
+}
+
+fn encode_varint(mut value: usize, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::commitment::v1::MerkleRoot;
+    use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+    use ibc_relayer_types::core::ics23_commitment::merkle::apply_prefix;
+
+    use super::*;
+
+    fn store(len: usize) -> Store {
+        let mut store = Store::default();
+        for i in 0..len {
+            store.set(format!("key/{}", i * 2), format!("value-{i}").into_bytes());
+        }
+        store
+    }
+
+    fn verify(store: &Store, key: &str) -> bool {
+        let prefix = CommitmentPrefix::try_from(IBC_STORE_NAME.as_bytes().to_vec()).unwrap();
+        let path = apply_prefix(&prefix, vec![key.to_string()]);
+        let root = MerkleRoot {
+            hash: store.app_hash(),
+        };
+        let proof = store.prove(key).unwrap();
+
+        match store.get(key) {
+            Some(value) => proof
+                .verify_membership(&proof_specs(), root, path, value.clone(), 0)
+                .is_ok(),
+            None => proof
+                .verify_non_membership(&proof_specs(), root, path)
+                .is_ok(),
+        }
+    }
+
+    #[test]
+    fn proves_membership() {
+        for len in 1..=9 {
+            let store = store(len);
+            for i in 0..len {
+                assert!(verify(&store, &format!("key/{}", i * 2)), "{i} of {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn proves_non_membership() {
+        for len in 1..=9 {
+            let store = store(len);
+            for i in 0..=len {
+                assert!(
+                    verify(&store, &format!("key/{}", i * 2 + 1)),
+                    "{i} of {len}"
+                );
+            }
+            assert!(verify(&store, "a"));
+            assert!(verify(&store, "z"));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_value() {
+        let store = store(5);
+        let prefix = CommitmentPrefix::try_from(IBC_STORE_NAME.as_bytes().to_vec()).unwrap();
+        let path = apply_prefix(&prefix, vec!["key/4".to_string()]);
+        let root = MerkleRoot {
+            hash: store.app_hash(),
+        };
+
+        let proof = store.prove("key/4").unwrap();
+        assert!(proof
+            .verify_membership(
+                &proof_specs(),
+                root.clone(),
+                path.clone(),
+                b"other".to_vec(),
+                0
+            )
+            .is_err());
+        assert!(proof
+            .verify_non_membership(&proof_specs(), root, path)
+            .is_err());
+    }
+}
@@ -11,7 +11,9 @@ use ibc_proto::ibc::applications::fee::v1::{
 };
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::applications::ics28_ccv::msgs::{ConsumerChain, ConsumerId};
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
 };
@@ -426,6 +428,10 @@ impl ChainEndpoint for NamadaChain {
         Ok(responses)
     }
 
+    fn estimate_fees(&mut self, _tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        Err(Error::fee_estimation_unsupported(self.config.id.clone()))
+    }
+
     fn verify_header(
         &mut self,
         trusted: ICSHeight,
@@ -548,6 +554,15 @@ impl ChainEndpoint for NamadaChain {
         }
     }
 
+    fn query_interchain_account(
+        &self,
+        _owner: Signer,
+        _connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        // not supported
+        unimplemented!()
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!(
             "query_commitment_prefix",
@@ -1317,6 +1332,15 @@ impl ChainEndpoint for NamadaChain {
         unimplemented!()
     }
 
+    fn query_incentivized_packets(
+        &self,
+        _channel_id: ChannelId,
+        _port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        // not supported
+        unimplemented!()
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         // not supported
         unimplemented!()
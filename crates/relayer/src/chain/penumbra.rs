@@ -7,6 +7,7 @@ use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::Uri;
 use ibc_proto::ics23;
 use ibc_relayer_types::applications::ics28_ccv::msgs::ConsumerChain;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client;
 use ibc_relayer_types::core::ics04_channel;
 use pbjson_types;
@@ -709,6 +710,10 @@ impl ChainEndpoint for PenumbraChain {
         Ok(vec![res])
     }
 
+    fn estimate_fees(&mut self, _tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        Err(Error::fee_estimation_unsupported(self.config.id.clone()))
+    }
+
     fn verify_header(
         &mut self,
         trusted: ibc_relayer_types::Height,
@@ -770,6 +775,14 @@ impl ChainEndpoint for PenumbraChain {
         todo!("penumbra doesn't support denom trace querying yet")
     }
 
+    fn query_interchain_account(
+        &self,
+        _owner: ibc_relayer_types::signer::Signer,
+        _connection_id: ibc_relayer_types::core::ics24_host::identifier::ConnectionId,
+    ) -> Result<ibc_relayer_types::signer::Signer, Error> {
+        unimplemented!("penumbra does not support interchain accounts")
+    }
+
     fn query_commitment_prefix(
         &self,
     ) -> Result<ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix, Error>
@@ -1733,6 +1746,17 @@ impl ChainEndpoint for PenumbraChain {
         unimplemented!("not implemented in penumbra")
     }
 
+    fn query_incentivized_packets(
+        &self,
+        _channel_id: ibc_relayer_types::core::ics24_host::identifier::ChannelId,
+        _port_id: ibc_relayer_types::core::ics24_host::identifier::PortId,
+    ) -> Result<
+        Vec<ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees>,
+        Error,
+    > {
+        unimplemented!("not implemented in penumbra")
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         unimplemented!("not currently implemented in penumbra")
     }
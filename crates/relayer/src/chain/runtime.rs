@@ -12,7 +12,9 @@ use ibc_proto::ibc::{
 use ibc_relayer_types::{
     applications::{
         ics28_ccv::msgs::{ConsumerChain, ConsumerId},
+        ics29_fee::packet_fee::IdentifiedPacketFees,
         ics31_icq::response::CrossChainQueryResponse,
+        transfer::RawCoin,
     },
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
@@ -48,7 +50,7 @@ use crate::{
 
 use super::{
     client::ClientSettings,
-    endpoint::{ChainEndpoint, ChainStatus, HealthCheck},
+    endpoint::{ChainEndpoint, ChainStatus, HealthCheck, NodeStatus},
     handle::{ChainHandle, ChainRequest, ReplyTo, Subscription},
     requests::*,
     tracking::TrackedMsgs,
@@ -135,8 +137,19 @@ where
     }
 
     fn run(mut self) -> Result<(), Error> {
+        let maintenance = match self.chain.maintenance_interval() {
+            Some(interval) => channel::tick(interval),
+            None => channel::never(),
+        };
+
         loop {
             channel::select! {
+                recv(maintenance) -> _ => {
+                    if let Err(e) = self.chain.maintain() {
+                        error!("failed to perform chain maintenance: {}", e);
+                    }
+                },
+
                 recv(self.request_receiver) -> event => {
                     let (span, event) = match event {
                         Ok((span, event)) => (span, event),
@@ -162,6 +175,10 @@ where
                             self.health_check(reply_to)?
                         },
 
+                        ChainRequest::QueryNodeStatus { reply_to } => {
+                            self.query_node_status(reply_to)?
+                        },
+
                         ChainRequest::Subscribe { reply_to } => {
                             self.subscribe(reply_to)?
                         },
@@ -174,6 +191,10 @@ where
                             self.send_messages_and_wait_check_tx(tracked_msgs, reply_to)?
                         },
 
+                        ChainRequest::EstimateFees { tracked_msgs, reply_to } => {
+                            self.estimate_fees(tracked_msgs, reply_to)?
+                        },
+
                         ChainRequest::Signer { reply_to } => {
                             self.get_signer(reply_to)?
                         },
@@ -230,6 +251,10 @@ where
                             self.query_denom_trace(hash, reply_to)?
                         },
 
+                        ChainRequest::QueryInterchainAccount { owner, connection_id, reply_to } => {
+                            self.query_interchain_account(owner, connection_id, reply_to)?
+                        },
+
                         ChainRequest::QueryApplicationStatus { reply_to } => {
                             self.query_application_status(reply_to)?
                         },
@@ -354,6 +379,10 @@ where
                             self.query_incentivized_packet(request, reply_to)?
                         },
 
+                        ChainRequest::QueryIncentivizedPackets { channel_id, port_id, reply_to } => {
+                            self.query_incentivized_packets(channel_id, port_id, reply_to)?
+                        },
+
                         ChainRequest::QueryConsumerChains { reply_to } => {
                             self.query_consumer_chains(reply_to)?
                         },
@@ -382,6 +411,11 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_node_status(&self, reply_to: ReplyTo<Vec<NodeStatus>>) -> Result<(), Error> {
+        let result = self.chain.query_node_status();
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn subscribe(&mut self, reply_to: ReplyTo<Subscription>) -> Result<(), Error> {
         let subscription = self.chain.subscribe();
         reply_to.send(subscription).map_err(Error::send)
@@ -405,6 +439,15 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn estimate_fees(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+        reply_to: ReplyTo<Vec<RawCoin>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.estimate_fees(tracked_msgs);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_balance(
         &self,
         key_name: Option<String>,
@@ -432,6 +475,16 @@ where
         reply_to.send(denom_trace).map_err(Error::send)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<Signer>,
+    ) -> Result<(), Error> {
+        let address = self.chain.query_interchain_account(owner, connection_id);
+        reply_to.send(address).map_err(Error::send)
+    }
+
     fn query_application_status(&self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
         let latest_timestamp = self.chain.query_application_status();
         reply_to.send(latest_timestamp).map_err(Error::send)
@@ -871,6 +924,18 @@ where
         Ok(())
     }
 
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_incentivized_packets(channel_id, port_id);
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
+
     fn query_consumer_chains(&self, reply_to: ReplyTo<Vec<ConsumerChain>>) -> Result<(), Error> {
         let result = self.chain.query_consumer_chains();
         reply_to.send(result).map_err(Error::send)?;
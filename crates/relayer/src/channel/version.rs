@@ -6,6 +6,9 @@
 
 use ibc_relayer_types::{applications::transfer, core::ics24_host::identifier::PortId};
 
+use crate::chain::handle::ChainHandle;
+use crate::chain::version::Specs;
+
 pub use ibc_relayer_types::core::ics04_channel::version::Version;
 
 /// Returns the default channel version, depending on the given [`PortId`].
@@ -17,3 +20,34 @@ pub fn default_by_port(port_id: &PortId) -> Option<Version> {
         None
     }
 }
+
+/// Returns the version to propose for a new transfer channel between the given ports,
+/// or `None` if either port is not a transfer port.
+///
+/// The version is the most recent ICS20 version supported by both chains, i.e. `ics20-2`
+/// if both chains support it, and `ics20-1` otherwise.
+pub fn negotiate_transfer_version<ChainA: ChainHandle, ChainB: ChainHandle>(
+    a_chain: &ChainA,
+    a_port_id: &PortId,
+    b_chain: &ChainB,
+    b_port_id: &PortId,
+) -> Option<Version> {
+    if a_port_id.as_str() != transfer::PORT_ID_STR || b_port_id.as_str() != transfer::PORT_ID_STR {
+        return None;
+    }
+
+    if supports_ics20_v2(a_chain) && supports_ics20_v2(b_chain) {
+        Some(Version::ics20_v2())
+    } else {
+        Some(Version::ics20())
+    }
+}
+
+/// Returns true if the given chain supports ICS20 v2 channels, which were
+/// introduced in ibc-go v9 and removed in ibc-go v10.
+pub fn supports_ics20_v2<Chain: ChainHandle>(chain: &Chain) -> bool {
+    match chain.version_specs() {
+        Ok(Specs::Cosmos(specs)) => specs.ibc_go.is_some_and(|version| version.major == 9),
+        _ => false,
+    }
+}
@@ -0,0 +1,233 @@
+//! Monitoring of the time left before the clients refreshed by Hermes expire.
+//!
+//! A client expires once the time elapsed since the timestamp of the consensus state
+//! at its latest height exceeds its trusting period. From then on it can no longer be
+//! updated, and only a governance proposal submitting a `MsgRecoverClient`
+//! can bring it back, see [`crate::recover_client`].
+//!
+//! The client refresh workers record the time to expiry of their client every time
+//! they check whether it needs to be refreshed. It is exported in the
+//! `client_time_to_expiry` gauge, and a warning is logged when a client gets within
+//! [`WARNING_THRESHOLD`] of its trusting period from expiring.
+
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::{error, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::telemetry;
+
+/// The fraction of its trusting period left before expiry under which a warning
+/// is logged for a client, i.e. 10% of a 14 days trusting period is about 33 hours
+pub const WARNING_THRESHOLD: f64 = 0.1;
+
+static GLOBAL_MONITOR: Lazy<ClientExpiryMonitor> = Lazy::new(ClientExpiryMonitor::new);
+
+/// The monitor fed by the client refresh workers
+pub fn global() -> &'static ClientExpiryMonitor {
+    &GLOBAL_MONITOR
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientStatus {
+    /// The client is within its trusting period
+    Active,
+    /// The client is within [`WARNING_THRESHOLD`] of its trusting period from expiring
+    ExpiringSoon,
+    /// The client is past its trusting period
+    Expired,
+    /// The client was frozen following a misbehaviour
+    Frozen,
+}
+
+/// The time left before a client expires, as last observed by Hermes
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClientExpiry {
+    /// The chain hosting the client
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    /// The chain tracked by the client
+    pub counterparty_chain_id: ChainId,
+    pub status: ClientStatus,
+    #[serde(with = "humantime_serde")]
+    pub trusting_period: Option<Duration>,
+    /// The time left before the client expires, zero if it expired already
+    #[serde(with = "humantime_serde")]
+    pub time_to_expiry: Option<Duration>,
+    #[serde(with = "humantime_serde")]
+    pub observed_at: SystemTime,
+}
+
+impl ClientExpiry {
+    /// The expiry of an active client, given the time elapsed since
+    /// the timestamp of the consensus state at its latest height
+    pub fn active(
+        chain_id: ChainId,
+        client_id: ClientId,
+        counterparty_chain_id: ChainId,
+        trusting_period: Duration,
+        elapsed: Duration,
+    ) -> Self {
+        let time_to_expiry = trusting_period.saturating_sub(elapsed);
+
+        let status = if time_to_expiry.is_zero() {
+            ClientStatus::Expired
+        } else if time_to_expiry < trusting_period.mul_f64(WARNING_THRESHOLD) {
+            ClientStatus::ExpiringSoon
+        } else {
+            ClientStatus::Active
+        };
+
+        Self {
+            chain_id,
+            client_id,
+            counterparty_chain_id,
+            status,
+            trusting_period: Some(trusting_period),
+            time_to_expiry: Some(time_to_expiry),
+            observed_at: SystemTime::now(),
+        }
+    }
+
+    /// The expiry of a client which is expired or frozen already
+    pub fn inactive(
+        chain_id: ChainId,
+        client_id: ClientId,
+        counterparty_chain_id: ChainId,
+        status: ClientStatus,
+    ) -> Self {
+        Self {
+            chain_id,
+            client_id,
+            counterparty_chain_id,
+            status,
+            trusting_period: None,
+            time_to_expiry: (status == ClientStatus::Expired).then_some(Duration::ZERO),
+            observed_at: SystemTime::now(),
+        }
+    }
+}
+
+/// The last observed expiry of the clients refreshed by Hermes, see the [module documentation](self)
+pub struct ClientExpiryMonitor {
+    clients: Mutex<BTreeMap<(ChainId, ClientId), ClientExpiry>>,
+}
+
+impl ClientExpiryMonitor {
+    fn new() -> Self {
+        Self {
+            clients: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// The last observed expiry of all the clients, ordered by host chain and client
+    pub fn all(&self) -> Vec<ClientExpiry> {
+        self.clients.lock().unwrap().values().cloned().collect()
+    }
+
+    /// The last observed expiry of the given client, hosted on `chain_id`
+    pub fn get(&self, chain_id: &ChainId, client_id: &ClientId) -> Option<ClientExpiry> {
+        self.clients
+            .lock()
+            .unwrap()
+            .get(&(chain_id.clone(), client_id.clone()))
+            .cloned()
+    }
+
+    /// Record the expiry of a client, and log a warning if its status worsened since last time
+    pub fn record(&self, expiry: ClientExpiry) {
+        if let Some(time_to_expiry) = expiry.time_to_expiry {
+            telemetry!(
+                client_time_to_expiry,
+                &expiry.chain_id,
+                &expiry.client_id,
+                &expiry.counterparty_chain_id,
+                time_to_expiry
+            );
+        }
+
+        let mut clients = self.clients.lock().unwrap();
+
+        let previous = clients
+            .get(&(expiry.chain_id.clone(), expiry.client_id.clone()))
+            .map(|previous| previous.status);
+
+        if previous != Some(expiry.status) {
+            match expiry.status {
+                ClientStatus::Active => {}
+                ClientStatus::ExpiringSoon => warn!(
+                    chain = %expiry.chain_id,
+                    client = %expiry.client_id,
+                    counterparty_chain = %expiry.counterparty_chain_id,
+                    time_to_expiry = ?expiry.time_to_expiry,
+                    "client is about to expire, it must be updated before its trusting period elapses",
+                ),
+                ClientStatus::Expired | ClientStatus::Frozen => error!(
+                    chain = %expiry.chain_id,
+                    client = %expiry.client_id,
+                    counterparty_chain = %expiry.counterparty_chain_id,
+                    status = ?expiry.status,
+                    "client can no longer be updated, it must be recovered with `hermes tx recover-client`",
+                ),
+            }
+        }
+
+        clients.insert((expiry.chain_id.clone(), expiry.client_id.clone()), expiry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn expiry(elapsed: Duration) -> ClientExpiry {
+        ClientExpiry::active(
+            ChainId::from_string("ibc-1"),
+            ClientId::default(),
+            ChainId::from_string("ibc-0"),
+            14 * DAY,
+            elapsed,
+        )
+    }
+
+    #[test]
+    fn computes_time_to_expiry() {
+        let active = expiry(3 * DAY);
+        assert_eq!(active.status, ClientStatus::Active);
+        assert_eq!(active.time_to_expiry, Some(11 * DAY));
+
+        let expiring = expiry(13 * DAY);
+        assert_eq!(expiring.status, ClientStatus::ExpiringSoon);
+        assert_eq!(expiring.time_to_expiry, Some(DAY));
+
+        let expired = expiry(15 * DAY);
+        assert_eq!(expired.status, ClientStatus::Expired);
+        assert_eq!(expired.time_to_expiry, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn keeps_latest_expiry() {
+        let monitor = ClientExpiryMonitor::new();
+
+        monitor.record(expiry(3 * DAY));
+        monitor.record(expiry(13 * DAY));
+
+        let all = monitor.all();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].status, ClientStatus::ExpiringSoon);
+
+        let client = monitor
+            .get(&ChainId::from_string("ibc-1"), &ClientId::default())
+            .unwrap();
+        assert_eq!(client, all[0]);
+    }
+}
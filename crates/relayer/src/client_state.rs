@@ -6,9 +6,15 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SoloMachineClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
@@ -21,54 +27,73 @@ use ibc_relayer_types::Height;
 #[serde(tag = "type")]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    Wasm(WasmClientState),
+    SoloMachine(SoloMachineClientState),
 }
 
 impl AnyClientState {
     pub fn chain_id(&self) -> ChainId {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+            AnyClientState::SoloMachine(sm_state) => sm_state.chain_id(),
         }
     }
 
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::Wasm(wasm_state) => wasm_state.latest_height(),
+            Self::SoloMachine(sm_state) => sm_state.latest_height(),
         }
     }
 
     pub fn frozen_height(&self) -> Option<Height> {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
+            Self::Wasm(wasm_state) => wasm_state.frozen_height(),
+            Self::SoloMachine(sm_state) => sm_state.frozen_height(),
         }
     }
 
     pub fn trust_threshold(&self) -> Option<TrustThreshold> {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
+            AnyClientState::Wasm(state) => Some(state.tendermint.trust_threshold),
+            AnyClientState::SoloMachine(_) => None,
         }
     }
 
     pub fn trusting_period(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.trusting_period,
+            AnyClientState::Wasm(state) => state.tendermint.trusting_period,
+            // Solo machine clients never expire
+            AnyClientState::SoloMachine(_) => Duration::MAX,
         }
     }
 
     pub fn max_clock_drift(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
+            AnyClientState::Wasm(state) => state.tendermint.max_clock_drift,
+            AnyClientState::SoloMachine(_) => Duration::ZERO,
         }
     }
 
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::Wasm(state) => state.client_type(),
+            Self::SoloMachine(state) => state.client_type(),
         }
     }
 
     pub fn expired(&self, elapsed: Duration) -> bool {
         match self {
             Self::Tendermint(state) => state.expired(elapsed),
+            Self::Wasm(state) => state.expired(elapsed),
+            Self::SoloMachine(state) => state.expired(elapsed),
         }
     }
 }
@@ -87,6 +112,12 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(WasmClientState::try_from(raw)?)),
+
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::SoloMachine(
+                SoloMachineClientState::try_from(raw)?,
+            )),
+
             _ => Err(Error::unknown_client_state_type(raw.type_url)),
         }
     }
@@ -99,6 +130,8 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawTmClientState>::encode_vec(value),
             },
+            AnyClientState::Wasm(value) => value.into(),
+            AnyClientState::SoloMachine(value) => value.into(),
         }
     }
 }
@@ -131,6 +164,18 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl From<SoloMachineClientState> for AnyClientState {
+    fn from(cs: SoloMachineClientState) -> Self {
+        Self::SoloMachine(cs)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub struct IdentifiedAnyClientState {
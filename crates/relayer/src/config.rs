@@ -16,7 +16,13 @@ use core::str::FromStr;
 use core::time::Duration;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use std::borrow::Cow;
-use std::{fs, fs::File, io::Write, ops::Range, path::Path};
+use std::{
+    fs,
+    fs::File,
+    io::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
 use byte_unit::Byte;
 use serde::{Deserialize, Serialize};
@@ -29,12 +35,14 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::cosmos::config::CosmosSdkConfig;
+#[cfg(any(test, feature = "mock-chain"))]
+use crate::chain::mock::config::MockChainConfig;
 use crate::chain::penumbra::config::PenumbraConfig;
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::TrustThreshold;
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
-use crate::keyring::{AnySigningKeyPair, KeyRing, Store};
+use crate::keyring::{AnySigningKeyPair, KeyRing};
 
 use crate::keyring;
 
@@ -255,6 +263,58 @@ pub mod default {
     pub fn clear_limit() -> usize {
         50
     }
+
+    pub fn verify_proofs() -> bool {
+        false
+    }
+
+    pub fn failover_check_interval() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn failover_max_height_lag() -> u64 {
+        5
+    }
+
+    pub fn failover_threshold() -> u32 {
+        3
+    }
+
+    pub fn top_up_cooldown() -> Duration {
+        Duration::from_secs(10 * 60)
+    }
+
+    pub fn remote_signer_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn max_replayed_blocks() -> u64 {
+        1000
+    }
+
+    pub fn sink_max_file_size() -> Byte {
+        Byte::from_bytes(100 * 1024 * 1024)
+    }
+
+    pub fn sink_max_files() -> usize {
+        5
+    }
+
+    pub fn webhook_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
+    pub fn webhook_max_retries() -> u32 {
+        5
+    }
+
+    pub fn webhook_retry_delay() -> Duration {
+        Duration::from_secs(1)
+    }
+
+    pub fn webhook_max_retry_delay() -> Duration {
+        Duration::from_secs(30)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -272,6 +332,12 @@ pub struct Config {
     pub chains: Vec<ChainConfig>,
     #[serde(default)]
     pub tracing_server: TracingServerConfig,
+    #[serde(default)]
+    pub state: StateConfig,
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub sinks: Vec<SinkConfig>,
+    #[serde(default)]
+    pub prices: PricesConfig,
 }
 
 impl Config {
@@ -329,6 +395,8 @@ impl Config {
                         .map_err(Into::<Diagnostic<Error>>::into)?;
                 }
                 ChainConfig::Penumbra { .. } => { /* no-op for now (erwan) */ }
+                #[cfg(any(test, feature = "mock-chain"))]
+                ChainConfig::Mock(_) => {}
             }
         }
 
@@ -339,7 +407,7 @@ impl Config {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -392,7 +460,7 @@ impl Default for ModeConfig {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -402,19 +470,19 @@ pub struct Clients {
     pub misbehaviour: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -432,6 +500,8 @@ pub struct Packets {
     pub ics20_max_receiver_size: Ics20FieldSizeLimit,
     #[serde(default = "default::clear_limit")]
     pub clear_limit: usize,
+    #[serde(default = "default::verify_proofs")]
+    pub verify_proofs: bool,
 
     #[serde(skip)]
     pub force_disable_clear_on_start: bool,
@@ -448,6 +518,7 @@ impl Default for Packets {
             ics20_max_memo_size: default::ics20_max_memo_size(),
             ics20_max_receiver_size: default::ics20_max_receiver_size(),
             clear_limit: default::clear_limit(),
+            verify_proofs: default::verify_proofs(),
             force_disable_clear_on_start: false,
         }
     }
@@ -500,6 +571,10 @@ pub struct TelemetryConfig {
     pub buckets: HistogramBuckets,
     #[serde(default)]
     pub prefix: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthConfig>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -565,6 +640,8 @@ impl Default for TelemetryConfig {
             port: 3001,
             buckets: HistogramBuckets::default(),
             prefix: Default::default(),
+            tls: None,
+            auth: None,
         }
     }
 }
@@ -575,6 +652,15 @@ pub struct RestConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthConfig>,
+
+    /// Whether the read-only endpoints require authentication as well,
+    /// in addition to the ones acting on the relayer
+    #[serde(default)]
+    pub protect_reads: bool,
 }
 
 impl Default for RestConfig {
@@ -583,10 +669,148 @@ impl Default for RestConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3000,
+            tls: None,
+            auth: None,
+            protect_reads: false,
+        }
+    }
+}
+
+/// Settings for serving the REST API or the metrics over TLS
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// The PEM file holding the certificate chain of the server
+    pub cert: PathBuf,
+
+    /// The PEM file holding the private key of the server
+    pub key: PathBuf,
+
+    /// The PEM file holding the CA certificates to verify client certificates against.
+    /// Clients presenting a verified certificate are authenticated (mTLS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ca: Option<PathBuf>,
+}
+
+/// Settings for authenticating the clients of the REST API or of the metrics
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    /// The file holding the bearer token clients authenticate with
+    pub token_file: PathBuf,
+}
+
+/// Settings of the on-disk store the relay state is persisted to,
+/// so that Hermes can resume from it after a restart.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateConfig {
+    pub enabled: bool,
+
+    /// The directory holding the store. Default: `$HOME/.hermes/state`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+
+    /// The maximum number of blocks to fetch the events of on start,
+    /// for chains whose events were last processed this many blocks ago at most.
+    #[serde(default = "default::max_replayed_blocks")]
+    pub max_replayed_blocks: u64,
+}
+
+impl Default for StateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: None,
+            max_replayed_blocks: default::max_replayed_blocks(),
+        }
+    }
+}
+
+/// Prices of the denominations fees and gas are paid in, expressed in a common unit,
+/// used to evaluate the profitability of relaying incentivized packets.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PricesConfig {
+    /// The price of one unit of each denomination
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub denoms: BTreeMap<String, f64>,
+
+    /// A TOML file mapping denominations to their price, which takes precedence
+    /// over `denoms` and is read again whenever it is modified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+}
+
+/// A destination the events observed and relayed by Hermes are streamed to,
+/// as newline-delimited JSON. See [`crate::sink`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfig {
+    File(FileSinkConfig),
+    Webhook(WebhookSinkConfig),
+    Socket(SocketSinkConfig),
+}
+
+impl Display for SinkConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            SinkConfig::File(config) => write!(f, "file {}", config.path.display()),
+            SinkConfig::Webhook(config) => write!(f, "webhook {}", config.url),
+            SinkConfig::Socket(config) => write!(f, "socket {}", config.path.display()),
         }
     }
 }
 
+/// Append the events to a file, which is rotated once it reaches a given size
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileSinkConfig {
+    pub path: PathBuf,
+
+    /// The size past which the file is rotated
+    #[serde(default = "default::sink_max_file_size")]
+    pub max_size: Byte,
+
+    /// The number of rotated files to keep besides the current one,
+    /// the oldest one being deleted on rotation
+    #[serde(default = "default::sink_max_files")]
+    pub max_files: usize,
+}
+
+/// POST the events to an HTTP endpoint, in batches
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSinkConfig {
+    pub url: String,
+
+    /// Additional headers to send with every request, eg. for authentication
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    #[serde(default = "default::webhook_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+
+    /// The number of times a failed request is retried before its events are dropped
+    #[serde(default = "default::webhook_max_retries")]
+    pub max_retries: u32,
+
+    /// The delay before the first retry, doubled after every retry
+    #[serde(default = "default::webhook_retry_delay", with = "humantime_serde")]
+    pub retry_delay: Duration,
+
+    /// The maximum delay between two retries
+    #[serde(default = "default::webhook_max_retry_delay", with = "humantime_serde")]
+    pub max_retry_delay: Duration,
+}
+
+/// Write the events to a Unix socket, connecting to it again whenever the connection is lost
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SocketSinkConfig {
+    pub path: PathBuf,
+}
+
 /// It defines the address generation method
 /// TODO: Ethermint `pk_type` to be restricted
 /// after the Cosmos SDK release with ethsecp256k1
@@ -664,6 +888,9 @@ pub enum ChainConfig {
     // Reuse CosmosSdkConfig for tendermint light clients
     Namada(CosmosSdkConfig),
     Penumbra(PenumbraConfig),
+    /// An in-process chain, for testing purposes
+    #[cfg(any(test, feature = "mock-chain"))]
+    Mock(MockChainConfig),
 }
 
 impl ChainConfig {
@@ -672,6 +899,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => &config.id,
             Self::Namada(config) => &config.id,
             Self::Penumbra(config) => &config.id,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => &config.id,
         }
     }
 
@@ -680,6 +909,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => &config.packet_filter,
             Self::Namada(config) => &config.packet_filter,
             Self::Penumbra(config) => &config.packet_filter,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => &config.packet_filter,
         }
     }
 
@@ -688,6 +919,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => config.max_block_time,
             Self::Namada(config) => config.max_block_time,
             Self::Penumbra(config) => config.max_block_time,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.max_block_time,
         }
     }
 
@@ -696,6 +929,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => &config.key_name,
             Self::Namada(config) => &config.key_name,
             Self::Penumbra(config) => &config.stub_key_name,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => &config.key_name,
         }
     }
 
@@ -704,6 +939,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => config.key_name = key_name,
             Self::Namada(config) => config.key_name = key_name,
             Self::Penumbra(_) => { /* no-op */ }
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.key_name = key_name,
         }
     }
 
@@ -711,7 +948,7 @@ impl ChainConfig {
         let keys = match self {
             ChainConfig::CosmosSdk(config) => {
                 let keyring = KeyRing::new_secp256k1(
-                    Store::Test,
+                    config.key_store_type.on_disk()?,
                     &config.account_prefix,
                     &config.id,
                     &config.key_store_folder,
@@ -723,8 +960,11 @@ impl ChainConfig {
                     .collect()
             }
             ChainConfig::Namada(config) => {
-                let keyring =
-                    KeyRing::new_namada(Store::Test, &config.id, &config.key_store_folder)?;
+                let keyring = KeyRing::new_namada(
+                    config.key_store_type.on_disk()?,
+                    &config.id,
+                    &config.key_store_folder,
+                )?;
                 keyring
                     .keys()?
                     .into_iter()
@@ -732,6 +972,8 @@ impl ChainConfig {
                     .collect()
             }
             ChainConfig::Penumbra(_) => vec![],
+            #[cfg(any(test, feature = "mock-chain"))]
+            ChainConfig::Mock(_) => vec![],
         };
 
         Ok(keys)
@@ -742,6 +984,8 @@ impl ChainConfig {
             Self::CosmosSdk(config) => config.trust_threshold,
             Self::Namada(config) => config.trust_threshold,
             Self::Penumbra(config) => config.trust_threshold,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.trust_threshold,
         }
     }
 
@@ -749,6 +993,8 @@ impl ChainConfig {
         match self {
             Self::CosmosSdk(config) | Self::Namada(config) => config.clear_interval,
             Self::Penumbra(config) => config.clear_interval,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.clear_interval,
         }
     }
 
@@ -756,6 +1002,8 @@ impl ChainConfig {
         match self {
             Self::CosmosSdk(config) | Self::Namada(config) => config.query_packets_chunk_size,
             Self::Penumbra(config) => config.query_packets_chunk_size,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.query_packets_chunk_size,
         }
     }
 
@@ -765,6 +1013,8 @@ impl ChainConfig {
                 config.query_packets_chunk_size = query_packets_chunk_size
             }
             Self::Penumbra(config) => config.query_packets_chunk_size = query_packets_chunk_size,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.query_packets_chunk_size = query_packets_chunk_size,
         }
     }
 
@@ -777,6 +1027,8 @@ impl ChainConfig {
                 .map(|seqs| Cow::Borrowed(seqs.as_slice()))
                 .unwrap_or_else(|| Cow::Owned(Vec::new())),
             Self::Penumbra(_config) => Cow::Owned(Vec::new()),
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(_config) => Cow::Owned(Vec::new()),
         }
     }
 
@@ -784,6 +1036,8 @@ impl ChainConfig {
         match self {
             Self::CosmosSdk(config) | Self::Namada(config) => config.allow_ccq,
             Self::Penumbra(_config) => false,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(_config) => false,
         }
     }
 
@@ -791,6 +1045,8 @@ impl ChainConfig {
         match self {
             Self::CosmosSdk(config) | Self::Namada(config) => config.clock_drift,
             Self::Penumbra(config) => config.clock_drift,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(config) => config.clock_drift,
         }
     }
 
@@ -798,6 +1054,8 @@ impl ChainConfig {
         match self {
             Self::Namada(_) | Self::CosmosSdk(_) => true,
             Self::Penumbra(_) => false,
+            #[cfg(any(test, feature = "mock-chain"))]
+            Self::Mock(_) => false,
         }
     }
 }
@@ -834,6 +1092,10 @@ impl<'de> Deserialize<'de> for ChainConfig {
             "Penumbra" => PenumbraConfig::deserialize(value)
                 .map(Self::Penumbra)
                 .map_err(|e| serde::de::Error::custom(format!("invalid Penumbra config: {e}"))),
+            #[cfg(any(test, feature = "mock-chain"))]
+            "Mock" => MockChainConfig::deserialize(value)
+                .map(Self::Mock)
+                .map_err(|e| serde::de::Error::custom(format!("invalid Mock config: {e}"))),
             //
             chain_type => Err(serde::de::Error::custom(format!(
                 "unknown chain type: {chain_type}",
@@ -1031,6 +1293,7 @@ mod tests {
                 chain_config.excluded_sequences.clone()
             }
             ChainConfig::Penumbra(_) => panic!("expected cosmos chain config"),
+            ChainConfig::Mock(_) => panic!("expected cosmos chain config"),
         };
 
         assert_eq!(excluded_sequences1, excluded_sequences2);
@@ -9,7 +9,8 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::hash::Hash;
 
-use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::applications::transfer::packet::RawTransferPacketData;
+use ibc_relayer_types::applications::transfer::{Amount, PrefixedDenom, RawCoin};
 use ibc_relayer_types::bigint::U256;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::events::IbcEventType;
@@ -21,6 +22,8 @@ pub struct PacketFilter {
     pub channel_policy: ChannelPolicy,
     #[serde(default)]
     pub min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+    #[serde(default, skip_serializing_if = "Ics20Filter::is_empty")]
+    pub ics20: Ics20Filter,
 }
 
 impl Default for PacketFilter {
@@ -29,6 +32,7 @@ impl Default for PacketFilter {
         Self {
             channel_policy: ChannelPolicy::default(),
             min_fees: HashMap::new(),
+            ics20: Ics20Filter::default(),
         }
     }
 }
@@ -41,6 +45,7 @@ impl PacketFilter {
         Self {
             channel_policy,
             min_fees,
+            ics20: Ics20Filter::default(),
         }
     }
 
@@ -70,18 +75,41 @@ pub enum ChannelPolicy {
 }
 
 /// Represents the policy used to filter incentivized packets.
-/// Currently only filtering on `recv_fee` is authorized.
+/// Filtering on raw amounts is only authorized on the `recv_fee`,
+/// while the profitability policy considers all the fees of a packet.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FeePolicy {
+    #[serde(default)]
     recv: Vec<MinFee>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profitability: Option<ProfitabilityPolicy>,
 }
 
 impl FeePolicy {
     pub fn new(recv: Vec<MinFee>) -> Self {
-        Self { recv }
+        Self {
+            recv,
+            profitability: None,
+        }
     }
 
+    pub fn with_profitability(mut self, profitability: ProfitabilityPolicy) -> Self {
+        self.profitability = Some(profitability);
+        self
+    }
+
+    pub fn profitability(&self) -> Option<&ProfitabilityPolicy> {
+        self.profitability.as_ref()
+    }
+
+    /// Whether the given fees satisfy the minimum `recv` fees.
+    /// When only a profitability policy is configured, there is no minimum to satisfy.
     pub fn should_relay(&self, event_type: IbcEventType, fees: &[RawCoin]) -> bool {
+        if self.recv.is_empty() && self.profitability.is_some() {
+            return true;
+        }
+
         match event_type {
             IbcEventType::SendPacket => fees
                 .iter()
@@ -91,6 +119,30 @@ impl FeePolicy {
     }
 }
 
+/// Only relay incentivized packets whose fees cover the estimated cost of relaying them,
+/// valued with the prices configured in the `[prices]` section.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfitabilityPolicy {
+    /// How much the fees must exceed the estimated cost, in percent of that cost
+    #[serde(default)]
+    pub min_margin: u64,
+    /// What to do with the packets which are not profitable
+    #[serde(default)]
+    pub unprofitable: UnprofitableAction,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnprofitableAction {
+    /// Keep the packet aside and relay it once its fees are increased
+    /// or the prices changed enough for it to become profitable
+    #[default]
+    Defer,
+    /// Never relay the packet
+    Skip,
+}
+
 /// Represents the minimum fee authorized when filtering.
 /// If no denom is specified, any denom is allowed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -112,6 +164,205 @@ impl MinFee {
     }
 }
 
+/// Represents the rules used to filter ICS-20 packets based on their content.
+/// If `allow` rules are specified, every token of a packet must match one of them
+/// for the packet to be relayed. Packets with a token matching one of the `deny`
+/// rules are never relayed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ics20Filter {
+    #[serde(default)]
+    pub allow: Vec<Ics20Rule>,
+    #[serde(default)]
+    pub deny: Vec<Ics20Rule>,
+}
+
+impl Ics20Filter {
+    pub fn new(allow: Vec<Ics20Rule>, deny: Vec<Ics20Rule>) -> Self {
+        Self { allow, deny }
+    }
+
+    /// Returns true if there are no rules, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Checks the given ICS-20 packet data against the rules,
+    /// returning the reason for which the packet is rejected, if any.
+    pub fn check(&self, packet_data: &RawTransferPacketData) -> Result<(), Ics20FilterReason> {
+        let memos = packet_data.memos();
+        let has_memo = memos.iter().any(|memo| !memo.is_empty());
+
+        for (denom, amount) in packet_data.tokens() {
+            let token = Ics20Token {
+                base_denom: base_denom(&denom),
+                denom: &denom,
+                amount: Amount::from_str(amount).ok(),
+                sender: packet_data.sender(),
+                receiver: packet_data.receiver(),
+                has_memo,
+            };
+
+            if self.deny.iter().any(|rule| rule.matches(&token)) {
+                return Err(Ics20FilterReason::Denied);
+            }
+
+            if !self.allow.is_empty() && !self.allow.iter().any(|rule| rule.matches(&token)) {
+                return Err(Ics20FilterReason::NotAllowed);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The base denomination of the given denomination prefixed with its trace path.
+/// Denominations which are not valid ICS-20 denominations are their own base denomination.
+fn base_denom(denom: &str) -> String {
+    PrefixedDenom::from_str(denom)
+        .map(|denom| denom.base_denom().to_string())
+        .unwrap_or_else(|_| denom.to_string())
+}
+
+/// The reason for which an ICS-20 packet is rejected by an [`Ics20Filter`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Ics20FilterReason {
+    /// A token of the packet matches a `deny` rule
+    Denied,
+    /// A token of the packet matches none of the `allow` rules
+    NotAllowed,
+    /// The data of the packet could not be decoded, while `allow` rules are configured
+    Undecodable,
+}
+
+impl Ics20FilterReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Denied => "ics20_denied",
+            Self::NotAllowed => "ics20_not_allowed",
+            Self::Undecodable => "ics20_undecodable",
+        }
+    }
+}
+
+impl fmt::Display for Ics20FilterReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A rule matching the tokens of ICS-20 packets.
+/// A token matches the rule if it satisfies all of the conditions specified in the rule.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ics20Rule {
+    /// Pattern for the denomination of the token, prefixed with its trace path,
+    /// e.g. `transfer/channel-0/uatom` or `transfer/channel-0/*`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denom: Option<Wildcard>,
+    /// Pattern for the base denomination of the token, e.g. `uatom`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_denom: Option<Wildcard>,
+    /// Pattern for the address of the sender
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<Wildcard>,
+    /// Pattern for the address of the receiver
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<Wildcard>,
+    /// Minimum amount of the token, inclusive
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "amount")]
+    pub min_amount: Option<Amount>,
+    /// Maximum amount of the token, inclusive
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "amount")]
+    pub max_amount: Option<Amount>,
+    /// Whether the packet has a memo, either for the receiver or for
+    /// the final destination of the tokens when they are forwarded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_memo: Option<bool>,
+}
+
+/// A token of an ICS-20 packet, along with the fields of the packet matched by an [`Ics20Rule`].
+struct Ics20Token<'a> {
+    denom: &'a str,
+    base_denom: String,
+    amount: Option<Amount>,
+    sender: &'a str,
+    receiver: &'a str,
+    has_memo: bool,
+}
+
+impl Ics20Rule {
+    fn matches(&self, token: &Ics20Token<'_>) -> bool {
+        fn matches_pattern(pattern: &Option<Wildcard>, value: &str) -> bool {
+            pattern
+                .as_ref()
+                .is_none_or(|pattern| pattern.is_match(value))
+        }
+
+        // Amounts which cannot be parsed match no amount range
+        let matches_amount = |bound: Option<Amount>, in_range: fn(&Amount, &Amount) -> bool| {
+            bound.is_none_or(|bound| token.amount.is_some_and(|amount| in_range(&amount, &bound)))
+        };
+
+        matches_pattern(&self.denom, token.denom)
+            && matches_pattern(&self.base_denom, &token.base_denom)
+            && matches_pattern(&self.sender, token.sender)
+            && matches_pattern(&self.receiver, token.receiver)
+            && matches_amount(self.min_amount, |amount, min| amount >= min)
+            && matches_amount(self.max_amount, |amount, max| amount <= max)
+            && self
+                .has_memo
+                .is_none_or(|has_memo| has_memo == token.has_memo)
+    }
+}
+
+/// (De)serializes the amount bounds of an [`Ics20Rule`] as decimal strings,
+/// since amounts may not fit in the integers supported by TOML.
+/// Integers are accepted as well, for amounts which do fit.
+mod amount {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        amount: &Option<Amount>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match amount {
+            Some(amount) => serializer.serialize_str(&amount.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Amount>, D::Error> {
+        deserializer.deserialize_any(AmountVisitor).map(Some)
+    }
+
+    struct AmountVisitor;
+
+    impl de::Visitor<'_> for AmountVisitor {
+        type Value = Amount;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a non-negative integer, or a string containing one")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(Amount::from(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            u64::try_from(v)
+                .map(Amount::from)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Amount::from_str(v.trim()).map_err(E::custom)
+        }
+    }
+}
+
 impl Default for ChannelPolicy {
     /// By default, allows all channels & ports.
     fn default() -> Self {
@@ -276,6 +527,13 @@ impl Serialize for Wildcard {
     }
 }
 
+impl<'de> Deserialize<'de> for Wildcard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Wildcard, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Wildcard::new(pattern.trim().to_string()).map_err(de::Error::custom)
+    }
+}
+
 impl PartialEq for Wildcard {
     fn eq(&self, other: &Self) -> bool {
         self.pattern == other.pattern
@@ -435,6 +693,34 @@ pub(crate) mod channel {
 mod tests {
     use super::*;
 
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+
+    #[test]
+    fn deserialize_fee_policy_with_profitability() {
+        let toml_content = r#"
+            [profitability]
+            min_margin = 20
+            unprofitable = 'skip'
+            "#;
+
+        let fee_policy: FeePolicy =
+            toml::from_str(toml_content).expect("could not parse fee policy");
+
+        assert_eq!(
+            fee_policy,
+            FeePolicy::new(vec![]).with_profitability(ProfitabilityPolicy {
+                min_margin: 20,
+                unprofitable: UnprofitableAction::Skip,
+            })
+        );
+
+        // No minimum `recv` fee is required when only checking profitability
+        assert!(fee_policy.should_relay(IbcEventType::SendPacket, &[]));
+
+        // An empty list of minimum `recv` fees still filters out all packets otherwise
+        assert!(!FeePolicy::new(vec![]).should_relay(IbcEventType::SendPacket, &[]));
+    }
+
     #[test]
     fn deserialize_packet_filter_policy() {
         let toml_content = r#"
@@ -603,6 +889,155 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn deserialize_ics20_filter() {
+        let toml_content = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-0'],
+            ]
+
+            [ics20]
+            allow = [
+              { base_denom = 'uatom', min_amount = 1000 },
+              { denom = 'transfer/channel-0/*', max_amount = 500 },
+            ]
+            deny = [
+              { sender = 'cosmos1spammer*' },
+              { has_memo = true },
+            ]
+            "#;
+
+        let pf: PacketFilter = toml::from_str(toml_content).expect("could not parse filter");
+
+        assert_eq!(pf.ics20.allow.len(), 2);
+        assert_eq!(pf.ics20.allow[0].min_amount, Some(Amount::from(1000u64)));
+        assert_eq!(
+            pf.ics20.allow[1].denom.as_ref().map(ToString::to_string),
+            Some("transfer/channel-0/*".to_string())
+        );
+        assert_eq!(pf.ics20.deny.len(), 2);
+        assert_eq!(pf.ics20.deny[1].has_memo, Some(true));
+
+        let pf: PacketFilter =
+            toml::from_str("policy = 'allowall'").expect("could not parse filter");
+        assert!(pf.ics20.is_empty());
+    }
+
+    #[test]
+    fn ics20_filter_check() {
+        fn packet_data(denom: &str, amount: &str, sender: &str, memo: &str) -> Vec<u8> {
+            format!(
+                r#"{{"denom":"{denom}","amount":"{amount}","sender":"{sender}","receiver":"cosmos1receiver","memo":"{memo}"}}"#
+            )
+            .into_bytes()
+        }
+
+        fn check(filter: &Ics20Filter, data: &[u8]) -> Result<(), Ics20FilterReason> {
+            filter.check(&RawTransferPacketData::decode(data, &Version::ics20()).unwrap())
+        }
+
+        let toml_content = r#"
+            allow = [
+              { base_denom = 'uatom', min_amount = 1000 },
+              { denom = 'transfer/channel-0/*', max_amount = 500 },
+            ]
+            deny = [
+              { sender = 'cosmos1spammer*' },
+              { has_memo = true },
+            ]
+            "#;
+
+        let filter: Ics20Filter = toml::from_str(toml_content).expect("could not parse filter");
+
+        let allowed = [
+            packet_data("uatom", "1000", "cosmos1sender", ""),
+            packet_data("transfer/channel-1/uatom", "2000", "cosmos1sender", ""),
+            packet_data("transfer/channel-0/uosmo", "500", "cosmos1sender", ""),
+        ];
+        for data in allowed {
+            assert_eq!(check(&filter, &data), Ok(()));
+        }
+
+        let not_allowed = [
+            packet_data("uatom", "999", "cosmos1sender", ""),
+            packet_data("uosmo", "1000", "cosmos1sender", ""),
+            packet_data("transfer/channel-0/uosmo", "501", "cosmos1sender", ""),
+            packet_data("gamm/pool/1", "1000", "cosmos1sender", ""),
+            packet_data("uatom", "not-an-amount", "cosmos1sender", ""),
+        ];
+        for data in not_allowed {
+            assert_eq!(check(&filter, &data), Err(Ics20FilterReason::NotAllowed));
+        }
+
+        let denied = [
+            packet_data("uatom", "1000", "cosmos1spammer", ""),
+            packet_data("uatom", "1000", "cosmos1sender", "hello"),
+        ];
+        for data in denied {
+            assert_eq!(check(&filter, &data), Err(Ics20FilterReason::Denied));
+        }
+
+        // Without `allow` rules, any packet which is not denied is allowed
+        let filter = Ics20Filter::new(vec![], filter.deny);
+        assert_eq!(
+            check(
+                &filter,
+                &packet_data("gamm/pool/1", "1", "cosmos1sender", "")
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn ics20_filter_amounts_above_u64_max() {
+        fn packet_data(amount: &str) -> RawTransferPacketData {
+            let data = format!(
+                r#"{{"denom":"aevmos","amount":"{amount}","sender":"cosmos1sender","receiver":"cosmos1receiver"}}"#
+            );
+            RawTransferPacketData::decode(data.as_bytes(), &Version::ics20()).unwrap()
+        }
+
+        // Both 2 * 10^19 and 10^21 exceed u64::MAX, which is about 1.8 * 10^19
+        let toml_content = r#"
+            allow = [
+              { base_denom = 'aevmos', min_amount = '20000000000000000000', max_amount = '1000000000000000000000' },
+            ]
+            deny = [
+              { base_denom = 'aevmos', min_amount = 1000000, max_amount = 2000000 },
+            ]
+            "#;
+
+        let filter: Ics20Filter = toml::from_str(toml_content).expect("could not parse filter");
+
+        assert_eq!(
+            filter.allow[0].max_amount,
+            Some(Amount::from(1_000_000_000_000_000_000_000u128))
+        );
+        assert_eq!(filter.deny[0].min_amount, Some(Amount::from(1_000_000u64)));
+
+        assert_eq!(filter.check(&packet_data("20000000000000000000")), Ok(()));
+        assert_eq!(filter.check(&packet_data("100000000000000000000")), Ok(()));
+        assert_eq!(filter.check(&packet_data("1000000000000000000000")), Ok(()));
+        assert_eq!(
+            filter.check(&packet_data("1000000000000000000001")),
+            Err(Ics20FilterReason::NotAllowed)
+        );
+        assert_eq!(
+            filter.check(&packet_data("19999999999999999999")),
+            Err(Ics20FilterReason::NotAllowed)
+        );
+
+        // The bounds are serialized as strings, so that they can be read back
+        let serialized = toml::to_string(&filter).expect("could not serialize filter");
+        let deserialized: Ics20Filter =
+            toml::from_str(&serialized).expect("could not parse serialized filter");
+        assert_eq!(deserialized, filter);
+
+        assert!(toml::from_str::<Ics20Filter>("allow = [ { min_amount = -1 } ]").is_err());
+        assert!(toml::from_str::<Ics20Filter>("allow = [ { min_amount = '1.5' } ]").is_err());
+    }
+
     #[test]
     fn to_string_wildcards() {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
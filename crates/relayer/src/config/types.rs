@@ -320,6 +320,94 @@ pub mod ics20_field_size_limit {
     }
 }
 
+pub use token_amount::TokenAmount;
+
+pub mod token_amount {
+    use core::fmt::{self, Display, Formatter};
+    use core::str::FromStr;
+
+    use serde::{de, Deserializer, Serialize, Serializer};
+
+    use ibc_relayer_types::applications::transfer::Amount;
+
+    /// An amount of tokens, given either as an integer or as a string holding
+    /// an integer, since amounts may not fit in the integers supported by TOML.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct TokenAmount(Amount);
+
+    impl TokenAmount {
+        pub fn new(amount: Amount) -> Self {
+            Self(amount)
+        }
+
+        pub fn amount(self) -> Amount {
+            self.0
+        }
+    }
+
+    impl From<u64> for TokenAmount {
+        fn from(amount: u64) -> Self {
+            Self(Amount::from(amount))
+        }
+    }
+
+    impl FromStr for TokenAmount {
+        type Err = <Amount as FromStr>::Err;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Amount::from_str(s).map(Self)
+        }
+    }
+
+    impl Display for TokenAmount {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    struct TokenAmountVisitor;
+
+    impl de::Visitor<'_> for TokenAmountVisitor {
+        type Value = TokenAmount;
+
+        fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a non-negative integer, or a string holding one")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(TokenAmount::from(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            u64::try_from(v)
+                .map(TokenAmount::from)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            TokenAmount::from_str(v).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for TokenAmount {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(TokenAmountVisitor)
+        }
+    }
+
+    impl Serialize for TokenAmount {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.to_string().serialize(serializer)
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(dead_code)] // the fields of the structs defined below are never accessed
 mod tests {
@@ -385,4 +473,22 @@ mod tests {
 
         assert!(err.contains("a string length of at most"));
     }
+
+    #[test]
+    fn parse_token_amount() {
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            amount: TokenAmount,
+        }
+
+        let parse = |s: &str| toml::from_str::<DummyConfig>(s).map(|c| c.amount.to_string());
+
+        assert_eq!(parse("amount = 1000").unwrap(), "1000");
+        assert_eq!(
+            parse("amount = '50000000000000000000'").unwrap(),
+            "50000000000000000000"
+        );
+        assert!(parse("amount = -1").is_err());
+        assert!(parse("amount = 'foo'").is_err());
+    }
 }
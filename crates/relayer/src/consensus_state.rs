@@ -4,9 +4,15 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SoloMachineConsensusState, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::{
     ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::error::Error;
@@ -18,18 +24,24 @@ use ibc_relayer_types::Height;
 #[serde(tag = "type")]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Wasm(WasmConsensusState),
+    SoloMachine(SoloMachineConsensusState),
 }
 
 impl AnyConsensusState {
     pub fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
+            Self::Wasm(cs_state) => cs_state.tendermint.timestamp.into(),
+            Self::SoloMachine(cs_state) => cs_state.timestamp,
         }
     }
 
     pub fn client_type(&self) -> ClientType {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
+            AnyConsensusState::Wasm(_cs) => ClientType::Wasm,
+            AnyConsensusState::SoloMachine(_cs) => ClientType::SoloMachine,
         }
     }
 }
@@ -48,6 +60,14 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            WASM_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Wasm(
+                WasmConsensusState::try_from(value)?,
+            )),
+
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::SoloMachine(
+                SoloMachineConsensusState::try_from(value)?,
+            )),
+
             _ => Err(Error::unknown_consensus_state_type(value.type_url)),
         }
     }
@@ -60,6 +80,8 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawConsensusState>::encode_vec(value),
             },
+            AnyConsensusState::Wasm(value) => value.into(),
+            AnyConsensusState::SoloMachine(value) => value.into(),
         }
     }
 }
@@ -70,6 +92,18 @@ impl From<TmConsensusState> for AnyConsensusState {
     }
 }
 
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl From<SoloMachineConsensusState> for AnyConsensusState {
+    fn from(cs: SoloMachineConsensusState) -> Self {
+        Self::SoloMachine(cs)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct AnyConsensusStateWithHeight {
     pub height: Height,
@@ -115,6 +149,8 @@ impl ConsensusState for AnyConsensusState {
     fn root(&self) -> &CommitmentRoot {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
+            Self::Wasm(cs_state) => cs_state.root(),
+            Self::SoloMachine(cs_state) => cs_state.root(),
         }
     }
 
@@ -1,6 +1,8 @@
 //! Data structures related to the denomination of coins used by the relayer.
 
+use ibc_relayer_types::applications::transfer::PrefixedDenom;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// The denom trace
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -10,3 +12,15 @@ pub struct DenomTrace {
     /// The base denomination for that coin
     pub base_denom: String,
 }
+
+/// The denomination under which the tokens with the given trace are held on a chain,
+/// i.e. `ibc/{hash}` for tokens received over IBC, or the base denomination otherwise.
+pub fn ibc_denom(denom: &PrefixedDenom) -> String {
+    let denom = denom.to_string();
+
+    if denom.contains('/') {
+        format!("ibc/{}", hex::encode_upper(Sha256::digest(&denom)))
+    } else {
+        denom
+    }
+}
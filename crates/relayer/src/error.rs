@@ -26,6 +26,7 @@ use tendermint_rpc::Error as TendermintRpcError;
 
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::applications::ics31_icq::error::Error as CrossChainQueryError;
+use ibc_relayer_types::applications::transfer::error::Error as TransferError;
 use ibc_relayer_types::clients::ics07_tendermint::error as tendermint_error;
 use ibc_relayer_types::core::ics02_client::{client_type::ClientType, error as client_error};
 use ibc_relayer_types::core::ics03_connection::error as connection_error;
@@ -112,6 +113,41 @@ define_error! {
             [ LightClientError ]
             |e| { format!("light client verification error for chain id {0}", e.chain_id) },
 
+        UntrustedQueryAnchor
+            {
+                chain_id: ChainId,
+                height: u64,
+                expected: tendermint::Hash,
+                actual: tendermint::Hash,
+            }
+            |e| {
+                format!("the header at height {} of chain {} has hash {} rather than the hash {} configured in `verified_queries`",
+                    e.height, e.chain_id, e.actual, e.expected)
+            },
+
+        QueryProofVerification
+            {
+                chain_id: ChainId,
+                path: String,
+                height: u64,
+            }
+            [ commitment_error::Error ]
+            |e| {
+                format!("the proof of `{}` at height {} returned by the full node of chain {} failed verification",
+                    e.path, e.height, e.chain_id)
+            },
+
+        QueryHeaderTimeout
+            {
+                chain_id: ChainId,
+                height: u64,
+                timeout: Duration,
+            }
+            |e| {
+                format!("chain {} did not reach height {}, whose header is needed to verify a query, within {}",
+                    e.chain_id, e.height, format_duration(e.timeout))
+            },
+
         LightClientState
             [ client_error::Error ]
             |_| { "light client encountered error due to client state".to_string() },
@@ -362,6 +398,15 @@ define_error! {
                     e.chain_id, e.estimated_gas, e.max_gas)
             },
 
+        FeeEstimationUnsupported
+            { chain_id: ChainId }
+            |e| { format!("estimating the fees of a transaction is not supported on chain {}", e.chain_id) },
+
+        InvalidFee
+            { chain_id: ChainId }
+            [ TransferError ]
+            |e| { format!("invalid fee estimated for a transaction on chain {}", e.chain_id) },
+
         HealthCheckJsonRpc
             {
                 chain_id: ChainId,
@@ -555,6 +600,17 @@ define_error! {
                     "Query/DenomTrace RPC returned an empty denom trace for trace hash: {}", e.hash)
             },
 
+        EmptyInterchainAccount
+            {
+                owner: String,
+                connection_id: ConnectionId,
+            }
+            |e| {
+                format_args!(
+                    "Query/InterchainAccount RPC returned an empty address for owner {} on connection {}",
+                    e.owner, e.connection_id)
+            },
+
         MessageTooBigForTx
             { len: usize }
             |e| {
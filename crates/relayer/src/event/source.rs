@@ -98,12 +98,28 @@ impl TxEventSourceCmd {
         let subscription = rx.recv().map_err(|_| Error::channel_recv_failed())?;
         Ok(subscription)
     }
+
+    /// Instruct the event source to collect events from another full node,
+    /// while keeping the existing subscriptions alive.
+    pub fn switch_node(&self, node: EventSourceNode) -> Result<()> {
+        self.0
+            .send(EventSourceCmd::SwitchNode(node))
+            .map_err(|_| Error::channel_send_failed())
+    }
+}
+
+/// The full node an event source collects events from
+#[derive(Clone, Debug)]
+pub enum EventSourceNode {
+    WebSocket(WebSocketClientUrl),
+    Rpc(HttpClient),
 }
 
 #[derive(Debug)]
 pub enum EventSourceCmd {
     Shutdown,
     Subscribe(channel::Sender<Subscription>),
+    SwitchNode(EventSourceNode),
 }
 
 // TODO: These are SDK specific, should be eventually moved.
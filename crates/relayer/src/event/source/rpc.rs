@@ -7,7 +7,7 @@ use tokio::{
     runtime::Runtime as TokioRuntime,
     time::{sleep, Duration, Instant},
 };
-use tracing::{debug, error, error_span, trace};
+use tracing::{debug, error, error_span, info, trace, warn};
 
 use tendermint::abci;
 use tendermint::block::Height as BlockHeight;
@@ -24,11 +24,11 @@ use ibc_relayer_types::{
 use crate::{
     chain::tracking::TrackingId,
     event::{bus::EventBus, error::ErrorDetail, source::Error, IbcEventWithHeight},
-    telemetry,
+    state, telemetry,
     util::retry::ConstantGrowth,
 };
 
-use super::{EventBatch, EventSourceCmd, TxEventSourceCmd};
+use super::{EventBatch, EventSourceCmd, EventSourceNode, TxEventSourceCmd};
 
 use self::extract::extract_events;
 
@@ -96,9 +96,11 @@ impl EventSource {
         rt.block_on(async {
             let mut backoff = poll_backoff(self.poll_interval);
 
-            // Initialize the latest fetched height
+            // Initialize the latest fetched height, resuming from the
+            // last processed height if Hermes was recently restarted
             if let Ok(latest_height) = latest_height(&self.rpc_client).await {
-                self.last_fetched_height = latest_height;
+                self.last_fetched_height =
+                    self.resume_height(latest_height).unwrap_or(latest_height);
             }
 
             // Continuously run the event loop, so that when it aborts
@@ -139,6 +141,31 @@ impl EventSource {
         debug!("shutting down event source");
     }
 
+    /// The height of the last block whose events were processed before Hermes was
+    /// restarted, if recorded in the relay state store and recent enough to fetch the
+    /// events of the blocks emitted since then.
+    fn resume_height(&self, latest_height: BlockHeight) -> Option<BlockHeight> {
+        let store = state::global()?;
+        let latest_height = Height::from_tm(latest_height, &self.chain_id);
+
+        match store.replay_height(&self.chain_id, latest_height) {
+            Ok(Some(height)) => {
+                info!(
+                    "resuming from height {height}, fetching the events emitted up to height {latest_height}"
+                );
+
+                store.mark_resumed(&self.chain_id);
+
+                BlockHeight::try_from(height.revision_height()).ok()
+            }
+            Ok(None) => None,
+            Err(e) => {
+                warn!("failed to read the last processed height from the state store: {e}");
+                None
+            }
+        }
+    }
+
     async fn step(&mut self) -> Result<Next> {
         // Process any shutdown or subscription commands before we start doing any work
         if let Next::Abort = self.try_process_cmd() {
@@ -191,6 +218,16 @@ impl EventSource {
                         error!("failed to send back subscription: {e}");
                     }
                 }
+
+                EventSourceCmd::SwitchNode(EventSourceNode::Rpc(rpc_client)) => {
+                    debug!("switching to another RPC endpoint");
+
+                    self.rpc_client = rpc_client;
+                }
+
+                EventSourceCmd::SwitchNode(EventSourceNode::WebSocket(ws_url)) => {
+                    error!("an RPC event source cannot switch to WebSocket endpoint {ws_url}");
+                }
             }
         }
 
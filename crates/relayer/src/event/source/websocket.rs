@@ -31,7 +31,9 @@ use crate::{
     },
 };
 
-use super::{EventBatch, EventSourceCmd, Result, SubscriptionStream, TxEventSourceCmd};
+use super::{
+    EventBatch, EventSourceCmd, EventSourceNode, Result, SubscriptionStream, TxEventSourceCmd,
+};
 
 use self::extract::extract_events;
 
@@ -306,9 +308,10 @@ impl EventSource {
         pin_mut!(batches);
 
         loop {
-            // Process any shutdown or subscription commands before we start doing any work.
-            if let Next::Abort = self.try_process_cmd() {
-                return Next::Abort;
+            // Process any shutdown, subscription or node switch commands before we start doing any work.
+            match self.try_process_cmd() {
+                Next::Continue => {}
+                next => return next,
             }
 
             let result = tokio::select! {
@@ -324,12 +327,21 @@ impl EventSource {
             //
             // It also allows subscribers to receive the latest event batch even if they
             // subscribe while the batch being fetched.
-            if let Next::Abort = self.try_process_cmd() {
+            let next = self.try_process_cmd();
+
+            if let Next::Abort = next {
                 return Next::Abort;
             }
 
             match result {
-                Ok(batch) => self.broadcast_batch(batch),
+                Ok(batch) => {
+                    self.broadcast_batch(batch);
+
+                    // Only switch to another node once the batch at hand has been broadcast.
+                    if let Next::Reconnect = next {
+                        return Next::Reconnect;
+                    }
+                }
                 Err(e) => {
                     if let ErrorDetail::SubscriptionCancelled(reason) = e.detail() {
                         error!("subscription cancelled, reason: {}", reason);
@@ -386,6 +398,18 @@ impl EventSource {
                         error!("failed to send back subscription: {e}");
                     }
                 }
+
+                EventSourceCmd::SwitchNode(EventSourceNode::WebSocket(ws_url)) => {
+                    info!("switching to WebSocket endpoint {ws_url}");
+
+                    self.ws_url = ws_url;
+
+                    return Next::Reconnect;
+                }
+
+                EventSourceCmd::SwitchNode(EventSourceNode::Rpc(_)) => {
+                    error!("a WebSocket event source cannot switch to an RPC endpoint");
+                }
             }
         }
 
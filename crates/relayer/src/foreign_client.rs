@@ -15,7 +15,10 @@ use tracing::{debug, error, info, instrument, trace, warn};
 
 use flex_error::define_error;
 use ibc_relayer_types::applications::ics28_ccv::msgs::ccv_misbehaviour::MsgSubmitIcsConsumerMisbehaviour;
+use ibc_relayer_types::clients::ics08_wasm::client_state::ClientState as WasmClientState;
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::ConsensusState as WasmConsensusState;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
@@ -24,6 +27,7 @@ use ibc_relayer_types::core::ics02_client::msgs::misbehaviour::MsgSubmitMisbehav
 use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
 use ibc_relayer_types::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::downcast;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
@@ -35,6 +39,7 @@ use crate::chain::client::ClientSettings;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
+use crate::client_expiry::{self, ClientExpiry, ClientStatus};
 use crate::client_state::AnyClientState;
 use crate::config::ChainConfig;
 use crate::consensus_state::AnyConsensusState;
@@ -357,6 +362,9 @@ pub struct CreateOptions {
     pub max_clock_drift: Option<Duration>,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: Option<TrustThreshold>,
+    /// The checksum of the Wasm light client contract to create the client with.
+    /// If set, a 08-wasm client wrapping the Tendermint client is created.
+    pub wasm_checksum: Option<Vec<u8>>,
 }
 
 /// Captures the diagnostic of verifying whether a certain
@@ -643,6 +651,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
+        let wasm_checksum = options.wasm_checksum.clone();
         let settings = ClientSettings::for_create_command(options, &src_config, &dst_config);
 
         let client_state: AnyClientState = self
@@ -671,6 +680,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        let (client_state, consensus_state) = match wasm_checksum {
+            Some(checksum) => wrap_in_wasm(checksum, client_state, consensus_state)?,
+            None => (client_state, consensus_state),
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
             .map_err(ForeignClientError::client)?;
@@ -837,6 +851,40 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// Same as [`ForeignClient::validated_client_state`], but also records the time
+    /// left before the client expires in the [`client_expiry`] monitor.
+    pub fn check_expiry(&self) -> Result<(AnyClientState, Option<Duration>), ForeignClientError> {
+        let validated = self.validated_client_state();
+
+        let inactive = |status| {
+            ClientExpiry::inactive(
+                self.dst_chain.id(),
+                self.id.clone(),
+                self.src_chain.id(),
+                status,
+            )
+        };
+
+        let expiry = match &validated {
+            Ok((client_state, Some(elapsed))) => Some(ClientExpiry::active(
+                self.dst_chain.id(),
+                self.id.clone(),
+                self.src_chain.id(),
+                client_state.trusting_period(),
+                *elapsed,
+            )),
+            Err(e) if e.is_frozen_error() => Some(inactive(ClientStatus::Frozen)),
+            Err(e) if e.is_expired_error() => Some(inactive(ClientStatus::Expired)),
+            _ => None,
+        };
+
+        if let Some(expiry) = expiry {
+            client_expiry::global().record(expiry);
+        }
+
+        validated
+    }
+
     pub fn is_frozen(&self) -> bool {
         match self.validated_client_state() {
             Ok(_) => false,
@@ -895,7 +943,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
     }
 
     fn try_refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
-        let (client_state, elapsed) = self.validated_client_state()?;
+        let (client_state, elapsed) = self.check_expiry()?;
+
+        // Solo machine clients never expire, there is no need to refresh them
+        if matches!(client_state, AnyClientState::SoloMachine(_)) {
+            return Ok(None);
+        }
 
         let src_config = self.src_chain.config().map_err(|e| {
             ForeignClientError::client_create(
@@ -910,6 +963,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 config.client_refresh_rate
             }
             ChainConfig::Penumbra(config) => config.client_refresh_rate,
+            #[cfg(any(test, feature = "mock-chain"))]
+            ChainConfig::Mock(config) => config.client_refresh_rate,
         };
 
         let refresh_period = client_state
@@ -1174,6 +1229,17 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             return Ok(vec![]);
         }
 
+        self.wait_for_src_height(target_height)?;
+
+        let messages = self.build_update_client_with_trusted(target_height, trusted_height)?;
+
+        let encoded_messages = messages.into_iter().map(Msg::to_any).collect();
+
+        Ok(encoded_messages)
+    }
+
+    /// Wait for the source chain to produce block(s) & reach `target_height`.
+    fn wait_for_src_height(&self, target_height: Height) -> Result<(), ForeignClientError> {
         let src_application_latest_height = || {
             self.src_chain().query_latest_height().map_err(|e| {
                 ForeignClientError::client_create(
@@ -1184,26 +1250,19 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             })
         };
 
-        {
-            crate::time!(
-                "wait_and_build_update_client_with_trusted_sleep",
-                {
-                    "src_chain": self.src_chain().id(),
-                    "dst_chain": self.dst_chain().id(),
-                }
-            );
-
-            // Wait for the source network to produce block(s) & reach `target_height`.
-            while src_application_latest_height()? < target_height {
-                thread::sleep(Duration::from_millis(100));
+        crate::time!(
+            "wait_and_build_update_client_with_trusted_sleep",
+            {
+                "src_chain": self.src_chain().id(),
+                "dst_chain": self.dst_chain().id(),
             }
-        }
-
-        let messages = self.build_update_client_with_trusted(target_height, trusted_height)?;
+        );
 
-        let encoded_messages = messages.into_iter().map(Msg::to_any).collect();
+        while src_application_latest_height()? < target_height {
+            thread::sleep(Duration::from_millis(100));
+        }
 
-        Ok(encoded_messages)
+        Ok(())
     }
 
     #[instrument(
@@ -1289,6 +1348,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         self.wait_for_header_validation_delay(&client_state, &header)?;
 
+        let header = wrap_header(&client_state, header);
+
         let mut msgs = vec![];
 
         for header in support {
@@ -1298,7 +1359,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             );
 
             msgs.push(MsgUpdateClient {
-                header: header.into(),
+                header: wrap_header(&client_state, header).into(),
                 client_id: self.id.clone(),
                 signer: signer.clone(),
             });
@@ -1478,6 +1539,21 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(Some(update))
     }
 
+    /// Returns the commitment root of the source chain at `height`, as trusted by this client:
+    /// the root of the consensus state it holds for that height. Fails if the client has no
+    /// consensus state at `height`.
+    #[instrument(
+        name = "foreign_client.commitment_root",
+        level = "error",
+        skip_all,
+        fields(client = %self, %height)
+    )]
+    pub fn commitment_root(&self, height: Height) -> Result<CommitmentRoot, ForeignClientError> {
+        let consensus_state = self.fetch_consensus_state(height)?;
+
+        Ok(consensus_state.root().clone())
+    }
+
     /// Returns the consensus state at `height` or error if not found.
     #[instrument(
         name = "foreign_client.fetch_consensus_state",
@@ -1765,6 +1841,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 config.ccv_consumer_chain
             }
             ChainConfig::Penumbra(_) => false,
+            #[cfg(any(test, feature = "mock-chain"))]
+            ChainConfig::Mock(_) => false,
         };
 
         let mut msgs = vec![];
@@ -1782,6 +1860,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let tm_misbehaviour = match &evidence.misbehaviour {
             AnyMisbehaviour::Tendermint(tm_misbehaviour) => Some(tm_misbehaviour.clone()),
+            AnyMisbehaviour::Wasm(wasm_misbehaviour) => Some(wasm_misbehaviour.tendermint.clone()),
+            AnyMisbehaviour::SoloMachine(_) => None,
         }
         .ok_or_else(|| {
             ForeignClientError::misbehaviour_desc(format!(
@@ -1967,3 +2047,40 @@ pub fn fetch_ccv_consumer_id(
 
     Ok(consumer_id)
 }
+
+/// Wrap the Tendermint client and consensus states built by the source chain
+/// in the states of a 08-wasm client running the contract with the given checksum.
+fn wrap_in_wasm(
+    checksum: Vec<u8>,
+    client_state: AnyClientState,
+    consensus_state: AnyConsensusState,
+) -> Result<(AnyClientState, AnyConsensusState), ForeignClientError> {
+    match (client_state, consensus_state) {
+        (
+            AnyClientState::Tendermint(client_state),
+            AnyConsensusState::Tendermint(consensus_state),
+        ) => {
+            let client_state = WasmClientState::new(checksum, client_state)
+                .map_err(|e| ForeignClientError::client(e.into()))?;
+
+            Ok((
+                client_state.into(),
+                WasmConsensusState::from(consensus_state).into(),
+            ))
+        }
+        (client_state, _) => Err(ForeignClientError::client(ClientError::client_specific(
+            format!(
+                "cannot wrap a client state of type {} in a 08-wasm client state",
+                client_state.client_type()
+            ),
+        ))),
+    }
+}
+
+/// Headers updating a 08-wasm client must be wrapped in Wasm client messages.
+fn wrap_header(client_state: &AnyClientState, header: AnyHeader) -> AnyHeader {
+    match (client_state, header) {
+        (AnyClientState::Wasm(_), AnyHeader::Tendermint(header)) => AnyHeader::Wasm(header.into()),
+        (_, header) => header,
+    }
+}
@@ -0,0 +1,239 @@
+//! Registration of interchain accounts (ICS27) and submission of transactions through them.
+//!
+//! An interchain account is an account on a _host_ chain which is controlled by an
+//! _owner_ account on a _controller_ chain, through an ordered or unordered channel
+//! opened on a connection between both chains. The messages below are submitted to
+//! the controller chain, the relayer then opens the channel and relays the packets.
+
+use core::time::Duration;
+
+use flex_error::{define_error, DetailOnly};
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::applications::ics27_ica::cosmos_tx::CosmosTx;
+use ibc_relayer_types::applications::ics27_ica::msgs::register::{
+    LegacyMsgRegisterInterchainAccount, MsgRegisterInterchainAccount,
+};
+use ibc_relayer_types::applications::ics27_ica::msgs::send_tx::MsgSendTx;
+use ibc_relayer_types::applications::ics27_ica::packet_data::InterchainAccountPacketData;
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::{ParseTimestampError, Timestamp};
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::tracking::TrackedMsgs;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::upgrade_chain::{requires_legacy_upgrade_proposal, UpgradeChainError};
+
+define_error! {
+    InterchainAccountError {
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the interchain account message to chain {0}",
+                    e.chain_id)
+            },
+
+        MessageVersion
+            [ UpgradeChainError ]
+            |_| { "failed to determine which message version the controller chain supports" },
+
+        TxResponse
+            { event: String }
+            |e| {
+                format!("tx response event consists of an error: {}",
+                    e.event)
+            },
+
+        MissingEvent
+            { event_type: String }
+            |e| {
+                format!("no {} event was emitted after submitting the interchain account message",
+                    e.event_type)
+            },
+
+        EmptyMessages
+            |_| { "an interchain account transaction must contain at least one message" },
+
+        ZeroTimeout
+            |_| { "the timeout of an interchain account transaction cannot be 0" },
+
+        InvalidTimeout
+            [ DetailOnly<ParseTimestampError> ]
+            |_| { "invalid timeout for the interchain account transaction" },
+
+        InvalidMessages
+            { reason: String }
+            |e| {
+                format!("invalid JSON array of messages: {}", e.reason)
+            },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RegisterInterchainAccountOptions {
+    pub connection_id: ConnectionId,
+    /// The owner of the interchain account, which must be the account of the relayer key
+    /// on the controller chain. Defaults to that account if not specified.
+    pub owner: Option<Signer>,
+    /// The version of the channel, the default ICS27 metadata of the connection if empty
+    pub version: Version,
+    /// Ignored by controller chains running ibc-go versions below v8.0.0,
+    /// which only open ordered channels
+    pub ordering: Ordering,
+}
+
+#[derive(Clone, Debug)]
+pub struct SendInterchainAccountTxOptions {
+    pub connection_id: ConnectionId,
+    /// The owner of the interchain account, which must be the account of the relayer key
+    /// on the controller chain. Defaults to that account if not specified.
+    pub owner: Option<Signer>,
+    /// The messages executed by the interchain account on the host chain
+    pub messages: Vec<Any>,
+    pub memo: String,
+    /// The timeout of the packet, relative to the time of the controller chain
+    pub timeout: Duration,
+}
+
+/// Register an interchain account on the host chain at the other end of the connection,
+/// returning the `OpenInitChannel` event of the channel opened for the account.
+pub fn build_and_send_register_interchain_account(
+    controller_chain: &impl ChainHandle,
+    opts: &RegisterInterchainAccountOptions,
+) -> Result<IbcEventWithHeight, InterchainAccountError> {
+    let owner = match &opts.owner {
+        Some(owner) => owner.clone(),
+        None => controller_chain
+            .get_signer()
+            .map_err(InterchainAccountError::key)?,
+    };
+
+    let legacy = requires_legacy_upgrade_proposal(controller_chain.clone())
+        .map_err(InterchainAccountError::message_version)?;
+
+    let msg = if legacy {
+        LegacyMsgRegisterInterchainAccount {
+            owner,
+            connection_id: opts.connection_id.clone(),
+            version: opts.version.clone(),
+        }
+        .to_any()
+    } else {
+        MsgRegisterInterchainAccount {
+            owner,
+            connection_id: opts.connection_id.clone(),
+            version: opts.version.clone(),
+            ordering: opts.ordering,
+        }
+        .to_any()
+    };
+
+    let events = send_message(controller_chain, msg, "register interchain account")?;
+
+    events
+        .into_iter()
+        .find(|event| matches!(event.event, IbcEvent::OpenInitChannel(_)))
+        .ok_or_else(|| InterchainAccountError::missing_event("OpenInitChannel".to_string()))
+}
+
+/// Send a transaction to be executed by the interchain account of the owner,
+/// returning the `SendPacket` event of the packet carrying it.
+pub fn build_and_send_interchain_account_tx(
+    controller_chain: &impl ChainHandle,
+    opts: &SendInterchainAccountTxOptions,
+) -> Result<IbcEventWithHeight, InterchainAccountError> {
+    if opts.messages.is_empty() {
+        return Err(InterchainAccountError::empty_messages());
+    }
+
+    if opts.timeout == Duration::ZERO {
+        return Err(InterchainAccountError::zero_timeout());
+    }
+
+    let owner = match &opts.owner {
+        Some(owner) => owner.clone(),
+        None => controller_chain
+            .get_signer()
+            .map_err(InterchainAccountError::key)?,
+    };
+
+    let cosmos_tx = CosmosTx {
+        messages: opts.messages.clone(),
+    };
+
+    let mut packet_data = InterchainAccountPacketData::new(cosmos_tx.to_any().value);
+    packet_data.memo = opts.memo.clone();
+
+    let msg = MsgSendTx {
+        owner,
+        connection_id: opts.connection_id.clone(),
+        packet_data,
+        relative_timeout: Timestamp::from_nanoseconds(opts.timeout.as_nanos() as u64)
+            .map_err(InterchainAccountError::invalid_timeout)?,
+    };
+
+    let events = send_message(controller_chain, msg.to_any(), "send interchain account tx")?;
+
+    events
+        .into_iter()
+        .find(|event| matches!(event.event, IbcEvent::SendPacket(_)))
+        .ok_or_else(|| InterchainAccountError::missing_event("SendPacket".to_string()))
+}
+
+/// Parse a JSON array of protobuf messages, each with a `type_url` (or `typeUrl`)
+/// and a base64-encoded `value`.
+pub fn parse_messages(json: &str) -> Result<Vec<Any>, InterchainAccountError> {
+    serde_json::from_str(json).map_err(|e| InterchainAccountError::invalid_messages(e.to_string()))
+}
+
+fn send_message(
+    controller_chain: &impl ChainHandle,
+    msg: Any,
+    tracking_id: &'static str,
+) -> Result<Vec<IbcEventWithHeight>, InterchainAccountError> {
+    let events = controller_chain
+        .send_messages_and_wait_commit(TrackedMsgs::new_single(msg, tracking_id))
+        .map_err(|e| InterchainAccountError::submit(controller_chain.id(), e))?;
+
+    for event in events.iter() {
+        if let IbcEvent::ChainError(e) = &event.event {
+            return Err(InterchainAccountError::tx_response(e.clone()));
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_messages;
+
+    #[test]
+    fn parse_messages_json() {
+        let msgs = parse_messages(
+            r#"[{"type_url": "/cosmos.bank.v1beta1.MsgSend", "value": "AQID"}, {"typeUrl": "/cosmos.gov.v1.MsgVote"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(msgs[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(msgs[0].value, vec![1, 2, 3]);
+        assert_eq!(msgs[1].type_url, "/cosmos.gov.v1.MsgVote");
+        assert!(msgs[1].value.is_empty());
+    }
+
+    #[test]
+    fn parse_messages_not_an_array() {
+        assert!(parse_messages(r#"{"type_url": "/cosmos.bank.v1beta1.MsgSend"}"#).is_err());
+    }
+}
@@ -1,8 +1,11 @@
+pub mod encrypted;
 pub mod errors;
+pub mod remote;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
 pub use key_type::KeyType;
 pub use secp256k1_key_pair::Secp256k1KeyPair;
+pub use secp256k1_signer::Secp256k1Signer;
 pub use signing_key_pair::{SigningKeyPair, SigningKeyPairSized};
 
 pub use crate::chain::namada::key::NamadaKeyPair;
@@ -13,17 +16,23 @@ mod key_type;
 mod key_utils;
 mod pub_key;
 mod secp256k1_key_pair;
+mod secp256k1_signer;
 mod signing_key_pair;
 
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use std::ffi::OsStr;
 use std::fs::{self, File};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use serde::{Deserialize, Serialize};
 
+use encrypted::{Encrypted, Passphrase, KEYSTORE_ENCRYPTED_BACKEND};
 use errors::Error;
+use remote::{Remote, RemoteSignerConfig};
+
+use crate::config::AddressType;
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
@@ -186,6 +195,8 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
 pub enum Store {
     Memory,
     Test,
+    Encrypted,
+    Remote,
 }
 
 impl Default for Store {
@@ -194,10 +205,26 @@ impl Default for Store {
     }
 }
 
+impl Store {
+    /// The on-disk store in which the `keys` commands manage the keys of a chain
+    /// configured with this store, since keys held in memory do not outlive the process.
+    ///
+    /// Fails for the remote store, whose keys are managed at the remote signer.
+    pub fn on_disk(self) -> Result<Self, Error> {
+        match self {
+            Self::Memory | Self::Test => Ok(Self::Test),
+            Self::Encrypted => Ok(Self::Encrypted),
+            Self::Remote => Err(Error::remote_key_store_unsupported()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum KeyRing<S> {
     Memory(Memory<S>),
     Test(Test),
+    Encrypted(Encrypted),
+    Remote(Remote),
 }
 
 impl<S: SigningKeyPairSized> KeyRing<S> {
@@ -211,29 +238,55 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str(), ks_folder)?;
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_DISK_BACKEND)?;
 
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Error::key_file_io(
-                        keys_folder.display().to_string(),
-                        "failed to create keys folder".to_string(),
-                        e,
-                    )
-                })?;
+                create_keys_folder(&keys_folder)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::Encrypted => {
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_ENCRYPTED_BACKEND)?;
+
+                create_keys_folder(&keys_folder)?;
+
+                let passphrase = Passphrase::from_env_or_prompt()?;
+
+                Ok(Self::Encrypted(Encrypted::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                    passphrase,
+                )))
+            }
+
+            // The remote store needs the settings of the signer, see `KeyRing::new_remote`
+            Store::Remote => Err(Error::remote_signer_not_configured()),
         }
     }
 
+    pub fn new_remote(
+        signer: &RemoteSignerConfig,
+        account_prefix: &str,
+        address_type: &AddressType,
+    ) -> Self {
+        Self::Remote(Remote::new(
+            account_prefix.to_string(),
+            address_type.clone(),
+            signer.clone(),
+        ))
+    }
+
     pub fn get_key(&self, key_name: &str) -> Result<S, Error> {
         match self {
             Self::Memory(m) => m.get_key(key_name),
             Self::Test(d) => d.get_key(key_name),
+            Self::Encrypted(e) => e.get_key(key_name),
+            Self::Remote(_) => Err(Error::remote_key_store_unsupported()),
         }
     }
 
@@ -241,6 +294,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.add_key(key_name, key_entry),
             Self::Test(d) => d.add_key(key_name, key_entry),
+            Self::Encrypted(e) => e.add_key(key_name, key_entry),
+            Self::Remote(_) => Err(Error::remote_key_store_unsupported()),
         }
     }
 
@@ -248,6 +303,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.remove_key(key_name),
             Self::Test(d) => <Test as KeyStore<S>>::remove_key(d, key_name),
+            Self::Encrypted(e) => <Encrypted as KeyStore<S>>::remove_key(e, key_name),
+            Self::Remote(_) => Err(Error::remote_key_store_unsupported()),
         }
     }
 
@@ -255,6 +312,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.keys(),
             Self::Test(d) => d.keys(),
+            Self::Encrypted(e) => e.keys(),
+            Self::Remote(_) => Err(Error::remote_key_store_unsupported()),
         }
     }
 
@@ -262,6 +321,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => &m.account_prefix,
             Self::Test(d) => &d.account_prefix,
+            Self::Encrypted(e) => e.account_prefix(),
+            Self::Remote(r) => r.account_prefix(),
         }
     }
 }
@@ -275,6 +336,15 @@ impl KeyRing<Secp256k1KeyPair> {
     ) -> Result<Self, Error> {
         Self::new(store, account_prefix, chain_id, ks_folder)
     }
+
+    /// The signer of the transactions submitted from the account of the given key,
+    /// which delegates the signing to the remote signer for the remote store.
+    pub fn get_signer(&self, key_name: &str) -> Result<Arc<dyn Secp256k1Signer>, Error> {
+        match self {
+            Self::Remote(r) => Ok(Arc::new(r.get_signer(key_name)?)),
+            _ => Ok(Arc::new(self.get_key(key_name)?)),
+        }
+    }
 }
 
 impl KeyRing<Ed25519KeyPair> {
@@ -298,7 +368,11 @@ impl KeyRing<NamadaKeyPair> {
     }
 }
 
-fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Result<PathBuf, Error> {
+fn disk_store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+    backend: &str,
+) -> Result<PathBuf, Error> {
     let ks_folder = match keystore_folder {
         Some(folder) => folder.to_owned(),
         None => {
@@ -307,7 +381,18 @@ fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Resu
         }
     };
 
-    let folder = ks_folder.join(folder_name).join(KEYSTORE_DISK_BACKEND);
+    let folder = ks_folder.join(folder_name).join(backend);
 
     Ok(folder)
 }
+
+/// Create the keys folder if it does not exist
+fn create_keys_folder(keys_folder: &Path) -> Result<(), Error> {
+    fs::create_dir_all(keys_folder).map_err(|e| {
+        Error::key_file_io(
+            keys_folder.display().to_string(),
+            "failed to create keys folder".to_string(),
+            e,
+        )
+    })
+}
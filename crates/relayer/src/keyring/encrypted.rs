@@ -0,0 +1,513 @@
+//! An on-disk key store which keeps every key encrypted at rest.
+//!
+//! Each key is serialized to JSON, as with the [`Test`](super::Test) store, and then
+//! encrypted with ChaCha20-Poly1305 under a key derived from the keyring passphrase
+//! with Argon2id. Every key file gets its own random salt and nonce.
+//!
+//! The passphrase is read from the file pointed to by `HERMES_KEYRING_PASSPHRASE_FILE`,
+//! or from the `HERMES_KEYRING_PASSPHRASE` environment variable. If neither is set,
+//! it is prompted for on the terminal, and must then be entered a second time before
+//! a key is added. The passphrase is only read once per process.
+//!
+//! Note: the format of the key files is specific to Hermes and cannot be read by
+//! the `file` backend of the Cosmos SDK keyring, nor the other way around.
+
+use core::fmt;
+use std::env;
+use std::ffi::OsStr;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::errors::Error;
+use super::{KeyStore, SigningKeyPairSized, KEYSTORE_FILE_EXTENSION};
+
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-encrypted";
+
+pub const PASSPHRASE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE";
+pub const PASSPHRASE_FILE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE_FILE";
+
+const ENCRYPTED_KEY_FILE_VERSION: u8 = 1;
+const KDF_ARGON2ID: &str = "argon2id";
+const CIPHER_CHACHA20_POLY1305: &str = "chacha20-poly1305";
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+// Upper bounds on the Argon2id parameters read from key files, so that a tampered
+// file cannot make unlocking it use an unreasonable amount of memory or time.
+const MAX_M_COST: u32 = 1024 * 1024; // 1 GiB
+const MAX_T_COST: u32 = 16;
+const MAX_P_COST: u32 = 16;
+
+static PASSPHRASE: OnceCell<Passphrase> = OnceCell::new();
+static PASSPHRASE_CONFIRMED: OnceCell<()> = OnceCell::new();
+
+/// The passphrase protecting an [`Encrypted`] key store
+#[derive(Clone, Default)]
+pub struct Passphrase {
+    passphrase: Zeroizing<String>,
+    prompted: bool,
+}
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Result<Self, Error> {
+        if passphrase.is_empty() {
+            return Err(Error::empty_keyring_passphrase());
+        }
+
+        Ok(Self {
+            passphrase: Zeroizing::new(passphrase),
+            prompted: false,
+        })
+    }
+
+    /// Get the keyring passphrase from the environment or, failing that, prompt for it.
+    ///
+    /// The passphrase is only read the first time this is called, and then reused
+    /// for every key store opened by the process.
+    pub fn from_env_or_prompt() -> Result<Self, Error> {
+        PASSPHRASE.get_or_try_init(read_passphrase).cloned()
+    }
+
+    /// If the passphrase was prompted for, prompt for it a second time and check that
+    /// both entries match, so that a typo does not lock the keys added with it.
+    ///
+    /// The passphrase is only confirmed the first time this is called.
+    fn confirm(&self) -> Result<(), Error> {
+        if !self.prompted {
+            return Ok(());
+        }
+
+        PASSPHRASE_CONFIRMED
+            .get_or_try_init(|| {
+                let prompt = "Enter the passphrase of the Hermes keyring again: ";
+                let confirmation = Zeroizing::new(
+                    rpassword::read_password_from_tty(Some(prompt))
+                        .map_err(Error::keyring_passphrase_unavailable)?,
+                );
+
+                if *confirmation == *self.passphrase {
+                    Ok(())
+                } else {
+                    Err(Error::keyring_passphrase_mismatch())
+                }
+            })
+            .copied()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.passphrase.as_bytes()
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+fn read_passphrase() -> Result<Passphrase, Error> {
+    if let Ok(path) = env::var(PASSPHRASE_FILE_ENV_VAR) {
+        let contents = fs::read_to_string(&path).map_err(|e| {
+            Error::key_file_io(path, "failed to read passphrase file".to_string(), e)
+        })?;
+
+        // Ignore the trailing newline most editors add at the end of the file
+        let passphrase = contents.trim_end_matches(['\r', '\n']).to_string();
+
+        return Passphrase::new(passphrase);
+    }
+
+    if let Ok(passphrase) = env::var(PASSPHRASE_ENV_VAR) {
+        return Passphrase::new(passphrase);
+    }
+
+    let prompt = "Enter the passphrase of the Hermes keyring: ";
+    let passphrase = rpassword::read_password_from_tty(Some(prompt))
+        .map_err(Error::keyring_passphrase_unavailable)?;
+
+    Ok(Passphrase {
+        prompted: true,
+        ..Passphrase::new(passphrase)?
+    })
+}
+
+/// The contents of an encrypted key file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct EncryptedKeyFile {
+    version: u8,
+    kdf: String,
+    kdf_params: KdfParams,
+    cipher: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+struct KdfParams {
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl KdfParams {
+    /// Check that the parameters are within the bounds accepted by Hermes.
+    fn validate(&self) -> Result<(), String> {
+        let check = |name: &str, value: u32, min: u32, max: u32| {
+            if (min..=max).contains(&value) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "key derivation parameter '{name}' is {value}, expected a value between {min} and {max}"
+                ))
+            }
+        };
+
+        check("m_cost", self.m_cost, Params::MIN_M_COST, MAX_M_COST)?;
+        check("t_cost", self.t_cost, Params::MIN_T_COST, MAX_T_COST)?;
+        check("p_cost", self.p_cost, Params::MIN_P_COST, MAX_P_COST)
+    }
+
+    fn derive_key(
+        &self,
+        passphrase: &Passphrase,
+        salt: &[u8],
+    ) -> Result<Zeroizing<[u8; KEY_LENGTH]>, Error> {
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_LENGTH))
+            .map_err(Error::key_derivation)?;
+
+        let mut key = Zeroizing::new([0; KEY_LENGTH]);
+
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key[..])
+            .map_err(Error::key_derivation)?;
+
+        Ok(key)
+    }
+}
+
+impl EncryptedKeyFile {
+    fn encrypt(passphrase: &Passphrase, plaintext: &[u8], file_path: &str) -> Result<Self, Error> {
+        let mut salt = [0; SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut nonce = [0; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut nonce);
+
+        let kdf_params = KdfParams::default();
+        let key = kdf_params.derive_key(passphrase, &salt)?;
+
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| Error::key_file_encrypt(file_path.to_string()))?;
+
+        Ok(Self {
+            version: ENCRYPTED_KEY_FILE_VERSION,
+            kdf: KDF_ARGON2ID.to_string(),
+            kdf_params,
+            cipher: CIPHER_CHACHA20_POLY1305.to_string(),
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    fn decrypt(
+        &self,
+        passphrase: &Passphrase,
+        file_path: &str,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let corrupted =
+            |reason: &str| Error::key_file_corrupted(file_path.to_string(), reason.to_string());
+
+        if self.version != ENCRYPTED_KEY_FILE_VERSION {
+            return Err(corrupted(&format!("unsupported version {}", self.version)));
+        }
+
+        if self.kdf != KDF_ARGON2ID || self.cipher != CIPHER_CHACHA20_POLY1305 {
+            return Err(corrupted(&format!(
+                "unsupported key derivation function '{}' or cipher '{}'",
+                self.kdf, self.cipher
+            )));
+        }
+
+        self.kdf_params
+            .validate()
+            .map_err(|reason| corrupted(&reason))?;
+
+        let salt = hex::decode(&self.salt).map_err(|_| corrupted("invalid salt"))?;
+        let nonce = hex::decode(&self.nonce).map_err(|_| corrupted("invalid nonce"))?;
+        let ciphertext =
+            hex::decode(&self.ciphertext).map_err(|_| corrupted("invalid ciphertext"))?;
+
+        if nonce.len() != NONCE_LENGTH {
+            return Err(corrupted("invalid nonce length"));
+        }
+
+        let key = self.kdf_params.derive_key(passphrase, &salt)?;
+
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key[..]))
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| Error::key_file_decrypt(file_path.to_string()))?;
+
+        Ok(Zeroizing::new(plaintext))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Encrypted {
+    account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+}
+
+impl Encrypted {
+    pub fn new(account_prefix: String, store: PathBuf, passphrase: Passphrase) -> Self {
+        Self {
+            account_prefix,
+            store,
+            passphrase,
+        }
+    }
+
+    pub fn account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    fn key_file_path(&self, key_name: &str) -> PathBuf {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_FILE_EXTENSION);
+        key_file
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for Encrypted {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(file_path));
+        }
+
+        let file = File::open(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to open file".to_string(), e)
+        })?;
+
+        let encrypted: EncryptedKeyFile = serde_json::from_reader(file)
+            .map_err(|e| Error::key_file_decode(file_path.clone(), e))?;
+
+        let plaintext = encrypted.decrypt(&self.passphrase, &file_path)?;
+
+        let key_entry =
+            serde_json::from_slice(&plaintext).map_err(|e| Error::key_file_decode(file_path, e))?;
+
+        Ok(key_entry)
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: S) -> Result<(), Error> {
+        self.passphrase.confirm()?;
+
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        let plaintext = Zeroizing::new(
+            serde_json::to_vec(&key_entry)
+                .map_err(|e| Error::key_file_encode(file_path.clone(), e))?,
+        );
+
+        let encrypted = EncryptedKeyFile::encrypt(&self.passphrase, &plaintext, &file_path)?;
+
+        let file = create_private_file(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        serde_json::to_writer_pretty(file, &encrypted)
+            .map_err(|e| Error::key_file_encode(file_path, e))?;
+
+        Ok(())
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let key_file = self.key_file_path(key_name);
+
+        fs::remove_file(&key_file)
+            .map_err(|e| Error::remove_io_fail(key_file.display().to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        let dir = fs::read_dir(&self.store).map_err(|e| {
+            Error::key_file_io(
+                self.store.display().to_string(),
+                "failed to list keys".to_string(),
+                e,
+            )
+        })?;
+
+        let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+
+        dir.into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// Create (or truncate) a file which is only readable and writable by its owner.
+fn create_private_file(path: &Path) -> std::io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use hdpath::StandardHDPath;
+
+    use crate::config::AddressType;
+    use crate::keyring::errors::ErrorDetail;
+    use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn key_pair() -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+
+        Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos").unwrap()
+    }
+
+    fn store(folder: &Path, passphrase: &str) -> Encrypted {
+        Encrypted::new(
+            "cosmos".to_string(),
+            folder.to_path_buf(),
+            Passphrase::new(passphrase.to_string()).unwrap(),
+        )
+    }
+
+    fn temp_folder() -> PathBuf {
+        let folder = env::temp_dir().join(format!("hermes-keyring-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    #[test]
+    fn round_trip() {
+        let folder = temp_folder();
+        let key = key_pair();
+
+        let mut keystore = store(&folder, "correct horse battery staple");
+        keystore.add_key("relayer", key.clone()).unwrap();
+
+        let contents = fs::read_to_string(folder.join("relayer.json")).unwrap();
+        assert!(!contents.contains("abandon"));
+
+        let stored: Secp256k1KeyPair = keystore.get_key("relayer").unwrap();
+        assert_eq!(stored.account(), key.account());
+
+        let keys: Vec<(String, Secp256k1KeyPair)> = keystore.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+
+        KeyStore::<Secp256k1KeyPair>::remove_key(&mut keystore, "relayer").unwrap();
+        assert!(KeyStore::<Secp256k1KeyPair>::get_key(&keystore, "relayer").is_err());
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn wrong_passphrase() {
+        let folder = temp_folder();
+
+        let mut keystore = store(&folder, "correct horse battery staple");
+        keystore.add_key("relayer", key_pair()).unwrap();
+
+        let keystore = store(&folder, "incorrect horse battery staple");
+        let result = KeyStore::<Secp256k1KeyPair>::get_key(&keystore, "relayer");
+        assert!(result.is_err());
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn out_of_bounds_kdf_params() {
+        let folder = temp_folder();
+
+        let mut keystore = store(&folder, "correct horse battery staple");
+        keystore.add_key("relayer", key_pair()).unwrap();
+
+        let key_file = folder.join("relayer.json");
+        let encrypted: EncryptedKeyFile =
+            serde_json::from_str(&fs::read_to_string(&key_file).unwrap()).unwrap();
+
+        for kdf_params in [
+            KdfParams {
+                m_cost: u32::MAX,
+                ..KdfParams::default()
+            },
+            KdfParams {
+                t_cost: MAX_T_COST + 1,
+                ..KdfParams::default()
+            },
+            KdfParams {
+                p_cost: 0,
+                ..KdfParams::default()
+            },
+        ] {
+            let tampered = EncryptedKeyFile {
+                kdf_params,
+                ..encrypted.clone()
+            };
+            fs::write(&key_file, serde_json::to_vec(&tampered).unwrap()).unwrap();
+
+            let result = KeyStore::<Secp256k1KeyPair>::get_key(&keystore, "relayer");
+            assert!(matches!(
+                result.unwrap_err().detail(),
+                ErrorDetail::KeyFileCorrupted(_)
+            ));
+        }
+
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn empty_passphrase() {
+        assert!(Passphrase::new(String::new()).is_err());
+    }
+}
@@ -156,6 +156,76 @@ define_error! {
             }
             |e| {
                 format!("Invalid public key length: expected {}, got {}", e.expected, e.got)
+            },
+
+        KeyringPassphraseUnavailable
+            [ TraceError<IoError> ]
+            |_| {
+                format!("failed to read the keyring passphrase from the terminal, \
+                    set either {} or {} to provide it non-interactively",
+                    super::encrypted::PASSPHRASE_ENV_VAR,
+                    super::encrypted::PASSPHRASE_FILE_ENV_VAR)
+            },
+
+        EmptyKeyringPassphrase
+            |_| { "the keyring passphrase cannot be empty" },
+
+        KeyringPassphraseMismatch
+            |_| { "the keyring passphrases entered do not match" },
+
+        KeyDerivation
+            [ DisplayOnly<argon2::Error> ]
+            |_| { "failed to derive the encryption key from the keyring passphrase" },
+
+        KeyFileEncrypt
+            { file_path: String }
+            |e| {
+                format!("failed to encrypt key file at '{}'",
+                    e.file_path)
+            },
+
+        KeyFileDecrypt
+            { file_path: String }
+            |e| {
+                format!("failed to decrypt key file at '{}', the keyring passphrase may be wrong",
+                    e.file_path)
+            },
+
+        RemoteSigner
+            {
+                url: String,
+                reason: String,
+            }
+            |e| {
+                format!("remote signer request to '{}' failed: {}",
+                    e.url, e.reason)
+            },
+
+        RemoteSignerNotConfigured
+            |_| { "the remote key store requires the `remote_signer` setting of the chain to be set" },
+
+        RemoteKeyStoreUnsupported
+            |_| {
+                "the keys of the remote key store are held by the remote signer, \
+                    and must be listed, added or removed at the signer itself"
+            },
+
+        InvalidRemotePublicKey
+            [ TraceError<secp256k1::Error> ]
+            |_| { "the remote signer returned an invalid public key" },
+
+        InvalidRemoteSignature
+            { reason: String }
+            |e| { format!("the remote signer returned an invalid signature: {}", e.reason) },
+
+        KeyFileCorrupted
+            {
+                file_path: String,
+                reason: String,
             }
+            |e| {
+                format!("invalid encrypted key file at '{}': {}",
+                    e.file_path, e.reason)
+            },
     }
 }
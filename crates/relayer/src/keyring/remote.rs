@@ -0,0 +1,431 @@
+//! A key store whose private keys are held by an external signer.
+//!
+//! Hermes only ever learns the public keys from the signer, and sends it the bytes
+//! to sign over HTTP(S), so that the private keys never touch the relayer host.
+//! This makes it possible to put the relayer keys behind a tmkms/horcrux-style
+//! signing service or an HSM gateway.
+//!
+//! The signer must expose the following JSON API, where public keys and signatures
+//! are hex-encoded:
+//!
+//! - `GET /v1/keys` lists the available keys as `{ "keys": [{ "key_id", "public_key" }] }`
+//! - `GET /v1/keys/{key_id}` returns a single key as `{ "key_id", "public_key" }`
+//! - `POST /v1/keys/{key_id}/sign` takes `{ "sign_bytes", "hash" }` and returns
+//!   `{ "signature" }`, where `hash` is the digest (`sha256` or `keccak256`) the signer
+//!   must apply to `sign_bytes` before signing, and `signature` is the 64-byte
+//!   compact encoding of the secp256k1 ECDSA signature.
+//!
+//! Only secp256k1 keys are currently supported.
+
+#[cfg(any(test, feature = "mock-signer"))]
+pub mod mock;
+
+use core::time::Duration;
+
+use digest::Digest;
+use generic_array::{typenum::U32, GenericArray};
+use once_cell::sync::OnceCell;
+use secp256k1::ecdsa::Signature;
+use secp256k1::{Message, PublicKey, Secp256k1};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tendermint_rpc::Url;
+
+use super::errors::Error;
+use super::key_utils::{encode_bech32, keccak256_hash};
+use super::secp256k1_key_pair::{get_address, Secp256k1AddressType};
+use super::Secp256k1Signer;
+use crate::config::{default, AddressType};
+
+pub const REMOTE_SIGNER_API_VERSION: &str = "v1";
+
+/// Where to reach the remote signer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// The base URL of the signer, eg. `https://signer.example.com:7100`
+    pub url: Url,
+
+    /// Timeout used for every request to the signer
+    #[serde(default = "default::remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+/// The digest the signer must apply to the sign bytes before signing them
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignHash {
+    Sha256,
+    Keccak256,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKeyResponse {
+    pub key_id: String,
+    pub public_key: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListKeysResponse {
+    pub keys: Vec<PublicKeyResponse>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignRequest {
+    pub sign_bytes: String,
+    pub hash: SignHash,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignResponse {
+    pub signature: String,
+}
+
+/// A client for the API of the remote signer.
+///
+/// Clones of the client share the same HTTP client, and thus its connections to the signer.
+#[derive(Clone, Debug)]
+pub struct RemoteSignerClient {
+    config: RemoteSignerConfig,
+    http: reqwest::blocking::Client,
+}
+
+impl RemoteSignerClient {
+    pub fn new(config: RemoteSignerConfig) -> Result<Self, Error> {
+        let timeout = config.timeout;
+
+        let http = blocking(|| {
+            reqwest::blocking::Client::builder()
+                .timeout(timeout)
+                .build()
+        })
+        .map_err(|e| Error::remote_signer(config.url.to_string(), e.to_string()))?;
+
+        Ok(Self { config, http })
+    }
+
+    /// Fetch the public key with the given identifier
+    pub fn public_key(&self, key_id: &str) -> Result<Vec<u8>, Error> {
+        let path = format!("keys/{key_id}");
+        let response: PublicKeyResponse = self.send(&path, None)?;
+
+        self.decode_hex(&path, "public key", &response.public_key)
+    }
+
+    /// List the identifiers and public keys of all the keys held by the signer
+    pub fn list_keys(&self) -> Result<Vec<(String, Vec<u8>)>, Error> {
+        let response: ListKeysResponse = self.send("keys", None)?;
+
+        response
+            .keys
+            .into_iter()
+            .map(|key| {
+                let public_key = self.decode_hex("keys", "public key", &key.public_key)?;
+                Ok((key.key_id, public_key))
+            })
+            .collect()
+    }
+
+    /// Have the signer sign the given bytes with the key with the given identifier
+    pub fn sign(&self, key_id: &str, sign_bytes: &[u8], hash: SignHash) -> Result<Vec<u8>, Error> {
+        let path = format!("keys/{key_id}/sign");
+
+        let request = SignRequest {
+            sign_bytes: hex::encode(sign_bytes),
+            hash,
+        };
+
+        let response: SignResponse = self.send(&path, Some(request))?;
+
+        self.decode_hex(&path, "signature", &response.signature)
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/{REMOTE_SIGNER_API_VERSION}/{path}",
+            self.config.url.to_string().trim_end_matches('/')
+        )
+    }
+
+    fn decode_hex(&self, path: &str, what: &str, value: &str) -> Result<Vec<u8>, Error> {
+        hex::decode(value)
+            .map_err(|e| Error::remote_signer(self.endpoint(path), format!("invalid {what}: {e}")))
+    }
+
+    fn send<T>(&self, path: &str, body: Option<SignRequest>) -> Result<T, Error>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let url = self.endpoint(path);
+
+        blocking(|| {
+            let request = match body {
+                Some(body) => self.http.post(&url).json(&body),
+                None => self.http.get(&url),
+            };
+
+            let response = request
+                .send()
+                .map_err(|e| Error::remote_signer(url.clone(), e.to_string()))?;
+
+            let status = response.status();
+
+            if !status.is_success() {
+                let reason = response.text().unwrap_or_default();
+                return Err(Error::remote_signer(
+                    url.clone(),
+                    format!("{status}: {reason}"),
+                ));
+            }
+
+            response
+                .json()
+                .map_err(|e| Error::remote_signer(url.clone(), e.to_string()))
+        })
+    }
+}
+
+/// Run the given blocking operation of the HTTP client, which must not be run
+/// from within an async context, so that keys can be used both from synchronous
+/// code and from within the async context of the chain runtime.
+fn blocking<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send,
+    T: Send,
+{
+    use tokio::runtime::{Handle, RuntimeFlavor};
+
+    match Handle::try_current() {
+        Err(_) => f(),
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        // A current-thread runtime cannot be blocked in place
+        Ok(_) => std::thread::scope(|scope| {
+            scope
+                .spawn(f)
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))
+        }),
+    }
+}
+
+/// A key held by the remote signer, which signs the transactions
+/// submitted from its account
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: RemoteSignerClient,
+    key_id: String,
+    public_key: PublicKey,
+    address_type: Secp256k1AddressType,
+    account: String,
+}
+
+impl RemoteSigner {
+    /// Build the signer of the key with the given identifier,
+    /// from the public key of the key as returned by the signer
+    pub fn new(
+        client: RemoteSignerClient,
+        key_id: String,
+        public_key: &[u8],
+        address_type: &AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        let public_key =
+            PublicKey::from_slice(public_key).map_err(Error::invalid_remote_public_key)?;
+        let address_type = Secp256k1AddressType::try_from(address_type)?;
+        let address = get_address(&public_key, address_type);
+        let account = encode_bech32(account_prefix, &address)?;
+
+        Ok(Self {
+            client,
+            key_id,
+            public_key,
+            address_type,
+            account,
+        })
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+impl Secp256k1Signer for RemoteSigner {
+    fn account(&self) -> String {
+        self.account.clone()
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Have the remote signer sign the message, and check that the signature
+    /// it returns is a valid one for the public key of the key.
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let (hash, hashed_message): (_, GenericArray<u8, U32>) = match self.address_type {
+            Secp256k1AddressType::Ethermint => {
+                (SignHash::Keccak256, keccak256_hash(message).into())
+            }
+            Secp256k1AddressType::Cosmos => (SignHash::Sha256, Sha256::digest(message)),
+        };
+
+        // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`.
+        let digest = Message::from_digest_slice(&hashed_message).unwrap();
+
+        let signature = self.client.sign(&self.key_id, message, hash)?;
+
+        let mut signature = Signature::from_compact(&signature)
+            .map_err(|e| Error::invalid_remote_signature(e.to_string()))?;
+
+        // Cosmos SDK chains only accept signatures in lower-S form
+        signature.normalize_s();
+
+        Secp256k1::verification_only()
+            .verify_ecdsa(&digest, &signature, &self.public_key)
+            .map_err(|e| Error::invalid_remote_signature(e.to_string()))?;
+
+        Ok(signature.serialize_compact().to_vec())
+    }
+}
+
+/// A key store backed by a remote signer, in which keys are looked up by their
+/// identifier at the signer. The keys can only be used through their [`RemoteSigner`],
+/// and must be listed, added and removed at the signer directly.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    account_prefix: String,
+    address_type: AddressType,
+    config: RemoteSignerConfig,
+    #[serde(skip)]
+    client: OnceCell<RemoteSignerClient>,
+}
+
+impl Remote {
+    pub fn new(
+        account_prefix: String,
+        address_type: AddressType,
+        config: RemoteSignerConfig,
+    ) -> Self {
+        Self {
+            account_prefix,
+            address_type,
+            config,
+            client: OnceCell::new(),
+        }
+    }
+
+    pub fn account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    /// The signer of the key with the given identifier
+    pub fn get_signer(&self, key_name: &str) -> Result<RemoteSigner, Error> {
+        let client = self.client()?;
+        let public_key = client.public_key(key_name)?;
+
+        RemoteSigner::new(
+            client.clone(),
+            key_name.to_string(),
+            &public_key,
+            &self.address_type,
+            &self.account_prefix,
+        )
+    }
+
+    /// The client for the signer, which is shared by all the keys of the store
+    fn client(&self) -> Result<&RemoteSignerClient, Error> {
+        self.client
+            .get_or_try_init(|| RemoteSignerClient::new(self.config.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use hdpath::StandardHDPath;
+
+    use super::mock::MockSigner;
+    use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn local_key() -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+
+        Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos").unwrap()
+    }
+
+    fn remote_store(url: Url) -> Remote {
+        Remote::new(
+            "cosmos".to_string(),
+            AddressType::Cosmos,
+            RemoteSignerConfig {
+                url,
+                timeout: Duration::from_secs(5),
+            },
+        )
+    }
+
+    #[test]
+    fn signs_like_local_key() {
+        let local = local_key();
+        let signer = MockSigner::new([("relayer".to_string(), local.clone())])
+            .spawn()
+            .unwrap();
+
+        let store = remote_store(signer.url());
+        let remote = store.get_signer("relayer").unwrap();
+
+        assert_eq!(remote.account(), SigningKeyPair::account(&local));
+        assert_eq!(remote.public_key(), local.public_key);
+
+        let message = b"sign doc bytes";
+        assert_eq!(
+            remote.sign(message).unwrap(),
+            SigningKeyPair::sign(&local, message).unwrap()
+        );
+    }
+
+    #[test]
+    fn keyring_signer_uses_remote_signer() {
+        let local = local_key();
+        let signer = MockSigner::new([("relayer".to_string(), local.clone())])
+            .spawn()
+            .unwrap();
+
+        let keyring: KeyRing<Secp256k1KeyPair> = KeyRing::Remote(remote_store(signer.url()));
+
+        let remote = keyring.get_signer("relayer").unwrap();
+        assert_eq!(remote.account(), SigningKeyPair::account(&local));
+
+        // The private keys are held by the signer, so that no key pair can be loaded
+        assert!(keyring.get_key("relayer").is_err());
+        assert!(keyring.keys().is_err());
+    }
+
+    #[test]
+    fn unknown_key() {
+        let signer = MockSigner::new([("relayer".to_string(), local_key())])
+            .spawn()
+            .unwrap();
+
+        let store = remote_store(signer.url());
+
+        assert!(store.get_signer("unknown").is_err());
+    }
+
+    #[test]
+    fn is_read_only() {
+        let signer = MockSigner::new([]).spawn().unwrap();
+
+        let mut keyring: KeyRing<Secp256k1KeyPair> = KeyRing::Remote(remote_store(signer.url()));
+
+        assert!(keyring.add_key("relayer", local_key()).is_err());
+        assert!(keyring.remove_key("relayer").is_err());
+    }
+}
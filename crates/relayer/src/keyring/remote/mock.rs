@@ -0,0 +1,118 @@
+//! A mock remote signer, serving the API expected by the [`Remote`](super::Remote)
+//! key store from a set of local keys. Only meant to be used in tests.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::net::{SocketAddr, TcpListener};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{Extension, Json, Path};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::Router;
+use tendermint_rpc::Url;
+use tokio::runtime::Runtime;
+
+use super::{ListKeysResponse, PublicKeyResponse, SignRequest, SignResponse};
+use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+type Keys = Arc<BTreeMap<String, Secp256k1KeyPair>>;
+
+/// A signer holding the given keys, indexed by their identifier.
+///
+/// The signer ignores the digest requested by the client and signs
+/// with whatever digest matches the address type of the key.
+pub struct MockSigner {
+    keys: BTreeMap<String, Secp256k1KeyPair>,
+}
+
+impl MockSigner {
+    pub fn new(keys: impl IntoIterator<Item = (String, Secp256k1KeyPair)>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+        }
+    }
+
+    /// Start serving the signer API on a random local port, until the returned handle is dropped.
+    pub fn spawn(self) -> io::Result<MockSignerHandle> {
+        let runtime = Runtime::new()?;
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        let app = Router::new()
+            .route("/v1/keys", get(list_keys))
+            .route("/v1/keys/:key_id", get(get_key))
+            .route("/v1/keys/:key_id/sign", post(sign))
+            .layer(Extension(Arc::new(self.keys)));
+
+        let server = {
+            let _guard = runtime.enter();
+
+            axum::Server::from_tcp(listener)
+                .map_err(io::Error::other)?
+                .serve(app.into_make_service())
+        };
+
+        runtime.spawn(server);
+
+        Ok(MockSignerHandle {
+            addr,
+            _runtime: runtime,
+        })
+    }
+}
+
+pub struct MockSignerHandle {
+    addr: SocketAddr,
+    _runtime: Runtime,
+}
+
+impl MockSignerHandle {
+    pub fn url(&self) -> Url {
+        Url::from_str(&format!("http://{}", self.addr)).expect("valid mock signer URL")
+    }
+}
+
+fn public_key_response(key_id: &str, key: &Secp256k1KeyPair) -> PublicKeyResponse {
+    PublicKeyResponse {
+        key_id: key_id.to_string(),
+        public_key: hex::encode(key.public_key.serialize()),
+    }
+}
+
+async fn list_keys(Extension(keys): Extension<Keys>) -> Json<ListKeysResponse> {
+    let keys = keys
+        .iter()
+        .map(|(key_id, key)| public_key_response(key_id, key))
+        .collect();
+
+    Json(ListKeysResponse { keys })
+}
+
+async fn get_key(
+    Extension(keys): Extension<Keys>,
+    Path(key_id): Path<String>,
+) -> Result<Json<PublicKeyResponse>, StatusCode> {
+    let key = keys.get(&key_id).ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(public_key_response(&key_id, key)))
+}
+
+async fn sign(
+    Extension(keys): Extension<Keys>,
+    Path(key_id): Path<String>,
+    Json(request): Json<SignRequest>,
+) -> Result<Json<SignResponse>, StatusCode> {
+    let key = keys.get(&key_id).ok_or(StatusCode::NOT_FOUND)?;
+    let sign_bytes = hex::decode(request.sign_bytes).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let signature = key
+        .sign(&sign_bytes)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(SignResponse {
+        signature: hex::encode(signature),
+    }))
+}
@@ -0,0 +1,32 @@
+use core::fmt::Debug;
+
+use secp256k1::PublicKey;
+
+use super::{errors::Error, Secp256k1KeyPair, SigningKeyPair};
+
+/// Signs the transactions submitted from the account of a secp256k1 key,
+/// whose private key is either held in-process or by a remote signer.
+pub trait Secp256k1Signer: Debug + Send + Sync {
+    /// The bech32-encoded address of the account of the key
+    fn account(&self) -> String;
+
+    /// The public key which the signatures are verified against
+    fn public_key(&self) -> PublicKey;
+
+    /// Sign the given sign bytes, returning the 64-byte compact encoding of the signature
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
+}
+
+impl Secp256k1Signer for Secp256k1KeyPair {
+    fn account(&self) -> String {
+        SigningKeyPair::account(self)
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        SigningKeyPair::sign(self, message)
+    }
+}
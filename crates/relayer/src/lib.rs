@@ -24,6 +24,7 @@ pub mod account;
 pub mod cache;
 pub mod chain;
 pub mod channel;
+pub mod client_expiry;
 pub mod client_state;
 pub mod config;
 pub mod connection;
@@ -33,16 +34,22 @@ pub mod error;
 pub mod event;
 pub mod extension_options;
 pub mod foreign_client;
+pub mod interchain_account;
 pub mod keyring;
+pub mod lifecycle;
 pub mod light_client;
 pub mod link;
 pub mod misbehaviour;
 pub mod object;
 pub mod path;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
+pub mod sink;
+pub mod solomachine;
 pub mod spawn;
+pub mod state;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
@@ -0,0 +1,421 @@
+//! Tracking of the lifecycle of the packets relayed by Hermes, from the moment
+//! they are sent on their source chain until they are acknowledged or time out.
+//!
+//! The lifecycle of a packet goes through the following stages, each one recorded
+//! when Hermes first observes the corresponding event:
+//!
+//! - `sent`: the `SendPacket` event on the source chain;
+//! - `received`: the `WriteAcknowledgement` event on the destination chain;
+//! - `acknowledged`: the `AcknowledgePacket` event on the source chain;
+//! - `timed_out`: the `TimeoutPacket` or `TimeoutOnClosePacket` event on the source chain.
+//!
+//! For every stage, the tracker records the height of the event, when it was observed,
+//! the [`TrackingId`] of the event batch it was observed in, and whether the transaction
+//! which emitted it was submitted by this Hermes instance or by another relayer.
+//! The time elapsed between two stages is recorded in the `packet_stage_latency`
+//! histogram, per channel.
+//!
+//! Packets are identified by their source chain, source channel and sequence,
+//! since channel identifiers are unique on a given chain. Only the lifecycles of
+//! the [`MAX_TRACKED_PACKETS`] most recent packets are kept in memory.
+
+use core::time::Duration;
+use std::time::SystemTime;
+
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+use tendermint::Hash as TxHash;
+
+use crate::chain::tracking::TrackingId;
+use crate::event::IbcEventWithHeight;
+use crate::telemetry;
+
+/// The maximum number of packets whose lifecycle is kept in memory
+pub const MAX_TRACKED_PACKETS: u64 = 100_000;
+
+/// How long the lifecycle of a packet is kept after its last update
+const RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+static GLOBAL_TRACKER: Lazy<LifecycleTracker> = Lazy::new(LifecycleTracker::new);
+
+/// The tracker fed by the supervisor and the packet workers
+pub fn global() -> &'static LifecycleTracker {
+    &GLOBAL_TRACKER
+}
+
+/// Identifies a packet by its source chain, source channel and sequence
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct PacketKey {
+    pub chain_id: ChainId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+/// Who submitted the transaction which emitted the event of a stage
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "by", rename_all = "snake_case")]
+pub enum Relayer {
+    /// This Hermes instance, in one of the given transactions
+    Hermes { tx_hashes: Vec<String> },
+    /// Another relayer, or Hermes did not see its transaction being confirmed
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Stage {
+    /// The height of the event, if observed in an event batch
+    pub height: Option<Height>,
+    #[serde(with = "humantime_serde")]
+    pub observed_at: SystemTime,
+    /// The tracking id of the event batch the event was observed in
+    pub tracking_id: Option<String>,
+    /// Who relayed the packet to this stage, not applicable to `sent`
+    pub relayed_by: Option<Relayer>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StageKind {
+    Sent,
+    Received,
+    Acknowledged,
+    TimedOut,
+}
+
+impl StageKind {
+    /// The stage reached by the packet of the given event, if any, along with
+    /// whether this event is emitted on the source chain of the packet
+    fn of(event: &IbcEvent) -> Option<(Self, bool)> {
+        match event {
+            IbcEvent::SendPacket(_) => Some((Self::Sent, true)),
+            IbcEvent::WriteAcknowledgement(_) => Some((Self::Received, false)),
+            IbcEvent::AcknowledgePacket(_) => Some((Self::Acknowledged, true)),
+            IbcEvent::TimeoutPacket(_) | IbcEvent::TimeoutOnClosePacket(_) => {
+                Some((Self::TimedOut, true))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The stages a packet went through, as observed by Hermes
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PacketLifecycle {
+    pub src_chain_id: ChainId,
+    pub src_port_id: PortId,
+    pub src_channel_id: ChannelId,
+    pub dst_chain_id: Option<ChainId>,
+    pub dst_port_id: PortId,
+    pub dst_channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub sent: Option<Stage>,
+    pub received: Option<Stage>,
+    pub acknowledged: Option<Stage>,
+    pub timed_out: Option<Stage>,
+}
+
+impl PacketLifecycle {
+    fn new(src_chain_id: ChainId, packet: &Packet) -> Self {
+        Self {
+            src_chain_id,
+            src_port_id: packet.source_port.clone(),
+            src_channel_id: packet.source_channel.clone(),
+            dst_chain_id: None,
+            dst_port_id: packet.destination_port.clone(),
+            dst_channel_id: packet.destination_channel.clone(),
+            sequence: packet.sequence,
+            sent: None,
+            received: None,
+            acknowledged: None,
+            timed_out: None,
+        }
+    }
+
+    fn stage_mut(&mut self, kind: StageKind) -> &mut Option<Stage> {
+        match kind {
+            StageKind::Sent => &mut self.sent,
+            StageKind::Received => &mut self.received,
+            StageKind::Acknowledged => &mut self.acknowledged,
+            StageKind::TimedOut => &mut self.timed_out,
+        }
+    }
+
+    /// The time elapsed between the given stages, if both were observed
+    pub fn elapsed(&self, from: Option<&Stage>, to: Option<&Stage>) -> Option<Duration> {
+        let (from, to) = (from?, to?);
+        to.observed_at.duration_since(from.observed_at).ok()
+    }
+
+    /// Record the latencies which can be computed now that the given stage was reached
+    fn record_latencies(&self, kind: StageKind) {
+        let latencies = match kind {
+            StageKind::Sent => vec![],
+            StageKind::Received => vec![(
+                "receive",
+                self.elapsed(self.sent.as_ref(), self.received.as_ref()),
+            )],
+            StageKind::Acknowledged => vec![
+                (
+                    "acknowledge",
+                    self.elapsed(self.received.as_ref(), self.acknowledged.as_ref()),
+                ),
+                (
+                    "total",
+                    self.elapsed(self.sent.as_ref(), self.acknowledged.as_ref()),
+                ),
+            ],
+            StageKind::TimedOut => vec![(
+                "timeout",
+                self.elapsed(self.sent.as_ref(), self.timed_out.as_ref()),
+            )],
+        };
+
+        let Some(dst_chain_id) = &self.dst_chain_id else {
+            return;
+        };
+
+        for (stage, latency) in latencies {
+            if let Some(latency) = latency {
+                telemetry!(
+                    packet_stage_latency,
+                    stage,
+                    &self.src_chain_id,
+                    &self.src_channel_id,
+                    &self.src_port_id,
+                    dst_chain_id,
+                    latency
+                );
+            }
+        }
+    }
+}
+
+/// The lifecycles of the most recent packets, see the [module documentation](self)
+pub struct LifecycleTracker {
+    packets: Cache<PacketKey, PacketLifecycle>,
+}
+
+impl LifecycleTracker {
+    fn new() -> Self {
+        Self {
+            packets: Cache::builder()
+                .max_capacity(MAX_TRACKED_PACKETS)
+                .time_to_idle(RETENTION)
+                .build(),
+        }
+    }
+
+    /// The lifecycle of the packet with the given sequence, sent on the given channel
+    pub fn get(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Option<PacketLifecycle> {
+        self.packets.get(&PacketKey {
+            chain_id: chain_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence,
+        })
+    }
+
+    /// Record the stage reached by the packet of an event observed on `chain_id`,
+    /// whose counterparty on the channel of the event is `counterparty_chain_id`, if known.
+    pub fn observe(
+        &self,
+        chain_id: &ChainId,
+        counterparty_chain_id: Option<&ChainId>,
+        event: &IbcEventWithHeight,
+        tracking_id: TrackingId,
+    ) {
+        self.record(
+            chain_id,
+            counterparty_chain_id,
+            &event.event,
+            Some(event.height),
+            Some(tracking_id.to_string()),
+            None,
+        );
+    }
+
+    /// Record the stages reached by the packets of the events emitted by transactions
+    /// submitted by Hermes on `chain_id`, and relaying packets to or from `counterparty_chain_id`.
+    pub fn relayed(
+        &self,
+        chain_id: &ChainId,
+        counterparty_chain_id: &ChainId,
+        events: &[IbcEvent],
+        tx_hashes: &[TxHash],
+    ) {
+        let relayer = Relayer::Hermes {
+            tx_hashes: tx_hashes.iter().map(|hash| hash.to_string()).collect(),
+        };
+
+        for event in events {
+            self.record(
+                chain_id,
+                Some(counterparty_chain_id),
+                event,
+                None,
+                None,
+                Some(relayer.clone()),
+            );
+        }
+    }
+
+    fn record(
+        &self,
+        chain_id: &ChainId,
+        counterparty_chain_id: Option<&ChainId>,
+        event: &IbcEvent,
+        height: Option<Height>,
+        tracking_id: Option<String>,
+        relayer: Option<Relayer>,
+    ) {
+        let (Some(packet), Some((kind, on_source))) = (event.packet(), StageKind::of(event)) else {
+            return;
+        };
+
+        let (src_chain_id, dst_chain_id) = if on_source {
+            (Some(chain_id), counterparty_chain_id)
+        } else {
+            (counterparty_chain_id, Some(chain_id))
+        };
+
+        // The source chain of a packet received on this chain is not known
+        let Some(src_chain_id) = src_chain_id else {
+            return;
+        };
+
+        let key = PacketKey {
+            chain_id: src_chain_id.clone(),
+            channel_id: packet.source_channel.clone(),
+            sequence: packet.sequence,
+        };
+
+        self.packets.entry(key).and_upsert_with(|entry| {
+            let mut lifecycle = entry
+                .map(|entry| entry.into_value())
+                .unwrap_or_else(|| PacketLifecycle::new(src_chain_id.clone(), packet));
+
+            if lifecycle.dst_chain_id.is_none() {
+                lifecycle.dst_chain_id = dst_chain_id.cloned();
+            }
+
+            let relayed_by = match kind {
+                StageKind::Sent => None,
+                _ => Some(relayer.clone().unwrap_or(Relayer::Other)),
+            };
+
+            match lifecycle.stage_mut(kind) {
+                Some(stage) => {
+                    // The stage was observed already, only complete what was missing
+                    if stage.height.is_none() {
+                        stage.height = height;
+                    }
+
+                    if stage.tracking_id.is_none() {
+                        stage.tracking_id = tracking_id;
+                    }
+
+                    if relayer.is_some() {
+                        stage.relayed_by = relayed_by;
+                    }
+                }
+                stage @ None => {
+                    *stage = Some(Stage {
+                        height,
+                        observed_at: SystemTime::now(),
+                        tracking_id,
+                        relayed_by,
+                    });
+
+                    lifecycle.record_latencies(kind);
+                }
+            }
+
+            lifecycle
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics04_channel::events::{
+        AcknowledgePacket, SendPacket, WriteAcknowledgement,
+    };
+    use ibc_relayer_types::timestamp::Timestamp;
+
+    fn packet() -> Packet {
+        Packet {
+            sequence: 7.into(),
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+            destination_port: PortId::transfer(),
+            destination_channel: ChannelId::new(3),
+            data: vec![],
+            timeout_height: Default::default(),
+            timeout_timestamp: Timestamp::none(),
+        }
+    }
+
+    fn at(event: IbcEvent, height: u64) -> IbcEventWithHeight {
+        IbcEventWithHeight::new(event, Height::new(0, height).unwrap())
+    }
+
+    #[test]
+    fn tracks_stages_across_chains() {
+        let tracker = LifecycleTracker::new();
+        let (a, b) = (ChainId::from_string("ibc-0"), ChainId::from_string("ibc-1"));
+
+        let send = IbcEvent::SendPacket(SendPacket { packet: packet() });
+        let write_ack = IbcEvent::WriteAcknowledgement(WriteAcknowledgement {
+            packet: packet(),
+            ack: vec![1],
+        });
+        let ack = IbcEvent::AcknowledgePacket(AcknowledgePacket { packet: packet() });
+
+        tracker.observe(&a, Some(&b), &at(send, 10), TrackingId::new_static("send"));
+
+        // The relayer confirms the transaction before the event is observed on the destination chain
+        tracker.relayed(&b, &a, core::slice::from_ref(&write_ack), &[TxHash::None]);
+        tracker.observe(
+            &b,
+            Some(&a),
+            &at(write_ack, 20),
+            TrackingId::new_static("recv"),
+        );
+
+        // Another relayer acknowledges the packet
+        tracker.observe(&a, None, &at(ack, 30), TrackingId::new_static("ack"));
+
+        let lifecycle = tracker.get(&a, &ChannelId::new(0), 7.into()).unwrap();
+
+        assert_eq!(lifecycle.dst_chain_id, Some(b));
+        assert_eq!(lifecycle.dst_channel_id, ChannelId::new(3));
+
+        let sent = lifecycle.sent.as_ref().unwrap();
+        assert_eq!(sent.height, Some(Height::new(0, 10).unwrap()));
+        assert_eq!(sent.relayed_by, None);
+
+        let received = lifecycle.received.as_ref().unwrap();
+        assert_eq!(received.height, Some(Height::new(0, 20).unwrap()));
+        assert_eq!(received.tracking_id.as_deref(), Some("recv"));
+        assert!(matches!(received.relayed_by, Some(Relayer::Hermes { .. })));
+
+        let acknowledged = lifecycle.acknowledged.as_ref().unwrap();
+        assert_eq!(acknowledged.relayed_by, Some(Relayer::Other));
+
+        assert!(lifecycle.timed_out.is_none());
+        assert!(lifecycle
+            .elapsed(lifecycle.sent.as_ref(), lifecycle.acknowledged.as_ref())
+            .is_some());
+    }
+}
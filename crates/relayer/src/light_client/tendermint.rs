@@ -1,5 +1,6 @@
 mod detector;
 
+use std::sync::Mutex;
 use std::time::Duration;
 
 use itertools::Itertools;
@@ -14,6 +15,7 @@ use tendermint_light_client::{
     light_client::LightClient as TmLightClient,
     state::State as LightClientState,
     store::{memory::MemoryStore, LightStore},
+    verifier::options::Options,
     verifier::types::{Height as TMHeight, LightBlock, PeerId, Status},
     verifier::ProdVerifier,
 };
@@ -22,13 +24,17 @@ use tendermint_rpc as rpc;
 
 use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::Height as ICSHeight;
 
 use crate::{
-    chain::cosmos::{config::CosmosSdkConfig, CosmosSdkChain},
+    chain::cosmos::{
+        config::{CosmosSdkConfig, VerifiedQueriesConfig},
+        CosmosSdkChain,
+    },
     chain::penumbra::config::PenumbraConfig,
     client_state::AnyClientState,
     error::Error,
@@ -41,11 +47,21 @@ use super::{
     Verified,
 };
 
+use self::detector::Witness;
+
+/// The number of headers verified for verified queries which are kept, to verify the next ones from
+const MAX_QUERY_BLOCKS: usize = 100;
+
 pub struct LightClient {
     chain_id: ChainId,
     peer_id: PeerId,
     io: AnyIo,
     enable_verification: bool,
+    witnesses: Vec<Witness>,
+
+    /// The headers verified so far for verified queries, in ascending order of height,
+    /// from which the next ones are verified
+    query_blocks: Mutex<Vec<LightBlock>>,
 }
 
 impl super::LightClient<CosmosSdkChain> for LightClient {
@@ -156,10 +172,23 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
 
         let update_header = match any_header {
             AnyHeader::Tendermint(header) => Ok::<_, Error>(header),
+            AnyHeader::Wasm(header) => Ok(&header.tendermint),
+            AnyHeader::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
         }?;
 
+        // The evidence submitted to a Wasm client must be wrapped in Wasm client messages
+        let is_wasm = matches!(client_state, AnyClientState::Wasm(_));
+
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok::<_, Error>(client_state),
+            AnyClientState::Wasm(client_state) => Ok(&client_state.tendermint),
+            AnyClientState::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
         }?;
 
         let next_validators = self
@@ -191,7 +220,7 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
 
         let divergence = detector::detect(
             self.peer_id,
-            self.io.rpc_client().clone(),
+            &self.witnesses,
             target_block,
             trusted_block,
             client_state,
@@ -203,10 +232,13 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
                 debug!("no misbehavior detected");
                 Ok(None)
             }
-            Ok(Some(Divergence {
-                evidence,
-                challenging_block,
-            })) => {
+            Ok(Some((
+                witness,
+                Divergence {
+                    evidence,
+                    challenging_block,
+                },
+            ))) => {
                 warn!("misbehavior detected, reporting evidence to RPC witness node and primary chain");
                 debug!("evidence: {evidence:#?}");
                 debug!("challenging block: {challenging_block:#?}");
@@ -214,12 +246,15 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
                 warn!("waiting 5 seconds before reporting evidence to RPC witness node");
                 std::thread::sleep(Duration::from_secs(5));
 
-                match detector::report_evidence(
-                    self.io.rpc_client().clone(),
-                    evidence.against_primary,
-                ) {
-                    Ok(hash) => warn!("evidence reported to RPC witness node with hash: {hash}"),
-                    Err(e) => error!("failed to report evidence to RPC witness node: {e}"),
+                match detector::report_evidence(witness.rpc_client, evidence.against_primary) {
+                    Ok(hash) => warn!(
+                        "evidence reported to RPC witness node {} with hash: {hash}",
+                        witness.addr
+                    ),
+                    Err(e) => error!(
+                        "failed to report evidence to RPC witness node {}: {e}",
+                        witness.addr
+                    ),
                 }
 
                 let target_block = self.fetch(update_header.height())?;
@@ -236,21 +271,33 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
                 let (target_header, supporting_headers) =
                     self.adjust_headers(update_header.trusted_height, target_block, trace)?;
 
-                let evidence = MisbehaviourEvidence {
-                    misbehaviour: AnyMisbehaviour::Tendermint(TmMisbehaviour {
-                        client_id: update.client_id().clone(),
-                        header1: update_header.clone(),
-                        header2: TmHeader {
-                            signed_header: challenging_block.signed_header,
-                            validator_set: challenging_block.validators,
-                            trusted_height: target_header.trusted_height,
-                            trusted_validator_set: target_header.trusted_validator_set,
-                        },
-                    }),
-                    supporting_headers: supporting_headers
-                        .into_iter()
-                        .map(AnyHeader::Tendermint)
-                        .collect(),
+                let misbehaviour = TmMisbehaviour {
+                    client_id: update.client_id().clone(),
+                    header1: update_header.clone(),
+                    header2: TmHeader {
+                        signed_header: challenging_block.signed_header,
+                        validator_set: challenging_block.validators,
+                        trusted_height: target_header.trusted_height,
+                        trusted_validator_set: target_header.trusted_validator_set,
+                    },
+                };
+
+                let evidence = if is_wasm {
+                    MisbehaviourEvidence {
+                        misbehaviour: AnyMisbehaviour::Wasm(misbehaviour.into()),
+                        supporting_headers: supporting_headers
+                            .into_iter()
+                            .map(|header| AnyHeader::Wasm(header.into()))
+                            .collect(),
+                    }
+                } else {
+                    MisbehaviourEvidence {
+                        misbehaviour: AnyMisbehaviour::Tendermint(misbehaviour),
+                        supporting_headers: supporting_headers
+                            .into_iter()
+                            .map(AnyHeader::Tendermint)
+                            .collect(),
+                    }
                 };
 
                 Ok(Some(evidence))
@@ -263,18 +310,40 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
     }
 }
 
+fn rpc_client_for_addr(addr: &rpc::Url) -> Result<rpc::HttpClient, Error> {
+    rpc::HttpClient::builder(addr.clone().try_into().unwrap())
+        .user_agent(format!("hermes/{}", HERMES_VERSION))
+        .build()
+        .map_err(|e| Error::rpc(addr.clone(), e))
+}
+
 fn io_for_addr(
     addr: &rpc::Url,
     peer_id: PeerId,
     timeout: Option<Duration>,
 ) -> Result<ProdIo, Error> {
-    let rpc_client = rpc::HttpClient::builder(addr.clone().try_into().unwrap())
-        .user_agent(format!("hermes/{}", HERMES_VERSION))
-        .build()
-        .map_err(|e| Error::rpc(addr.clone(), e))?;
+    let rpc_client = rpc_client_for_addr(addr)?;
     Ok(ProdIo::new(peer_id, rpc_client, timeout))
 }
 
+/// The full node Hermes connects to comes first, followed by the
+/// additional witnesses, without duplicates.
+fn witnesses_for_addrs<'a>(
+    primary: &'a rpc::Url,
+    witnesses: impl IntoIterator<Item = &'a rpc::Url>,
+) -> Result<Vec<Witness>, Error> {
+    std::iter::once(primary)
+        .chain(witnesses)
+        .unique_by(|addr| addr.to_string())
+        .map(|addr| {
+            Ok(Witness {
+                addr: addr.clone(),
+                rpc_client: rpc_client_for_addr(addr)?,
+            })
+        })
+        .collect()
+}
+
 impl LightClient {
     pub fn from_penumbra_config(config: &PenumbraConfig, peer_id: PeerId) -> Result<Self, Error> {
         let live_io = io_for_addr(&config.rpc_addr, peer_id, Some(config.rpc_timeout))?;
@@ -301,12 +370,16 @@ impl LightClient {
         // updates after significant change in validator sets.
         let enable_verification = false;
 
+        let witnesses = witnesses_for_addrs(&config.rpc_addr, [])?;
+
         Ok(Self {
             chain_id: config.id.clone(),
             peer_id,
             io,
 
             enable_verification,
+            witnesses,
+            query_blocks: Mutex::new(Vec::new()),
         })
     }
 
@@ -338,36 +411,129 @@ impl LightClient {
         // updates after significant change in validator sets.
         let enable_verification = !config.trusted_node;
 
+        let witnesses = witnesses_for_addrs(&config.rpc_addr, &config.witnesses)?;
+
         Ok(Self {
             chain_id: config.id.clone(),
             peer_id,
             io,
 
             enable_verification,
+            witnesses,
+            query_blocks: Mutex::new(Vec::new()),
         })
     }
 
+    /// Verify the header at `target_height` for a verified query, starting from the highest
+    /// header at or below it among those verified so far for such queries, or from the trusted
+    /// header of `config` for the first one.
+    pub fn verify_for_query(
+        &self,
+        config: &VerifiedQueriesConfig,
+        target_height: ICSHeight,
+        options: Options,
+        now: Time,
+    ) -> Result<LightBlock, Error> {
+        crate::time!(
+            "light_client.tendermint.verify_for_query",
+            {
+                "src_chain": self.chain_id.to_string(),
+            }
+        );
+
+        let target_height = TMHeight::from(target_height);
+
+        let mut verified = self.query_blocks.lock().unwrap();
+
+        if verified.is_empty() {
+            verified.push(self.fetch_query_anchor(config)?);
+        }
+
+        if let Some(block) = verified.iter().find(|b| b.height() == target_height) {
+            return Ok(block.clone());
+        }
+
+        // Verification only goes backwards, through the hash chain,
+        // for a header below the trusted header of `config`
+        let trusted_block = verified
+            .iter()
+            .rev()
+            .find(|b| b.height() < target_height)
+            .unwrap_or(&verified[0])
+            .clone();
+
+        let mut store = MemoryStore::new();
+        store.insert(trusted_block.clone(), Status::Trusted);
+        let mut state = LightClientState::new(store);
+
+        let client = self.build_client(options, now);
+
+        trace!(trusted_height = %trusted_block.height(), %target_height, "verifying header for query");
+
+        let target = client
+            .verify_to_target(target_height, &mut state)
+            .map_err(|e| Error::light_client_verification(self.chain_id.to_string(), e))?;
+
+        let index = verified.partition_point(|b| b.height() < target.height());
+        verified.insert(index, target.clone());
+
+        if verified.len() > MAX_QUERY_BLOCKS {
+            verified.remove(0);
+        }
+
+        Ok(target)
+    }
+
+    /// Fetch the trusted header of `config`, and check that its hash matches the configured one.
+    fn fetch_query_anchor(&self, config: &VerifiedQueriesConfig) -> Result<LightBlock, Error> {
+        let height = TMHeight::try_from(config.trusted_height)
+            .map_err(|_| Error::invalid_height_no_source())?;
+
+        let block = self.fetch_light_block(AtHeight::At(height))?;
+        let hash = block.signed_header.header.hash();
+
+        if hash != config.trusted_hash {
+            return Err(Error::untrusted_query_anchor(
+                self.chain_id.clone(),
+                config.trusted_height,
+                config.trusted_hash,
+                hash,
+            ));
+        }
+
+        Ok(block)
+    }
+
     fn prepare_client(
         &self,
         client_state: &AnyClientState,
         now: Time,
     ) -> Result<TmLightClient, Error> {
-        let clock = components::clock::FixedClock::new(now);
-        let verifier = ProdVerifier::default();
-        let scheduler = components::scheduler::basic_bisecting_schedule;
-
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok::<_, Error>(client_state),
+            AnyClientState::Wasm(client_state) => Ok(&client_state.tendermint),
+            AnyClientState::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
         }?;
 
-        Ok(TmLightClient::new(
+        Ok(self.build_client(client_state.as_light_client_options(), now))
+    }
+
+    fn build_client(&self, options: Options, now: Time) -> TmLightClient {
+        let clock = components::clock::FixedClock::new(now);
+        let verifier = ProdVerifier::default();
+        let scheduler = components::scheduler::basic_bisecting_schedule;
+
+        TmLightClient::new(
             self.peer_id,
-            client_state.as_light_client_options(),
+            options,
             clock,
             scheduler,
             verifier,
             self.io.clone(),
-        ))
+        )
     }
 
     fn prepare_state(&self, trusted_height: ICSHeight) -> Result<LightClientState, Error> {
@@ -450,3 +616,50 @@ impl LightClient {
         Ok((target_header, supporting_headers))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(addr: &str) -> rpc::Url {
+        addr.parse().unwrap()
+    }
+
+    fn addrs(witnesses: &[Witness]) -> Vec<String> {
+        witnesses.iter().map(|w| w.addr.to_string()).collect()
+    }
+
+    #[test]
+    fn witnesses_start_with_primary() {
+        let primary = url("http://primary:26657");
+        let others = [url("http://witness-1:26657"), url("http://witness-2:26657")];
+
+        let witnesses = witnesses_for_addrs(&primary, &others).unwrap();
+
+        assert_eq!(
+            addrs(&witnesses),
+            vec![
+                "http://primary:26657/",
+                "http://witness-1:26657/",
+                "http://witness-2:26657/"
+            ]
+        );
+    }
+
+    #[test]
+    fn witnesses_are_deduplicated() {
+        let primary = url("http://primary:26657");
+        let others = [
+            url("http://witness-1:26657"),
+            url("http://primary:26657"),
+            url("http://witness-1:26657"),
+        ];
+
+        let witnesses = witnesses_for_addrs(&primary, &others).unwrap();
+
+        assert_eq!(
+            addrs(&witnesses),
+            vec!["http://primary:26657/", "http://witness-1:26657/"]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use core::fmt::Display;
+
 use tracing::{error, info};
 
 use tendermint::{
@@ -13,60 +15,102 @@ use tendermint_light_client::{
     verifier::ProdVerifier,
 };
 use tendermint_light_client_detector::{detect_divergence, Divergence, Provider};
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::{Client, HttpClient, Url};
 
 use ibc_relayer_types::clients::ics07_tendermint::client_state::ClientState;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::{error::Error, util::block_on};
 
 type Hasher = tendermint::crypto::default::Sha256;
 
+/// A full node against which the headers of client updates are cross-checked
+#[derive(Clone)]
+pub struct Witness {
+    pub addr: Url,
+    pub rpc_client: HttpClient,
+}
+
+/// Cross-check the given target block against every witness in turn, and return
+/// the first witness whose view of the chain diverges from it, if any.
+///
+/// A witness which cannot be queried does not prevent the others from being checked.
 pub fn detect(
     peer_id: PeerId,
-    rpc_client: HttpClient,
+    witnesses: &[Witness],
     target_block: LightBlock,
     trusted_block: LightBlock,
     client_state: &ClientState,
     now: Time,
-) -> Result<Option<Divergence>, Error> {
+) -> Result<Option<(Witness, Divergence)>, Error> {
     let primary_trace = vec![trusted_block.clone(), target_block];
     let options = client_state.as_light_client_options();
-    let mut provider = make_provider(peer_id, rpc_client, client_state, trusted_block, now)?;
-
-    let divergence = block_on(detect_divergence::<Hasher>(
-        None,
-        &mut provider,
-        primary_trace,
-        options.clock_drift,
-        options.trusting_period,
-    ));
-
-    match divergence {
-        Ok(None) => {
-            info!(
-                "No evidence of misbehavior detected for chain {}",
-                client_state.chain_id
-            );
-
-            Ok(None)
-        }
-        Ok(Some(divergence)) => {
-            info!(
-                "Evidence of misbehavior detected for chain {}",
-                client_state.chain_id
-            );
 
-            Ok(Some(divergence))
-        }
-        Err(e) => {
-            error!(
-                "Error while detecting misbehavior for chain {}: {}",
-                client_state.chain_id, e
-            );
+    let providers = witnesses
+        .iter()
+        .map(|witness| {
+            make_provider(
+                peer_id,
+                witness.rpc_client.clone(),
+                client_state,
+                trusted_block.clone(),
+                now,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let divergence = first_divergence(
+        &client_state.chain_id,
+        witnesses.iter().zip(providers),
+        |mut provider| {
+            block_on(detect_divergence::<Hasher>(
+                None,
+                &mut provider,
+                primary_trace.clone(),
+                options.clock_drift,
+                options.trusting_period,
+            ))
+        },
+    );
 
-            Ok(None)
+    Ok(divergence)
+}
+
+/// Run the given check against every witness in turn, and return the first witness
+/// for which a divergence is found, along with that divergence.
+///
+/// A witness whose check fails does not prevent the others from being checked.
+fn first_divergence<'a, P, D, E: Display>(
+    chain_id: &ChainId,
+    witnesses: impl IntoIterator<Item = (&'a Witness, P)>,
+    mut check: impl FnMut(P) -> Result<Option<D>, E>,
+) -> Option<(Witness, D)> {
+    for (witness, provider) in witnesses {
+        match check(provider) {
+            Ok(None) => {
+                info!(
+                    "No evidence of misbehavior detected for chain {} by witness {}",
+                    chain_id, witness.addr
+                );
+            }
+            Ok(Some(divergence)) => {
+                info!(
+                    "Evidence of misbehavior detected for chain {} by witness {}",
+                    chain_id, witness.addr
+                );
+
+                return Some((witness.clone(), divergence));
+            }
+            Err(e) => {
+                error!(
+                    "Error while detecting misbehavior for chain {} with witness {}: {}",
+                    chain_id, witness.addr, e
+                );
+            }
         }
     }
+
+    None
 }
 
 fn make_provider(
@@ -110,3 +154,74 @@ pub fn report_evidence(
         .map(|response| response.hash)
         .map_err(|e| Error::rpc_response(e.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn witness(addr: &str) -> Witness {
+        let addr: Url = addr.parse().unwrap();
+
+        Witness {
+            rpc_client: HttpClient::new(addr.clone()).unwrap(),
+            addr,
+        }
+    }
+
+    #[test]
+    fn detects_divergent_second_witness() {
+        let chain_id = ChainId::from_string("chain-0");
+        let witnesses = [
+            witness("http://primary:26657"),
+            witness("http://other:26657"),
+        ];
+
+        // The primary agrees with itself, while the second witness diverges
+        let outcomes: [Result<Option<&str>, String>; 2] = [Ok(None), Ok(Some("divergence"))];
+
+        let (witness, divergence) =
+            first_divergence(&chain_id, witnesses.iter().zip(outcomes), |outcome| outcome)
+                .expect("the divergence of the second witness is detected");
+
+        assert_eq!(witness.addr, witnesses[1].addr);
+        assert_eq!(divergence, "divergence");
+    }
+
+    #[test]
+    fn failing_witness_does_not_stop_detection() {
+        let chain_id = ChainId::from_string("chain-0");
+        let witnesses = [
+            witness("http://primary:26657"),
+            witness("http://down:26657"),
+            witness("http://other:26657"),
+        ];
+
+        let outcomes: [Result<Option<&str>, String>; 3] = [
+            Ok(None),
+            Err("connection refused".to_string()),
+            Ok(Some("divergence")),
+        ];
+
+        let (witness, _) =
+            first_divergence(&chain_id, witnesses.iter().zip(outcomes), |outcome| outcome)
+                .expect("the divergence of the last witness is detected");
+
+        assert_eq!(witness.addr, witnesses[2].addr);
+    }
+
+    #[test]
+    fn no_divergence_when_all_witnesses_agree() {
+        let chain_id = ChainId::from_string("chain-0");
+        let witnesses = [
+            witness("http://primary:26657"),
+            witness("http://other:26657"),
+        ];
+
+        let outcomes: [Result<Option<&str>, String>; 2] = [Ok(None), Ok(None)];
+
+        assert!(
+            first_divergence(&chain_id, witnesses.iter().zip(outcomes), |outcome| outcome)
+                .is_none()
+        );
+    }
+}
@@ -20,6 +20,7 @@ pub mod cli;
 pub mod error;
 pub mod operational_data;
 pub mod packet_events;
+pub mod verify;
 
 mod pending;
 mod relay_path;
@@ -32,7 +33,7 @@ use tx_hashes::TxHashes;
 // Re-export the telemetries summary
 pub use relay_summary::RelaySummary;
 
-pub use relay_path::{RelayPath, Resubmit};
+pub use relay_path::{PacketRelayFees, RelayPath, Resubmit};
 
 #[derive(Clone, Debug)]
 pub struct LinkParameters {
@@ -41,6 +42,8 @@ pub struct LinkParameters {
     pub max_memo_size: Ics20FieldSizeLimit,
     pub max_receiver_size: Ics20FieldSizeLimit,
     pub exclude_src_sequences: Vec<Sequence>,
+    pub ics20_filter: Ics20Filter,
+    pub verify_proofs: bool,
 }
 
 pub struct Link<ChainA: ChainHandle, ChainB: ChainHandle> {
@@ -217,6 +217,122 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
     }
 
+    /// Implements the `packet-timeout` CLI
+    pub fn relay_timeout_packet_messages(
+        &self,
+        sequence_filter: Vec<RangeInclusive<Sequence>>,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_timeout_packet_messages",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+        )
+        .entered();
+
+        self.relay_timeout_messages(
+            sequence_filter,
+            false,
+            TrackingId::new_static("packet-timeout"),
+        )
+    }
+
+    /// Implements the `packet-timeout-on-close` CLI
+    pub fn relay_timeout_on_close_packet_messages(
+        &self,
+        sequence_filter: Vec<RangeInclusive<Sequence>>,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_timeout_on_close_packet_messages",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+        )
+        .entered();
+
+        self.relay_timeout_messages(
+            sequence_filter,
+            true,
+            TrackingId::new_static("packet-timeout-on-close"),
+        )
+    }
+
+    /// Times out the packets which have not been received on the destination chain,
+    /// with `MsgTimeoutOnClose` messages if `on_close` is set, or with `MsgTimeout`
+    /// messages otherwise.
+    fn relay_timeout_messages(
+        &self,
+        sequence_filter: Vec<RangeInclusive<Sequence>>,
+        on_close: bool,
+        tracking_id: TrackingId,
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        // Find the sequence numbers of unreceived packets
+        let (mut sequences, src_response_height) = unreceived_packets(
+            self.a_to_b.dst_chain(),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+            Paginate::All,
+        )
+        .map_err(LinkError::supervisor)?;
+
+        if sequences.is_empty() {
+            return Ok(vec![]);
+        }
+
+        if !sequence_filter.is_empty() {
+            info!("filtering unreceived packets by given sequence ranges");
+            sequences.retain(|seq| sequence_filter.iter().any(|range| range.contains(seq)));
+        }
+
+        info!(
+            "{} unreceived packets found: {} ",
+            sequences.len(),
+            PrettySlice(&sequences)
+        );
+
+        let chunk_size = self
+            .a_to_b
+            .src_chain()
+            .config()
+            .map_or(50, |cfg| cfg.query_packets_chunk_size());
+
+        let event_chunks = query_packet_events_with(
+            &sequences,
+            Qualified::SmallerEqual(src_response_height),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+            chunk_size,
+            query_send_packet_events,
+        );
+
+        let mut results = vec![];
+
+        for event_chunk in event_chunks {
+            let tracked_events = TrackedEvents::new(event_chunk, tracking_id);
+
+            let Some(odata) = self
+                .a_to_b
+                .build_timeout_operational_data(tracked_events, on_close)?
+            else {
+                continue;
+            };
+
+            let odata = wait_for_conn_delay(
+                odata,
+                &|| self.a_to_b.src_time_latest(),
+                &|| self.a_to_b.src_max_block_time(),
+                &|| self.a_to_b.src_latest_height(),
+            )?;
+
+            self.a_to_b
+                .relay_and_accumulate_results(vec![odata], &mut results)?;
+        }
+
+        Ok(results)
+    }
+
     fn relay_packet_messages<QueryFn>(
         &self,
         sequences: Vec<Sequence>,
@@ -32,6 +32,18 @@ define_error! {
                 format!("failed to construct packet proofs for chain {0}", e.chain_id)
             },
 
+        InvalidProof
+            {
+                chain_id: ChainId,
+                path: String,
+                height: Height,
+                reason: String,
+            }
+            |e| {
+                format!("proof of `{}` at height {} returned by the full node of chain {} failed local verification: {}",
+                    e.path, e.height, e.chain_id, e.reason)
+            },
+
         Query
             { chain_id: ChainId }
             [ Error ]
@@ -39,6 +51,13 @@ define_error! {
                 format!("failed during query to chain id {0}", e.chain_id)
             },
 
+        FeeEstimation
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed to estimate the fees of relaying a packet on chain {}", e.chain_id)
+            },
+
         Channel
             [ ChannelError ]
             |_| { "channel error" },
@@ -117,6 +136,16 @@ define_error! {
                     e.channel_id, e.chain_id)
             },
 
+        ChannelNotClosed
+            {
+                channel_id: ChannelId,
+                chain_id: ChainId,
+            }
+            |e| {
+                format!("channel {} on chain {} is not closed, packets cannot be timed out on close",
+                    e.channel_id, e.chain_id)
+            },
+
         ChannelNotOpened
             {
                 channel_id: ChannelId,
@@ -16,6 +16,7 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::event::IbcEventWithHeight;
 use crate::link::error::LinkError;
+use crate::link::verify::{self, PendingProof};
 use crate::link::RelayPath;
 
 /// The chain that the events associated with a piece of [`OperationalData`] are bound for.
@@ -68,6 +69,14 @@ impl TrackedEvents {
     }
 }
 
+/// A message built from the proofs of a chain, alongside these
+/// proofs if they must be verified before the message is submitted.
+#[derive(Clone)]
+pub struct ProvenMsg {
+    pub msg: Any,
+    pub proofs: Vec<PendingProof>,
+}
+
 /// A packet message that is prepared for sending
 /// to a chain, but has not been sent yet.
 ///
@@ -77,6 +86,19 @@ impl TrackedEvents {
 pub struct TransitMessage {
     pub event_with_height: IbcEventWithHeight,
     pub msg: Any,
+    /// The proofs included in the message, which are verified
+    /// when the batch of messages is assembled
+    pub proofs: Vec<PendingProof>,
+}
+
+impl TransitMessage {
+    pub fn new(event_with_height: IbcEventWithHeight, msg: ProvenMsg) -> Self {
+        Self {
+            event_with_height,
+            msg: msg.msg,
+            proofs: msg.proofs,
+        }
+    }
 }
 
 /// Holds all the necessary information for handling a batch of in-transit messages. This includes
@@ -208,6 +230,21 @@ impl OperationalData {
             vec![]
         };
 
+        let proofs = self.batch.iter().flat_map(|gm| &gm.proofs);
+
+        match self.target {
+            OperationalDataTarget::Source => verify::verify_proofs(
+                &relay_path.restore_src_client(),
+                proofs,
+                &client_update_msgs,
+            )?,
+            OperationalDataTarget::Destination => verify::verify_proofs(
+                &relay_path.restore_dst_client(),
+                proofs,
+                &client_update_msgs,
+            )?,
+        }
+
         let msgs = client_update_msgs
             .into_iter()
             .chain(self.batch.iter().map(|gm| gm.msg.clone()))
@@ -1,7 +1,8 @@
 use core::time::Duration;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
-use tracing::{debug, error, trace, trace_span};
+use tendermint::Hash as TxHash;
+use tracing::{debug, error, info, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -9,7 +10,9 @@ use ibc_relayer_types::events::IbcEvent;
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
+use crate::lifecycle;
 use crate::link::{error::LinkError, RelayPath};
+use crate::state::{self, InFlightTx};
 use crate::telemetry;
 use crate::util::queue::Queue;
 use crate::{
@@ -48,6 +51,10 @@ pub struct PendingTxs<Chain> {
     pub port_id: PortId,
     pub counterparty_chain_id: ChainId,
     pub pending_queue: Queue<PendingData>,
+
+    /// Transactions submitted before Hermes was restarted,
+    /// which had not been confirmed yet
+    pub restored_txs: Queue<InFlightTx>,
 }
 
 impl<Chain> PendingTxs<Chain> {
@@ -63,6 +70,7 @@ impl<Chain> PendingTxs<Chain> {
             port_id,
             counterparty_chain_id,
             pending_queue: Queue::new(),
+            restored_txs: Queue::new(),
         }
     }
 }
@@ -72,6 +80,85 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
+    /// Load the transactions submitted on this channel before Hermes was restarted
+    /// which had not been confirmed yet, if the relay state store is enabled.
+    pub fn restore_in_flight_txs(&self) {
+        let Some(store) = state::global() else {
+            return;
+        };
+
+        match store.in_flight_txs(&self.chain_id(), &self.port_id, &self.channel_id) {
+            Ok(txs) => {
+                if !txs.is_empty() {
+                    info!(
+                        chain = %self.chain_id(),
+                        port = %self.port_id,
+                        channel = %self.channel_id,
+                        "restored {} transactions in flight before restart",
+                        txs.len()
+                    );
+                }
+
+                for tx in txs {
+                    self.restored_txs.push_back(tx);
+                }
+            }
+            Err(e) => warn!("failed to restore the transactions in flight before restart: {e}"),
+        }
+    }
+
+    /// Whether some of the transactions in flight before Hermes was restarted may
+    /// still be committed. Forgets about the ones which have been committed or
+    /// have timed out since.
+    pub fn has_restored_txs_in_flight(&self) -> bool {
+        for _ in 0..self.restored_txs.len() {
+            let Some(tx) = self.restored_txs.pop_front() else {
+                break;
+            };
+
+            let committed = self
+                .check_tx_events(&TxHashes(vec![tx.hash]))
+                .map(|events| events.is_some());
+
+            match committed {
+                Ok(false) if !tx.has_timed_out(TIMEOUT) => self.restored_txs.push_back(tx),
+                Ok(_) => self.forget_in_flight_txs(&[tx.hash]),
+                Err(e) => {
+                    error!("error querying for restored tx hash {}: {}", tx.hash, e);
+                    self.restored_txs.push_back(tx);
+                }
+            }
+        }
+
+        !self.restored_txs.is_empty()
+    }
+
+    fn persist_in_flight_txs(&self, tx_hashes: &TxHashes) {
+        if tx_hashes.0.is_empty() {
+            return;
+        }
+
+        if let Some(store) = state::global() {
+            store
+                .insert_in_flight_txs(
+                    &self.chain_id(),
+                    &self.port_id,
+                    &self.channel_id,
+                    &tx_hashes.0,
+                    SystemTime::now(),
+                )
+                .unwrap_or_else(|e| warn!("failed to record in flight transactions: {e}"));
+        }
+    }
+
+    fn forget_in_flight_txs(&self, tx_hashes: &[TxHash]) {
+        if let Some(store) = state::global() {
+            store
+                .remove_in_flight_txs(&self.chain_id(), tx_hashes)
+                .unwrap_or_else(|e| warn!("failed to forget in flight transactions: {e}"));
+        }
+    }
+
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
         let mut tx_hashes = Vec::new();
@@ -115,9 +202,12 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             }
         }
 
+        let tx_hashes = TxHashes(tx_hashes);
+        self.persist_in_flight_txs(&tx_hashes);
+
         let u = PendingData {
             original_od: od,
-            tx_hashes: TxHashes(tx_hashes),
+            tx_hashes,
             submit_time: Instant::now(),
             error_events,
         };
@@ -190,6 +280,8 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         // relayer to resubmit the transaction to the chain again.
                         error!("timed out while confirming {}", tx_hashes);
 
+                        self.forget_in_flight_txs(&tx_hashes.0);
+
                         match resubmit {
                             Some(f) => {
                                 // The pending tx needs to be resubmitted. This involves replacing the tx's
@@ -250,6 +342,15 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    self.forget_in_flight_txs(&tx_hashes.0);
+
+                    lifecycle::global().relayed(
+                        &self.chain.id(),
+                        &self.counterparty_chain_id,
+                        &events,
+                        &tx_hashes.0,
+                    );
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
@@ -5,10 +5,12 @@ use std::ops::Sub;
 use std::time::{Duration, Instant};
 
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
 use itertools::Itertools;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::transfer::packet::RawTransferPacketData;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Ordering, State as ChannelState,
@@ -19,8 +21,13 @@ use ibc_relayer_types::core::ics04_channel::msgs::{
     recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
 };
 use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+use ibc_relayer_types::core::ics04_channel::version::Version;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, CommitmentsPath, ReceiptsPath, SeqRecvsPath,
+};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::tx_msg::Msg;
@@ -45,6 +52,7 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::filter::{Ics20Filter, Ics20FilterReason};
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::ics20_field_size_limit::ValidationResult;
 use crate::event::source::EventBatch;
@@ -52,7 +60,7 @@ use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
 use crate::link::error::{self, LinkError};
 use crate::link::operational_data::{
-    OperationalData, OperationalDataTarget, TrackedEvents, TransitMessage,
+    OperationalData, OperationalDataTarget, ProvenMsg, TrackedEvents, TransitMessage,
 };
 use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
@@ -60,6 +68,7 @@ use crate::link::packet_events::query_write_ack_events;
 use crate::link::pending::PendingTxs;
 use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
+use crate::link::verify::{self, PendingProof};
 use crate::link::LinkParameters;
 use crate::link::{pending, relay_sender};
 use crate::path::PathIdentifiers;
@@ -67,6 +76,7 @@ use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::PrettyEvents;
 use crate::util::queue::Queue;
+use crate::worker::wallet::is_relaying_paused;
 
 const MAX_RETRIES: usize = 5;
 
@@ -91,6 +101,15 @@ impl Resubmit {
     }
 }
 
+/// The estimated fees of relaying a packet on its own
+#[derive(Clone, Debug, PartialEq)]
+pub struct PacketRelayFees {
+    /// Whether the packet is timed out on the source chain,
+    /// rather than received on the destination chain
+    pub timeout: bool,
+    pub fees: Vec<RawCoin>,
+}
+
 pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     channel: Channel<ChainA, ChainB>,
 
@@ -118,6 +137,10 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     pub max_memo_size: Ics20FieldSizeLimit,
     pub max_receiver_size: Ics20FieldSizeLimit,
     pub exclude_src_sequences: Vec<Sequence>,
+    pub ics20_filter: Ics20Filter,
+
+    // Toggle for the local verification of the proofs included in packet messages.
+    verify_proofs: bool,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -152,6 +175,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             counterparty_channel_id: src_channel_id.clone(),
         };
 
+        let pending_txs_src = PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id);
+        let pending_txs_dst = PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id);
+
+        if with_tx_confirmation {
+            pending_txs_src.restore_in_flight_txs();
+            pending_txs_dst.restore_in_flight_txs();
+        }
+
         Ok(Self {
             channel,
 
@@ -161,13 +192,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             dst_operational_data: Queue::new(),
 
             confirm_txes: with_tx_confirmation,
-            pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
-            pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+            pending_txs_src,
+            pending_txs_dst,
 
             max_memo_size: link_parameters.max_memo_size,
             max_receiver_size: link_parameters.max_receiver_size,
 
             exclude_src_sequences: link_parameters.exclude_src_sequences,
+            ics20_filter: link_parameters.ics20_filter,
+
+            verify_proofs: link_parameters.verify_proofs,
         })
     }
 
@@ -346,7 +380,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     fn build_chan_close_confirm_from_event(
         &self,
         event: &IbcEventWithHeight,
-    ) -> Result<Option<Any>, LinkError> {
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         // Build the `MsgChannelCloseConfirm` only from `Timeout` or `CloseInitChannel` event types
         if event.event.event_type() != IbcEventType::Timeout
             && event.event.event_type() != IbcEventType::CloseInitChannel
@@ -367,6 +401,21 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .build_channel_proofs(self.src_port_id(), src_channel_id, event.height)
             .map_err(|e| LinkError::channel(ChannelError::channel_proof(e)))?;
 
+        let mut pending_proofs = vec![];
+
+        if self.verify_proofs {
+            let path = ChannelEndsPath(self.src_port_id().clone(), src_channel_id.clone());
+
+            let channel = self.src_channel(QueryHeight::Specific(event.height))?;
+
+            pending_proofs.push(PendingProof::new(
+                proofs.object_proof().clone(),
+                proofs.height(),
+                path,
+                Some(channel.encode_vec()),
+            ));
+        }
+
         let counterparty_upgrade_sequence = self.src_channel(QueryHeight::Latest)?.upgrade_sequence;
 
         // Build the domain type message
@@ -378,7 +427,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             counterparty_upgrade_sequence,
         };
 
-        Ok(Some(new_msg.to_any()))
+        Ok(Some(ProvenMsg {
+            msg: new_msg.to_any(),
+            proofs: pending_proofs,
+        }))
     }
 
     /// Determines if the events received are relevant and should be processed.
@@ -434,6 +486,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         height: Option<Height>,
         clear_limit: usize,
+        retain_send_packets: &dyn Fn(&mut Vec<IbcEventWithHeight>),
     ) -> Result<(), LinkError> {
         let _span = span!(Level::ERROR, "relay_pending_packets", ?height).entered();
 
@@ -449,6 +502,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 chunk_size,
                 clear_limit,
                 tracking_id,
+                retain_send_packets,
             );
 
             let cleared_ack =
@@ -472,6 +526,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         height: Option<Height>,
         clear_limit: usize,
+    ) -> Result<(), LinkError> {
+        self.schedule_packet_clearing_with(height, clear_limit, |_| {})
+    }
+
+    /// Same as [`RelayPath::schedule_packet_clearing`], but only relays the packets whose
+    /// `SendPacket` events are kept by `retain_send_packets`, which is given each chunk of
+    /// the events of the packets to clear.
+    pub fn schedule_packet_clearing_with(
+        &self,
+        height: Option<Height>,
+        clear_limit: usize,
+        retain_send_packets: impl Fn(&mut Vec<IbcEventWithHeight>),
     ) -> Result<(), LinkError> {
         let _span = span!(Level::ERROR, "schedule_packet_clearing", ?height).entered();
 
@@ -479,7 +545,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .map(|h| h.decrement().map_err(|e| LinkError::decrement_height(h, e)))
             .transpose()?;
 
-        self.relay_pending_packets(clear_height, clear_limit)?;
+        self.relay_pending_packets(clear_height, clear_limit, &retain_send_packets)?;
 
         debug!(height = ?clear_height, "done relaying pending packets at clear height");
 
@@ -577,17 +643,37 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             self.channel.connection_delay,
         );
 
+        // The encoding of the data of ICS-20 packets depends on the version of the channel,
+        // which is therefore only queried if there are packets to relay
+        let src_version = if input.iter().any(|ev| packet_to_relay(&ev.event).is_some()) {
+            Some(self.src_channel(QueryHeight::Latest)?.version)
+        } else {
+            None
+        };
+
         for event_with_height in input {
             trace!(event = %event_with_height, "processing event");
 
-            if let Some(packet) = event_with_height.event.packet() {
-                // If the event is a ICS-04 packet event, and the packet contains ICS-20
-                // packet data, check that the ICS-20 fields are within the configured limits.
-                if !check_ics20_fields_size(
+            if let (Some(packet), Some(src_version)) =
+                (packet_to_relay(&event_with_height.event), &src_version)
+            {
+                // If the event is a packet event to relay, and the packet contains ICS-20
+                // packet data, check that the ICS-20 fields are within the configured limits,
+                // and that the content of the packet is allowed by the ICS-20 packet filter.
+                let filtered_reason = if !check_ics20_fields_size(
                     &packet.data,
+                    src_version,
                     self.max_memo_size,
                     self.max_receiver_size,
                 ) {
+                    Some("ics20_field_size")
+                } else {
+                    check_ics20_content(&packet.data, src_version, &self.ics20_filter)
+                        .err()
+                        .map(|reason| reason.as_str())
+                };
+
+                if let Some(reason) = filtered_reason {
                     telemetry!(
                         filtered_packets,
                         &self.src_chain().id(),
@@ -596,6 +682,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         &packet.destination_channel,
                         &packet.source_port,
                         &packet.destination_port,
+                        reason,
                         1
                     );
                     continue;
@@ -664,12 +751,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
             // Collect messages to be sent to the destination chain (e.g., RecvPacket)
             if let Some(msg) = dst_msg {
-                trace!(%msg.type_url, event = %event_with_height, "collected event");
+                trace!(msg.type_url = %msg.msg.type_url, event = %event_with_height, "collected event");
 
-                dst_od.batch.push(TransitMessage {
-                    event_with_height: event_with_height.clone(),
-                    msg,
-                });
+                dst_od
+                    .batch
+                    .push(TransitMessage::new(event_with_height.clone(), msg));
             }
 
             // Collect timeout messages, to be sent to the source chain
@@ -677,12 +763,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 // For Ordered channels a single timeout event should be sent as this closes the channel.
                 // Otherwise a multi message transaction will fail.
                 if self.unordered_channel() || src_od.batch.is_empty() {
-                    trace!(%msg.type_url, event = %event_with_height, "collected event");
+                    trace!(msg.type_url = %msg.msg.type_url, event = %event_with_height, "collected event");
 
-                    src_od.batch.push(TransitMessage {
-                        event_with_height: event_with_height.clone(),
-                        msg,
-                    });
+                    src_od
+                        .batch
+                        .push(TransitMessage::new(event_with_height.clone(), msg));
                 }
             }
         }
@@ -1148,6 +1233,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     /// chain where to query for packet data. If `None`, the latest available
     /// height on the source chain is used.
     ///
+    /// Only the packets whose `SendPacket` events are kept by `retain_send_packets`
+    /// are relayed.
+    ///
     /// Blocks until _all_ outstanding messages have been scheduled.
     pub fn schedule_recv_packet_and_timeout_msgs(
         &self,
@@ -1155,6 +1243,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         chunk_size: usize,
         clear_limit: usize,
         tracking_id: TrackingId,
+        retain_send_packets: &dyn Fn(&mut Vec<IbcEventWithHeight>),
     ) -> Result<(), LinkError> {
         let _span = span!(
             Level::ERROR,
@@ -1197,7 +1286,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         // Chunk-up the list of sequence nrs. into smaller parts,
         // and schedule operational data incrementally across each chunk.
-        for events_chunk in query_packet_events_with(
+        for mut events_chunk in query_packet_events_with(
             sequences,
             Qualified::SmallerEqual(query_height),
             self.src_chain(),
@@ -1205,6 +1294,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             chunk_size,
             query_send_packet_events,
         ) {
+            retain_send_packets(&mut events_chunk);
+
             // Update telemetry info
             telemetry!({
                 for event_with_height in events_chunk.iter() {
@@ -1288,7 +1379,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
-    fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
+    fn build_recv_packet(
+        &self,
+        packet: &Packet,
+        height: Height,
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         let proofs = self
             .src_chain()
             .build_packet_proofs(
@@ -1300,18 +1395,38 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
+        let mut pending_proofs = vec![];
+
+        if self.verify_proofs {
+            let path = CommitmentsPath {
+                port_id: packet.source_port.clone(),
+                channel_id: packet.source_channel.clone(),
+                sequence: packet.sequence,
+            };
+
+            pending_proofs.push(PendingProof::new(
+                proofs.object_proof().clone(),
+                proofs.height(),
+                path,
+                Some(verify::packet_commitment(packet)),
+            ));
+        }
+
         let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
 
         trace!(packet = %packet, height = %proofs.height(), "built recv_packet msg");
 
-        Ok(Some(msg.to_any()))
+        Ok(Some(ProvenMsg {
+            msg: msg.to_any(),
+            proofs: pending_proofs,
+        }))
     }
 
     fn build_ack_from_recv_event(
         &self,
         event: &WriteAcknowledgement,
         height: Height,
-    ) -> Result<Option<Any>, LinkError> {
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         let packet = event.packet.clone();
 
         let proofs = self
@@ -1325,6 +1440,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
+        let mut pending_proofs = vec![];
+
+        if self.verify_proofs {
+            let path = AcksPath {
+                port_id: packet.destination_port.clone(),
+                channel_id: packet.destination_channel.clone(),
+                sequence: packet.sequence,
+            };
+
+            pending_proofs.push(PendingProof::new(
+                proofs.object_proof().clone(),
+                proofs.height(),
+                path,
+                Some(verify::ack_commitment(&event.ack)),
+            ));
+        }
+
         let msg = MsgAcknowledgement::new(
             packet,
             event.ack.clone().into(),
@@ -1334,14 +1466,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built acknowledgment msg");
 
-        Ok(Some(msg.to_any()))
+        Ok(Some(ProvenMsg {
+            msg: msg.to_any(),
+            proofs: pending_proofs,
+        }))
     }
 
     fn build_timeout_packet(
         &self,
         packet: &Packet,
         height: Height,
-    ) -> Result<Option<Any>, LinkError> {
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
         trace!(%packet, %height, "build timeout for channel");
@@ -1375,6 +1510,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
 
+        let pending_proofs = if self.verify_proofs {
+            self.timeout_proofs(packet, next_sequence_received, &proofs, height)?
+        } else {
+            vec![]
+        };
+
         let msg = MsgTimeout::new(
             packet.clone(),
             next_sequence_received,
@@ -1384,14 +1525,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout msg");
 
-        Ok(Some(msg.to_any()))
+        Ok(Some(ProvenMsg {
+            msg: msg.to_any(),
+            proofs: pending_proofs,
+        }))
     }
 
     fn build_timeout_on_close_packet(
         &self,
         packet: &Packet,
         height: Height,
-    ) -> Result<Option<Any>, LinkError> {
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
         trace!(%packet, %height, "build timeout on close for channel");
@@ -1424,6 +1568,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
 
+        let pending_proofs = if self.verify_proofs {
+            self.timeout_proofs(packet, next_sequence_received, &proofs, height)?
+        } else {
+            vec![]
+        };
+
         let counterparty_upgrade_sequence = self.src_channel(QueryHeight::Latest)?.upgrade_sequence;
 
         let msg = MsgTimeoutOnClose::new(
@@ -1436,14 +1586,73 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout on close msg");
 
-        Ok(Some(msg.to_any()))
+        Ok(Some(ProvenMsg {
+            msg: msg.to_any(),
+            proofs: pending_proofs,
+        }))
+    }
+
+    /// The proofs built by the destination chain at `height` for a timeout, or a timeout on
+    /// close, of the given packet: that it did not receive the packet, or, on an ordered channel,
+    /// that the next sequence it expects is `next_sequence_received`, and that the channel is
+    /// closed if the proofs include one of the channel.
+    fn timeout_proofs(
+        &self,
+        packet: &Packet,
+        next_sequence_received: Sequence,
+        proofs: &Proofs,
+        height: Height,
+    ) -> Result<Vec<PendingProof>, LinkError> {
+        let (path, value) = if self.ordered_channel() {
+            let path = SeqRecvsPath(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            );
+
+            let value = u64::from(next_sequence_received).to_be_bytes().to_vec();
+
+            (path.to_string(), Some(value))
+        } else {
+            let path = ReceiptsPath {
+                port_id: packet.destination_port.clone(),
+                channel_id: packet.destination_channel.clone(),
+                sequence: packet.sequence,
+            };
+
+            (path.to_string(), None)
+        };
+
+        let mut pending_proofs = vec![PendingProof::new(
+            proofs.object_proof().clone(),
+            proofs.height(),
+            path,
+            value,
+        )];
+
+        if let Some(channel_proof) = proofs.other_proof() {
+            let path = ChannelEndsPath(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            );
+
+            let channel = self.dst_channel(QueryHeight::Specific(height))?;
+
+            pending_proofs.push(PendingProof::new(
+                channel_proof.clone(),
+                proofs.height(),
+                path,
+                Some(channel.encode_vec()),
+            ));
+        }
+
+        Ok(pending_proofs)
     }
 
     fn build_timeout_from_send_packet_event(
         &self,
         event: &SendPacket,
         dst_info: &ChainStatus,
-    ) -> Result<Option<Any>, LinkError> {
+    ) -> Result<Option<ProvenMsg>, LinkError> {
         let packet = event.packet.clone();
 
         if self
@@ -1458,12 +1667,74 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Builds the operational data timing out, on the source chain, the packets of the
+    /// given `SendPacket` events, with `MsgTimeoutOnClose` messages if `on_close` is set,
+    /// or with `MsgTimeout` messages otherwise. Packets which have not timed out yet
+    /// are skipped.
+    pub(crate) fn build_timeout_operational_data(
+        &self,
+        events: TrackedEvents,
+        on_close: bool,
+    ) -> Result<Option<OperationalData>, LinkError> {
+        let dst_latest_info = self
+            .dst_chain()
+            .query_application_status()
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        if on_close
+            && !self
+                .dst_channel(QueryHeight::Specific(dst_latest_info.height))?
+                .state_matches(&ChannelState::Closed)
+        {
+            return Err(LinkError::channel_not_closed(
+                self.dst_channel_id().clone(),
+                self.dst_chain().id(),
+            ));
+        }
+
+        let mut src_od = OperationalData::new(
+            dst_latest_info.height,
+            OperationalDataTarget::Source,
+            events.tracking_id(),
+            self.channel.connection_delay,
+        );
+
+        for event_with_height in events.events() {
+            let IbcEvent::SendPacket(event) = &event_with_height.event else {
+                continue;
+            };
+
+            let msg = if on_close {
+                self.build_timeout_on_close_packet(&event.packet, dst_latest_info.height)?
+            } else if event
+                .packet
+                .timed_out(&dst_latest_info.timestamp, dst_latest_info.height)
+            {
+                self.build_timeout_packet(&event.packet, dst_latest_info.height)?
+            } else {
+                warn!(packet = %event.packet, "packet has not timed out yet, skipping");
+                None
+            };
+
+            // For Ordered channels a single timeout message should be sent as this closes the channel
+            if let Some(msg) = msg {
+                if self.unordered_channel() || src_od.batch.is_empty() {
+                    src_od
+                        .batch
+                        .push(TransitMessage::new(event_with_height.clone(), msg));
+                }
+            }
+        }
+
+        Ok(Some(src_od).filter(|od| !od.batch.is_empty()))
+    }
+
     fn build_recv_or_timeout_from_send_packet_event(
         &self,
         event: &SendPacket,
         dst_info: &ChainStatus,
         height: Height,
-    ) -> Result<(Option<Any>, Option<Any>), LinkError> {
+    ) -> Result<(Option<ProvenMsg>, Option<ProvenMsg>), LinkError> {
         crate::time!(
             "build_recv_or_timeout_from_send_packet_event",
             {
@@ -1481,6 +1752,57 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Estimates the fees of relaying the packet of the given `SendPacket` event on its own,
+    /// that is of the transaction updating the client on the destination chain and receiving
+    /// the packet there or, if the packet timed out, of the transaction updating the client
+    /// on the source chain and timing out the packet there.
+    ///
+    /// Returns `None` if there is nothing to relay for the packet.
+    pub fn estimate_packet_relay_fees(
+        &self,
+        event: &SendPacket,
+        height: Height,
+    ) -> Result<Option<PacketRelayFees>, LinkError> {
+        let dst_status = self
+            .dst_chain()
+            .query_application_status()
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        let tracking_id = "estimate packet relay fees";
+
+        match self.build_recv_or_timeout_from_send_packet_event(event, &dst_status, height)? {
+            (_, Some(timeout)) => {
+                let mut msgs = self.build_update_client_on_src(dst_status.height.increment())?;
+                msgs.push(timeout.msg);
+
+                let fees = self
+                    .src_chain()
+                    .estimate_fees(TrackedMsgs::new_static(msgs, tracking_id))
+                    .map_err(|e| LinkError::fee_estimation(self.src_chain().id(), e))?;
+
+                Ok(Some(PacketRelayFees {
+                    timeout: true,
+                    fees,
+                }))
+            }
+            (Some(recv), None) => {
+                let mut msgs = self.build_update_client_on_dst(height.increment())?;
+                msgs.push(recv.msg);
+
+                let fees = self
+                    .dst_chain()
+                    .estimate_fees(TrackedMsgs::new_static(msgs, tracking_id))
+                    .map_err(|e| LinkError::fee_estimation(self.dst_chain().id(), e))?;
+
+                Ok(Some(PacketRelayFees {
+                    timeout: false,
+                    fees,
+                }))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
     /// Drives the relaying of elapsed operational data items meant for
     /// a specified target chain forward.
     ///
@@ -1504,12 +1826,32 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     /// for processing; the operational data that failed to send is dropped.
     ///
     /// Note that pieces of operational data that have not elapsed yet are
-    /// also placed in the 'unprocessed' bucket.
+    /// also placed in the 'unprocessed' bucket, as are all pieces of operational
+    /// data while relaying to the target chain is paused by the wallet worker.
     fn execute_schedule_for_target_chain<I: Iterator<Item = OperationalData>>(
         &mut self,
         mut operations: I,
         target_chain: OperationalDataTarget,
     ) -> Result<VecDeque<OperationalData>, (VecDeque<OperationalData>, LinkError)> {
+        let target_chain_id = match target_chain {
+            OperationalDataTarget::Source => self.src_chain().id(),
+            OperationalDataTarget::Destination => self.dst_chain().id(),
+        };
+
+        if is_relaying_paused(&target_chain_id) {
+            let unprocessed: VecDeque<_> = operations.collect();
+
+            if !unprocessed.is_empty() {
+                debug!(
+                    chain = %target_chain_id,
+                    "relaying is paused because of the critical wallet balance, keeping {} operational data for later",
+                    unprocessed.len()
+                );
+            }
+
+            return Ok(unprocessed);
+        }
+
         let mut unprocessed = VecDeque::new();
 
         while let Some(od) = operations.next() {
@@ -1600,6 +1942,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Whether some of the transactions submitted on this path before Hermes was restarted
+    /// may still be committed, in which case clearing packets should wait for them,
+    /// so as not to relay the same packets twice.
+    pub fn has_restored_txs_in_flight(&self) -> bool {
+        // Query both chains, so that the transactions
+        // which are done with are forgotten on both sides
+        let src = self.pending_txs_src.has_restored_txs_in_flight();
+        let dst = self.pending_txs_dst.has_restored_txs_in_flight();
+
+        src || dst
+    }
+
     /// Kicks off the process of relaying pending txs to the source and destination chains.
     ///
     /// See [`Resubmit::from_clear_interval`] for more info about the `resubmit` parameter.
@@ -1715,10 +2069,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                                         self.channel.connection_delay,
                                     )
                                 })
-                                .push(TransitMessage {
-                                    event_with_height: event_with_height.clone(),
-                                    msg: new_msg,
-                                });
+                                .push(TransitMessage::new(event_with_height.clone(), new_msg));
                         } else {
                             // A SendPacket event, but did not time-out yet, retain
                             retain_batch.push(gm.clone());
@@ -1860,7 +2211,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok((elapsed_src_ods, elapsed_dst_ods))
     }
 
-    fn restore_src_client(&self) -> ForeignClient<ChainA, ChainB> {
+    pub(crate) fn restore_src_client(&self) -> ForeignClient<ChainA, ChainB> {
         ForeignClient::restore(
             self.src_client_id().clone(),
             self.src_chain().clone(),
@@ -1868,7 +2219,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         )
     }
 
-    fn restore_dst_client(&self) -> ForeignClient<ChainB, ChainA> {
+    pub(crate) fn restore_dst_client(&self) -> ForeignClient<ChainB, ChainA> {
         ForeignClient::restore(
             self.dst_client_id().clone(),
             self.dst_chain().clone(),
@@ -1975,17 +2326,37 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 }
 
+/// The packet of a `SendPacket` or `WriteAcknowledgement` event,
+/// whose data is checked before the packet is relayed
+fn packet_to_relay(event: &IbcEvent) -> Option<&Packet> {
+    match event {
+        IbcEvent::SendPacket(event) => Some(&event.packet),
+        IbcEvent::WriteAcknowledgement(event) => Some(&event.packet),
+        _ => None,
+    }
+}
+
 #[tracing::instrument(skip_all)]
 fn check_ics20_fields_size(
     data: &[u8],
+    version: &Version,
     memo_limit: Ics20FieldSizeLimit,
     receiver_limit: Ics20FieldSizeLimit,
 ) -> bool {
-    match serde_json::from_slice::<RawPacketData>(data) {
+    match RawTransferPacketData::decode(data, version) {
         Ok(packet_data) => {
+            // The memo delivered to the final destination of forwarded
+            // ICS-20 v2 tokens is subject to the same limit as the memo.
+            let memo_validity = packet_data
+                .memos()
+                .into_iter()
+                .map(|memo| memo_limit.check_field_size(memo))
+                .find(|validity| !matches!(validity, ValidationResult::Valid))
+                .unwrap_or(ValidationResult::Valid);
+
             match (
-                memo_limit.check_field_size(&packet_data.memo),
-                receiver_limit.check_field_size(&packet_data.receiver),
+                memo_validity,
+                receiver_limit.check_field_size(packet_data.receiver()),
             ) {
                 (ValidationResult::Valid, ValidationResult::Valid) => true,
 
@@ -2005,3 +2376,37 @@ fn check_ics20_fields_size(
         }
     }
 }
+
+#[tracing::instrument(skip_all)]
+fn check_ics20_content(
+    data: &[u8],
+    version: &Version,
+    filter: &Ics20Filter,
+) -> Result<(), Ics20FilterReason> {
+    // The packets of other applications are not subject to the ICS-20 filter
+    if filter.is_empty() || !version.is_ics20() {
+        return Ok(());
+    }
+
+    match RawTransferPacketData::decode(data, version) {
+        Ok(packet_data) => filter.check(&packet_data).inspect_err(|reason| {
+            warn!(
+                "ICS-20 packet data rejected by the packet filter ({reason}), not relaying packet!"
+            );
+        }),
+        // Packet data which cannot be decoded can match none of the `allow` rules
+        Err(e) if !filter.allow.is_empty() => {
+            warn!(
+                "failed to decode ICS-20 packet data with error `{e}`, \
+                 rejected by the `allow` rules of the packet filter, not relaying packet!"
+            );
+
+            Err(Ics20FilterReason::Undecodable)
+        }
+        Err(e) => {
+            trace!("failed to decode ICS20 packet data with error `{e}`");
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Local verification of the proofs included in packet messages, enabled with
+//! `verify_proofs` in the `[mode.packets]` section of the configuration.
+//!
+//! A proof built by a full node is checked against the proof specs of the client of its
+//! chain on the counterparty chain, and against the commitment root this client will hold
+//! for the proof height when the message is delivered, so that a message carrying an invalid
+//! proof is never submitted. This root is taken either from the consensus state the client
+//! already holds, or from the header of the client update message prepended to the batch,
+//! and never from the full node which built the proof.
+
+use std::collections::BTreeMap;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::Protobuf;
+use sha2::{Digest, Sha256};
+use tracing::trace;
+
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{self, MsgUpdateClient};
+use ibc_relayer_types::core::ics04_channel::packet::Packet;
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+use ibc_relayer_types::Height;
+
+use crate::chain::handle::ChainHandle;
+use crate::client_state::AnyClientState;
+use crate::foreign_client::ForeignClient;
+use crate::link::error::LinkError;
+use crate::telemetry;
+
+/// A proof included in a message, which proves that the chain which built it stores
+/// `value` at `path` at `height`, or that it stores nothing at `path` if no value is given.
+#[derive(Clone, Debug)]
+pub struct PendingProof {
+    pub proof: CommitmentProofBytes,
+    pub height: Height,
+    pub path: String,
+    pub value: Option<Vec<u8>>,
+}
+
+impl PendingProof {
+    pub fn new(
+        proof: CommitmentProofBytes,
+        height: Height,
+        path: impl ToString,
+        value: Option<Vec<u8>>,
+    ) -> Self {
+        Self {
+            proof,
+            height,
+            path: path.to_string(),
+            value,
+        }
+    }
+}
+
+/// The commitment to a packet, as stored by ibc-go
+pub fn packet_commitment(packet: &Packet) -> Vec<u8> {
+    let mut preimage = packet
+        .timeout_timestamp
+        .nanoseconds()
+        .to_be_bytes()
+        .to_vec();
+    preimage.extend(
+        packet
+            .timeout_height
+            .commitment_revision_number()
+            .to_be_bytes(),
+    );
+    preimage.extend(
+        packet
+            .timeout_height
+            .commitment_revision_height()
+            .to_be_bytes(),
+    );
+    preimage.extend(Sha256::digest(&packet.data));
+
+    Sha256::digest(preimage).to_vec()
+}
+
+/// The commitment to a packet acknowledgement, as stored by ibc-go
+pub fn ack_commitment(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
+/// Verify the given proofs, built by the chain tracked by `client`, before the batch of
+/// messages which include them is submitted along with the `client_updates` messages.
+///
+/// The client state and the commitment prefix are fetched once for the whole batch, and
+/// the commitment root once per proof height: from the header of the client update message
+/// for this height if there is one, or else from the consensus state the client holds.
+pub fn verify_proofs<'a, DstChain: ChainHandle, SrcChain: ChainHandle>(
+    client: &ForeignClient<DstChain, SrcChain>,
+    proofs: impl IntoIterator<Item = &'a PendingProof>,
+    client_updates: &[Any],
+) -> Result<(), LinkError> {
+    let mut proofs = proofs.into_iter().peekable();
+
+    if proofs.peek().is_none() {
+        return Ok(());
+    }
+
+    let chain_id = client.src_chain().id();
+
+    let (client_state, _) = client.validated_client_state().map_err(LinkError::client)?;
+
+    let proof_specs = match &client_state {
+        AnyClientState::Tendermint(client_state) => client_state.proof_specs.clone(),
+        AnyClientState::Wasm(client_state) => client_state.tendermint.proof_specs.clone(),
+        // The proofs of a solo machine are signatures, which only the host chain can check
+        AnyClientState::SoloMachine(_) => return Ok(()),
+    };
+
+    let prefix = client
+        .src_chain()
+        .query_commitment_prefix()
+        .map_err(|e| LinkError::query(chain_id.clone(), e))?;
+
+    let mut roots = BTreeMap::new();
+
+    for proof in proofs {
+        if !roots.contains_key(&proof.height) {
+            let root = match updated_root(client_updates, proof.height) {
+                Some(root) => root,
+                None => client
+                    .commitment_root(proof.height)
+                    .map_err(LinkError::client)?,
+            };
+
+            roots.insert(proof.height, root);
+        }
+
+        verify_proof(client, &proof_specs, &prefix, &roots[&proof.height], proof)?;
+    }
+
+    Ok(())
+}
+
+/// The commitment root of the consensus state installed at `height` by one of the given
+/// client update messages, if any.
+fn updated_root(client_updates: &[Any], height: Height) -> Option<CommitmentRoot> {
+    client_updates
+        .iter()
+        .filter(|msg| msg.type_url == update_client::TYPE_URL)
+        .filter_map(|msg| MsgUpdateClient::decode_vec(&msg.value).ok())
+        .filter_map(|msg| AnyHeader::try_from(msg.header).ok())
+        .find(|header| header.height() == height)
+        .and_then(|header| match header {
+            AnyHeader::Tendermint(header) => Some(TmConsensusState::from(header).root),
+            AnyHeader::Wasm(header) => Some(TmConsensusState::from(header.tendermint).root),
+            AnyHeader::SoloMachine(_) => None,
+        })
+}
+
+fn verify_proof<DstChain: ChainHandle, SrcChain: ChainHandle>(
+    client: &ForeignClient<DstChain, SrcChain>,
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    root: &CommitmentRoot,
+    proof: &PendingProof,
+) -> Result<(), LinkError> {
+    let PendingProof {
+        proof,
+        height,
+        path,
+        value,
+    } = proof;
+
+    let result = RawMerkleProof::try_from(proof.clone())
+        .map(MerkleProof::from)
+        .and_then(|proof| {
+            let merkle_path = apply_prefix(prefix, vec![path.clone()]);
+
+            match value {
+                Some(value) => proof.verify_membership(
+                    proof_specs,
+                    root.clone().into(),
+                    merkle_path,
+                    value.clone(),
+                    0,
+                ),
+                None => proof.verify_non_membership(proof_specs, root.clone().into(), merkle_path),
+            }
+        });
+
+    let chain_id = client.src_chain().id();
+
+    match result {
+        Ok(()) => {
+            trace!(chain = %chain_id, %path, %height, "verified proof");
+            Ok(())
+        }
+        Err(e) => {
+            telemetry!(invalid_proof, &chain_id, &client.dst_chain().id());
+
+            Err(LinkError::invalid_proof(
+                chain_id,
+                path.clone(),
+                *height,
+                e.to_string(),
+            ))
+        }
+    }
+}
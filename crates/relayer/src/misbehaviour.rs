@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SoloMachineMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::header::WASM_CLIENT_MESSAGE_TYPE_URL;
+use ibc_relayer_types::clients::ics08_wasm::misbehaviour::Misbehaviour as WasmMisbehaviour;
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics02_client::misbehaviour::Misbehaviour;
@@ -21,18 +26,24 @@ pub struct MisbehaviourEvidence {
 #[allow(clippy::large_enum_variant)]
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
+    Wasm(WasmMisbehaviour),
+    SoloMachine(SoloMachineMisbehaviour),
 }
 
 impl Misbehaviour for AnyMisbehaviour {
     fn client_id(&self) -> &ClientId {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::Wasm(misbehaviour) => misbehaviour.client_id(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.client_id(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::Wasm(misbehaviour) => misbehaviour.height(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.height(),
         }
     }
 }
@@ -48,6 +59,14 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                Ok(AnyMisbehaviour::Wasm(WasmMisbehaviour::try_from(raw)?))
+            }
+
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::SoloMachine(
+                SoloMachineMisbehaviour::try_from(raw)?,
+            )),
+
             _ => Err(Error::unknown_misbehaviour_type(raw.type_url)),
         }
     }
@@ -60,6 +79,8 @@ impl From<AnyMisbehaviour> for Any {
                 type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
                 value: misbehaviour.encode_vec(),
             },
+            AnyMisbehaviour::Wasm(misbehaviour) => misbehaviour.into(),
+            AnyMisbehaviour::SoloMachine(misbehaviour) => misbehaviour.into(),
         }
     }
 }
@@ -68,6 +89,8 @@ impl core::fmt::Display for AnyMisbehaviour {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
+            AnyMisbehaviour::Wasm(wasm) => write!(f, "{wasm}"),
+            AnyMisbehaviour::SoloMachine(sm) => write!(f, "{sm}"),
         }
     }
 }
@@ -77,3 +100,9 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
         Self::Tendermint(misbehaviour)
     }
 }
+
+impl From<WasmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: WasmMisbehaviour) -> Self {
+        Self::Wasm(misbehaviour)
+    }
+}
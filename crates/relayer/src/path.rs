@@ -1,8 +1,10 @@
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use serde::Serialize;
 
 /// Defines the channel & port identifiers which comprise
 /// the two ends of a relayer path.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct PathIdentifiers {
     /// Channel & port ids on the target network, usually called the __destination__.
     pub port_id: PortId,
@@ -0,0 +1,355 @@
+//! Governance proposals for recovering expired or frozen clients.
+//!
+//! An expired or frozen client can no longer be updated. Instead, a governance proposal
+//! can replace its client state and latest consensus state with those of a fresh
+//! _substitute_ client tracking the same chain. If the proposal passes, the _subject_
+//! client is active again, and the connections and channels built on top of it resume.
+
+use bytes::BufMut;
+use flex_error::define_error;
+use serde::Serialize;
+
+use tendermint::Hash as TxHash;
+
+use ibc_proto::cosmos::gov::v1::MsgSubmitProposal;
+use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal as LegacyMsgSubmitProposal;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::{ClientUpdateProposal, MsgRecoverClient};
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::Height;
+use tracing::info;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::error::Error;
+use crate::foreign_client::{
+    extract_client_id, CreateOptions, ForeignClient, ForeignClientError, HasExpiredOrFrozenError,
+};
+use crate::upgrade_chain::{requires_legacy_upgrade_proposal, UpgradeChainError};
+
+define_error! {
+    RecoverClientError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the client recovery proposal to chain {0}", e.chain_id)
+            },
+
+        ProposalVersion
+            [ UpgradeChainError ]
+            |_| { "failed to determine which governance proposal the host chain supports" },
+
+        SubjectStatus
+            { client_id: ClientId }
+            [ ForeignClientError ]
+            |e| {
+                format!("failed to check whether the subject client {} is expired or frozen", e.client_id)
+            },
+
+        SubjectActive
+            { client_id: ClientId }
+            |e| {
+                format!("the subject client {} is neither expired nor frozen, it does not need to be recovered", e.client_id)
+            },
+
+        CreateSubstitute
+            [ ForeignClientError ]
+            |_| { "failed to create the substitute client" },
+
+        ClientTypeMismatch
+            {
+                subject_type: ClientType,
+                substitute_type: ClientType,
+            }
+            |e| {
+                format!("the substitute client is of type {} while the subject client is of type {}",
+                    e.substitute_type, e.subject_type)
+            },
+
+        ChainIdMismatch
+            {
+                subject_chain_id: ChainId,
+                substitute_chain_id: ChainId,
+            }
+            |e| {
+                format!("the substitute client tracks chain {} while the subject client tracks chain {}",
+                    e.substitute_chain_id, e.subject_chain_id)
+            },
+
+        SubstituteHeightTooLow
+            {
+                subject_height: Height,
+                substitute_height: Height,
+            }
+            |e| {
+                format!("the latest height of the substitute client ({}) must be greater than the latest height of the subject client ({})",
+                    e.substitute_height, e.subject_height)
+            },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    /// The expired or frozen client to recover
+    pub subject_client_id: ClientId,
+    /// The client to recover the subject from, created if not specified
+    pub substitute_client_id: Option<ClientId>,
+    pub amount: u64,
+    pub denom: String,
+    pub gov_account: String,
+}
+
+/// The outcome of submitting a client recovery proposal
+#[derive(Clone, Debug, Serialize)]
+pub struct RecoverClientProposal {
+    pub substitute_client_id: ClientId,
+    pub tx_hash: TxHash,
+}
+
+pub fn build_and_send_recover_client_proposal(
+    host_chain: impl ChainHandle, // the chain hosting the subject client; the proposal is submitted to it
+    reference_chain: impl ChainHandle, // the chain tracked by the subject client
+    opts: &RecoverClientOptions,
+) -> Result<RecoverClientProposal, RecoverClientError> {
+    let subject = ForeignClient::restore(
+        opts.subject_client_id.clone(),
+        host_chain.clone(),
+        reference_chain.clone(),
+    );
+
+    match subject.validated_client_state() {
+        Ok(_) => {
+            return Err(RecoverClientError::subject_active(
+                opts.subject_client_id.clone(),
+            ))
+        }
+        Err(e) if e.is_expired_or_frozen_error() => {}
+        Err(e) => {
+            return Err(RecoverClientError::subject_status(
+                opts.subject_client_id.clone(),
+                e,
+            ))
+        }
+    }
+
+    let subject_state = query_client_state(&host_chain, &opts.subject_client_id)?;
+
+    let substitute_client_id = match &opts.substitute_client_id {
+        Some(substitute_client_id) => substitute_client_id.clone(),
+        None => create_substitute_client(&subject, &subject_state)?,
+    };
+
+    let substitute_state = query_client_state(&host_chain, &substitute_client_id)?;
+
+    check_substitute(&subject_state, &substitute_state)?;
+
+    let legacy = requires_legacy_upgrade_proposal(host_chain.clone())
+        .map_err(RecoverClientError::proposal_version)?;
+
+    let any_msg = build_recover_client_proposal(&host_chain, &substitute_client_id, opts, legacy)?;
+
+    // As for upgrade proposals, no IBC event is emitted when submitting the proposal
+    let responses = host_chain
+        .send_messages_and_wait_check_tx(TrackedMsgs::new_single(any_msg, "recover client"))
+        .map_err(|e| RecoverClientError::submit(host_chain.id(), e))?;
+
+    Ok(RecoverClientProposal {
+        substitute_client_id,
+        tx_hash: responses[0].hash,
+    })
+}
+
+fn query_client_state(
+    host_chain: &impl ChainHandle,
+    client_id: &ClientId,
+) -> Result<AnyClientState, RecoverClientError> {
+    let (client_state, _) = host_chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(RecoverClientError::query)?;
+
+    Ok(client_state)
+}
+
+/// Create a substitute client with the same parameters as the subject client,
+/// since the recovery fails if they do not match.
+fn create_substitute_client<HostChain: ChainHandle, ReferenceChain: ChainHandle>(
+    subject: &ForeignClient<HostChain, ReferenceChain>,
+    subject_state: &AnyClientState,
+) -> Result<ClientId, RecoverClientError> {
+    let substitute = ForeignClient::restore(
+        ClientId::default(),
+        subject.dst_chain(),
+        subject.src_chain(),
+    );
+
+    let options = CreateOptions {
+        max_clock_drift: Some(subject_state.max_clock_drift()),
+        trusting_period: Some(subject_state.trusting_period()),
+        trust_threshold: subject_state.trust_threshold(),
+        wasm_checksum: match subject_state {
+            AnyClientState::Wasm(client_state) => Some(client_state.checksum.clone()),
+            _ => None,
+        },
+    };
+
+    let event = substitute
+        .build_create_client_and_send(options)
+        .map_err(RecoverClientError::create_substitute)?;
+
+    let substitute_client_id = extract_client_id(&event.event)
+        .map_err(RecoverClientError::create_substitute)?
+        .clone();
+
+    info!(
+        subject = %subject.id(),
+        substitute = %substitute_client_id,
+        "created substitute client"
+    );
+
+    Ok(substitute_client_id)
+}
+
+fn check_substitute(
+    subject_state: &AnyClientState,
+    substitute_state: &AnyClientState,
+) -> Result<(), RecoverClientError> {
+    if subject_state.client_type() != substitute_state.client_type() {
+        return Err(RecoverClientError::client_type_mismatch(
+            subject_state.client_type(),
+            substitute_state.client_type(),
+        ));
+    }
+
+    if subject_state.chain_id() != substitute_state.chain_id() {
+        return Err(RecoverClientError::chain_id_mismatch(
+            subject_state.chain_id(),
+            substitute_state.chain_id(),
+        ));
+    }
+
+    if substitute_state.latest_height() <= subject_state.latest_height() {
+        return Err(RecoverClientError::substitute_height_too_low(
+            subject_state.latest_height(),
+            substitute_state.latest_height(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Ibc-go versions up to v7.x.x use the deprecated `ClientUpdateProposal` to recover a client,
+/// later versions use `MsgRecoverClient`
+fn build_recover_client_proposal(
+    host_chain: &impl ChainHandle,
+    substitute_client_id: &ClientId,
+    opts: &RecoverClientOptions,
+    legacy: bool,
+) -> Result<Any, RecoverClientError> {
+    let title = format!("recover client {}", opts.subject_client_id);
+    let summary = format!(
+        "recover the expired or frozen client {} from the client {}",
+        opts.subject_client_id, substitute_client_id
+    );
+
+    let proposal = if legacy {
+        Proposal::Legacy(ClientUpdateProposal {
+            title: title.clone(),
+            description: summary.clone(),
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+        })
+    } else {
+        Proposal::Default(MsgRecoverClient {
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+            signer: opts.gov_account.clone(),
+        })
+    };
+
+    let mut buf_proposal = Vec::new();
+    proposal.encode(&mut buf_proposal);
+    let any_proposal = Any {
+        type_url: proposal.type_url(),
+        value: buf_proposal,
+    };
+
+    // build the msg submit proposal
+    let proposer = host_chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let coins = ibc_proto::cosmos::base::v1beta1::Coin {
+        denom: opts.denom.clone(),
+        amount: opts.amount.to_string(),
+    };
+
+    let mut buf_msg = Vec::new();
+
+    let type_url = if legacy {
+        let msg = LegacyMsgSubmitProposal {
+            content: Some(any_proposal),
+            initial_deposit: vec![coins],
+            proposer: proposer.to_string(),
+        };
+
+        prost::Message::encode(&msg, &mut buf_msg).unwrap();
+        "/cosmos.gov.v1beta1.MsgSubmitProposal"
+    } else {
+        let msg = MsgSubmitProposal {
+            messages: vec![any_proposal],
+            initial_deposit: vec![coins],
+            proposer: proposer.to_string(),
+            metadata: "".to_string(),
+            title,
+            summary,
+            expedited: false,
+        };
+
+        prost::Message::encode(&msg, &mut buf_msg).unwrap();
+        "/cosmos.gov.v1.MsgSubmitProposal"
+    };
+
+    Ok(Any {
+        type_url: type_url.to_string(),
+        value: buf_msg,
+    })
+}
+
+enum Proposal {
+    Default(MsgRecoverClient),
+    Legacy(ClientUpdateProposal),
+}
+
+impl Proposal {
+    fn encode(&self, buf: &mut impl BufMut) {
+        match self {
+            Proposal::Default(p) => prost::Message::encode(p, buf),
+            Proposal::Legacy(p) => prost::Message::encode(p, buf),
+        }
+        .unwrap()
+    }
+
+    fn type_url(&self) -> String {
+        match self {
+            Proposal::Default(_) => "/ibc.core.client.v1.MsgRecoverClient",
+            Proposal::Legacy(_) => "/ibc.core.client.v1.ClientUpdateProposal",
+        }
+        .to_owned()
+    }
+}
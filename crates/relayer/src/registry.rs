@@ -81,6 +81,14 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
+    /// Replace the configuration which chain runtimes are spawned with.
+    ///
+    /// The runtimes which are already running keep the configuration they
+    /// were spawned with, until they are shut down and spawned again.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Shutdown the runtime associated with the given chain identifier.
     pub fn shutdown(&mut self, chain_id: &ChainId) {
         if let Some(handle) = self.handles.remove(chain_id) {
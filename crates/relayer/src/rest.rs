@@ -1,12 +1,19 @@
 use crossbeam_channel::TryRecvError;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::events::IbcEvent;
 use tracing::{error, trace};
 
 use crate::{
+    client_expiry,
     config::Config,
+    lifecycle,
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    supervisor::{
+        dump_state::{SupervisorState, WorkerDesc},
+        reload::ConfigDiff,
+    },
+    worker::WorkerId,
 };
 
 pub mod request;
@@ -26,13 +33,16 @@ pub const VER: &str = env!(
 pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
-//  We won't unify yet as it is possible we will never implement
-//  REST API `/chain` adding endpoint; instead of `/chain` we might
-//  implement `/reload` for supporting a broader range of functionality
-//  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
     ClearPackets(Option<ChainId>, ReplySender<()>),
+    Reload(ReplySender<ConfigDiff>),
+    GetWorkers(ReplySender<Vec<WorkerDesc>>),
+    StopWorker(WorkerId, ReplySender<WorkerDesc>),
+    PauseWorker(WorkerId, ReplySender<WorkerDesc>),
+    ResumeWorker(WorkerId, ReplySender<WorkerDesc>),
+    SpawnPacketWorker(ChainId, PortId, ChannelId, ReplySender<WorkerDesc>),
+    UpdateClient(ChainId, ClientId, ReplySender<Vec<IbcEvent>>),
 }
 
 /// Process incoming REST requests.
@@ -90,6 +100,103 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::ClearPackets(chain_id, reply_to));
             }
+
+            Request::Reload { reply_to } => {
+                trace!("Reload");
+
+                return Some(Command::Reload(reply_to));
+            }
+
+            Request::GetWorkers { reply_to } => {
+                trace!("GetWorkers");
+
+                return Some(Command::GetWorkers(reply_to));
+            }
+
+            Request::StopWorker {
+                worker_id,
+                reply_to,
+            } => {
+                trace!("StopWorker {}", worker_id);
+
+                return Some(Command::StopWorker(worker_id, reply_to));
+            }
+
+            Request::PauseWorker {
+                worker_id,
+                reply_to,
+            } => {
+                trace!("PauseWorker {}", worker_id);
+
+                return Some(Command::PauseWorker(worker_id, reply_to));
+            }
+
+            Request::ResumeWorker {
+                worker_id,
+                reply_to,
+            } => {
+                trace!("ResumeWorker {}", worker_id);
+
+                return Some(Command::ResumeWorker(worker_id, reply_to));
+            }
+
+            Request::SpawnPacketWorker {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                trace!(
+                    "SpawnPacketWorker {}/{} on {}",
+                    port_id,
+                    channel_id,
+                    chain_id
+                );
+
+                return Some(Command::SpawnPacketWorker(
+                    chain_id, port_id, channel_id, reply_to,
+                ));
+            }
+
+            Request::UpdateClient {
+                chain_id,
+                client_id,
+                reply_to,
+            } => {
+                trace!("UpdateClient {} on {}", client_id, chain_id);
+
+                return Some(Command::UpdateClient(chain_id, client_id, reply_to));
+            }
+
+            Request::GetPacketLifecycle {
+                chain_id,
+                channel_id,
+                sequence,
+                reply_to,
+            } => {
+                trace!(
+                    "GetPacketLifecycle {} on {}/{}",
+                    sequence,
+                    chain_id,
+                    channel_id
+                );
+
+                let result = lifecycle::global()
+                    .get(&chain_id, &channel_id, sequence)
+                    .ok_or(RestApiError::PacketNotFound(chain_id, channel_id, sequence));
+
+                reply_to
+                    .send(result)
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
+
+            Request::GetClients { reply_to } => {
+                trace!("GetClients");
+
+                reply_to
+                    .send(Ok(client_expiry::global().all()))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
@@ -1,7 +1,13 @@
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use thiserror::Error;
 
-use ibc_relayer_types::core::ics24_host::{error::ValidationErrorDetail, identifier::ChainId};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationErrorDetail,
+    identifier::{ChainId, ChannelId},
+};
+
+use crate::worker::WorkerId;
 
 #[derive(Error, Debug)]
 pub enum RestApiError {
@@ -23,6 +29,21 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to reload the configuration: {0}")]
+    Reload(String),
+
+    #[error("could not find worker with id: {0}")]
+    WorkerNotFound(WorkerId),
+
+    #[error("failed to spawn the worker: {0}")]
+    SpawnWorker(String),
+
+    #[error("failed to update the client: {0}")]
+    UpdateClient(String),
+
+    #[error("no lifecycle recorded for packet {2} sent on channel {1} of chain {0}")]
+    PacketNotFound(ChainId, ChannelId, Sequence),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +57,11 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::Reload(_) => "Reload",
+            RestApiError::WorkerNotFound(_) => "WorkerNotFound",
+            RestApiError::SpawnWorker(_) => "SpawnWorker",
+            RestApiError::UpdateClient(_) => "UpdateClient",
+            RestApiError::PacketNotFound(_, _, _) => "PacketNotFound",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
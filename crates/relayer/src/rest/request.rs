@@ -1,8 +1,20 @@
 use serde::{Deserialize, Serialize};
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::events::IbcEvent;
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    client_expiry::ClientExpiry,
+    config::ChainConfig,
+    lifecycle::PacketLifecycle,
+    rest::RestApiError,
+    supervisor::{
+        dump_state::{SupervisorState, WorkerDesc},
+        reload::ConfigDiff,
+    },
+    worker::WorkerId,
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -41,4 +53,51 @@ pub enum Request {
         chain_id: Option<ChainId>,
         reply_to: ReplySender<()>,
     },
+
+    Reload {
+        reply_to: ReplySender<ConfigDiff>,
+    },
+
+    GetWorkers {
+        reply_to: ReplySender<Vec<WorkerDesc>>,
+    },
+
+    StopWorker {
+        worker_id: WorkerId,
+        reply_to: ReplySender<WorkerDesc>,
+    },
+
+    PauseWorker {
+        worker_id: WorkerId,
+        reply_to: ReplySender<WorkerDesc>,
+    },
+
+    ResumeWorker {
+        worker_id: WorkerId,
+        reply_to: ReplySender<WorkerDesc>,
+    },
+
+    SpawnPacketWorker {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<WorkerDesc>,
+    },
+
+    UpdateClient {
+        chain_id: ChainId,
+        client_id: ClientId,
+        reply_to: ReplySender<Vec<IbcEvent>>,
+    },
+
+    GetPacketLifecycle {
+        chain_id: ChainId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        reply_to: ReplySender<PacketLifecycle>,
+    },
+
+    GetClients {
+        reply_to: ReplySender<Vec<ClientExpiry>>,
+    },
 }
@@ -0,0 +1,251 @@
+//! Optional sinks streaming the events observed and relayed by Hermes to external systems,
+//! configured in the `[[sinks]]` sections of the configuration.
+//!
+//! Every record is a single line of JSON (NDJSON) holding a `timestamp`, a `type`
+//! and the fields specific to that type:
+//!
+//! - `ibc_events`: a batch of IBC events received from a chain,
+//!   with its `chain_id`, `height` and `events`;
+//! - `relay_summary`: the IBC events emitted by the transactions of a packet worker
+//!   which got confirmed, with the `path` being relayed and the `events`;
+//! - `worker`: a change in the lifecycle of a worker, with its `worker_id`, `object`
+//!   and `status`, one of `spawned`, `paused`, `resumed` or `stopped`.
+//!
+//! Each sink is served by a dedicated thread, fed through a bounded queue.
+//! The records sent to a sink whose queue is full are dropped, so that
+//! a slow or unavailable sink never holds up relaying.
+
+pub mod error;
+pub use error::Error;
+
+mod file;
+#[cfg(unix)]
+mod socket;
+mod webhook;
+
+use std::thread;
+use std::time::SystemTime;
+
+use crossbeam_channel as channel;
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::config::SinkConfig;
+use crate::event::IbcEventWithHeight;
+use crate::object::{Object, Packet};
+use crate::worker::WorkerId;
+
+/// The number of records each sink can lag behind before records get dropped
+const QUEUE_CAPACITY: usize = 10_000;
+
+/// The maximum number of records handed over to a sink at once
+const MAX_BATCH_SIZE: usize = 100;
+
+static GLOBAL_SINKS: OnceCell<Sinks> = OnceCell::new();
+
+/// Set up the sinks configured in the `[[sinks]]` sections and make them available
+/// through [`global`], if any sink is configured.
+pub fn init(configs: &[SinkConfig]) -> Result<Option<&'static Sinks>, Error> {
+    if configs.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some(sinks) = GLOBAL_SINKS.get() {
+        return Ok(Some(sinks));
+    }
+
+    let sinks = Sinks::spawn(configs)?;
+
+    Ok(Some(GLOBAL_SINKS.get_or_init(|| sinks)))
+}
+
+/// The sinks set up by [`init`], if any
+pub fn global() -> Option<&'static Sinks> {
+    GLOBAL_SINKS.get()
+}
+
+/// Stream a batch of IBC events received from a chain to the sinks, if any
+pub fn ibc_events(chain_id: &ChainId, height: Height, events: &[IbcEventWithHeight]) {
+    if let Some(sinks) = global() {
+        sinks.send(&Record::IbcEvents {
+            chain_id,
+            height,
+            events,
+        });
+    }
+}
+
+/// Stream the IBC events emitted by the confirmed transactions of a packet worker
+/// to the sinks, if any
+pub fn relay_summary(path: &Packet, events: &[IbcEvent]) {
+    if let Some(sinks) = global() {
+        sinks.send(&Record::RelaySummary { path, events });
+    }
+}
+
+/// Stream a change in the lifecycle of a worker to the sinks, if any
+pub fn worker(worker_id: WorkerId, object: &Object, status: WorkerStatus) {
+    if let Some(sinks) = global() {
+        sinks.send(&Record::Worker {
+            worker_id,
+            object,
+            status,
+        });
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerStatus {
+    Spawned,
+    Paused,
+    Resumed,
+    Stopped,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Record<'a> {
+    IbcEvents {
+        chain_id: &'a ChainId,
+        height: Height,
+        events: &'a [IbcEventWithHeight],
+    },
+    RelaySummary {
+        path: &'a Packet,
+        events: &'a [IbcEvent],
+    },
+    Worker {
+        worker_id: WorkerId,
+        object: &'a Object,
+        status: WorkerStatus,
+    },
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    timestamp: String,
+    #[serde(flatten)]
+    record: &'a Record<'a>,
+}
+
+/// A destination records are written to, from the thread dedicated to it
+trait Sink: Send + 'static {
+    /// Write the given records, one JSON document per line, handling any error
+    fn write(&mut self, lines: &[String]);
+}
+
+struct Queue {
+    name: String,
+    tx: channel::Sender<String>,
+}
+
+/// The queues feeding the threads serving the configured sinks
+pub struct Sinks {
+    queues: Vec<Queue>,
+}
+
+impl Sinks {
+    fn spawn(configs: &[SinkConfig]) -> Result<Self, Error> {
+        let queues = configs
+            .iter()
+            .map(|config| match config {
+                SinkConfig::File(file) => spawn_sink(config, file::FileSink::open(file)?),
+                SinkConfig::Webhook(webhook) => {
+                    spawn_sink(config, webhook::WebhookSink::new(webhook)?)
+                }
+                #[cfg(unix)]
+                SinkConfig::Socket(socket) => spawn_sink(config, socket::SocketSink::new(socket)),
+                #[cfg(not(unix))]
+                SinkConfig::Socket(socket) => Err(Error::unsupported_socket(socket.path.clone())),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { queues })
+    }
+
+    /// Serialize the given record and queue it for every sink
+    pub fn send(&self, record: &Record<'_>) {
+        let line = Line {
+            timestamp: humantime::format_rfc3339_millis(SystemTime::now()).to_string(),
+            record,
+        };
+
+        let line = match serde_json::to_string(&line) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("failed to serialize record for the event sinks: {e}");
+                return;
+            }
+        };
+
+        for queue in &self.queues {
+            if let Err(channel::TrySendError::Full(_)) = queue.tx.try_send(line.clone()) {
+                warn!(sink = %queue.name, "event sink is lagging behind, dropping record");
+            }
+        }
+    }
+}
+
+fn spawn_sink(config: &SinkConfig, mut sink: impl Sink) -> Result<Queue, Error> {
+    let name = config.to_string();
+    let (tx, rx) = channel::bounded::<String>(QUEUE_CAPACITY);
+
+    thread::Builder::new()
+        .name(format!("sink:{name}"))
+        .spawn(move || {
+            while let Ok(line) = rx.recv() {
+                let mut lines = vec![line];
+                lines.extend(rx.try_iter().take(MAX_BATCH_SIZE - 1));
+
+                sink.write(&lines);
+            }
+        })
+        .map_err(|e| Error::spawn(name.clone(), e))?;
+
+    info!("streaming events to {name}");
+
+    Ok(Queue { name, tx })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics02_client::events::NewBlock;
+
+    #[test]
+    fn records_are_single_lines_of_json() {
+        let chain_id = ChainId::from_string("ibc-0");
+        let height = Height::new(0, 42).unwrap();
+        let events = vec![IbcEventWithHeight::new(
+            IbcEvent::NewBlock(NewBlock::new(height)),
+            height,
+        )];
+
+        let record = Record::IbcEvents {
+            chain_id: &chain_id,
+            height,
+            events: &events,
+        };
+
+        let line = serde_json::to_string(&Line {
+            timestamp: "2024-01-01T00:00:00.000Z".to_string(),
+            record: &record,
+        })
+        .unwrap();
+
+        assert!(!line.contains('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["timestamp"], "2024-01-01T00:00:00.000Z");
+        assert_eq!(value["type"], "ibc_events");
+        assert_eq!(value["chain_id"], "ibc-0");
+        assert_eq!(value["events"].as_array().unwrap().len(), 1);
+    }
+}
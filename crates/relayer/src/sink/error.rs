@@ -0,0 +1,29 @@
+use flex_error::{define_error, TraceError};
+use std::io::Error as IoError;
+use std::path::PathBuf;
+
+define_error! {
+    Error {
+        Io
+            { path: PathBuf }
+            [ TraceError<IoError> ]
+            |e| { format!("I/O error on event sink {}", e.path.display()) },
+
+        InvalidWebhook
+            { url: String, reason: String }
+            |e| { format!("invalid webhook event sink {}: {}", e.url, e.reason) },
+
+        Runtime
+            [ TraceError<IoError> ]
+            |_| { "failed to create the runtime of a webhook event sink" },
+
+        Spawn
+            { sink: String }
+            [ TraceError<IoError> ]
+            |e| { format!("failed to spawn the thread of event sink {}", e.sink) },
+
+        UnsupportedSocket
+            { path: PathBuf }
+            |e| { format!("cannot stream events to socket {}: Unix sockets are not supported on this platform", e.path.display()) },
+    }
+}
@@ -0,0 +1,137 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use tracing::error;
+
+use crate::config::FileSinkConfig;
+
+use super::{Error, Sink};
+
+/// Appends the records to a file, which is rotated once it reaches `max_size`:
+/// the file at `path` is renamed to `path.1`, `path.1` to `path.2`, and so on,
+/// up to `path.<max_files>`, the oldest file being deleted.
+pub struct FileSink {
+    path: PathBuf,
+    max_size: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl FileSink {
+    pub fn open(config: &FileSinkConfig) -> Result<Self, Error> {
+        if let Some(dir) = config.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::io(dir.to_path_buf(), e))?;
+        }
+
+        let file = open(&config.path).map_err(|e| Error::io(config.path.clone(), e))?;
+
+        let size = file
+            .metadata()
+            .map_err(|e| Error::io(config.path.clone(), e))?
+            .len();
+
+        Ok(Self {
+            path: config.path.clone(),
+            max_size: config.max_size.get_bytes(),
+            max_files: config.max_files,
+            file,
+            size,
+        })
+    }
+
+    fn rotated(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.max_files == 0 {
+            self.file.set_len(0)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated(index);
+
+                if from.exists() {
+                    fs::rename(from, self.rotated(index + 1))?;
+                }
+            }
+
+            fs::rename(&self.path, self.rotated(1))?;
+            self.file = open(&self.path)?;
+        }
+
+        self.size = 0;
+
+        Ok(())
+    }
+}
+
+impl Sink for FileSink {
+    fn write(&mut self, lines: &[String]) {
+        for line in lines {
+            let len = line.len() as u64 + 1;
+
+            if self.size > 0 && self.size + len > self.max_size {
+                if let Err(e) = self.rotate() {
+                    error!(
+                        "failed to rotate event sink file {}: {e}",
+                        self.path.display()
+                    );
+                }
+            }
+
+            match writeln!(self.file, "{line}") {
+                Ok(()) => self.size += len,
+                Err(e) => error!(
+                    "failed to write to event sink file {}: {e}",
+                    self.path.display()
+                ),
+            }
+        }
+
+        if let Err(e) = self.file.flush() {
+            error!(
+                "failed to flush event sink file {}: {e}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use byte_unit::Byte;
+
+    #[test]
+    fn rotates_files_past_max_size() {
+        let dir = std::env::temp_dir().join(format!("hermes-sink-{}", std::process::id()));
+        let path = dir.join("events.ndjson");
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut sink = FileSink::open(&FileSinkConfig {
+            path: path.clone(),
+            max_size: Byte::from_bytes(10),
+            max_files: 2,
+        })
+        .unwrap();
+
+        let lines = ["first", "second", "third", "fourth"].map(String::from);
+        sink.write(&lines);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(fs::read_to_string(sink.rotated(1)).unwrap(), "third\n");
+        assert_eq!(fs::read_to_string(sink.rotated(2)).unwrap(), "second\n");
+        assert!(!sink.rotated(3).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+use core::time::Duration;
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+use tracing::{info, warn};
+
+use crate::config::SocketSinkConfig;
+
+use super::Sink;
+
+/// How long to wait for the reader of the socket before giving up on the connection
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Writes the records to a Unix socket. The connection is established lazily
+/// and again after it is lost. The records written while no reader is listening
+/// on the socket are dropped.
+pub struct SocketSink {
+    path: PathBuf,
+    stream: Option<UnixStream>,
+    warned: bool,
+}
+
+impl SocketSink {
+    pub fn new(config: &SocketSinkConfig) -> Self {
+        Self {
+            path: config.path.clone(),
+            stream: None,
+            warned: false,
+        }
+    }
+
+    fn connect(&mut self) -> Option<&mut UnixStream> {
+        if self.stream.is_none() {
+            match UnixStream::connect(&self.path) {
+                Ok(stream) => {
+                    if let Err(e) = stream.set_write_timeout(Some(WRITE_TIMEOUT)) {
+                        warn!("failed to set write timeout on event sink socket: {e}");
+                    }
+
+                    info!("connected to event sink socket {}", self.path.display());
+
+                    self.stream = Some(stream);
+                    self.warned = false;
+                }
+                Err(e) => {
+                    // Only warn once until the connection is established again
+                    if !self.warned {
+                        warn!(
+                            "failed to connect to event sink socket {}, dropping records until it is available: {e}",
+                            self.path.display()
+                        );
+
+                        self.warned = true;
+                    }
+                }
+            }
+        }
+
+        self.stream.as_mut()
+    }
+}
+
+impl Sink for SocketSink {
+    fn write(&mut self, lines: &[String]) {
+        let Some(stream) = self.connect() else {
+            return;
+        };
+
+        let mut buf = lines.join("\n");
+        buf.push('\n');
+
+        if let Err(e) = stream.write_all(buf.as_bytes()) {
+            warn!(
+                "lost connection to event sink socket {}: {e}",
+                self.path.display()
+            );
+
+            self.stream = None;
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use core::cmp::min;
+use core::str::FromStr;
+use core::time::Duration;
+use std::thread;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{error, warn};
+
+use crate::config::WebhookSinkConfig;
+
+use super::{Error, Sink};
+
+/// POSTs the records to an HTTP endpoint as a newline-delimited JSON body,
+/// retrying with exponential backoff when the request fails.
+pub struct WebhookSink {
+    url: reqwest::Url,
+    client: reqwest::Client,
+    rt: TokioRuntime,
+    max_retries: u32,
+    retry_delay: Duration,
+    max_retry_delay: Duration,
+}
+
+impl WebhookSink {
+    pub fn new(config: &WebhookSinkConfig) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::invalid_webhook(config.url.clone(), reason);
+
+        let url = reqwest::Url::parse(&config.url).map_err(|e| invalid(e.to_string()))?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &config.headers {
+            let name = HeaderName::from_str(name).map_err(|e| invalid(e.to_string()))?;
+            let value = HeaderValue::from_str(value).map_err(|e| invalid(e.to_string()))?;
+            headers.insert(name, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .default_headers(headers)
+            .build()
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(Error::runtime)?;
+
+        Ok(Self {
+            url,
+            client,
+            rt,
+            max_retries: config.max_retries,
+            retry_delay: config.retry_delay,
+            max_retry_delay: config.max_retry_delay,
+        })
+    }
+
+    fn post(&self, body: String) -> Result<(), String> {
+        self.rt.block_on(async {
+            let response = self
+                .client
+                .post(self.url.clone())
+                .header(CONTENT_TYPE, "application/x-ndjson")
+                .body(body)
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let status = response.status();
+
+            if status.is_success() {
+                Ok(())
+            } else {
+                Err(status.to_string())
+            }
+        })
+    }
+}
+
+impl Sink for WebhookSink {
+    fn write(&mut self, lines: &[String]) {
+        let mut body = lines.join("\n");
+        body.push('\n');
+
+        let mut delay = self.retry_delay;
+
+        for attempt in 0..=self.max_retries {
+            match self.post(body.clone()) {
+                Ok(()) => return,
+                Err(e) if attempt < self.max_retries => {
+                    warn!(
+                        "failed to post {} records to webhook {}, retrying in {delay:?}: {e}",
+                        lines.len(),
+                        self.url
+                    );
+
+                    thread::sleep(delay);
+                    delay = min(delay * 2, self.max_retry_delay);
+                }
+                Err(e) => error!(
+                    "failed to post {} records to webhook {} after {} attempts, dropping them: {e}",
+                    lines.len(),
+                    self.url,
+                    attempt + 1
+                ),
+            }
+        }
+    }
+}
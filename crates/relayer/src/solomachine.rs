@@ -0,0 +1,272 @@
+//! Solo machine clients, tracking a key held by Hermes rather than a chain.
+//!
+//! A solo machine, e.g. an off-chain custodial service, is represented on a chain by a
+//! `06-solomachine` client storing its public key. The solo machine proves its state
+//! by signing it, so the relayer builds the headers of such a client itself, by signing
+//! them with the key of the solo machine from its keyring.
+
+use flex_error::define_error;
+use tracing::{debug, info};
+
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::ConsensusState;
+use ibc_relayer_types::clients::ics06_solomachine::header::Header;
+use ibc_relayer_types::clients::ics06_solomachine::{encode_signature, PublicKey};
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::error::Error as RelayerError;
+use crate::event::IbcEventWithHeight;
+use crate::foreign_client::{extract_client_id, ForeignClientError};
+use crate::keyring::errors::Error as KeyringError;
+use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+define_error! {
+    SoloMachineError {
+        Sign
+            [ KeyringError ]
+            |_| { "failed to sign with the key of the solo machine" },
+
+        Client
+            [ ClientError ]
+            |_| { "ICS02 client error" },
+
+        Query
+            { chain_id: ChainId }
+            [ RelayerError ]
+            |e| { format!("failed to query chain {}", e.chain_id) },
+
+        Submit
+            { chain_id: ChainId }
+            [ RelayerError ]
+            |e| { format!("failed to submit the message to chain {}", e.chain_id) },
+
+        MissingClientId
+            [ ForeignClientError ]
+            |_| { "failed to extract the identifier of the created client" },
+
+        NotSoloMachine
+            {
+                client_id: ClientId,
+                client_type: ClientType,
+            }
+            |e| {
+                format!("client {} is of type {}, not a solo machine client",
+                    e.client_id, e.client_type)
+            },
+
+        Frozen
+            { client_id: ClientId }
+            |e| { format!("solo machine client {} is frozen", e.client_id) },
+
+        PublicKeyMismatch
+            { client_id: ClientId }
+            |e| {
+                format!("the key of the solo machine does not match the public key of client {}",
+                    e.client_id)
+            },
+    }
+}
+
+/// A solo machine whose key is held by the relayer
+#[derive(Clone, Debug)]
+pub struct SoloMachine {
+    key: Secp256k1KeyPair,
+    diversifier: String,
+}
+
+impl SoloMachine {
+    pub fn new(key: Secp256k1KeyPair, diversifier: String) -> Self {
+        Self { key, diversifier }
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::secp256k1(self.key.public_key.serialize().to_vec())
+    }
+
+    pub fn diversifier(&self) -> &str {
+        &self.diversifier
+    }
+
+    /// The state of a new client tracking this solo machine, starting at sequence 1
+    pub fn client_state(&self, timestamp: Timestamp) -> Result<ClientState, SoloMachineError> {
+        let consensus_state =
+            ConsensusState::new(self.public_key(), self.diversifier.clone(), timestamp);
+
+        ClientState::new(1, consensus_state).map_err(|e| SoloMachineError::client(e.into()))
+    }
+
+    /// Sign the given bytes, returning the signature encoded as expected by the solo machine client
+    pub fn sign(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, SoloMachineError> {
+        let signature = self.key.sign(sign_bytes).map_err(SoloMachineError::sign)?;
+
+        Ok(encode_signature(signature))
+    }
+
+    /// Build a header advancing the given client state to the given timestamp,
+    /// keeping the public key and diversifier of the solo machine.
+    pub fn build_header(
+        &self,
+        client_state: &ClientState,
+        timestamp: Timestamp,
+    ) -> Result<Header, SoloMachineError> {
+        let new_public_key = self.public_key();
+
+        let sign_bytes = Header::sign_bytes(
+            client_state.sequence,
+            timestamp,
+            &client_state.consensus_state.diversifier,
+            &new_public_key,
+            &self.diversifier,
+        );
+
+        Ok(Header {
+            sequence: client_state.sequence,
+            timestamp,
+            signature: self.sign(&sign_bytes)?,
+            new_public_key,
+            new_diversifier: self.diversifier.clone(),
+        })
+    }
+}
+
+/// A solo machine client hosted on `dst_chain`, the counterpart of a
+/// [`ForeignClient`](crate::foreign_client::ForeignClient) for a solo machine
+pub struct SoloMachineClient<DstChain: ChainHandle> {
+    pub id: ClientId,
+    pub dst_chain: DstChain,
+    pub solo_machine: SoloMachine,
+}
+
+impl<DstChain: ChainHandle> SoloMachineClient<DstChain> {
+    pub fn restore(id: ClientId, dst_chain: DstChain, solo_machine: SoloMachine) -> Self {
+        Self {
+            id,
+            dst_chain,
+            solo_machine,
+        }
+    }
+
+    pub fn id(&self) -> &ClientId {
+        &self.id
+    }
+
+    pub fn build_create_client(&self) -> Result<MsgCreateClient, SoloMachineError> {
+        let signer = self
+            .dst_chain
+            .get_signer()
+            .map_err(|e| SoloMachineError::query(self.dst_chain.id(), e))?;
+
+        let client_state = self.solo_machine.client_state(Timestamp::now())?;
+        let consensus_state = client_state.consensus_state.clone();
+
+        MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
+            .map_err(SoloMachineError::client)
+    }
+
+    /// Create the client, returning the event emitted on creation
+    pub fn build_create_client_and_send(&self) -> Result<IbcEventWithHeight, SoloMachineError> {
+        let msg = self.build_create_client()?;
+
+        let res = self
+            .dst_chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "create client"))
+            .map_err(|e| SoloMachineError::submit(self.dst_chain.id(), e))?;
+
+        assert!(!res.is_empty());
+
+        let client_id =
+            extract_client_id(&res[0].event).map_err(SoloMachineError::missing_client_id)?;
+        info!(id = %client_id, "🍭 solo machine client was created successfully");
+
+        Ok(res[0].clone())
+    }
+
+    /// The current state of the client, which must be an active solo machine
+    /// client tracking the key of the solo machine
+    pub fn client_state(&self) -> Result<ClientState, SoloMachineError> {
+        let (client_state, _) = self
+            .dst_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| SoloMachineError::query(self.dst_chain.id(), e))?;
+
+        let client_state = match client_state {
+            AnyClientState::SoloMachine(client_state) => client_state,
+            client_state => {
+                return Err(SoloMachineError::not_solo_machine(
+                    self.id.clone(),
+                    client_state.client_type(),
+                ))
+            }
+        };
+
+        if client_state.is_frozen {
+            return Err(SoloMachineError::frozen(self.id.clone()));
+        }
+
+        if client_state.consensus_state.public_key != self.solo_machine.public_key() {
+            return Err(SoloMachineError::public_key_mismatch(self.id.clone()));
+        }
+
+        Ok(client_state)
+    }
+
+    pub fn build_update_client(&self) -> Result<MsgUpdateClient, SoloMachineError> {
+        let client_state = self.client_state()?;
+
+        let signer = self
+            .dst_chain
+            .get_signer()
+            .map_err(|e| SoloMachineError::query(self.dst_chain.id(), e))?;
+
+        // The timestamp of a header cannot be lower than that of the consensus state
+        let now = Timestamp::now();
+        let timestamp = if now.after(&client_state.consensus_state.timestamp) {
+            now
+        } else {
+            client_state.consensus_state.timestamp
+        };
+
+        let header = self.solo_machine.build_header(&client_state, timestamp)?;
+
+        debug!(
+            client = %self.id,
+            sequence = client_state.sequence,
+            "building a MsgUpdateClient for solo machine client",
+        );
+
+        Ok(MsgUpdateClient {
+            client_id: self.id.clone(),
+            header: header.into(),
+            signer,
+        })
+    }
+
+    /// Update the client with a new header signed by the solo machine
+    pub fn build_update_client_and_send(&self) -> Result<Vec<IbcEvent>, SoloMachineError> {
+        let msg = self.build_update_client()?;
+
+        let events = self
+            .dst_chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "update client"))
+            .map_err(|e| SoloMachineError::submit(self.dst_chain.id(), e))?;
+
+        Ok(events.into_iter().map(|ev| ev.event).collect())
+    }
+}
@@ -5,6 +5,8 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
+#[cfg(any(test, feature = "mock-chain"))]
+use crate::chain::mock::MockChain;
 use crate::{
     chain::{
         cosmos::CosmosSdkChain, handle::ChainHandle, namada::NamadaChain, penumbra::PenumbraChain,
@@ -87,6 +89,8 @@ pub fn spawn_chain_runtime_with_config<Handle: ChainHandle>(
         ChainConfig::CosmosSdk(_) => ChainRuntime::<CosmosSdkChain>::spawn(config, rt),
         ChainConfig::Namada(_) => ChainRuntime::<NamadaChain>::spawn(config, rt),
         ChainConfig::Penumbra(_) => ChainRuntime::<PenumbraChain>::spawn(config, rt),
+        #[cfg(any(test, feature = "mock-chain"))]
+        ChainConfig::Mock(_) => ChainRuntime::<MockChain>::spawn(config, rt),
     }
     .map_err(SpawnError::relayer)?;
 
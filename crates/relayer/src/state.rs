@@ -0,0 +1,485 @@
+//! An optional on-disk store of the relay state, which Hermes resumes from after a restart.
+//!
+//! The store is a SQLite database, by default at `$HOME/.hermes/state/state.sqlite`,
+//! which holds:
+//!
+//! - the height of the last event batch processed by the supervisor for each chain,
+//!   from which the events of chains using a pull-based event source are fetched again
+//!   on start, so that the events emitted while Hermes was down are not missed;
+//! - the transactions submitted by the packet workers which have not been confirmed yet,
+//!   so that packets whose transaction is still in flight are not relayed twice
+//!   when clearing packets on start;
+//! - the height at which the pending packets of each channel were last cleared.
+//!
+//! Failing to update the store never prevents relaying, the errors are only logged.
+
+pub mod error;
+pub use error::Error;
+
+use alloc::collections::BTreeSet;
+use core::time::Duration;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::OnceCell;
+use rusqlite::{params, Connection, OptionalExtension};
+use tendermint::Hash as TxHash;
+use tracing::info;
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::Height;
+
+use crate::config::StateConfig;
+
+pub const STATE_DEFAULT_FOLDER: &str = ".hermes/state/";
+pub const STATE_DB_FILE: &str = "state.sqlite";
+
+static GLOBAL_STORE: OnceCell<StateStore> = OnceCell::new();
+
+/// Open the store configured in the `[state]` section and make it available
+/// through [`global`], if the store is enabled.
+pub fn init(config: &StateConfig) -> Result<Option<&'static StateStore>, Error> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    if let Some(store) = GLOBAL_STORE.get() {
+        return Ok(Some(store));
+    }
+
+    let path = match &config.path {
+        Some(path) => path.clone(),
+        None => default_path()?,
+    };
+
+    let store = StateStore::open(&path, config.max_replayed_blocks)?;
+
+    info!("relay state store opened at {}", path.display());
+
+    Ok(Some(GLOBAL_STORE.get_or_init(|| store)))
+}
+
+/// The store opened by [`init`], if any
+pub fn global() -> Option<&'static StateStore> {
+    GLOBAL_STORE.get()
+}
+
+fn default_path() -> Result<PathBuf, Error> {
+    let home = dirs_next::home_dir().ok_or_else(Error::home_location_unavailable)?;
+    Ok(home.join(STATE_DEFAULT_FOLDER))
+}
+
+/// A transaction submitted by a packet worker, which has not been confirmed yet
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InFlightTx {
+    pub hash: TxHash,
+    pub submitted_at: SystemTime,
+}
+
+impl InFlightTx {
+    /// Whether the transaction was submitted more than `timeout` ago
+    pub fn has_timed_out(&self, timeout: Duration) -> bool {
+        self.submitted_at
+            .elapsed()
+            .map(|elapsed| elapsed > timeout)
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StateStore {
+    conn: Arc<Mutex<Connection>>,
+    max_replayed_blocks: u64,
+
+    /// The chains whose events have been fetched again from
+    /// their last processed height since Hermes started
+    resumed_chains: Arc<Mutex<BTreeSet<ChainId>>>,
+}
+
+impl StateStore {
+    /// Open the store in the given directory, creating it if needed.
+    pub fn open(dir: &Path, max_replayed_blocks: u64) -> Result<Self, Error> {
+        std::fs::create_dir_all(dir).map_err(|e| Error::io(dir.to_path_buf(), e))?;
+
+        let conn = Connection::open(dir.join(STATE_DB_FILE)).map_err(Error::sqlite)?;
+
+        Self::new(conn, max_replayed_blocks)
+    }
+
+    /// Open a store which only lives in memory, for testing purposes.
+    pub fn open_in_memory(max_replayed_blocks: u64) -> Result<Self, Error> {
+        let conn = Connection::open_in_memory().map_err(Error::sqlite)?;
+
+        Self::new(conn, max_replayed_blocks)
+    }
+
+    fn new(conn: Connection, max_replayed_blocks: u64) -> Result<Self, Error> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS processed_heights (
+                chain_id        TEXT PRIMARY KEY,
+                revision_number INTEGER NOT NULL,
+                revision_height INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS in_flight_txs (
+                chain_id     TEXT NOT NULL,
+                port_id      TEXT NOT NULL,
+                channel_id   TEXT NOT NULL,
+                tx_hash      TEXT NOT NULL,
+                submitted_at INTEGER NOT NULL,
+                PRIMARY KEY (chain_id, tx_hash)
+            );
+
+            CREATE TABLE IF NOT EXISTS cleared_heights (
+                chain_id        TEXT NOT NULL,
+                port_id         TEXT NOT NULL,
+                channel_id      TEXT NOT NULL,
+                revision_number INTEGER NOT NULL,
+                revision_height INTEGER NOT NULL,
+                PRIMARY KEY (chain_id, port_id, channel_id)
+            );",
+        )
+        .map_err(Error::sqlite)?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            max_replayed_blocks,
+            resumed_chains: Arc::new(Mutex::new(BTreeSet::new())),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record the height of the last event batch processed for the given chain
+    pub fn set_processed_height(&self, chain_id: &ChainId, height: Height) -> Result<(), Error> {
+        self.conn()
+            .execute(
+                "INSERT INTO processed_heights (chain_id, revision_number, revision_height)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT (chain_id) DO UPDATE SET
+                    revision_number = excluded.revision_number,
+                    revision_height = excluded.revision_height",
+                params![
+                    chain_id.as_str(),
+                    height.revision_number(),
+                    height.revision_height()
+                ],
+            )
+            .map_err(Error::sqlite)?;
+
+        Ok(())
+    }
+
+    /// The height of the last event batch processed for the given chain
+    pub fn processed_height(&self, chain_id: &ChainId) -> Result<Option<Height>, Error> {
+        let height = self
+            .conn()
+            .query_row(
+                "SELECT revision_number, revision_height FROM processed_heights
+                 WHERE chain_id = ?1",
+                params![chain_id.as_str()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(Error::sqlite)?;
+
+        height
+            .map(|(number, height)| to_height(number, height))
+            .transpose()
+    }
+
+    /// The height from which to fetch the events of the given chain again, that is the
+    /// height of the last event batch processed for it, unless it is older than
+    /// `max_replayed_blocks` blocks or belongs to a different revision of the chain.
+    pub fn replay_height(
+        &self,
+        chain_id: &ChainId,
+        latest_height: Height,
+    ) -> Result<Option<Height>, Error> {
+        let Some(processed) = self.processed_height(chain_id)? else {
+            return Ok(None);
+        };
+
+        let replayable = processed.revision_number() == latest_height.revision_number()
+            && processed <= latest_height
+            && latest_height.revision_height() - processed.revision_height()
+                <= self.max_replayed_blocks;
+
+        Ok(replayable.then_some(processed))
+    }
+
+    /// Record that the events of the given chain are fetched again from its last processed height
+    pub fn mark_resumed(&self, chain_id: &ChainId) {
+        self.resumed_chains
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(chain_id.clone());
+    }
+
+    /// Whether the events of the given chain have been fetched again from its last
+    /// processed height, such that no event was missed while Hermes was down
+    pub fn has_resumed(&self, chain_id: &ChainId) -> bool {
+        self.resumed_chains
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(chain_id)
+    }
+
+    /// Record the transactions submitted to the given chain to relay packets on the given channel
+    pub fn insert_in_flight_txs(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        tx_hashes: &[TxHash],
+        submitted_at: SystemTime,
+    ) -> Result<(), Error> {
+        let submitted_at = submitted_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut conn = self.conn();
+        let tx = conn.transaction().map_err(Error::sqlite)?;
+
+        for hash in tx_hashes {
+            tx.execute(
+                "INSERT OR REPLACE INTO in_flight_txs
+                    (chain_id, port_id, channel_id, tx_hash, submitted_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    chain_id.as_str(),
+                    port_id.as_str(),
+                    channel_id.as_str(),
+                    hash.to_string(),
+                    submitted_at
+                ],
+            )
+            .map_err(Error::sqlite)?;
+        }
+
+        tx.commit().map_err(Error::sqlite)
+    }
+
+    /// Forget about the given transactions submitted to the given chain,
+    /// once they have been confirmed or have timed out
+    pub fn remove_in_flight_txs(
+        &self,
+        chain_id: &ChainId,
+        tx_hashes: &[TxHash],
+    ) -> Result<(), Error> {
+        let mut conn = self.conn();
+        let tx = conn.transaction().map_err(Error::sqlite)?;
+
+        for hash in tx_hashes {
+            tx.execute(
+                "DELETE FROM in_flight_txs WHERE chain_id = ?1 AND tx_hash = ?2",
+                params![chain_id.as_str(), hash.to_string()],
+            )
+            .map_err(Error::sqlite)?;
+        }
+
+        tx.commit().map_err(Error::sqlite)
+    }
+
+    /// The transactions submitted to the given chain to relay packets
+    /// on the given channel, which have not been confirmed yet
+    pub fn in_flight_txs(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<InFlightTx>, Error> {
+        let conn = self.conn();
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT tx_hash, submitted_at FROM in_flight_txs
+                 WHERE chain_id = ?1 AND port_id = ?2 AND channel_id = ?3
+                 ORDER BY submitted_at, rowid",
+            )
+            .map_err(Error::sqlite)?;
+
+        let rows = stmt
+            .query_map(
+                params![chain_id.as_str(), port_id.as_str(), channel_id.as_str()],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, u64>(1)?)),
+            )
+            .map_err(Error::sqlite)?;
+
+        rows.map(|row| {
+            let (hash, submitted_at) = row.map_err(Error::sqlite)?;
+
+            let hash = TxHash::from_str(&hash)
+                .map_err(|e| Error::invalid_entry(format!("transaction hash {hash}: {e}")))?;
+
+            Ok(InFlightTx {
+                hash,
+                submitted_at: UNIX_EPOCH + Duration::from_secs(submitted_at),
+            })
+        })
+        .collect()
+    }
+
+    /// Record the height at which the pending packets of the given channel were cleared
+    pub fn set_cleared_height(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<(), Error> {
+        self.conn()
+            .execute(
+                "INSERT INTO cleared_heights
+                    (chain_id, port_id, channel_id, revision_number, revision_height)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT (chain_id, port_id, channel_id) DO UPDATE SET
+                    revision_number = excluded.revision_number,
+                    revision_height = excluded.revision_height",
+                params![
+                    chain_id.as_str(),
+                    port_id.as_str(),
+                    channel_id.as_str(),
+                    height.revision_number(),
+                    height.revision_height()
+                ],
+            )
+            .map_err(Error::sqlite)?;
+
+        Ok(())
+    }
+
+    /// The height at which the pending packets of the given channel were last cleared
+    pub fn cleared_height(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<Height>, Error> {
+        let height = self
+            .conn()
+            .query_row(
+                "SELECT revision_number, revision_height FROM cleared_heights
+                 WHERE chain_id = ?1 AND port_id = ?2 AND channel_id = ?3",
+                params![chain_id.as_str(), port_id.as_str(), channel_id.as_str()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(Error::sqlite)?;
+
+        height
+            .map(|(number, height)| to_height(number, height))
+            .transpose()
+    }
+}
+
+fn to_height(revision_number: u64, revision_height: u64) -> Result<Height, Error> {
+    Height::new(revision_number, revision_height)
+        .map_err(|e| Error::invalid_entry(format!("height: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_id() -> ChainId {
+        ChainId::from_string("ibc-0")
+    }
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).unwrap()
+    }
+
+    #[test]
+    fn replay_height_within_limit() {
+        let store = StateStore::open_in_memory(100).unwrap();
+
+        assert_eq!(store.replay_height(&chain_id(), height(50)).unwrap(), None);
+
+        store.set_processed_height(&chain_id(), height(10)).unwrap();
+        store.set_processed_height(&chain_id(), height(20)).unwrap();
+
+        assert_eq!(
+            store.processed_height(&chain_id()).unwrap(),
+            Some(height(20))
+        );
+        assert_eq!(
+            store.replay_height(&chain_id(), height(120)).unwrap(),
+            Some(height(20))
+        );
+        assert_eq!(store.replay_height(&chain_id(), height(121)).unwrap(), None);
+        assert_eq!(
+            store
+                .replay_height(&chain_id(), Height::new(1, 30).unwrap())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn in_flight_txs_roundtrip() {
+        let store = StateStore::open_in_memory(100).unwrap();
+
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+        let hashes = [TxHash::Sha256([1; 32]), TxHash::Sha256([2; 32])];
+
+        store
+            .insert_in_flight_txs(
+                &chain_id(),
+                &port_id,
+                &channel_id,
+                &hashes,
+                SystemTime::now(),
+            )
+            .unwrap();
+
+        let txs = store
+            .in_flight_txs(&chain_id(), &port_id, &channel_id)
+            .unwrap();
+        assert_eq!(
+            txs.iter().map(|tx| tx.hash).collect::<Vec<_>>(),
+            hashes.to_vec()
+        );
+        assert!(!txs[0].has_timed_out(Duration::from_secs(60)));
+
+        store
+            .remove_in_flight_txs(&chain_id(), &hashes[..1])
+            .unwrap();
+
+        let txs = store
+            .in_flight_txs(&chain_id(), &port_id, &channel_id)
+            .unwrap();
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].hash, hashes[1]);
+    }
+
+    #[test]
+    fn cleared_height_per_channel() {
+        let store = StateStore::open_in_memory(100).unwrap();
+
+        let port_id = PortId::transfer();
+
+        store
+            .set_cleared_height(&chain_id(), &port_id, &ChannelId::new(0), height(5))
+            .unwrap();
+
+        assert_eq!(
+            store
+                .cleared_height(&chain_id(), &port_id, &ChannelId::new(0))
+                .unwrap(),
+            Some(height(5))
+        );
+        assert_eq!(
+            store
+                .cleared_height(&chain_id(), &port_id, &ChannelId::new(1))
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use flex_error::{define_error, TraceError};
+use std::io::Error as IoError;
+use std::path::PathBuf;
+
+define_error! {
+    Error {
+        HomeLocationUnavailable
+            |_| { "home location is unavailable" },
+
+        Io
+            { path: PathBuf }
+            [ TraceError<IoError> ]
+            |e| { format!("I/O error on state store directory {}", e.path.display()) },
+
+        Sqlite
+            [ TraceError<rusqlite::Error> ]
+            |_| { "state store database error" },
+
+        InvalidEntry
+            { reason: String }
+            |e| { format!("invalid entry in the state store: {}", e.reason) },
+    }
+}
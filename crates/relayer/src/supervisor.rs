@@ -1,30 +1,40 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::convert::Infallible;
 use core::ops::Deref;
 use core::time::Duration;
-use std::sync::RwLock;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId},
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
+    chain::{
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryClientStateRequest, QueryHeight},
+        tracking::TrackingId,
+    },
     config::Config,
     event::{
         source::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
+    foreign_client::ForeignClient,
+    lifecycle,
     object::Object,
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{self, RestApiError},
+    sink, state,
     supervisor::scan::ScanMode,
     telemetry,
     util::{
@@ -41,7 +51,7 @@ pub mod error;
 pub use error::{Error, ErrorDetail};
 
 pub mod dump_state;
-use dump_state::SupervisorState;
+use dump_state::{SupervisorState, WorkerDesc};
 
 pub mod scan;
 pub mod spawn;
@@ -49,11 +59,20 @@ pub mod spawn;
 pub mod cmd;
 use cmd::SupervisorCmd;
 
-use self::{scan::ChainScanner, spawn::SpawnContext};
+pub mod reload;
+use reload::ConfigDiff;
+
+use self::{
+    scan::{ChainScanner, ChainsScan},
+    spawn::SpawnContext,
+};
 
 type ArcBatch = Arc<source::Result<EventBatch>>;
 type Subscription = Receiver<ArcBatch>;
 
+/// The subscriptions to the events of each chain, processed by the batch task
+type Subscriptions<Chain> = Arc<RwLock<HashMap<ChainId, (Chain, Subscription)>>>;
+
 /**
     A wrapper around the SupervisorCmd sender so that we can
     send stop signal to the supervisor before stopping the
@@ -66,7 +85,7 @@ pub struct SupervisorHandle {
 }
 
 /// Options for the supervisor
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SupervisorOptions {
     /// Perform a health check of all chains we connect to
     pub health_check: bool,
@@ -75,6 +94,25 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// The configuration file to reload the configuration from, when asked to.
+    /// Reloading the configuration is not possible if this is not set.
+    pub config_path: Option<PathBuf>,
+}
+
+/// The state of the supervisor shared between its tasks
+#[derive(Clone)]
+struct SupervisorContext<Chain: ChainHandle> {
+    config: Arc<RwLock<Config>>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
+    workers: Arc<RwLock<WorkerMap>>,
+    subscriptions: Subscriptions<Chain>,
+    options: SupervisorOptions,
+
+    /// Held while reloading the configuration, so that reloads
+    /// triggered concurrently are applied one after the other
+    reloading: Arc<Mutex<()>>,
 }
 
 /**
@@ -131,6 +169,17 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to reload its configuration from the configuration file
+    pub fn reload_config(&self) -> Result<ConfigDiff, Error> {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        self.sender
+            .send(SupervisorCmd::Reload(tx))
+            .map_err(|_| Error::handle_send())?;
+
+        rx.recv().map_err(|_| Error::handle_recv())?
+    }
 }
 
 /// Whether the supervisor should scan the chains for clients, connections, and channels.
@@ -156,6 +205,16 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     cmd_rx: Receiver<SupervisorCmd>,
     options: SupervisorOptions,
 ) -> Result<Vec<TaskHandle>, Error> {
+    // Open the relay state store before spawning the chain runtimes,
+    // so that their event sources can resume from it.
+    if let Err(e) = state::init(&config.state) {
+        error!("failed to open the relay state store, Hermes will not resume from it: {e}");
+    }
+
+    if let Err(e) = sink::init(&config.sinks) {
+        error!("failed to set up the event sinks, events will not be streamed to them: {e}");
+    }
+
     if options.health_check {
         health_check(&config, &mut registry.write());
     }
@@ -164,8 +223,8 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     // in the list of visible fee addresses.
     if config.telemetry.enabled {
         for chain in registry.read().chains() {
-            if let Ok(_key) = chain.get_key() {
-                telemetry!(add_visible_fee_address, _key.account());
+            if let Ok(_signer) = chain.get_signer() {
+                telemetry!(add_visible_fee_address, _signer.to_string());
             }
         }
     }
@@ -196,52 +255,59 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
-    let batch_tasks = spawn_batch_workers(
-        &config,
-        registry.clone(),
+    let ctx = SupervisorContext {
+        config: Arc::new(RwLock::new(config)),
+        registry,
         client_state_filter,
-        workers.clone(),
-        subscriptions,
-    );
+        workers,
+        subscriptions: Arc::new(RwLock::new(
+            subscriptions
+                .into_iter()
+                .map(|(chain, subscription)| (chain.id(), (chain, subscription)))
+                .collect(),
+        )),
+        options,
+        reloading: Arc::new(Mutex::new(())),
+    };
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    let batch_task = spawn_batch_worker(&ctx);
+    let cmd_task = spawn_cmd_worker(ctx.clone(), cmd_rx);
 
-    let mut tasks = vec![cmd_task];
-    tasks.extend(batch_tasks);
+    let mut tasks = vec![cmd_task, batch_task];
 
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers.clone(), rest_rx);
+        let rest_task = spawn_rest_worker(ctx.clone(), rest_rx);
         tasks.push(rest_task);
     }
 
-    let cleanup_task = spawn_cleanup_worker(workers);
+    let cleanup_task = spawn_cleanup_worker(ctx.workers.clone());
     tasks.push(cleanup_task);
 
     Ok(tasks)
 }
 
-fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
-    registry: SharedRegistry<Chain>,
-    client_state_filter: Arc<RwLock<FilterPolicy>>,
-    workers: Arc<RwLock<WorkerMap>>,
-    subscriptions: Vec<(Chain, Subscription)>,
-) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
-
-    for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
+/// Spawn the task processing the event batches received from the chains
+/// the supervisor is subscribed to, which change when the configuration is reloaded.
+///
+/// The subscriptions are locked while the batches received from them are processed,
+/// so that no batch of a chain is processed after unsubscribing from it.
+fn spawn_batch_worker<Chain: ChainHandle>(ctx: &SupervisorContext<Chain>) -> TaskHandle {
+    let config = ctx.config.clone();
+    let registry = ctx.registry.clone();
+    let client_state_filter = ctx.client_state_filter.clone();
+    let workers = ctx.workers.clone();
+    let subscriptions = ctx.subscriptions.clone();
+
+    spawn_background_task(
+        error_span!("worker.batch"),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            for (chain, subscription) in subscriptions.acquire_read().values() {
                 if let Ok(batch) = subscription.try_recv() {
+                    let _span = error_span!("batch", chain = %chain.id()).entered();
+
                     handle_batch(
-                        &config,
+                        &config.acquire_read(),
                         &mut registry.write(),
                         &mut client_state_filter.acquire_write(),
                         &mut workers.acquire_write(),
@@ -249,20 +315,15 @@ fn spawn_batch_workers<Chain: ChainHandle>(
                         batch,
                     );
                 }
+            }
 
-                Ok(Next::Continue)
-            },
-        );
-
-        handles.push(handle);
-    }
-
-    handles
+            Ok(Next::Continue)
+        },
+    )
 }
 
-pub fn spawn_cmd_worker<Chain: ChainHandle>(
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_cmd_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -272,7 +333,10 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
             if let Ok(cmd) = cmd_rx.try_recv() {
                 match cmd {
                     SupervisorCmd::DumpState(reply_to) => {
-                        dump_state(&registry.read(), &workers.acquire_read(), reply_to);
+                        dump_state(&ctx.registry.read(), &ctx.workers.acquire_read(), reply_to);
+                    }
+                    SupervisorCmd::Reload(reply_to) => {
+                        let _ = reply_to.try_send(reload_config(&ctx));
                     }
                 }
             }
@@ -282,17 +346,15 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
     )
 }
 
-pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_rest_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(&ctx, &rest_rx);
 
             Ok(Next::Continue)
         },
@@ -678,26 +740,8 @@ fn init_subscriptions<Chain: ChainHandle>(
     let mut subscriptions = Vec::with_capacity(chains.len());
 
     for chain_config in chains {
-        let chain = match registry.get_or_spawn(chain_config.id()) {
-            Ok(chain) => chain,
-            Err(e) => {
-                error!(
-                    "failed to spawn chain runtime for {}: {}",
-                    chain_config.id(),
-                    e
-                );
-
-                continue;
-            }
-        };
-
-        match chain.subscribe() {
-            Ok(subscription) => subscriptions.push((chain, subscription)),
-            Err(e) => error!(
-                "failed to subscribe to events of {}: {}",
-                chain_config.id(),
-                e
-            ),
+        if let Some(subscription) = subscribe(registry, chain_config.id()) {
+            subscriptions.push(subscription);
         }
     }
 
@@ -710,6 +754,164 @@ fn init_subscriptions<Chain: ChainHandle>(
     Ok(subscriptions)
 }
 
+/// Subscribe to the events emitted by the given chain, spawning its runtime if needed.
+fn subscribe<Chain: ChainHandle>(
+    registry: &mut Registry<Chain>,
+    chain_id: &ChainId,
+) -> Option<(Chain, Subscription)> {
+    let chain = match registry.get_or_spawn(chain_id) {
+        Ok(chain) => chain,
+        Err(e) => {
+            error!("failed to spawn chain runtime for {}: {}", chain_id, e);
+
+            return None;
+        }
+    };
+
+    match chain.subscribe() {
+        Ok(subscription) => Some((chain, subscription)),
+        Err(e) => {
+            error!("failed to subscribe to events of {}: {}", chain_id, e);
+
+            None
+        }
+    }
+}
+
+/// Reload the configuration from the configuration file, and apply the
+/// changes to the chain runtimes and workers of the supervisor.
+///
+/// Only the runtimes of the added, updated and removed chains are spawned or
+/// shut down, along with the workers relaying to or from these chains. The chains
+/// whose worker settings only changed keep their runtime, and only the workers
+/// relaying to or from them are restarted.
+#[instrument(name = "supervisor.reload_config", level = "error", skip_all)]
+fn reload_config<Chain: ChainHandle>(ctx: &SupervisorContext<Chain>) -> Result<ConfigDiff, Error> {
+    let _reloading = ctx.reloading.lock().unwrap_or_else(|e| e.into_inner());
+
+    let path = ctx
+        .options
+        .config_path
+        .as_ref()
+        .ok_or_else(Error::reload_unavailable)?;
+
+    info!("reloading configuration from {}", path.display());
+
+    let next = reload::load_config(path)?;
+    let diff = ConfigDiff::new(&ctx.config.acquire_read(), &next);
+
+    if diff.is_empty() {
+        info!("the configuration of the chains did not change, nothing to reload");
+        return Ok(diff);
+    }
+
+    info!(
+        added = ?diff.added,
+        updated = ?diff.updated,
+        reconfigured = ?diff.reconfigured,
+        removed = ?diff.removed,
+        "applying configuration changes"
+    );
+
+    // Stop processing the events of the chains which are restarted or removed,
+    // waiting for the batches of these chains being processed to complete.
+    {
+        let mut subscriptions = ctx.subscriptions.acquire_write();
+
+        for chain_id in diff.stopped_chains() {
+            subscriptions.remove(chain_id);
+        }
+    }
+
+    // Shut down the workers relaying to or from these chains and the reconfigured ones,
+    // and remember which chains must be scanned again to spawn them back.
+    let mut to_scan: BTreeSet<ChainId> = diff.scanned_chains().cloned().collect();
+
+    {
+        let mut workers = ctx.workers.acquire_write();
+
+        for chain_id in diff.stopped_workers() {
+            for object in workers.objects_for_chain(chain_id) {
+                to_scan.insert(object.src_chain_id().clone());
+                workers.shutdown_worker(&object);
+            }
+        }
+    }
+
+    to_scan.retain(|chain_id| next.has_chain(chain_id));
+
+    {
+        let mut registry = ctx.registry.write();
+
+        for chain_id in diff.stopped_chains() {
+            registry.shutdown(chain_id);
+        }
+
+        registry.set_config(next.clone());
+    }
+
+    *ctx.config.acquire_write() = next.clone();
+
+    // The client states allowed by the filter may depend on the configuration
+    *ctx.client_state_filter.acquire_write() = FilterPolicy::default();
+
+    if should_scan(&next, &ctx.options) {
+        let mut scan = ChainsScan {
+            chains: Vec::with_capacity(to_scan.len()),
+        };
+
+        {
+            let mut registry = ctx.registry.write();
+            let mut client_state_filter = ctx.client_state_filter.acquire_write();
+
+            let mut scanner = chain_scanner(
+                &next,
+                &mut registry,
+                &mut client_state_filter,
+                if ctx.options.force_full_scan {
+                    ScanMode::Full
+                } else {
+                    ScanMode::Auto
+                },
+            );
+
+            for chain_config in next.chains.iter().filter(|c| to_scan.contains(c.id())) {
+                scan.chains.push(scanner.scan_chain(chain_config));
+            }
+        }
+
+        info!("scanned chains:");
+        info!("{}", scan);
+
+        spawn_context(
+            &next,
+            &mut ctx.registry.write(),
+            &mut ctx.workers.acquire_write(),
+        )
+        .spawn_workers(scan);
+    }
+
+    for chain_id in diff.started_chains() {
+        let Some((chain, subscription)) = subscribe(&mut ctx.registry.write(), chain_id) else {
+            continue;
+        };
+
+        if next.telemetry.enabled {
+            if let Ok(_signer) = chain.get_signer() {
+                telemetry!(add_visible_fee_address, _signer.to_string());
+            }
+        }
+
+        ctx.subscriptions
+            .acquire_write()
+            .insert(chain_id.clone(), (chain, subscription));
+    }
+
+    info!("configuration reloaded");
+
+    Ok(diff)
+}
+
 /// Dump the state of the supervisor into a [`SupervisorState`] value,
 /// and send it back through the given channel.
 fn dump_state<Chain: ChainHandle>(
@@ -725,45 +927,62 @@ fn dump_state<Chain: ChainHandle>(
 /// as a [`SupervisorState`].
 fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) -> SupervisorState {
     let chains = registry.chains().map(|c| c.id()).collect_vec();
-    SupervisorState::new(chains, workers.handles())
+
+    let nodes = registry
+        .chains()
+        .filter_map(|chain| match chain.query_node_status() {
+            Ok(nodes) if nodes.len() > 1 => Some((chain.id(), nodes)),
+            Ok(_) => None,
+            Err(e) => {
+                error!(
+                    "failed to query the full nodes of chain {}: {e}",
+                    chain.id()
+                );
+                None
+            }
+        })
+        .collect();
+
+    SupervisorState::new(chains, workers.handles()).with_nodes(nodes)
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    ctx: &SupervisorContext<Chain>,
     rest_rx: &rest::Receiver,
 ) {
-    if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+    // Do not hold on to the configuration while handling the command,
+    // as reloading the configuration needs to replace it.
+    let cmd = rest::process_incoming_requests(&ctx.config.acquire_read(), rest_rx);
+
+    if let Some(cmd) = cmd {
+        handle_rest_cmd(ctx, cmd);
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
-fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
-    cmd: rest::Command,
-) {
+fn handle_rest_cmd<Chain: ChainHandle>(ctx: &SupervisorContext<Chain>, cmd: rest::Command) {
     match cmd {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&ctx.registry.read(), &ctx.workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
 
         rest::Command::ClearPackets(chain_id, reply) => {
+            let registry = ctx.registry.read();
+            let workers = ctx.workers.acquire_read();
+
             if let Some(chain_id) = chain_id {
                 info!("clearing packets for chain {chain_id} after REST request");
 
-                clear_pending_packets(workers, &chain_id)
+                clear_pending_packets(&workers, &chain_id)
                     .unwrap_or_else(|e| error!("error clearing packets for chain {chain_id}: {e}"));
             } else {
                 for chain_id in registry.chains().map(|c| c.id()) {
                     info!("clearing packets for chain {chain_id} after REST request");
 
-                    clear_pending_packets(workers, &chain_id).unwrap_or_else(|e| {
+                    clear_pending_packets(&workers, &chain_id).unwrap_or_else(|e| {
                         error!("error clearing packets for chain {chain_id}: {e}")
                     });
                 }
@@ -773,9 +992,177 @@ fn handle_rest_cmd<Chain: ChainHandle>(
                 .send(Ok(()))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
+
+        rest::Command::Reload(reply) => {
+            info!("reloading configuration after REST request");
+
+            let result = reload_config(ctx).map_err(|e| {
+                error!("failed to reload configuration: {e}");
+                RestApiError::Reload(e.to_string())
+            });
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::GetWorkers(reply) => {
+            let workers = ctx
+                .workers
+                .acquire_read()
+                .handles()
+                .map(WorkerDesc::from_handle)
+                .collect();
+
+            reply
+                .send(Ok(workers))
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::StopWorker(worker_id, reply) => {
+            let mut workers = ctx.workers.acquire_write();
+
+            let result = match workers.find(worker_id).map(WorkerDesc::from_handle) {
+                Some(desc) => {
+                    info!(
+                        "stopping worker {} after REST request",
+                        desc.object.short_name()
+                    );
+
+                    workers.shutdown_worker(&desc.object);
+                    Ok(desc)
+                }
+                None => Err(RestApiError::WorkerNotFound(worker_id)),
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::PauseWorker(worker_id, reply) => {
+            let workers = ctx.workers.acquire_read();
+
+            let result = match workers.find(worker_id) {
+                Some(handle) => {
+                    info!(
+                        "pausing worker {} after REST request",
+                        handle.object().short_name()
+                    );
+
+                    handle.pause();
+                    Ok(WorkerDesc::from_handle(handle))
+                }
+                None => Err(RestApiError::WorkerNotFound(worker_id)),
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::ResumeWorker(worker_id, reply) => {
+            let workers = ctx.workers.acquire_read();
+
+            let result = match workers.find(worker_id) {
+                Some(handle) => {
+                    info!(
+                        "resuming worker {} after REST request",
+                        handle.object().short_name()
+                    );
+
+                    handle.resume();
+                    Ok(WorkerDesc::from_handle(handle))
+                }
+                None => Err(RestApiError::WorkerNotFound(worker_id)),
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::SpawnPacketWorker(chain_id, port_id, channel_id, reply) => {
+            info!("spawning packet worker for {port_id}/{channel_id} on chain {chain_id} after REST request");
+
+            let result = spawn_packet_worker(ctx, &chain_id, &port_id, &channel_id).map_err(|e| {
+                error!("failed to spawn packet worker for {port_id}/{channel_id} on chain {chain_id}: {e}");
+                RestApiError::SpawnWorker(e.to_string())
+            });
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::UpdateClient(chain_id, client_id, reply) => {
+            info!("updating client {client_id} on chain {chain_id} after REST request");
+
+            let result = update_client(ctx, &chain_id, &client_id).map_err(|e| {
+                error!("failed to update client {client_id} on chain {chain_id}: {e}");
+                RestApiError::UpdateClient(e.to_string())
+            });
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
     }
 }
 
+/// Spawn a packet worker for the given channel on the given chain,
+/// and describe the worker in charge of it.
+fn spawn_packet_worker<Chain: ChainHandle>(
+    ctx: &SupervisorContext<Chain>,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<WorkerDesc, Error> {
+    let config = ctx.config.acquire_read();
+    let mut registry = ctx.registry.write();
+    let mut workers = ctx.workers.acquire_write();
+
+    let chain = registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+    spawn_context(&config, &mut registry, &mut workers)
+        .spawn_packet_worker(chain, port_id, channel_id)
+        .map(WorkerDesc::from_handle)
+}
+
+/// Update the given client on the given chain to the latest height of its counterparty chain.
+fn update_client<Chain: ChainHandle>(
+    ctx: &SupervisorContext<Chain>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+) -> Result<Vec<IbcEvent>, Error> {
+    // Do not hold the lock on the registry while the client is being updated
+    let (chain, counterparty_chain) = {
+        let mut registry = ctx.registry.write();
+
+        let chain = registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+        let (client_state, _) = chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)?;
+
+        let counterparty_chain = registry
+            .get_or_spawn(&client_state.chain_id())
+            .map_err(Error::spawn)?;
+
+        (chain, counterparty_chain)
+    };
+
+    ForeignClient::restore(client_id.clone(), chain, counterparty_chain)
+        .build_latest_update_client_and_send()
+        .map_err(Error::foreign_client)
+}
+
 #[instrument(
     name = "supervisor.clear_pending_packets",
     level = "error",
@@ -811,6 +1198,10 @@ fn process_batch<Chain: ChainHandle>(
 
     let collected = collect_events(config, workers, &src_chain, batch);
 
+    if config.mode.packets.enabled {
+        observe_packets_completed(config, batch);
+    }
+
     // If there is a NewBlock event, forward this event first to any workers affected by it.
     if let Some(IbcEvent::NewBlock(new_block)) = collected.new_block {
         workers.notify_new_block(&src_chain.id(), batch.height, new_block);
@@ -858,12 +1249,21 @@ fn process_batch<Chain: ChainHandle>(
             continue;
         }
 
-        if let Object::Packet(ref _path) = object {
+        if let Object::Packet(ref path) = object {
+            for event in &events_with_heights {
+                lifecycle::global().observe(
+                    &path.src_chain_id,
+                    Some(&path.dst_chain_id),
+                    event,
+                    batch.tracking_id,
+                );
+            }
+
             telemetry!(send_telemetry(
                 &src_chain,
                 &dst_chain,
                 &events_with_heights,
-                _path
+                path
             ));
         }
 
@@ -937,6 +1337,31 @@ fn send_telemetry<Src, Dst>(
     }
 }
 
+/// Record the packets sent from the chain of the batch which got acknowledged or timed out,
+/// whose events are not forwarded to any worker.
+fn observe_packets_completed(config: &Config, batch: &EventBatch) {
+    for event_with_height in &batch.events {
+        let packet = match &event_with_height.event {
+            IbcEvent::AcknowledgePacket(ack) => &ack.packet,
+            IbcEvent::TimeoutOnClosePacket(timeout) => &timeout.packet,
+            _ => continue,
+        };
+
+        if config.packets_on_channel_allowed(
+            &batch.chain_id,
+            &packet.source_port,
+            &packet.source_channel,
+        ) {
+            lifecycle::global().observe(
+                &batch.chain_id,
+                None,
+                event_with_height,
+                batch.tracking_id,
+            );
+        }
+    }
+}
+
 /// Process the given batch if it does not contain any errors,
 /// output the errors on the console otherwise.
 #[instrument(
@@ -957,11 +1382,19 @@ fn handle_batch<Chain: ChainHandle>(
 
     match batch.deref() {
         Ok(batch) => {
+            sink::ibc_events(&batch.chain_id, batch.height, &batch.events);
+
             if let Err(e) =
                 process_batch(config, registry, client_state_filter, workers, chain, batch)
             {
                 error!("error during batch processing: {}", e);
             }
+
+            if let Some(store) = state::global() {
+                store
+                    .set_processed_height(&chain_id, batch.height)
+                    .unwrap_or_else(|e| warn!("failed to record the processed height: {e}"));
+            }
         }
         Err(EventError(EventErrorDetail::SubscriptionCancelled(_), _)) => {
             warn!("event subscription was cancelled, clearing pending packets");
@@ -1,8 +1,14 @@
 use crossbeam_channel::Sender;
 
 use super::dump_state::SupervisorState;
+use super::reload::ConfigDiff;
+use super::Error;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
     DumpState(Sender<SupervisorState>),
+
+    /// Reload the configuration from the configuration file,
+    /// and reply with the changes which were applied.
+    Reload(Sender<Result<ConfigDiff, Error>>),
 }
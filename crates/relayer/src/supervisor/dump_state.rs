@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::{
+    chain::endpoint::NodeStatus,
     object::{Object, ObjectType},
     worker::{WorkerData, WorkerHandle, WorkerId},
 };
@@ -16,11 +17,26 @@ pub struct WorkerDesc {
     pub id: WorkerId,
     pub object: Object,
     pub data: Option<WorkerData>,
+    #[serde(default)]
+    pub paused: bool,
 }
 
 impl WorkerDesc {
     pub fn new(id: WorkerId, object: Object, data: Option<WorkerData>) -> Self {
-        Self { id, object, data }
+        Self {
+            id,
+            object,
+            data,
+            paused: false,
+        }
+    }
+
+    /// Describe the worker with the given handle
+    pub fn from_handle(handle: &WorkerHandle) -> Self {
+        Self {
+            paused: handle.is_paused(),
+            ..Self::new(handle.id(), handle.object().clone(), handle.data().cloned())
+        }
     }
 }
 
@@ -28,6 +44,8 @@ impl WorkerDesc {
 pub struct SupervisorState {
     pub chains: Vec<ChainId>,
     pub workers: BTreeMap<ObjectType, Vec<WorkerDesc>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub nodes: BTreeMap<ChainId, Vec<NodeStatus>>,
 }
 
 impl SupervisorState {
@@ -38,13 +56,24 @@ impl SupervisorState {
         chains.sort();
 
         let workers = workers
-            .map(|h| WorkerDesc::new(h.id(), h.object().clone(), h.data().cloned()))
+            .map(WorkerDesc::from_handle)
             .into_group_map_by(|desc| desc.object.object_type())
             .into_iter()
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
             .collect::<BTreeMap<_, _>>();
 
-        Self { chains, workers }
+        Self {
+            chains,
+            workers,
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    /// Include the status of the full nodes of the chains
+    /// which are configured with backup nodes.
+    pub fn with_nodes(mut self, nodes: BTreeMap<ChainId, Vec<NodeStatus>>) -> Self {
+        self.nodes = nodes;
+        self
     }
 
     pub fn print_info(&self) {
@@ -58,10 +87,24 @@ impl Display for SupervisorState {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         writeln!(f)?;
         writeln!(f, "* Chains: {}", self.chains.iter().join(", "))?;
+        for (chain_id, nodes) in &self.nodes {
+            writeln!(f, "* Full nodes of {chain_id}:")?;
+            for node in nodes {
+                let status = if node.healthy { "healthy" } else { "unhealthy" };
+                let active = if node.active { ", active" } else { "" };
+                writeln!(f, "  - {} ({status}{active})", node.rpc_addr)?;
+            }
+        }
         for (tpe, objects) in &self.workers {
             writeln!(f, "* {tpe:?} workers:")?;
             for desc in objects {
-                writeln!(f, "  - {} (id: {})", desc.object.short_name(), desc.id)?;
+                let paused = if desc.paused { ", paused" } else { "" };
+                writeln!(
+                    f,
+                    "  - {} (id: {}{paused})",
+                    desc.object.short_name(),
+                    desc.id
+                )?;
                 if let Some(WorkerData::Client {
                     misbehaviour,
                     refresh,
@@ -3,7 +3,9 @@ use flex_error::define_error;
 use ibc_relayer_types::core::ics03_connection::connection::Counterparty;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
 
+use crate::config::Error as ConfigError;
 use crate::error::Error as RelayerError;
+use crate::foreign_client::ForeignClientError;
 use crate::spawn::SpawnError;
 use crate::supervisor::scan::Error as ScanError;
 
@@ -59,6 +61,10 @@ define_error! {
             [ RelayerError ]
             |_| { "relayer error" },
 
+        ForeignClient
+            [ ForeignClientError ]
+            |_| { "foreign client error" },
+
         NoChainsAvailable
             |_| { "supervisor was not able to connect to any chains" },
 
@@ -75,6 +81,17 @@ define_error! {
 
         HandleRecv
             |_| { "failed to receive the result of a command from the supervisor through a channel" },
+
+        ReloadUnavailable
+            |_| { "cannot reload the configuration, as the supervisor was not given the path to the configuration file" },
+
+        LoadConfig
+            [ ConfigError ]
+            |_| { "failed to load the configuration file" },
+
+        InvalidConfig
+            [ ConfigError ]
+            |_| { "the new configuration is invalid, keeping the current configuration" },
     }
 }
 
@@ -0,0 +1,259 @@
+//! Reloading the configuration of a running supervisor.
+//!
+//! Only the parts of the configuration which differ between the current and the
+//! new configuration are applied: the chain runtimes of the added chains are spawned,
+//! the ones of the removed chains are shut down, and the ones of the updated chains
+//! are restarted along with the workers relaying to or from them.
+//!
+//! The settings which are only read by the workers when they are spawned, such as
+//! the packet filter of a chain, the `[mode]` section or the `[prices]` section,
+//! do not require restarting the chain runtimes: changing them only restarts the
+//! workers relaying to or from the affected chains. The other workers keep running
+//! untouched.
+//!
+//! Changes to the `[global]`, `[rest]`, `[telemetry]`, `[tracing_server]`, `[state]`
+//! and `[[sinks]]` sections are not applied and require a restart of Hermes.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{self, ChainConfig, Config, Diagnostic};
+use crate::util::diff::{gdiff, Change};
+
+use super::Error;
+
+/// The changes to the chains of the relayer between two configurations
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigDiff {
+    /// The chains which are only present in the new configuration
+    pub added: Vec<ChainId>,
+
+    /// The chains whose configuration changed, and which must be restarted
+    pub updated: Vec<ChainId>,
+
+    /// The chains whose configuration only changed in the settings read by the workers,
+    /// and whose workers must be restarted while their runtime keeps running
+    pub reconfigured: Vec<ChainId>,
+
+    /// The chains which are only present in the current configuration
+    pub removed: Vec<ChainId>,
+}
+
+impl ConfigDiff {
+    /// Compute the changes between the `prev` and `next` configurations.
+    ///
+    /// Changing the `[mode]` or `[prices]` sections affects the workers of every chain,
+    /// so all the chains present in both configurations are then deemed reconfigured.
+    pub fn new(prev: &Config, next: &Config) -> Self {
+        let prev_chains = prev.chains_map();
+        let next_chains = next.chains_map();
+
+        let mut diff = Self::default();
+
+        for change in gdiff(&prev_chains, &next_chains, |a, b| a == b) {
+            match change {
+                Change::Added(id) => diff.added.push((*id).clone()),
+                Change::Updated(id) => {
+                    if only_worker_settings_differ(prev_chains[id], next_chains[id]) {
+                        diff.reconfigured.push((*id).clone())
+                    } else {
+                        diff.updated.push((*id).clone())
+                    }
+                }
+                Change::Removed(id) => diff.removed.push((*id).clone()),
+            }
+        }
+
+        if prev.mode != next.mode || prev.prices != next.prices {
+            let unchanged = prev_chains
+                .keys()
+                .filter(|id| {
+                    next_chains.contains_key(*id)
+                        && !diff.updated.contains(*id)
+                        && !diff.reconfigured.contains(*id)
+                })
+                .map(|id| (*id).clone())
+                .collect::<Vec<_>>();
+
+            diff.reconfigured.extend(unchanged);
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.updated.is_empty()
+            && self.reconfigured.is_empty()
+            && self.removed.is_empty()
+    }
+
+    /// The chains whose runtime must be shut down
+    pub fn stopped_chains(&self) -> impl Iterator<Item = &ChainId> {
+        self.updated.iter().chain(self.removed.iter())
+    }
+
+    /// The chains whose runtime must be spawned
+    pub fn started_chains(&self) -> impl Iterator<Item = &ChainId> {
+        self.added.iter().chain(self.updated.iter())
+    }
+
+    /// The chains whose workers must be shut down
+    pub fn stopped_workers(&self) -> impl Iterator<Item = &ChainId> {
+        self.stopped_chains().chain(self.reconfigured.iter())
+    }
+
+    /// The chains which must be scanned again to spawn their workers
+    pub fn scanned_chains(&self) -> impl Iterator<Item = &ChainId> {
+        self.started_chains().chain(self.reconfigured.iter())
+    }
+}
+
+/// Whether the two configurations of a chain only differ in the settings which are
+/// read by the workers when they are spawned, rather than by the chain runtime.
+fn only_worker_settings_differ(prev: &ChainConfig, next: &ChainConfig) -> bool {
+    let mut prev = prev.clone();
+
+    match (&mut prev, next) {
+        (ChainConfig::CosmosSdk(prev), ChainConfig::CosmosSdk(next))
+        | (ChainConfig::Namada(prev), ChainConfig::Namada(next)) => {
+            prev.packet_filter = next.packet_filter.clone();
+            prev.clear_interval = next.clear_interval;
+            prev.excluded_sequences = next.excluded_sequences.clone();
+        }
+        (ChainConfig::Penumbra(prev), ChainConfig::Penumbra(next)) => {
+            prev.packet_filter = next.packet_filter.clone();
+            prev.clear_interval = next.clear_interval;
+        }
+        #[cfg(any(test, feature = "mock-chain"))]
+        (ChainConfig::Mock(prev), ChainConfig::Mock(next)) => {
+            prev.packet_filter = next.packet_filter.clone();
+            prev.clear_interval = next.clear_interval;
+        }
+        _ => return false,
+    }
+
+    prev == *next
+}
+
+/// Load the configuration file at the given path and validate it,
+/// logging any warning raised by the validation.
+pub fn load_config(path: &Path) -> Result<Config, Error> {
+    let config = config::load(path).map_err(Error::load_config)?;
+
+    match config.validate_config() {
+        Ok(()) => {}
+        Err(Diagnostic::Warning(e)) => warn!("relayer may be misconfigured: {e}"),
+        Err(Diagnostic::Error(e)) => return Err(Error::invalid_config(e)),
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::{load, ChainConfig};
+
+    fn example_config() -> Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    fn chain_id(id: &str) -> ChainId {
+        ChainId::from_string(id)
+    }
+
+    #[test]
+    fn same_config_is_empty() {
+        let config = example_config();
+
+        assert!(ConfigDiff::new(&config, &config.clone()).is_empty());
+    }
+
+    #[test]
+    fn added_updated_removed_chains() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        let mut added = next.chains[1].clone();
+        if let ChainConfig::CosmosSdk(config) = &mut added {
+            config.id = chain_id("chain_C");
+        }
+
+        next.chains.remove(1);
+        next.chains.push(added);
+
+        if let ChainConfig::CosmosSdk(config) = &mut next.chains[0] {
+            config.max_gas = Some(400000);
+        }
+
+        let diff = ConfigDiff::new(&prev, &next);
+
+        assert_eq!(diff.added, vec![chain_id("chain_C")]);
+        assert_eq!(diff.updated, vec![chain_id("chain_A")]);
+        assert!(diff.reconfigured.is_empty());
+        assert_eq!(diff.removed, vec![chain_id("chain_B")]);
+    }
+
+    #[test]
+    fn worker_settings_change_reconfigures_chain() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        if let ChainConfig::CosmosSdk(config) = &mut next.chains[0] {
+            config.clear_interval = Some(42);
+            config.packet_filter = Default::default();
+        }
+
+        let diff = ConfigDiff::new(&prev, &next);
+
+        assert!(diff.added.is_empty());
+        assert!(diff.updated.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.reconfigured, vec![chain_id("chain_A")]);
+    }
+
+    #[test]
+    fn runtime_and_worker_settings_change_updates_chain() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        if let ChainConfig::CosmosSdk(config) = &mut next.chains[0] {
+            config.clear_interval = Some(42);
+            config.max_gas = Some(400000);
+        }
+
+        let diff = ConfigDiff::new(&prev, &next);
+
+        assert_eq!(diff.updated, vec![chain_id("chain_A")]);
+        assert!(diff.reconfigured.is_empty());
+    }
+
+    #[test]
+    fn mode_change_reconfigures_all_chains() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        next.mode.packets.clear_interval += 1;
+
+        let diff = ConfigDiff::new(&prev, &next);
+
+        assert!(diff.added.is_empty());
+        assert!(diff.updated.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.reconfigured,
+            vec![chain_id("chain_A"), chain_id("chain_B")]
+        );
+    }
+}
@@ -3,17 +3,21 @@ use tracing::{error, info};
 use ibc_relayer_types::core::{
     ics03_connection::connection::IdentifiedConnectionEnd,
     ics04_channel::channel::State as ChannelState,
+    ics24_host::identifier::{ChannelId, PortId},
 };
 
 use crate::{
-    chain::{counterparty::connection_state_on_destination, handle::ChainHandle},
+    chain::{
+        counterparty::{channel_connection_client, connection_state_on_destination},
+        handle::ChainHandle,
+    },
     client_state::IdentifiedAnyClientState,
     config::Config,
     object::{Channel, Client, Connection, Object, Packet, Wallet},
     registry::Registry,
     supervisor::error::Error as SupervisorError,
     telemetry,
-    worker::WorkerMap,
+    worker::{WorkerHandle, WorkerMap},
 };
 
 use super::{
@@ -212,6 +216,48 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
         }
     }
 
+    /// Spawn a packet worker for the given channel of the given source chain,
+    /// regardless of the packet filter and of whether there are pending packets.
+    ///
+    /// Returns the handle to the worker, which may have been spawned already.
+    pub fn spawn_packet_worker(
+        &mut self,
+        chain: Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<&WorkerHandle, Error> {
+        let channel_connection_client = channel_connection_client(&chain, port_id, channel_id)?;
+
+        let channel_end = &channel_connection_client.channel.channel_end;
+        if !channel_end.is_open() {
+            return Err(Error::channel_uninitialized(
+                port_id.clone(),
+                channel_id.clone(),
+                chain.id(),
+            ));
+        }
+
+        let counterparty_chain = self
+            .registry
+            .get_or_spawn(&channel_connection_client.client.client_state.chain_id())
+            .map_err(SupervisorError::spawn)?;
+
+        let path_object = Object::Packet(Packet {
+            dst_chain_id: counterparty_chain.id(),
+            src_chain_id: chain.id(),
+            src_channel_id: channel_id.clone(),
+            src_port_id: port_id.clone(),
+        });
+
+        let worker = self
+            .workers
+            .get_or_spawn(path_object, chain, counterparty_chain, self.config);
+
+        info!("spawned packet worker: {}", worker.object().short_name());
+
+        Ok(worker)
+    }
+
     /// Spawns all the [`WorkerHandle`](crate::worker::WorkerHandle)s that will
     /// handle a given channel for a given source chain.
     pub fn spawn_workers_for_channel(
@@ -8,9 +8,12 @@ use flex_error::{define_error, DetailOnly};
 use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::applications::transfer::error::Error as Ics20Error;
-use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
-use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{
+    Forwarding, MsgTransfer, MsgTransferV2,
+};
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
 use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+use ibc_relayer_types::core::ics04_channel::version::Version;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::signer::Signer;
@@ -19,10 +22,13 @@ use ibc_relayer_types::tx_msg::Msg;
 
 use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
 use crate::chain::tracking::TrackedMsgs;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 
+pub mod forward;
+
 define_error! {
     TransferError {
         ReceiverAddress
@@ -68,6 +74,33 @@ define_error! {
 
         ZeroTimeout
             | _ | { "packet timeout height and packet timeout timestamp cannot both be 0" },
+
+        InvalidRoute
+            { reason: String }
+            |e| {
+                format!("invalid forwarding route: {}", e.reason)
+            },
+
+        InvalidMemo
+            { reason: String }
+            |e| {
+                format!("invalid memo: {}", e.reason)
+            },
+
+        MissingCounterpartyChannel
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId }
+            |e| {
+                format!("the counterparty of channel {}/{} on chain {} has no channel identifier",
+                    e.port_id, e.channel_id, e.chain_id)
+            },
+
+        Ics20V2Required
+            { chain_id: ChainId, port_id: PortId, channel_id: ChannelId, version: Version }
+            |e| {
+                format!("transferring several tokens or forwarding them requires an ICS20 v2 channel, \
+                    but channel {}/{} on chain {} has version '{}'",
+                    e.port_id, e.channel_id, e.chain_id, e.version)
+            },
     }
 }
 
@@ -127,6 +160,17 @@ pub struct TransferOptions {
     pub timeout_duration: Duration,
     pub number_msgs: usize,
     pub memo: Option<String>,
+    /// Tokens transferred along with `amount` of `denom`, over an ICS20 v2 channel
+    pub additional_tokens: Vec<RawCoin>,
+    /// Hops through which the tokens are forwarded by the destination chain, over an ICS20 v2 channel
+    pub forwarding: Option<Forwarding>,
+}
+
+impl TransferOptions {
+    /// Returns true if the transfer can only be sent over an ICS20 v2 channel.
+    pub fn requires_ics20_v2(&self) -> bool {
+        !self.additional_tokens.is_empty() || self.forwarding.is_some()
+    }
 }
 
 pub fn build_transfer_message(
@@ -157,6 +201,35 @@ pub fn build_transfer_message(
     msg.to_any()
 }
 
+/// Builds a transfer message for several tokens at once, optionally forwarded
+/// through other chains by the destination chain. Unless a single token is
+/// transferred without forwarding, the message requires an ICS20 v2 channel.
+pub fn build_transfer_message_v2(
+    src_port_id: PortId,
+    src_channel_id: ChannelId,
+    tokens: Vec<Coin>,
+    sender: Signer,
+    receiver: Signer,
+    timeout_height: TimeoutHeight,
+    timeout_timestamp: Timestamp,
+    memo: Option<String>,
+    forwarding: Option<Forwarding>,
+) -> Any {
+    let msg = MsgTransferV2 {
+        source_port: src_port_id,
+        source_channel: src_channel_id,
+        tokens,
+        sender,
+        receiver,
+        timeout_height,
+        timeout_timestamp,
+        memo,
+        forwarding,
+    };
+
+    msg.to_any()
+}
+
 pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
     src_chain: &SrcChain, // the chain whose account is debited
     dst_chain: &DstChain, // the chain whose account eventually gets credited
@@ -169,6 +242,10 @@ pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
 
     let sender = src_chain.get_signer().map_err(TransferError::key)?;
 
+    if opts.requires_ics20_v2() {
+        check_ics20_v2_channel(src_chain, &opts.src_port_id, &opts.src_channel_id)?;
+    }
+
     let destination_chain_status = dst_chain
         .query_application_status()
         .map_err(TransferError::relayer)?;
@@ -179,23 +256,79 @@ pub fn build_transfer_messages<SrcChain: ChainHandle, DstChain: ChainHandle>(
         &destination_chain_status,
     )?;
 
-    let message = build_transfer_message(
-        opts.src_port_id.clone(),
-        opts.src_channel_id.clone(),
-        opts.amount,
-        opts.denom.clone(),
-        sender,
-        receiver,
-        timeout.timeout_height,
-        timeout.timeout_timestamp,
-        opts.memo.clone(),
-    );
+    let message = if opts.requires_ics20_v2() {
+        let token = Coin {
+            denom: opts.denom.clone(),
+            amount: opts.amount.to_string(),
+        };
+
+        let tokens = core::iter::once(token)
+            .chain(opts.additional_tokens.iter().map(|coin| Coin {
+                denom: coin.denom.clone(),
+                amount: coin.amount.to_string(),
+            }))
+            .collect();
+
+        build_transfer_message_v2(
+            opts.src_port_id.clone(),
+            opts.src_channel_id.clone(),
+            tokens,
+            sender,
+            receiver,
+            timeout.timeout_height,
+            timeout.timeout_timestamp,
+            opts.memo.clone(),
+            opts.forwarding.clone(),
+        )
+    } else {
+        build_transfer_message(
+            opts.src_port_id.clone(),
+            opts.src_channel_id.clone(),
+            opts.amount,
+            opts.denom.clone(),
+            sender,
+            receiver,
+            timeout.timeout_height,
+            timeout.timeout_timestamp,
+            opts.memo.clone(),
+        )
+    };
 
     let msgs = vec![message; opts.number_msgs];
 
     Ok(msgs)
 }
 
+/// Checks that the given channel is an ICS20 v2 channel,
+/// over which several tokens can be sent at once and forwarded.
+fn check_ics20_v2_channel<Chain: ChainHandle>(
+    chain: &Chain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), TransferError> {
+    let (channel_end, _) = chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(TransferError::relayer)?;
+
+    if channel_end.version().is_ics20_v2() {
+        Ok(())
+    } else {
+        Err(TransferError::ics20_v2_required(
+            chain.id(),
+            port_id.clone(),
+            channel_id.clone(),
+            channel_end.version().clone(),
+        ))
+    }
+}
+
 pub fn send_messages<Chain: ChainHandle>(
     chain: &Chain,
     msgs: Vec<Any>,
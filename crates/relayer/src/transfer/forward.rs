@@ -0,0 +1,778 @@
+//! Multi-hop token transfers through the packet-forward-middleware (PFM).
+//!
+//! A transfer to a chain without a direct channel from the source chain is sent to the
+//! first chain of a route, with a memo instructing each chain of the route to forward the
+//! tokens over the given channel, until they reach the destination chain:
+//!
+//! ```json
+//! {
+//!   "forward": {
+//!     "receiver": "<address on the next chain>",
+//!     "port": "transfer",
+//!     "channel": "channel-1",
+//!     "timeout": "600s",
+//!     "retries": 2,
+//!     "next": { "forward": { ... } }
+//!   }
+//! }
+//! ```
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use serde::Serialize;
+use serde_json::Value;
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement;
+use ibc_relayer_types::applications::transfer::denom::{is_receiver_chain_source, TracePrefix};
+use ibc_relayer_types::applications::transfer::packet::TransferPacketData;
+use ibc_relayer_types::applications::transfer::{BaseDenom, PrefixedDenom};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::signer::Signer;
+
+use super::{build_transfer_message, TransferError, TransferOptions, TransferTimeout};
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, Paginate, Qualified, QueryChannelRequest, QueryHeight,
+    QueryPacketCommitmentsRequest, QueryPacketEventDataRequest, QueryUnreceivedPacketsRequest,
+};
+use crate::denom::ibc_denom;
+use crate::event::IbcEventWithHeight;
+use crate::path::PathIdentifiers;
+use crate::util::pretty::PrettySlice;
+
+/// A chain of a forwarding route, along with the channel over which it forwards the
+/// tokens to the next chain of the route, or to the destination chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteHop {
+    pub chain_id: ChainId,
+    pub channel_id: ChannelId,
+}
+
+impl FromStr for RouteHop {
+    type Err = TransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (chain_id, channel_id) = s.rsplit_once(':').ok_or_else(|| {
+            TransferError::invalid_route(format!("expected `CHAIN_ID:CHANNEL_ID`, got `{s}`"))
+        })?;
+
+        if chain_id.is_empty() {
+            return Err(TransferError::invalid_route(format!(
+                "missing chain identifier in `{s}`"
+            )));
+        }
+
+        let channel_id = ChannelId::from_str(channel_id)
+            .map_err(|e| TransferError::invalid_route(format!("in `{s}`: {e}")))?;
+
+        Ok(Self {
+            chain_id: ChainId::from_string(chain_id),
+            channel_id,
+        })
+    }
+}
+
+impl Display for RouteHop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}:{}", self.chain_id, self.channel_id)
+    }
+}
+
+/// The intermediate chains through which the tokens are forwarded, in order,
+/// e.g. `chain-b:channel-1,chain-c:channel-7`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardRoute(pub Vec<RouteHop>);
+
+impl ForwardRoute {
+    pub fn hops(&self) -> &[RouteHop] {
+        &self.0
+    }
+}
+
+impl FromStr for ForwardRoute {
+    type Err = TransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hops = s
+            .split(',')
+            .map(|hop| RouteHop::from_str(hop.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(hops))
+    }
+}
+
+impl Display for ForwardRoute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let hops = self
+            .0
+            .iter()
+            .map(|hop| hop.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(f, "{hops}")
+    }
+}
+
+/// A `forward` instruction of the memo, executed by a chain of the route.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardHop {
+    /// The port over which the tokens are forwarded
+    pub port_id: PortId,
+    /// The channel over which the tokens are forwarded
+    pub channel_id: ChannelId,
+    /// The address receiving the tokens at the other end of the channel
+    pub receiver: Signer,
+    /// How long the middleware waits for the forwarded packet to be relayed
+    /// before it times out, the middleware default if not specified
+    pub timeout: Option<Duration>,
+    /// How many times the middleware retries forwarding the packet after
+    /// a timeout, the middleware default if not specified
+    pub retries: Option<u8>,
+}
+
+#[derive(Serialize)]
+struct ForwardMemo {
+    forward: ForwardMetadata,
+}
+
+#[derive(Serialize)]
+struct ForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retries: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next: Option<Value>,
+}
+
+/// Build the nested `forward` memo for the given hops, in order.
+///
+/// The optional `next` memo, which must be a JSON object, is included in the
+/// transfer to the destination chain, e.g. to trigger a contract call there.
+pub fn build_forward_memo(
+    hops: &[ForwardHop],
+    next: Option<&str>,
+) -> Result<String, TransferError> {
+    let mut next = next
+        .map(|memo| match serde_json::from_str(memo) {
+            Ok(value @ Value::Object(_)) => Ok(value),
+            _ => Err(TransferError::invalid_memo(
+                "the memo of a forwarded transfer must be a JSON object".to_string(),
+            )),
+        })
+        .transpose()?;
+
+    if hops.is_empty() {
+        return Err(TransferError::invalid_route(
+            "the route must contain at least one hop".to_string(),
+        ));
+    }
+
+    for hop in hops.iter().rev() {
+        let memo = ForwardMemo {
+            forward: ForwardMetadata {
+                receiver: hop.receiver.to_string(),
+                port: hop.port_id.to_string(),
+                channel: hop.channel_id.to_string(),
+                timeout: hop.timeout.map(go_duration),
+                retries: hop.retries,
+                next,
+            },
+        };
+
+        next = Some(
+            serde_json::to_value(memo).map_err(|e| TransferError::invalid_memo(e.to_string()))?,
+        );
+    }
+
+    // `next` holds the memo of the first hop at this point
+    Ok(next.map(|memo| memo.to_string()).unwrap_or_default())
+}
+
+/// Format a duration as expected by the middleware, which parses it as a Go `time.Duration`.
+fn go_duration(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        format!("{}s", duration.as_secs())
+    } else {
+        format!("{}ns", duration.as_nanos())
+    }
+}
+
+/// The denomination of the tokens received at the end of the given channel, when
+/// tokens of the given denomination are sent from its source end (see ICS20).
+pub fn receive_denom(denom: &PrefixedDenom, path: &PathIdentifiers) -> PrefixedDenom {
+    let mut denom = denom.clone();
+
+    let source_prefix = TracePrefix::new(
+        path.counterparty_port_id.clone(),
+        path.counterparty_channel_id.clone(),
+    );
+
+    if is_receiver_chain_source(
+        path.counterparty_port_id.clone(),
+        path.counterparty_channel_id.clone(),
+        &denom,
+    ) {
+        // The tokens go back towards the chain they came from, unwind the trace
+        denom.remove_trace_prefix(&source_prefix);
+    } else {
+        denom.add_trace_prefix(TracePrefix::new(
+            path.port_id.clone(),
+            path.channel_id.clone(),
+        ));
+    }
+
+    denom
+}
+
+/// One of the transfers making up a forwarded transfer, between two consecutive
+/// chains of the route.
+#[derive(Clone, Debug, Serialize)]
+pub struct TransferLeg {
+    /// The chain receiving the tokens
+    pub chain_id: ChainId,
+    /// The port and channel on the sending chain (`counterparty_*`) and on the receiving chain
+    pub path: PathIdentifiers,
+    /// The address receiving the tokens on the receiving chain
+    pub receiver: Signer,
+    /// The denomination of the tokens in the packet data, i.e. as seen by the sending chain
+    #[serde(serialize_with = "serialize_denom")]
+    pub denom: PrefixedDenom,
+}
+
+fn serialize_denom<S: serde::Serializer>(
+    denom: &PrefixedDenom,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(denom)
+}
+
+#[derive(Clone, Debug)]
+pub struct ForwardTransferOptions {
+    pub transfer: TransferOptions,
+    pub route: ForwardRoute,
+    /// The timeout of the forwarded packets, applied to every hop
+    pub hop_timeout: Option<Duration>,
+    /// The number of retries of the forwarded packets, applied to every hop
+    pub hop_retries: Option<u8>,
+}
+
+/// The messages of a forwarded transfer, along with the legs
+/// it is expected to follow to reach the destination chain.
+#[derive(Clone, Debug)]
+pub struct ForwardTransfer {
+    pub messages: Vec<Any>,
+    pub legs: Vec<TransferLeg>,
+    /// The denomination of the tokens received on the destination chain
+    pub final_denom: PrefixedDenom,
+}
+
+impl ForwardTransfer {
+    /// The denomination under which the tokens are held on the destination chain
+    pub fn final_ibc_denom(&self) -> String {
+        ibc_denom(&self.final_denom)
+    }
+}
+
+/// Build the messages of a transfer from `src_chain` to `dst_chain`, forwarded through the
+/// chains of the route. `hop_chains` are the handles of these chains, in the order of the route.
+pub fn build_forward_transfer_messages<Chain: ChainHandle>(
+    src_chain: &Chain,
+    hop_chains: &[Chain],
+    dst_chain: &Chain,
+    opts: &ForwardTransferOptions,
+) -> Result<ForwardTransfer, TransferError> {
+    let route = opts.route.hops();
+
+    if route.is_empty() {
+        return Err(TransferError::invalid_route(
+            "the route must contain at least one hop".to_string(),
+        ));
+    }
+
+    if route.len() != hop_chains.len()
+        || route
+            .iter()
+            .zip(hop_chains)
+            .any(|(hop, chain)| hop.chain_id != chain.id())
+    {
+        return Err(TransferError::invalid_route(format!(
+            "expected handles for the chains of the route {}, got {}",
+            opts.route,
+            PrettySlice(&hop_chains.iter().map(|c| c.id()).collect::<Vec<_>>())
+        )));
+    }
+
+    let final_receiver = match &opts.transfer.receiver {
+        Some(receiver) => Signer::from_str(receiver).map_err(TransferError::receiver_address)?,
+        None => dst_chain.get_signer().map_err(TransferError::key)?,
+    };
+
+    // The receivers at the end of each leg: the relayer wallets on the chains of the
+    // route, which the middleware refunds if forwarding fails, then the final receiver.
+    let mut receivers = hop_chains
+        .iter()
+        .map(|chain| chain.get_signer().map_err(TransferError::key))
+        .collect::<Result<Vec<_>, _>>()?;
+    receivers.push(final_receiver);
+
+    let mut denom = source_denom(src_chain, &opts.transfer.denom)?;
+    let mut port_id = opts.transfer.src_port_id.clone();
+    let mut channel_id = opts.transfer.src_channel_id.clone();
+    let mut legs = Vec::with_capacity(route.len() + 1);
+
+    for (i, receiver) in receivers.into_iter().enumerate() {
+        let sending_chain = if i == 0 {
+            src_chain
+        } else {
+            &hop_chains[i - 1]
+        };
+        let chain_id = hop_chains.get(i).unwrap_or(dst_chain).id();
+
+        let path = counterparty_path(sending_chain, &port_id, &channel_id)?;
+        let next_denom = receive_denom(&denom, &path);
+
+        // The chains of the route forward the tokens over the port they received them on
+        if let Some(hop) = route.get(i) {
+            port_id = path.port_id.clone();
+            channel_id = hop.channel_id.clone();
+        }
+
+        legs.push(TransferLeg {
+            chain_id,
+            path,
+            receiver,
+            denom,
+        });
+
+        denom = next_denom;
+    }
+
+    let hops = legs[1..]
+        .iter()
+        .map(|leg| ForwardHop {
+            port_id: leg.path.counterparty_port_id.clone(),
+            channel_id: leg.path.counterparty_channel_id.clone(),
+            receiver: leg.receiver.clone(),
+            timeout: opts.hop_timeout,
+            retries: opts.hop_retries,
+        })
+        .collect::<Vec<_>>();
+
+    let memo = build_forward_memo(&hops, opts.transfer.memo.as_deref())?;
+
+    let sender = src_chain.get_signer().map_err(TransferError::key)?;
+
+    // The packet sent by the source chain times out on the first chain of the route
+    let first_hop_status = hop_chains[0]
+        .query_application_status()
+        .map_err(TransferError::relayer)?;
+
+    let timeout = TransferTimeout::new(
+        opts.transfer.timeout_height_offset,
+        opts.transfer.timeout_duration,
+        &first_hop_status,
+    )?;
+
+    let message = build_transfer_message(
+        opts.transfer.src_port_id.clone(),
+        opts.transfer.src_channel_id.clone(),
+        opts.transfer.amount,
+        opts.transfer.denom.clone(),
+        sender,
+        legs[0].receiver.clone(),
+        timeout.timeout_height,
+        timeout.timeout_timestamp,
+        Some(memo),
+    );
+
+    Ok(ForwardTransfer {
+        messages: vec![message; opts.transfer.number_msgs],
+        legs,
+        final_denom: denom,
+    })
+}
+
+/// The full trace of the given denomination on the source chain
+fn source_denom<Chain: ChainHandle>(
+    src_chain: &Chain,
+    denom: &str,
+) -> Result<PrefixedDenom, TransferError> {
+    match denom.strip_prefix("ibc/") {
+        Some(hash) => {
+            let trace = src_chain
+                .query_denom_trace(hash.to_string())
+                .map_err(TransferError::relayer)?;
+
+            let full_denom = if trace.path.is_empty() {
+                trace.base_denom
+            } else {
+                format!("{}/{}", trace.path, trace.base_denom)
+            };
+
+            PrefixedDenom::from_str(&full_denom).map_err(TransferError::token_transfer)
+        }
+        None => BaseDenom::from_str(denom)
+            .map(PrefixedDenom::from)
+            .map_err(TransferError::token_transfer),
+    }
+}
+
+/// The identifiers of the given channel, along with those of its counterparty
+/// as `counterparty_*`, which is the receiving end of the transfers over it.
+fn counterparty_path<Chain: ChainHandle>(
+    chain: &Chain,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<PathIdentifiers, TransferError> {
+    let (channel_end, _) = chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(TransferError::relayer)?;
+
+    let counterparty = channel_end.counterparty();
+
+    let counterparty_channel_id = counterparty.channel_id().cloned().ok_or_else(|| {
+        TransferError::missing_counterparty_channel(chain.id(), port_id.clone(), channel_id.clone())
+    })?;
+
+    // The path is seen from the receiving end, so that `counterparty_*` is the sending end
+    Ok(PathIdentifiers {
+        port_id: counterparty.port_id().clone(),
+        channel_id: counterparty_channel_id,
+        counterparty_port_id: port_id.clone(),
+        counterparty_channel_id: channel_id.clone(),
+    })
+}
+
+/// The progress of the packet of a leg of a forwarded transfer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LegStatus {
+    /// The packet has not been received yet on the receiving chain of the leg
+    Pending,
+    /// The packet was received, and either forwarded to the next chain of the route,
+    /// or is being forwarded
+    Received,
+    /// The receiving chain acknowledged the packet, after the tokens reached the
+    /// destination chain or the transfer failed further along the route
+    Acknowledged { success: bool },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LegProgress {
+    pub chain_id: ChainId,
+    pub sequence: Sequence,
+    #[serde(flatten)]
+    pub status: LegStatus,
+}
+
+/// The progress of a forwarded transfer along its route, up to the last leg
+/// the transfer is known to have reached.
+#[derive(Clone, Debug, Serialize)]
+pub struct ForwardTransferProgress {
+    pub legs: Vec<LegProgress>,
+}
+
+impl ForwardTransferProgress {
+    /// Whether the transfer reached the destination chain, or failed along the route
+    pub fn is_complete(&self, transfer: &ForwardTransfer) -> bool {
+        match self.legs.last() {
+            Some(last) => match last.status {
+                LegStatus::Acknowledged { .. } => true,
+                LegStatus::Received => self.legs.len() == transfer.legs.len(),
+                LegStatus::Pending => false,
+            },
+            None => false,
+        }
+    }
+}
+
+/// Follow the packet with the given sequence, sent by the source chain for the given
+/// forwarded transfer, along the route, from the packet events of each chain.
+pub fn track_forward_transfer<Chain: ChainHandle>(
+    hop_chains: &[Chain],
+    dst_chain: &Chain,
+    transfer: &ForwardTransfer,
+    sequence: Sequence,
+) -> Result<ForwardTransferProgress, TransferError> {
+    let receiving_chains = hop_chains.iter().chain(core::iter::once(dst_chain));
+
+    let mut progress = Vec::with_capacity(transfer.legs.len());
+    let mut sequence = sequence;
+
+    for (i, (leg, chain)) in transfer.legs.iter().zip(receiving_chains).enumerate() {
+        let status = leg_status(chain, leg, sequence)?;
+
+        progress.push(LegProgress {
+            chain_id: leg.chain_id.clone(),
+            sequence,
+            status: status.clone(),
+        });
+
+        if status != LegStatus::Received {
+            break;
+        }
+
+        // Look for the packet forwarded by the receiving chain over the next leg
+        let forwarded = match transfer.legs.get(i + 1) {
+            Some(next_leg) => forwarded_sequence(chain, next_leg)?,
+            None => None,
+        };
+
+        match forwarded {
+            Some(forwarded) => sequence = forwarded,
+            None => break,
+        }
+    }
+
+    Ok(ForwardTransferProgress { legs: progress })
+}
+
+fn leg_status<Chain: ChainHandle>(
+    chain: &Chain,
+    leg: &TransferLeg,
+    sequence: Sequence,
+) -> Result<LegStatus, TransferError> {
+    let unreceived = chain
+        .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+            port_id: leg.path.port_id.clone(),
+            channel_id: leg.path.channel_id.clone(),
+            packet_commitment_sequences: vec![sequence],
+        })
+        .map_err(TransferError::relayer)?;
+
+    if unreceived.contains(&sequence) {
+        return Ok(LegStatus::Pending);
+    }
+
+    // The chains of the route only acknowledge the packet once the forwarded packet
+    // is acknowledged, while the destination chain acknowledges it upon receipt.
+    let events = query_leg_events(chain, leg, WithBlockDataType::WriteAck, vec![sequence])?;
+
+    let status = events
+        .into_iter()
+        .find_map(|event| match event.event {
+            IbcEvent::WriteAcknowledgement(write_ack) => Some(LegStatus::Acknowledged {
+                success: matches!(
+                    serde_json::from_slice::<Acknowledgement>(&write_ack.ack),
+                    Ok(Acknowledgement::Success(_))
+                ),
+            }),
+            _ => None,
+        })
+        .unwrap_or(LegStatus::Received);
+
+    Ok(status)
+}
+
+/// The sequence of the packet forwarded over the given leg by its sending chain, found
+/// among the packets sent over the leg which are still in flight, from their data.
+fn forwarded_sequence<Chain: ChainHandle>(
+    chain: &Chain,
+    leg: &TransferLeg,
+) -> Result<Option<Sequence>, TransferError> {
+    let (in_flight, _) = chain
+        .query_packet_commitments(QueryPacketCommitmentsRequest {
+            query_height: QueryHeight::Latest,
+            port_id: leg.path.counterparty_port_id.clone(),
+            channel_id: leg.path.counterparty_channel_id.clone(),
+            pagination: Paginate::All,
+        })
+        .map_err(TransferError::relayer)?;
+
+    if in_flight.is_empty() {
+        return Ok(None);
+    }
+
+    let (channel_end, _) = chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: leg.path.counterparty_port_id.clone(),
+                channel_id: leg.path.counterparty_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(TransferError::relayer)?;
+
+    let events = query_leg_events(chain, leg, WithBlockDataType::SendPacket, in_flight)?;
+
+    let sequence = events.into_iter().find_map(|event| match event.event {
+        IbcEvent::SendPacket(send_packet) => {
+            let data =
+                TransferPacketData::decode(&send_packet.packet.data, channel_end.version()).ok()?;
+
+            let sends_denom = data.tokens().iter().any(|token| token.denom == leg.denom);
+
+            (data.receiver() == &leg.receiver && sends_denom).then_some(send_packet.packet.sequence)
+        }
+        _ => None,
+    });
+
+    Ok(sequence)
+}
+
+fn query_leg_events<Chain: ChainHandle>(
+    chain: &Chain,
+    leg: &TransferLeg,
+    event_id: WithBlockDataType,
+    sequences: Vec<Sequence>,
+) -> Result<Vec<IbcEventWithHeight>, TransferError> {
+    chain
+        .query_packet_events(QueryPacketEventDataRequest {
+            event_id,
+            source_channel_id: leg.path.counterparty_channel_id.clone(),
+            source_port_id: leg.path.counterparty_port_id.clone(),
+            destination_channel_id: leg.path.channel_id.clone(),
+            destination_port_id: leg.path.port_id.clone(),
+            sequences,
+            height: Qualified::SmallerEqual(QueryHeight::Latest),
+        })
+        .map_err(TransferError::relayer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hop(receiver: &str, channel: &str) -> ForwardHop {
+        ForwardHop {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::from_str(channel).unwrap(),
+            receiver: Signer::from_str(receiver).unwrap(),
+            timeout: None,
+            retries: None,
+        }
+    }
+
+    #[test]
+    fn parse_route() {
+        let route = ForwardRoute::from_str("chain-b:channel-1, chain-c:channel-7").unwrap();
+
+        assert_eq!(
+            route.hops(),
+            &[
+                RouteHop {
+                    chain_id: ChainId::from_string("chain-b"),
+                    channel_id: ChannelId::new(1),
+                },
+                RouteHop {
+                    chain_id: ChainId::from_string("chain-c"),
+                    channel_id: ChannelId::new(7),
+                },
+            ]
+        );
+        assert_eq!(route.to_string(), "chain-b:channel-1,chain-c:channel-7");
+
+        assert!(ForwardRoute::from_str("chain-b").is_err());
+        assert!(ForwardRoute::from_str(":channel-1").is_err());
+        assert!(ForwardRoute::from_str("chain-b:channel-1,").is_err());
+    }
+
+    #[test]
+    fn single_hop_memo() {
+        let mut hop = hop("cosmos1receiver", "channel-1");
+        hop.timeout = Some(Duration::from_secs(600));
+        hop.retries = Some(2);
+
+        let memo = build_forward_memo(&[hop], None).unwrap();
+
+        assert_eq!(
+            memo,
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":"600s","retries":2}}"#
+        );
+    }
+
+    #[test]
+    fn nested_memo() {
+        let memo = build_forward_memo(
+            &[
+                hop("cosmos1middle", "channel-1"),
+                hop("osmo1receiver", "channel-7"),
+            ],
+            Some(r#"{"wasm":{"contract":"osmo1contract"}}"#),
+        )
+        .unwrap();
+
+        let memo: Value = serde_json::from_str(&memo).unwrap();
+
+        assert_eq!(
+            memo,
+            serde_json::json!({
+                "forward": {
+                    "receiver": "cosmos1middle",
+                    "port": "transfer",
+                    "channel": "channel-1",
+                    "next": {
+                        "forward": {
+                            "receiver": "osmo1receiver",
+                            "port": "transfer",
+                            "channel": "channel-7",
+                            "next": { "wasm": { "contract": "osmo1contract" } }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn memo_must_be_an_object() {
+        assert!(build_forward_memo(&[hop("cosmos1receiver", "channel-1")], Some("memo")).is_err());
+        assert!(build_forward_memo(&[], None).is_err());
+    }
+
+    #[test]
+    fn denom_trace_along_route() {
+        // A --(channel-0 -> channel-10)--> B --(channel-1 -> channel-20)--> C
+        let a_to_b = PathIdentifiers {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(10),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id: ChannelId::new(0),
+        };
+        let b_to_c = PathIdentifiers {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(20),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id: ChannelId::new(1),
+        };
+
+        let native = PrefixedDenom::from_str("uatom").unwrap();
+
+        let on_b = receive_denom(&native, &a_to_b);
+        assert_eq!(on_b.to_string(), "transfer/channel-10/uatom");
+
+        let on_c = receive_denom(&on_b, &b_to_c);
+        assert_eq!(
+            on_c.to_string(),
+            "transfer/channel-20/transfer/channel-10/uatom"
+        );
+
+        // Sending the tokens back from B to A unwinds the trace
+        let b_to_a = PathIdentifiers {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id: ChannelId::new(10),
+        };
+        assert_eq!(receive_denom(&on_b, &b_to_a), native);
+    }
+}
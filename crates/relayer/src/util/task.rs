@@ -1,5 +1,6 @@
 use core::fmt::Display;
 use core::mem;
+use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
 use crossbeam_channel::{bounded, Sender};
 use std::sync::{Arc, RwLock};
@@ -22,9 +23,14 @@ use crate::util::lock::LockExt;
 pub struct TaskHandle {
     shutdown_sender: Sender<()>,
     stopped: Arc<RwLock<bool>>,
+    paused: Arc<AtomicBool>,
     join_handle: DropJoinHandle,
 }
 
+/// How long a paused background task sleeps before checking whether it was resumed,
+/// when it was not given an interval to sleep for between two steps.
+const PAUSED_TASK_INTERVAL: Duration = Duration::from_millis(500);
+
 /**
    A wrapper to [`std::thread::JoinHandle`] so that the handle is joined
    when it is dropped.
@@ -95,6 +101,9 @@ pub fn spawn_background_task<E: Display>(
     let stopped = Arc::new(RwLock::new(false));
     let write_stopped = stopped.clone();
 
+    let paused = Arc::new(AtomicBool::new(false));
+    let read_paused = paused.clone();
+
     let (shutdown_sender, receiver) = bounded(1);
 
     let join_handle = thread::spawn(move || {
@@ -104,6 +113,10 @@ pub fn spawn_background_task<E: Display>(
                 Ok(()) => {
                     break;
                 }
+                _ if read_paused.load(Ordering::Acquire) => {
+                    thread::sleep(interval_pause.unwrap_or(PAUSED_TASK_INTERVAL));
+                    continue;
+                }
                 _ => match step_runner() {
                     Ok(Next::Continue) => {}
                     Ok(Next::Abort) => {
@@ -132,6 +145,7 @@ pub fn spawn_background_task<E: Display>(
     TaskHandle {
         shutdown_sender,
         stopped,
+        paused,
         join_handle: DropJoinHandle(Some(join_handle)),
     }
 }
@@ -173,6 +187,28 @@ impl TaskHandle {
         let _ = self.shutdown_sender.send(());
     }
 
+    /**
+       Pause the background task, which stops calling the step runner
+       until the task is [resumed](TaskHandle::resume) or shut down.
+    */
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+    }
+
+    /**
+       Resume a background task which was [paused](TaskHandle::pause).
+    */
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Release);
+    }
+
+    /**
+       Check whether a background task is paused.
+    */
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
     /**
        Check whether a background task has been stopped prematurely.
     */
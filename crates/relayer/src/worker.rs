@@ -32,6 +32,7 @@ pub mod client;
 pub mod connection;
 pub mod cross_chain_query;
 pub mod packet;
+pub mod profitability;
 pub mod wallet;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -116,6 +117,11 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 .unwrap_or_default()
                 .to_vec();
 
+            let ics20_filter = config
+                .find_chain(&chains.a.id())
+                .map(|chain_config| chain_config.packet_filter().ics20.clone())
+                .unwrap_or_default();
+
             let packets_config = config.mode.packets;
             let link_res = Link::new_from_opts(
                 chains.a.clone(),
@@ -126,6 +132,8 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                     max_memo_size: packets_config.ics20_max_memo_size,
                     max_receiver_size: packets_config.ics20_max_receiver_size,
                     exclude_src_sequences,
+                    ics20_filter,
+                    verify_proofs: packets_config.verify_proofs,
                 },
                 packets_config.tx_confirmation,
                 packets_config.auto_register_counterparty_payee,
@@ -169,13 +177,25 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                     let resubmit = Resubmit::from_clear_interval(clear_interval);
 
+                    let profitability = fee_filter
+                        .as_ref()
+                        .and_then(|filter| filter.profitability())
+                        .map(|policy| {
+                            Arc::new(profitability::Profitability::new(
+                                policy.clone(),
+                                &config.prices,
+                            ))
+                        });
+
                     let (clear_cmd_tx, clear_cmd_rx) = crossbeam_channel::unbounded();
                     let clear_task = packet::spawn_clear_cmd_worker(
                         cmd_rx,
                         link.clone(),
+                        path.clone(),
                         should_clear_on_start,
                         clear_interval,
                         config.mode.packets.clear_limit,
+                        profitability.clone(),
                         clear_cmd_tx,
                     );
                     task_handles.push(clear_task);
@@ -187,6 +207,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                             link.clone(),
                             path.clone(),
                             filter,
+                            profitability,
                         ),
                         None => packet::spawn_packet_cmd_worker(
                             clear_cmd_rx,
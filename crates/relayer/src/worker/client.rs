@@ -12,7 +12,7 @@ use crate::util::retry::clamp_total;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::ChainHandle,
-    foreign_client::{ForeignClient, MisbehaviourResults},
+    foreign_client::{ForeignClient, HasExpiredOrFrozenError, MisbehaviourResults},
 };
 
 use super::WorkerCmd;
@@ -25,13 +25,15 @@ const MAX_REFRESH_TOTAL_DELAY: Duration = Duration::from_secs(60 * 60 * 24); //
 pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
     mut client: ForeignClient<ChainA, ChainB>,
 ) -> Option<TaskHandle> {
-    if client.is_expired_or_frozen() {
-        warn!(
-            client = %client.id,
-            "skipping refresh client task on frozen client",
-        );
-
-        return None;
+    if let Err(e) = client.check_expiry() {
+        if e.is_expired_or_frozen_error() {
+            warn!(
+                client = %client.id,
+                "skipping refresh client task on frozen client",
+            );
+
+            return None;
+        }
     }
 
     Some(spawn_background_task(
@@ -13,6 +13,7 @@ use ibc_relayer_types::{
 
 use crate::chain::tracking::TrackingId;
 use crate::event::IbcEventWithHeight;
+use crate::sink::{self, WorkerStatus};
 use crate::util::lock::{LockExt, RwArc};
 use crate::util::task::TaskHandle;
 use crate::{event::source::EventBatch, object::Object};
@@ -51,6 +52,11 @@ impl WorkerHandle {
     }
 
     pub fn try_send_command(&self, cmd: WorkerCmd) {
+        if self.is_paused() {
+            trace!(worker = %self.object.short_name(), "dropping command sent to paused worker");
+            return;
+        }
+
         let res = if let Some(tx) = self.tx.acquire_read().as_ref() {
             tx.send(cmd)
         } else {
@@ -107,6 +113,34 @@ impl WorkerHandle {
         // Drop handle automatically handles the waiting for tasks to terminate.
     }
 
+    /// Pause all worker tasks. The commands sent to the worker
+    /// while it is paused are dropped.
+    pub fn pause(&self) {
+        for task in self.task_handles.iter() {
+            task.pause()
+        }
+
+        sink::worker(self.id, &self.object, WorkerStatus::Paused);
+    }
+
+    /// Resume all worker tasks. A packet worker then clears the pending packets,
+    /// to relay the packets whose events were dropped while it was paused.
+    pub fn resume(&self) {
+        for task in self.task_handles.iter() {
+            task.resume()
+        }
+
+        sink::worker(self.id, &self.object, WorkerStatus::Resumed);
+
+        if matches!(self.object, Object::Packet(_)) {
+            self.clear_pending_packets();
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        !self.task_handles.is_empty() && self.task_handles.iter().all(|t| t.is_paused())
+    }
+
     pub fn is_stopped(&self) -> bool {
         for task in self.task_handles.iter() {
             if !task.is_stopped() {
@@ -10,6 +10,7 @@ use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
     object::Object,
+    sink::{self, WorkerStatus},
     telemetry,
 };
 
@@ -65,6 +66,8 @@ impl WorkerMap {
                     "worker loop has ended"
                 );
 
+                sink::worker(id, &object, WorkerStatus::Stopped);
+
                 true
             }
             Some(handle) => {
@@ -89,6 +92,11 @@ impl WorkerMap {
         }
     }
 
+    /// Get the handle to the worker with the given [`WorkerId`], if any.
+    pub fn find(&self, id: WorkerId) -> Option<&WorkerHandle> {
+        self.workers.values().find(|w| w.id() == id)
+    }
+
     /// Returns all the [`WorkerHandle`] which are interested in new block events originating
     /// from the chain with the given [`ChainId`].
     /// See: [`Object::notify_new_block`]
@@ -171,12 +179,18 @@ impl WorkerMap {
     ) -> WorkerHandle {
         telemetry!(worker, metric_type(object), 1);
 
-        spawn_worker_tasks(
+        let id = self.next_worker_id();
+
+        let handle = spawn_worker_tasks(
             ChainHandlePair { a: src, b: dst },
-            self.next_worker_id(),
+            id,
             object.clone(),
             config,
-        )
+        );
+
+        sink::worker(id, object, WorkerStatus::Spawned);
+
+        handle
     }
 
     /// Compute the next worker id
@@ -214,11 +228,23 @@ impl WorkerMap {
         if let Some(handle) = self.workers.remove(object) {
             telemetry!(worker, metric_type(object), -1);
 
+            let id = handle.id();
             handle.shutdown_and_wait();
+
+            sink::worker(id, object, WorkerStatus::Stopped);
         }
         // Drop handle automatically handles the waiting for tasks to terminate.
     }
 
+    /// Shutdown the worker with the given [`WorkerId`], synchronously.
+    ///
+    /// Returns the [`Object`] the worker was in charge of, if such a worker was found.
+    pub fn shutdown_worker_by_id(&mut self, id: WorkerId) -> Option<Object> {
+        let object = self.find(id)?.object().clone();
+        self.shutdown_worker(&object);
+        Some(object)
+    }
+
     /// Shut down all the workers, asynchronously.
     pub fn shutdown(&mut self) {
         let workers = mem::take(&mut self.workers);
@@ -1,5 +1,6 @@
 use core::time::Duration;
 use std::borrow::BorrowMut;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use crossbeam_channel::{Receiver, Sender};
@@ -10,6 +11,7 @@ use tracing::{debug, error, error_span, info, trace, warn};
 use ibc_proto::ibc::apps::fee::v1::{IdentifiedPacketFees, QueryIncentivizedPacketRequest};
 use ibc_proto::ibc::core::channel::v1::PacketId;
 use ibc_relayer_types::applications::ics29_fee::events::IncentivizedPacket;
+use ibc_relayer_types::applications::ics29_fee::packet_fee;
 use ibc_relayer_types::applications::transfer::{Amount, Coin, RawCoin};
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use ibc_relayer_types::core::ics04_channel::events::WriteAcknowledgement;
@@ -21,18 +23,21 @@ use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::QueryHeight;
-use crate::config::filter::FeePolicy;
+use crate::config::filter::{FeePolicy, UnprofitableAction};
 use crate::event::source::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::HasExpiredOrFrozenError;
-use crate::link::Resubmit;
 use crate::link::{error::LinkError, Link};
+use crate::link::{PacketRelayFees, RelayPath, Resubmit};
 use crate::object::Packet;
+use crate::sink;
+use crate::state;
 use crate::telemetry;
 use crate::util::lock::{LockExt, RwArc};
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 
 use super::error::RunError;
+use super::profitability::{Decision, Profitability};
 use super::WorkerCmd;
 
 const INCENTIVIZED_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
@@ -148,6 +153,7 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     path: Packet,
     fee_filter: FeePolicy,
+    profitability: Option<Arc<Profitability>>,
 ) -> TaskHandle {
     let span = {
         let relay_path = &link.lock().unwrap().a_to_b;
@@ -169,6 +175,14 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
             .build(),
     );
 
+    // This Cache will store the SendPacket events which were deferred because relaying
+    // them was not profitable, along with the estimated fees of relaying them, until
+    // their fees are increased or they expire.
+    let deferred_cache: Cache<Sequence, (IbcEventWithHeight, PacketRelayFees)> = Cache::builder()
+        .time_to_live(INCENTIVIZED_CACHE_TTL)
+        .max_capacity(INCENTIVIZED_CACHE_MAX_CAPACITY)
+        .build();
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             handle_incentivized_packet_cmd(
@@ -177,6 +191,7 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
                 cmd,
                 &incentivized_recv_cache,
                 &fee_filter,
+                profitability.as_deref().map(|p| (p, &deferred_cache)),
             )?;
         }
 
@@ -188,9 +203,11 @@ pub fn spawn_clear_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd_rx: Receiver<WorkerCmd>,
     // Mutex is used to prevent race condition between the packet workers
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
+    path: Packet,
     mut should_clear_on_start: bool,
     clear_interval: u64,
     clear_limit: usize,
+    profitability: Option<Arc<Profitability>>,
     clear_cmd_tx: Sender<WorkerCmd>,
 ) -> TaskHandle {
     let span = {
@@ -232,6 +249,8 @@ pub fn spawn_clear_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                 &mut should_clear_on_start,
                 clear_interval,
                 clear_limit,
+                &path,
+                profitability.as_deref(),
                 cmd,
             )?;
 
@@ -284,7 +303,9 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             )
             .ok();
 
-            if *should_clear_on_start || next_sequence < lowest_sequence {
+            if (*should_clear_on_start || next_sequence < lowest_sequence)
+                && !link.a_to_b.has_restored_txs_in_flight()
+            {
                 handle_clear_packet(link, clear_interval, path, Some(batch.height), clear_limit)?;
             }
         }
@@ -304,11 +325,16 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
 ///
 /// Given a `NewBlock` command, checks if packet clearing should occur
 /// and performs it if so.
+///
+/// If a profitability policy is given, the incentivized packets which are
+/// not profitable to relay are left to a later clearing.
 fn handle_clear_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     should_clear_on_start: &mut bool,
     clear_interval: u64,
     clear_limit: usize,
+    path: &Packet,
+    profitability: Option<&Profitability>,
     cmd: WorkerCmd,
 ) -> Result<(), TaskError<RunError>> {
     // Handle packet clearing which is triggered from a command
@@ -342,17 +368,42 @@ fn handle_clear_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     };
 
     if do_clear {
-        info!("packets clearing triggered, looking for packets to clear");
+        // Clearing packets while the transactions submitted before a restart are still
+        // in flight would relay their packets again, so wait for them to be committed.
+        if link.a_to_b.has_restored_txs_in_flight() {
+            info!("transactions submitted before restart are still in flight, deferring packets clearing");
+            return Ok(());
+        }
 
         // Reset the `clear_on_start` flag and attempt packet clearing once now.
         // More clearing will be done at clear interval.
         if *should_clear_on_start {
             *should_clear_on_start = false;
+
+            if !matches!(cmd, WorkerCmd::ClearPendingPackets)
+                && resumes_from_state(link, clear_interval)
+            {
+                info!("packets were cleared before restart and missed events are replayed, skipping clearing on start");
+                return Ok(());
+            }
         }
 
-        link.a_to_b
-            .schedule_packet_clearing(maybe_height, clear_limit)
-            .map_err(handle_link_error_in_task)?;
+        info!("packets clearing triggered, looking for packets to clear");
+
+        let relay_path = &link.a_to_b;
+
+        let cleared = match profitability {
+            Some(profitability) => {
+                relay_path.schedule_packet_clearing_with(maybe_height, clear_limit, |events| {
+                    filter_unprofitable_cleared(relay_path, events, path, profitability)
+                })
+            }
+            None => relay_path.schedule_packet_clearing(maybe_height, clear_limit),
+        };
+
+        cleared.map_err(handle_link_error_in_task)?;
+
+        record_cleared_height(link, maybe_height);
     }
 
     Ok(())
@@ -374,6 +425,10 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     cmd: WorkerCmd,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
     fee_filter: &FeePolicy,
+    profitability: Option<(
+        &Profitability,
+        &Cache<Sequence, (IbcEventWithHeight, PacketRelayFees)>,
+    )>,
 ) -> Result<(), TaskError<RunError>> {
     // Handle command-specific task
     if let WorkerCmd::IbcEvents { mut batch } = cmd {
@@ -394,6 +449,18 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
         }
         filter_batch(batch.borrow_mut(), incentivized_recv_cache, fee_filter);
+
+        if let Some((profitability, deferred_cache)) = profitability {
+            filter_unprofitable(
+                &link.a_to_b,
+                batch.borrow_mut(),
+                path,
+                incentivized_recv_cache,
+                profitability,
+                deferred_cache,
+            );
+        }
+
         handle_update_schedule(link, 0, path, batch)
     } else {
         Ok(())
@@ -464,6 +531,188 @@ fn filter_batch(
     });
 }
 
+/// Remove from the batch the SendPacket events whose fees do not cover the estimated cost
+/// of relaying them, deferring them if the policy says so, and add back the deferred
+/// events which became profitable, eg. because their fees were increased.
+///
+/// The cost of relaying a packet is estimated once, when its event is first seen.
+fn filter_unprofitable<ChainA: ChainHandle, ChainB: ChainHandle>(
+    relay_path: &RelayPath<ChainA, ChainB>,
+    batch: &mut EventBatch,
+    path: &Packet,
+    incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
+    profitability: &Profitability,
+    deferred_cache: &Cache<Sequence, (IbcEventWithHeight, PacketRelayFees)>,
+) {
+    let fees_of = |sequence: &Sequence| incentivized_recv_cache.acquire_read().get(sequence);
+
+    // The deferred packets which became profitable are relayed at the height of the
+    // current batch, as the events they were sent in may be too old to be proven
+    let readmitted: Vec<_> = deferred_cache
+        .iter()
+        .filter(|(sequence, (_, estimate))| {
+            fees_of(sequence).is_some_and(|incentivized_event| {
+                profitability.evaluate(&incentivized_event, estimate) == Decision::Profitable
+            })
+        })
+        .map(|(sequence, (event, _))| {
+            info!(%sequence, "relaying deferred packet which became profitable");
+
+            deferred_cache.invalidate(&*sequence);
+            IbcEventWithHeight::new(event.event, batch.height)
+        })
+        .collect();
+
+    retain_profitable(
+        relay_path,
+        &mut batch.events,
+        path,
+        profitability,
+        fees_of,
+        |sequence, event, estimate| {
+            if profitability.unprofitable_action() == UnprofitableAction::Defer {
+                deferred_cache.insert(sequence, (event.clone(), estimate));
+            }
+        },
+    );
+
+    batch.events.extend(readmitted);
+}
+
+/// Remove from the SendPacket events of the packets to clear the ones whose fees do not
+/// cover the estimated cost of relaying them, as for the packets of new events.
+///
+/// The fees of the packets are queried from the source chain, since the `IncentivizedPacket`
+/// events of the packets to clear may not have been observed by the relayer. The packets
+/// which are not relayed are evaluated again the next time packets are cleared.
+fn filter_unprofitable_cleared<ChainA: ChainHandle, ChainB: ChainHandle>(
+    relay_path: &RelayPath<ChainA, ChainB>,
+    events: &mut Vec<IbcEventWithHeight>,
+    path: &Packet,
+    profitability: &Profitability,
+) {
+    let incentivized_packets = match relay_path.src_chain().query_incentivized_packets(
+        relay_path.src_channel_id().clone(),
+        relay_path.src_port_id().clone(),
+    ) {
+        Ok(incentivized_packets) => incentivized_packets,
+        Err(e) => {
+            warn!("relaying the packets to clear whose fees cannot be queried: {e}");
+            return;
+        }
+    };
+
+    let incentivized_packets: HashMap<Sequence, IncentivizedPacket> = incentivized_packets
+        .into_iter()
+        .map(|fees| (fees.packet_id.sequence, incentivized_packet(fees)))
+        .collect();
+
+    retain_profitable(
+        relay_path,
+        events,
+        path,
+        profitability,
+        |sequence| incentivized_packets.get(sequence).cloned(),
+        |_, _, _| {},
+    );
+}
+
+/// The total fees escrowed for a packet, as they are reported by the `IncentivizedPacket` event
+fn incentivized_packet(fees: packet_fee::IdentifiedPacketFees) -> IncentivizedPacket {
+    let total = |fee_of: fn(&packet_fee::PacketFee) -> &Vec<RawCoin>| -> Vec<RawCoin> {
+        fees.packet_fees
+            .iter()
+            .flat_map(|fee| fee_of(fee).iter().cloned())
+            .collect()
+    };
+
+    IncentivizedPacket {
+        port_id: fees.packet_id.port_id.clone(),
+        channel_id: fees.packet_id.channel_id.clone(),
+        sequence: fees.packet_id.sequence,
+        total_recv_fee: total(|fee| &fee.fee.recv_fee),
+        total_ack_fee: total(|fee| &fee.fee.ack_fee),
+        total_timeout_fee: total(|fee| &fee.fee.timeout_fee),
+    }
+}
+
+/// Remove from `events` the SendPacket events of the incentivized packets, whose fees are
+/// given by `fees_of`, which do not cover the estimated cost of relaying them, calling
+/// `on_unprofitable` with the sequence of each of them and the estimated fees of relaying it.
+fn retain_profitable<ChainA: ChainHandle, ChainB: ChainHandle>(
+    relay_path: &RelayPath<ChainA, ChainB>,
+    events: &mut Vec<IbcEventWithHeight>,
+    path: &Packet,
+    profitability: &Profitability,
+    fees_of: impl Fn(&Sequence) -> Option<IncentivizedPacket>,
+    mut on_unprofitable: impl FnMut(Sequence, &IbcEventWithHeight, PacketRelayFees),
+) {
+    let mut unprofitable = 0;
+
+    events.retain(|e| {
+        let IbcEvent::SendPacket(packet) = &e.event else {
+            return true;
+        };
+
+        let sequence = packet.packet.sequence;
+
+        let Some(incentivized_event) = fees_of(&sequence) else {
+            return true;
+        };
+
+        let estimate = match relay_path.estimate_packet_relay_fees(packet, e.height) {
+            Ok(Some(estimate)) => estimate,
+            Ok(None) => return true,
+            Err(err) => {
+                warn!(
+                    %sequence,
+                    "relaying packet whose cost cannot be estimated: {err}"
+                );
+                return true;
+            }
+        };
+
+        match profitability.evaluate(&incentivized_event, &estimate) {
+            Decision::Unprofitable { fees, cost } => {
+                debug!(
+                    %sequence,
+                    "fees worth {fees} do not cover the estimated cost of {cost} to relay packet"
+                );
+
+                on_unprofitable(sequence, e, estimate);
+
+                unprofitable += 1;
+                false
+            }
+            Decision::Unknown(reason) => {
+                warn!(
+                    %sequence,
+                    "relaying packet whose profitability cannot be evaluated: {reason}"
+                );
+                true
+            }
+            Decision::Profitable => true,
+        }
+    });
+
+    if unprofitable > 0 {
+        let decision = match profitability.unprofitable_action() {
+            UnprofitableAction::Defer => "deferred",
+            UnprofitableAction::Skip => "skipped",
+        };
+
+        telemetry!(
+            unprofitable_packets,
+            &path.src_chain_id,
+            &path.dst_chain_id,
+            &path.src_channel_id,
+            &path.src_port_id,
+            decision,
+            unprofitable
+        );
+    }
+}
+
 /// Multiple fees with different denoms can be specified as rewards,
 /// in an `IncentivizedPacket`. This method extract all and groups all
 /// the fees with the same denom.
@@ -492,6 +741,69 @@ fn should_clear_packets(clear_interval: u64, height: Height) -> bool {
     clear_interval != 0 && height.revision_height() % clear_interval == 0
 }
 
+/// Whether clearing packets on start can be skipped, because the packets of the path
+/// were cleared before Hermes was restarted and the events emitted by the source chain
+/// since then are fetched again from its last processed height.
+///
+/// Only unordered channels which are periodically cleared are considered, so that
+/// packets which were being relayed when Hermes stopped are still cleared eventually.
+fn resumes_from_state<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
+    clear_interval: u64,
+) -> bool {
+    if clear_interval == 0 || link.a_to_b.channel().ordering == Ordering::Ordered {
+        return false;
+    }
+
+    let Some(store) = state::global() else {
+        return false;
+    };
+
+    let chain_id = link.a_to_b.src_chain().id();
+
+    store.has_resumed(&chain_id)
+        && matches!(
+            store.cleared_height(
+                &chain_id,
+                link.a_to_b.src_port_id(),
+                link.a_to_b.src_channel_id()
+            ),
+            Ok(Some(_))
+        )
+}
+
+/// Record in the relay state store the height at which the packets of the path were cleared.
+fn record_cleared_height<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
+    height: Option<Height>,
+) {
+    let Some(store) = state::global() else {
+        return;
+    };
+
+    let relay_path = &link.a_to_b;
+
+    let height = match height {
+        Some(height) => height,
+        None => match relay_path.src_chain().query_latest_height() {
+            Ok(height) => height,
+            Err(e) => {
+                warn!("failed to query the latest height to record packets clearing: {e}");
+                return;
+            }
+        },
+    };
+
+    if let Err(e) = store.set_cleared_height(
+        &relay_path.src_chain().id(),
+        relay_path.src_port_id(),
+        relay_path.src_channel_id(),
+        height,
+    ) {
+        warn!("failed to record the height at which packets were cleared: {e}");
+    }
+}
+
 fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
     clear_interval: u64,
@@ -516,12 +828,14 @@ fn handle_clear_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
         .schedule_packet_clearing(height, clear_limit)
         .map_err(handle_link_error_in_task)?;
 
+    record_cleared_height(link, height);
+
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
 
 fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
-    _path: &Packet,
+    path: &Packet,
     resubmit: Resubmit,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
@@ -542,8 +856,10 @@ fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     if !summary.is_empty() {
         trace!("produced relay summary: {:?}", summary);
 
+        sink::relay_summary(path, &summary.events);
+
         telemetry!(packet_metrics(
-            _path,
+            path,
             &summary,
             &link.a_to_b.path_id.counterparty_channel_id,
             &link.a_to_b.path_id.counterparty_port_id
@@ -0,0 +1,438 @@
+//! Evaluation of the profitability of relaying incentivized (ICS-29) packets.
+//!
+//! The fees escrowed for a packet are compared with the estimated fees of relaying it,
+//! both valued with the prices configured in the `[prices]` section. A packet which is
+//! received on the destination chain earns its `recv` and `ack` fees, and costs the fees
+//! of the transaction updating the client and receiving the packet there. A packet which
+//! timed out earns its `timeout` fee instead, and costs the fees of the transaction
+//! updating the client and timing out the packet on the source chain.
+//!
+//! The values are computed exactly, from the amounts of the coins and the prices of their
+//! denominations as written in decimal notation, so that rounding never decides whether a
+//! packet is relayed.
+//!
+//! The fees of a transaction are estimated by simulating it for each packet
+//! (see [`RelayPath::estimate_packet_relay_fees`]). The fees of the `MsgAcknowledgement`,
+//! which cannot be simulated before the packet is received, are not part of the cost,
+//! and can be accounted for with the `min_margin` of the policy.
+//!
+//! [`RelayPath::estimate_packet_relay_fees`]: crate::link::RelayPath::estimate_packet_relay_fees
+
+use core::fmt;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use tracing::{debug, warn};
+
+use ibc_relayer_types::applications::ics29_fee::events::IncentivizedPacket;
+use ibc_relayer_types::applications::transfer::RawCoin;
+
+use crate::config::filter::{ProfitabilityPolicy, UnprofitableAction};
+use crate::config::PricesConfig;
+use crate::link::PacketRelayFees;
+
+/// How often the price file is checked for changes
+const PRICE_FILE_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The outcome of the evaluation of the profitability of a packet
+#[derive(Clone, Debug, PartialEq)]
+pub enum Decision {
+    Profitable,
+    Unprofitable {
+        fees: Value,
+        cost: Value,
+    },
+    /// The cost of relaying the packet cannot be estimated, eg. because
+    /// the denomination gas is paid in on one of the chains has no price
+    Unknown(String),
+}
+
+/// A value in the unit the prices are expressed in
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Value(BigRational);
+
+/// The number of decimal places values are displayed with
+const VALUE_DISPLAY_DECIMALS: u32 = 6;
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = BigInt::from(10u32).pow(VALUE_DISPLAY_DECIMALS);
+        let scaled = (&self.0 * BigRational::from_integer(scale.clone()))
+            .round()
+            .to_integer();
+
+        let decimals = format!(
+            "{:0width$}",
+            &scaled % &scale,
+            width = VALUE_DISPLAY_DECIMALS as usize
+        );
+
+        match decimals.trim_end_matches('0') {
+            "" => write!(f, "{}", &scaled / &scale),
+            decimals => write!(f, "{}.{decimals}", &scaled / &scale),
+        }
+    }
+}
+
+/// The exact value of a price, as written in decimal notation in the configuration
+/// or in the price file, or `None` if the price is negative or not a number
+fn decimal(price: f64) -> Option<BigRational> {
+    if !price.is_finite() || price < 0.0 {
+        return None;
+    }
+
+    // `Display` writes the shortest decimal notation which parses back to the same
+    // float, ie. the price as written, and never uses an exponent
+    let price = price.to_string();
+    let (integer, fraction) = price.split_once('.').unwrap_or((price.as_str(), ""));
+
+    let numer = format!("{integer}{fraction}").parse::<BigInt>().ok()?;
+    let denom = BigInt::from(10u32).pow(fraction.len() as u32);
+
+    Some(BigRational::new(numer, denom))
+}
+
+/// The prices of denominations, from the configuration and the price file
+pub struct PriceTable {
+    prices: BTreeMap<String, f64>,
+    file: Option<PriceFile>,
+}
+
+struct PriceFile {
+    path: PathBuf,
+    loaded: Mutex<LoadedPrices>,
+}
+
+/// The prices read from the price file
+#[derive(Default)]
+struct LoadedPrices {
+    prices: BTreeMap<String, f64>,
+    /// The time the file was last modified, when it was read
+    modified: Option<SystemTime>,
+    /// The time the file was last checked for changes
+    checked_at: Option<Instant>,
+}
+
+impl PriceTable {
+    pub fn new(config: &PricesConfig) -> Self {
+        Self {
+            prices: config.denoms.clone(),
+            file: config.file.clone().map(|path| PriceFile {
+                path,
+                loaded: Mutex::new(LoadedPrices::default()),
+            }),
+        }
+    }
+
+    /// The price of one unit of the given denomination, the prices from
+    /// the price file taking precedence over the ones from the configuration
+    pub fn price(&self, denom: &str) -> Option<BigRational> {
+        self.file
+            .as_ref()
+            .and_then(|file| file.price(denom))
+            .or_else(|| self.prices.get(denom).copied())
+            .and_then(decimal)
+    }
+
+    /// The total value of the given coins, ignoring the denominations without a price
+    pub fn value(&self, coins: &[RawCoin]) -> Value {
+        let value = coins
+            .iter()
+            .filter_map(|coin| match self.price(&coin.denom) {
+                Some(price) => coin
+                    .amount
+                    .to_string()
+                    .parse::<BigInt>()
+                    .ok()
+                    .map(|amount| BigRational::from_integer(amount) * price),
+                None => {
+                    debug!("no price for denomination '{}', ignoring it", coin.denom);
+                    None
+                }
+            })
+            .sum();
+
+        Value(value)
+    }
+}
+
+impl PriceFile {
+    fn price(&self, denom: &str) -> Option<f64> {
+        let mut loaded = self.loaded.lock().expect("poisoned lock");
+
+        let should_check = loaded
+            .checked_at
+            .is_none_or(|checked_at| checked_at.elapsed() >= PRICE_FILE_RELOAD_INTERVAL);
+
+        if should_check {
+            loaded.checked_at = Some(Instant::now());
+            self.reload_if_modified(&mut loaded);
+        }
+
+        loaded.prices.get(denom).copied()
+    }
+
+    /// Read the price file again if it was modified since it was last read
+    fn reload_if_modified(&self, loaded: &mut LoadedPrices) {
+        let modified = fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        if modified.is_none() || modified == loaded.modified {
+            return;
+        }
+
+        match fs::read_to_string(&self.path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| toml::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(prices) => {
+                loaded.prices = prices;
+                loaded.modified = modified;
+            }
+            Err(e) => warn!(
+                "failed to read the price file {}, keeping the previous prices: {e}",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+/// Evaluates whether relaying the incentivized packets of a path is profitable
+pub struct Profitability {
+    policy: ProfitabilityPolicy,
+    prices: PriceTable,
+}
+
+impl Profitability {
+    pub fn new(policy: ProfitabilityPolicy, prices: &PricesConfig) -> Self {
+        Self {
+            policy,
+            prices: PriceTable::new(prices),
+        }
+    }
+
+    pub fn unprofitable_action(&self) -> UnprofitableAction {
+        self.policy.unprofitable
+    }
+
+    /// Compare the fees of the given packet with the estimated fees of relaying it
+    pub fn evaluate(&self, packet: &IncentivizedPacket, estimate: &PacketRelayFees) -> Decision {
+        if let Some(coin) = estimate
+            .fees
+            .iter()
+            .find(|coin| self.prices.price(&coin.denom).is_none())
+        {
+            return Decision::Unknown(format!(
+                "no price for denomination '{}' of the fees of relaying the packet",
+                coin.denom
+            ));
+        }
+
+        let cost = self.prices.value(&estimate.fees);
+
+        let fees = if estimate.timeout {
+            self.prices.value(&packet.total_timeout_fee)
+        } else {
+            let recv = self.prices.value(&packet.total_recv_fee);
+            let ack = self.prices.value(&packet.total_ack_fee);
+            Value(recv.0 + ack.0)
+        };
+
+        let percent =
+            |value: &Value, percentage: BigInt| &value.0 * BigRational::from_integer(percentage);
+
+        if percent(&fees, BigInt::from(100))
+            >= percent(&cost, BigInt::from(self.policy.min_margin) + 100)
+        {
+            Decision::Profitable
+        } else {
+            Decision::Unprofitable { fees, cost }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::applications::transfer::{Amount, Coin};
+    use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+    fn packet(recv_fee: u64, ack_fee: u64, timeout_fee: u64) -> IncentivizedPacket {
+        IncentivizedPacket {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            sequence: 1.into(),
+            total_recv_fee: vec![Coin::new("uatom".to_string(), recv_fee)],
+            total_ack_fee: vec![Coin::new("uatom".to_string(), ack_fee)],
+            total_timeout_fee: vec![Coin::new("uatom".to_string(), timeout_fee)],
+        }
+    }
+
+    fn estimate(timeout: bool, amount: u64) -> PacketRelayFees {
+        PacketRelayFees {
+            timeout,
+            fees: vec![Coin::new("stake".to_string(), amount)],
+        }
+    }
+
+    fn value(value: i64) -> Value {
+        Value(BigRational::from_integer(value.into()))
+    }
+
+    fn evaluator(min_margin: u64, prices: &[(&str, f64)]) -> Profitability {
+        Profitability::new(
+            ProfitabilityPolicy {
+                min_margin,
+                unprofitable: UnprofitableAction::Defer,
+            },
+            &PricesConfig {
+                denoms: prices
+                    .iter()
+                    .map(|(denom, price)| (denom.to_string(), *price))
+                    .collect(),
+                file: None,
+            },
+        )
+    }
+
+    #[test]
+    fn compares_fees_with_cost() {
+        // The cost of relaying the packet is worth 4000 * 0.5 = 2000
+        let profitability = evaluator(0, &[("uatom", 2.0), ("stake", 0.5)]);
+        let cost = estimate(false, 4000);
+
+        assert_eq!(
+            profitability.evaluate(&packet(600, 400, 0), &cost),
+            Decision::Profitable
+        );
+        assert_eq!(
+            profitability.evaluate(&packet(500, 400, 0), &cost),
+            Decision::Unprofitable {
+                fees: value(1800),
+                cost: value(2000)
+            }
+        );
+
+        // With a margin of 10%, the fees must be worth at least 2200
+        let profitability = evaluator(10, &[("uatom", 2.0), ("stake", 0.5)]);
+        assert!(matches!(
+            profitability.evaluate(&packet(600, 400, 0), &cost),
+            Decision::Unprofitable { .. }
+        ));
+        assert_eq!(
+            profitability.evaluate(&packet(700, 400, 0), &cost),
+            Decision::Profitable
+        );
+    }
+
+    #[test]
+    fn timeout_earns_timeout_fee() {
+        // The cost of timing out the packet is worth 1000 * 0.5 = 500
+        let profitability = evaluator(0, &[("uatom", 2.0), ("stake", 0.5)]);
+        let cost = estimate(true, 1000);
+
+        // The recv and ack fees are not paid when the packet times out
+        assert_eq!(
+            profitability.evaluate(&packet(600, 400, 200), &cost),
+            Decision::Unprofitable {
+                fees: value(400),
+                cost: value(500)
+            }
+        );
+        assert_eq!(
+            profitability.evaluate(&packet(0, 0, 250), &cost),
+            Decision::Profitable
+        );
+    }
+
+    #[test]
+    fn unknown_prices() {
+        // Fees in a denomination without a price are worthless
+        let profitability = evaluator(0, &[("stake", 0.5)]);
+        assert!(matches!(
+            profitability.evaluate(&packet(600, 400, 0), &estimate(false, 4000)),
+            Decision::Unprofitable { fees, .. } if fees == value(0)
+        ));
+
+        // The cost cannot be evaluated without the price of the fees
+        let profitability = evaluator(0, &[("uatom", 2.0)]);
+        assert!(matches!(
+            profitability.evaluate(&packet(600, 400, 0), &estimate(false, 4000)),
+            Decision::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn compares_decimal_values_exactly() {
+        // The fees and the cost are both worth exactly 0.3, while
+        // 3 * 0.1 = 0.30000000000000004 > 0.3 with floating point arithmetic
+        let profitability = evaluator(0, &[("uatom", 0.3), ("stake", 0.1)]);
+        assert_eq!(
+            profitability.evaluate(&packet(1, 0, 0), &estimate(false, 3)),
+            Decision::Profitable
+        );
+
+        // Amounts beyond the precision of floats are not rounded
+        let profitability = evaluator(0, &[("uatom", 1.0), ("stake", 1.0)]);
+        let cost = PacketRelayFees {
+            timeout: false,
+            fees: vec![Coin::new(
+                "stake".to_string(),
+                "100000000000000000001".parse::<Amount>().unwrap(),
+            )],
+        };
+        let fees = IncentivizedPacket {
+            total_recv_fee: vec![Coin::new(
+                "uatom".to_string(),
+                "100000000000000000000".parse::<Amount>().unwrap(),
+            )],
+            ..packet(0, 0, 0)
+        };
+        assert!(matches!(
+            profitability.evaluate(&fees, &cost),
+            Decision::Unprofitable { .. }
+        ));
+    }
+
+    #[test]
+    fn displays_values_as_decimals() {
+        let profitability = evaluator(0, &[("uatom", 0.25), ("stake", 0.000001)]);
+
+        assert_eq!(
+            profitability
+                .prices
+                .value(&[Coin::new("uatom".to_string(), 3u64)])
+                .to_string(),
+            "0.75"
+        );
+        assert_eq!(
+            profitability
+                .prices
+                .value(&[Coin::new("stake".to_string(), 1500u64)])
+                .to_string(),
+            "0.0015"
+        );
+        assert_eq!(value(2000).to_string(), "2000");
+    }
+
+    #[test]
+    fn free_relaying_is_profitable() {
+        let profitability = evaluator(10, &[]);
+        let cost = PacketRelayFees {
+            timeout: false,
+            fees: vec![],
+        };
+
+        assert_eq!(
+            profitability.evaluate(&packet(0, 0, 0), &cost),
+            Decision::Profitable
+        );
+    }
+}
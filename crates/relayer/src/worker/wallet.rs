@@ -1,16 +1,115 @@
-use std::time::Duration;
+use alloc::collections::BTreeSet;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
-use tracing::{error_span, trace, warn};
+use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::google::protobuf::Any;
+use once_cell::sync::Lazy;
+use prost::Message;
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{error, error_span, info, trace, warn};
+
+use ibc_relayer_types::applications::transfer::amount::Amount;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::{
-    chain::handle::ChainHandle,
+    chain::cosmos::config::{TopUpConfig, WalletConfig},
+    chain::{handle::ChainHandle, tracking::TrackedMsgs},
+    config::{types::TokenAmount, ChainConfig},
+    spawn::spawn_chain_runtime_with_config,
     telemetry,
     util::task::{spawn_background_task, Next, TaskError, TaskHandle},
 };
 
+const MSG_SEND_TYPE_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// The chains on which relaying is paused because of the critical balance of the wallet
+static PAUSED_CHAINS: Lazy<RwLock<BTreeSet<ChainId>>> = Lazy::new(Default::default);
+
+/// Whether relaying to the given chain is paused because the balance
+/// of the wallet Hermes relays with is critical
+pub fn is_relaying_paused(chain_id: &ChainId) -> bool {
+    PAUSED_CHAINS
+        .read()
+        .is_ok_and(|paused| paused.contains(chain_id))
+}
+
+/// How the balance of the wallet compares to the configured thresholds
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BalanceLevel {
+    Ok = 0,
+    Low = 1,
+    Critical = 2,
+}
+
+impl BalanceLevel {
+    fn of(balance: Amount, wallet: &WalletConfig) -> Self {
+        let below =
+            |threshold: Option<TokenAmount>| threshold.is_some_and(|t| balance < t.amount());
+
+        if below(wallet.critical_balance) {
+            Self::Critical
+        } else if below(wallet.min_balance) {
+            Self::Low
+        } else {
+            Self::Ok
+        }
+    }
+}
+
+/// Pauses relaying to the chain while the balance of its wallet is critical,
+/// and resumes it when the balance recovers or when the wallet worker stops.
+struct RelayingPause {
+    chain_id: ChainId,
+    paused: bool,
+}
+
+impl RelayingPause {
+    fn new(chain_id: ChainId) -> Self {
+        Self {
+            chain_id,
+            paused: false,
+        }
+    }
+
+    fn set(&mut self, paused: bool) {
+        if self.paused == paused {
+            return;
+        }
+
+        if let Ok(mut chains) = PAUSED_CHAINS.write() {
+            if paused {
+                chains.insert(self.chain_id.clone());
+            } else {
+                chains.remove(&self.chain_id);
+            }
+
+            self.paused = paused;
+        }
+    }
+}
+
+impl Drop for RelayingPause {
+    fn drop(&mut self) {
+        self.set(false);
+    }
+}
+
+/// The chain runtime submitting the top-up transfers from the treasury key
+struct Treasury<Chain> {
+    chain: Chain,
+    _rt: Arc<TokioRuntime>,
+}
+
 pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
     let span = error_span!("wallet", chain = %chain.id());
 
+    let mut level = BalanceLevel::Ok;
+    let mut pause = RelayingPause::new(chain.id());
+    let mut treasury: Option<Treasury<Chain>> = None;
+    let mut last_top_up: Option<Instant> = None;
+
     spawn_background_task(span, Some(Duration::from_secs(5)), move || {
         let chain_config = chain
             .config()
@@ -22,11 +121,16 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
             ));
         }
 
-        let key = chain.get_key().map_err(|e| {
-            TaskError::Fatal(Box::new(format!(
-                "failed to get key in use by the relayer: {e}"
-            )))
-        })?;
+        // The address of the account of the key in use by the relayer,
+        // which may be held by a remote signer
+        let account = chain
+            .get_signer()
+            .map_err(|e| {
+                TaskError::Fatal(Box::new(format!(
+                    "failed to get key in use by the relayer: {e}"
+                )))
+            })?
+            .to_string();
 
         let balance = chain.query_balance(None, None).map_err(|e| {
             TaskError::Ignore(Box::new(format!(
@@ -39,33 +143,187 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
                 telemetry!(
                     wallet_balance,
                     &chain.id(),
-                    &key.account(),
+                    &account,
                     amount,
                     &balance.denom,
                 );
-                trace!(%amount, denom = %balance.denom, account = %key.account(), "wallet balance");
-                telemetry!(
-                    update_period_fees,
-                    &chain.id(),
-                    &key.account(),
-                    &balance.denom
-                );
+                trace!(%amount, denom = %balance.denom, account = %account, "wallet balance");
+                telemetry!(update_period_fees, &chain.id(), &account, &balance.denom);
             }
             Err(e) => {
                 warn!(
-                    %balance.amount, denom = %balance.denom, account = %key.account(),
+                    %balance.amount, denom = %balance.denom, account = %account,
                     "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
                 );
             }
         }
+
+        let wallet = match &chain_config {
+            ChainConfig::CosmosSdk(config) | ChainConfig::Namada(config) => config.wallet.clone(),
+            ChainConfig::Penumbra(_) => WalletConfig::default(),
+            #[cfg(any(test, feature = "mock-chain"))]
+            ChainConfig::Mock(_) => WalletConfig::default(),
+        };
+
+        if wallet.min_balance.is_none() && wallet.critical_balance.is_none() {
+            level = BalanceLevel::Ok;
+            pause.set(false);
+            return Ok(Next::Continue);
+        }
+
+        let amount = balance.amount.parse::<Amount>().map_err(|e| {
+            TaskError::Ignore(Box::new(format!(
+                "failed to parse the wallet balance '{}': {e}",
+                balance.amount
+            )))
+        })?;
+
+        let new_level = BalanceLevel::of(amount, &wallet);
+
+        telemetry!(
+            wallet_balance_level,
+            &chain.id(),
+            &account,
+            new_level as u64,
+            &balance.denom,
+        );
+
+        if new_level != level {
+            match new_level {
+                BalanceLevel::Critical => error!(
+                    %amount, denom = %balance.denom, account = %account,
+                    critical_balance = ?wallet.critical_balance.map(|b| b.to_string()),
+                    "wallet balance is critical"
+                ),
+                BalanceLevel::Low => warn!(
+                    %amount, denom = %balance.denom, account = %account,
+                    min_balance = ?wallet.min_balance.map(|b| b.to_string()),
+                    "wallet balance is low"
+                ),
+                BalanceLevel::Ok => info!(
+                    %amount, denom = %balance.denom, account = %account,
+                    "wallet balance is back above the configured thresholds"
+                ),
+            }
+
+            level = new_level;
+        }
+
+        let paused = wallet.pause_on_critical && level == BalanceLevel::Critical;
+        if paused != pause.paused {
+            if paused {
+                warn!(
+                    "pausing relaying to the chain until the wallet balance is no longer critical"
+                );
+            } else {
+                info!("resuming relaying to the chain");
+            }
+
+            pause.set(paused);
+        }
+
+        if let Some(top_up) = &wallet.top_up {
+            let below_threshold = wallet
+                .top_up_threshold()
+                .is_some_and(|threshold| amount < threshold.amount());
+
+            let cooled_down = last_top_up.is_none_or(|at| at.elapsed() >= top_up.cooldown);
+
+            if below_threshold && cooled_down {
+                last_top_up = Some(Instant::now());
+
+                match top_up_wallet(
+                    &mut treasury,
+                    &chain_config,
+                    top_up,
+                    &account,
+                    &balance.denom,
+                ) {
+                    Ok(()) => {
+                        info!(
+                            amount = %top_up.amount, denom = %balance.denom, account = %account,
+                            treasury = %top_up.key_name,
+                            "topped up the wallet"
+                        );
+
+                        telemetry!(wallet_top_up, &chain.id(), &account, &balance.denom);
+                    }
+                    Err(e) => error!(
+                        treasury = %top_up.key_name,
+                        "failed to top up the wallet: {e}"
+                    ),
+                }
+            }
+        }
+
         Ok(Next::Continue)
     })
 }
 
+/// Transfer the configured amount from the treasury key to the given account,
+/// spawning the chain runtime of the treasury key on first use.
+fn top_up_wallet<Chain: ChainHandle>(
+    treasury: &mut Option<Treasury<Chain>>,
+    chain_config: &ChainConfig,
+    top_up: &TopUpConfig,
+    account: &str,
+    denom: &str,
+) -> Result<(), String> {
+    if treasury.is_none() {
+        let rt = Arc::new(
+            TokioRuntime::new().map_err(|e| format!("failed to create a Tokio runtime: {e}"))?,
+        );
+
+        let mut treasury_config = chain_config.clone();
+        treasury_config.set_key_name(top_up.key_name.clone());
+        if let ChainConfig::CosmosSdk(config) | ChainConfig::Namada(config) = &mut treasury_config {
+            config.key_names.clear();
+        }
+
+        let chain = spawn_chain_runtime_with_config::<Chain>(treasury_config, rt.clone())
+            .map_err(|e| format!("failed to spawn the chain runtime of the treasury key: {e}"))?;
+
+        *treasury = Some(Treasury { chain, _rt: rt });
+    }
+
+    let treasury = treasury
+        .as_ref()
+        .expect("the treasury chain runtime was just spawned");
+
+    let from_address = treasury
+        .chain
+        .get_signer()
+        .map_err(|e| format!("failed to get the treasury key: {e}"))?
+        .to_string();
+
+    let msg = MsgSend {
+        from_address,
+        to_address: account.to_string(),
+        amount: vec![Coin {
+            denom: denom.to_string(),
+            amount: top_up.amount.to_string(),
+        }],
+    };
+
+    let any = Any {
+        type_url: MSG_SEND_TYPE_URL.to_string(),
+        value: msg.encode_to_vec(),
+    };
+
+    treasury
+        .chain
+        .send_messages_and_wait_commit(TrackedMsgs::new_single(any, "wallet-top-up"))
+        .map_err(|e| format!("failed to submit the transfer: {e}"))?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use ibc_relayer_types::bigint::U256;
 
+    use super::*;
+
     // Test to confirm that any u256 fits in f64
     #[test]
     fn compare_f64_max_to_u256_max() {
@@ -74,4 +332,39 @@ mod tests {
 
         assert!(f64_max > u256_max.to_string().parse::<f64>().unwrap());
     }
+
+    #[test]
+    fn balance_level() {
+        let wallet = WalletConfig {
+            min_balance: Some(1000u64.into()),
+            critical_balance: Some(100u64.into()),
+            ..WalletConfig::default()
+        };
+
+        assert_eq!(BalanceLevel::of(5000u64.into(), &wallet), BalanceLevel::Ok);
+        assert_eq!(BalanceLevel::of(1000u64.into(), &wallet), BalanceLevel::Ok);
+        assert_eq!(BalanceLevel::of(999u64.into(), &wallet), BalanceLevel::Low);
+        assert_eq!(
+            BalanceLevel::of(99u64.into(), &wallet),
+            BalanceLevel::Critical
+        );
+
+        let critical_only = WalletConfig {
+            critical_balance: Some(100u64.into()),
+            ..WalletConfig::default()
+        };
+
+        assert_eq!(
+            BalanceLevel::of(99u64.into(), &critical_only),
+            BalanceLevel::Critical
+        );
+        assert_eq!(
+            BalanceLevel::of(100u64.into(), &critical_only),
+            BalanceLevel::Ok
+        );
+        assert_eq!(
+            BalanceLevel::of(0u64.into(), &WalletConfig::default()),
+            BalanceLevel::Ok
+        );
+    }
 }
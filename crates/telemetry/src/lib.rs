@@ -1,6 +1,7 @@
 pub mod broadcast_error;
 pub mod encoder;
 mod path_identifier;
+pub mod secure;
 pub mod server;
 pub mod state;
 
@@ -84,11 +85,25 @@ pub fn spawn<A>(
     addr: A,
     state: Arc<TelemetryState>,
 ) -> Result<(SocketAddr, JoinHandle<Result<(), BoxError>>), BoxError>
+where
+    A: ToSocketAddrs + Send + 'static,
+{
+    spawn_with_options(addr, state, secure::ServerOptions::default())
+}
+
+/// Spawn the telemetry server, serving the metrics over TLS and only
+/// to authenticated clients if the given options say so.
+pub fn spawn_with_options<A>(
+    addr: A,
+    state: Arc<TelemetryState>,
+    options: secure::ServerOptions,
+) -> Result<(SocketAddr, JoinHandle<Result<(), BoxError>>), BoxError>
 where
     A: ToSocketAddrs + Send + 'static,
 {
     let addr = addr.to_socket_addrs()?.next().unwrap();
-    let handle = tokio::spawn(server::listen(addr, state));
+    let tls = options.tls.as_ref().map(secure::tls_acceptor).transpose()?;
+    let handle = tokio::spawn(server::listen_with_options(addr, state, tls, options.auth));
 
     Ok((addr, handle))
 }
@@ -0,0 +1,328 @@
+//! Serving HTTP endpoints over TLS, and authenticating their clients with
+//! a bearer token or with a client certificate (mTLS).
+//!
+//! Used by both the telemetry and the REST servers.
+
+use core::time::Duration;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use axum::extract::connect_info::{ConnectInfo, Connected};
+use axum::extract::State;
+use axum::http::{header, HeaderMap, Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use hyper::server::accept;
+use hyper::server::conn::AddrStream;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::rustls::server::AllowAnyAnonymousOrAuthenticatedClient;
+use tokio_rustls::rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::{debug, warn};
+
+use crate::BoxError;
+
+/// How long a client has to complete the TLS handshake
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The maximum number of connections which completed the
+/// TLS handshake and are waiting to be served
+const MAX_PENDING_CONNECTIONS: usize = 64;
+
+/// Options for serving endpoints over TLS and authenticating their clients
+#[derive(Clone, Debug, Default)]
+pub struct ServerOptions {
+    pub tls: Option<TlsOptions>,
+    pub auth: AuthOptions,
+}
+
+/// The files to set up TLS with
+#[derive(Clone, Debug)]
+pub struct TlsOptions {
+    /// The PEM file holding the certificate chain of the server
+    pub cert: PathBuf,
+
+    /// The PEM file holding the private key of the server
+    pub key: PathBuf,
+
+    /// The PEM file holding the CA certificates the client certificates are verified against.
+    /// Clients are not asked for a certificate if not set.
+    pub client_ca: Option<PathBuf>,
+}
+
+/// How the clients of the protected endpoints authenticate
+#[derive(Clone, Debug, Default)]
+pub struct AuthOptions {
+    /// The token clients may send in the `Authorization: Bearer <token>` header
+    pub token: Option<String>,
+
+    /// Whether clients presenting a certificate verified against the client CA are authenticated
+    pub client_cert: bool,
+}
+
+impl AuthOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some() || self.client_cert
+    }
+
+    /// Whether the client sending a request with the given headers is authenticated
+    fn authenticates(&self, client: &ClientInfo, headers: &HeaderMap) -> bool {
+        if self.client_cert && client.verified_cert {
+            return true;
+        }
+
+        let Some(token) = &self.token else {
+            return false;
+        };
+
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|given| constant_time_eq(given.trim().as_bytes(), token.as_bytes()))
+    }
+}
+
+/// Information about the client of a connection
+#[derive(Copy, Clone, Debug)]
+pub struct ClientInfo {
+    pub remote_addr: SocketAddr,
+
+    /// Whether the client presented a certificate verified against the client CA
+    pub verified_cert: bool,
+}
+
+impl Connected<&AddrStream> for ClientInfo {
+    fn connect_info(stream: &AddrStream) -> Self {
+        Self {
+            remote_addr: stream.remote_addr(),
+            verified_cert: false,
+        }
+    }
+}
+
+impl Connected<&TlsStream<TcpStream>> for ClientInfo {
+    fn connect_info(stream: &TlsStream<TcpStream>) -> Self {
+        let (tcp_stream, connection) = stream.get_ref();
+
+        Self {
+            remote_addr: tcp_stream
+                .peer_addr()
+                .unwrap_or_else(|_| SocketAddr::from(([0, 0, 0, 0], 0))),
+            // The certificate was verified during the handshake, if the server was set up
+            // with a client CA. Otherwise clients are not asked for a certificate at all.
+            verified_cert: connection
+                .peer_certificates()
+                .is_some_and(|certs| !certs.is_empty()),
+        }
+    }
+}
+
+/// Only let the authenticated clients use the routes of the given router,
+/// if authentication is enabled.
+pub fn require_auth(router: Router, auth: &AuthOptions) -> Router {
+    if !auth.is_enabled() {
+        return router;
+    }
+
+    router.route_layer(middleware::from_fn_with_state(
+        Arc::new(auth.clone()),
+        authenticate,
+    ))
+}
+
+async fn authenticate<B>(
+    State(auth): State<Arc<AuthOptions>>,
+    ConnectInfo(client): ConnectInfo<ClientInfo>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if auth.authenticates(&client, request.headers()) {
+        next.run(request).await
+    } else {
+        debug!(
+            "rejecting unauthenticated request from {}",
+            client.remote_addr
+        );
+
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "unauthorized",
+        )
+            .into_response()
+    }
+}
+
+/// Set up TLS with the given certificate and key, and with the CA certificates
+/// to verify the client certificates against, if any.
+pub fn tls_acceptor(tls: &TlsOptions) -> Result<TlsAcceptor, BoxError> {
+    let certs = read_certs(&tls.cert)?;
+    let key = read_private_key(&tls.key)?;
+
+    let builder = ServerConfig::builder().with_safe_defaults();
+
+    let config = match &tls.client_ca {
+        Some(client_ca) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(client_ca)? {
+                roots.add(&cert)?;
+            }
+
+            builder
+                .with_client_cert_verifier(
+                    AllowAnyAnonymousOrAuthenticatedClient::new(roots).boxed(),
+                )
+                .with_single_cert(certs, key)?
+        }
+        None => builder.with_no_client_auth().with_single_cert(certs, key)?,
+    };
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Serve the given router on the given address, over TLS if an acceptor is given.
+pub async fn serve(
+    addr: SocketAddr,
+    app: Router,
+    tls: Option<TlsAcceptor>,
+) -> Result<(), BoxError> {
+    let service = app.into_make_service_with_connect_info::<ClientInfo>();
+
+    match tls {
+        None => axum::Server::bind(&addr).serve(service).await?,
+        Some(acceptor) => {
+            let listener = TcpListener::bind(addr).await?;
+            let (sender, receiver) = mpsc::channel(MAX_PENDING_CONNECTIONS);
+
+            tokio::spawn(accept_tls(listener, acceptor, sender));
+
+            axum::Server::builder(accept::from_stream(ReceiverStream::new(receiver)))
+                .serve(service)
+                .await?
+        }
+    }
+
+    Ok(())
+}
+
+/// Accept the incoming connections, and hand over the ones which completed
+/// the TLS handshake to the server. Handshakes are performed concurrently,
+/// so that a slow client does not hold the other ones back.
+async fn accept_tls(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    sender: mpsc::Sender<io::Result<TlsStream<TcpStream>>>,
+) {
+    while !sender.is_closed() {
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                warn!("failed to accept connection: {e}");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let sender = sender.clone();
+
+        tokio::spawn(async move {
+            match tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                Ok(Ok(stream)) => {
+                    let _ = sender.send(Ok(stream)).await;
+                }
+                Ok(Err(e)) => debug!("TLS handshake with {remote_addr} failed: {e}"),
+                Err(_) => debug!("TLS handshake with {remote_addr} timed out"),
+            }
+        });
+    }
+}
+
+fn read_certs(path: &Path) -> Result<Vec<Certificate>, BoxError> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    if certs.is_empty() {
+        return Err(format!("no certificate found in {}", path.display()).into());
+    }
+
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn read_private_key(path: &Path) -> Result<PrivateKey, BoxError> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    for item in rustls_pemfile::read_all(&mut reader)? {
+        match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => return Ok(PrivateKey(key)),
+            _ => {}
+        }
+    }
+
+    Err(format!("no private key found in {}", path.display()).into())
+}
+
+/// Compare the two byte strings in a time which does not depend on their contents,
+/// so that the token cannot be guessed from the time taken to reject a request.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(verified_cert: bool) -> ClientInfo {
+        ClientInfo {
+            remote_addr: SocketAddr::from(([127, 0, 0, 1], 1234)),
+            verified_cert,
+        }
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            format!("Bearer {token}").parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn authenticates_with_token() {
+        let auth = AuthOptions {
+            token: Some("secret".to_string()),
+            client_cert: false,
+        };
+
+        assert!(auth.authenticates(&client(false), &bearer("secret")));
+        assert!(!auth.authenticates(&client(false), &bearer("secreT")));
+        assert!(!auth.authenticates(&client(false), &bearer("secret2")));
+        assert!(!auth.authenticates(&client(false), &HeaderMap::new()));
+
+        // A client certificate does not authenticate unless enabled
+        assert!(!auth.authenticates(&client(true), &HeaderMap::new()));
+    }
+
+    #[test]
+    fn authenticates_with_client_cert() {
+        let auth = AuthOptions {
+            token: None,
+            client_cert: true,
+        };
+
+        assert!(auth.authenticates(&client(true), &HeaderMap::new()));
+        assert!(!auth.authenticates(&client(false), &HeaderMap::new()));
+        assert!(!auth.authenticates(&client(false), &bearer("secret")));
+    }
+}
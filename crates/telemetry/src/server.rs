@@ -8,8 +8,10 @@ use axum::routing::get;
 use axum::{Extension, Router};
 
 use prometheus::{Encoder, TextEncoder};
+use tokio_rustls::TlsAcceptor;
 
 use crate::encoder::JsonEncoder;
+use crate::secure::{self, AuthOptions};
 use crate::state::TelemetryState;
 
 #[derive(Copy, Clone, Debug, Default, serde::Deserialize)]
@@ -31,15 +33,21 @@ pub async fn listen(
     addr: SocketAddr,
     state: Arc<TelemetryState>,
 ) -> Result<(), Box<dyn Error + Send + Sync>> {
-    let app = Router::new()
-        .route("/metrics", get(get_metrics))
-        .layer(Extension(state));
+    listen_with_options(addr, state, None, AuthOptions::default()).await
+}
 
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
-        .await?;
+/// Serve the metrics over TLS if an acceptor is given. The metrics are
+/// only served to authenticated clients if authentication is enabled.
+pub async fn listen_with_options(
+    addr: SocketAddr,
+    state: Arc<TelemetryState>,
+    tls: Option<TlsAcceptor>,
+    auth: AuthOptions,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let app = Router::new().route("/metrics", get(get_metrics));
+    let app = secure::require_auth(app, &auth).layer(Extension(state));
 
-    Ok(())
+    secure::serve(addr, app, tls).await
 }
 
 async fn get_metrics(
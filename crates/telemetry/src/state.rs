@@ -136,6 +136,13 @@ pub struct TelemetryState {
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
+    /// Whether the balance of each wallet Hermes uses per chain is
+    /// above the configured thresholds (0), low (1) or critical (2)
+    wallet_balance_level: ObservableGauge<u64>,
+
+    /// Number of top-up transfers submitted to each wallet Hermes uses per chain
+    wallet_top_ups: Counter<u64>,
+
     /// Indicates the latency for all transactions submitted to a specific chain,
     /// i.e. the difference between the moment when Hermes received a batch of events
     /// until the corresponding transaction(s) were submitted. Milliseconds.
@@ -217,6 +224,10 @@ pub struct TelemetryState {
     /// Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits
     filtered_packets: Counter<u64>,
 
+    /// Number of incentivized packets which were not relayed because their fees did not cover
+    /// the estimated cost of relaying them, per channel and decision (skipped or deferred)
+    unprofitable_packets: Counter<u64>,
+
     /// Observed ICS31 CrossChainQueries
     cross_chain_queries: Counter<u64>,
 
@@ -225,6 +236,20 @@ pub struct TelemetryState {
 
     /// Observed ICS31 CrossChainQuery error Responses
     cross_chain_query_error_responses: Counter<u64>,
+
+    /// Number of times Hermes switched to another full node, per chain
+    endpoint_switches: Counter<u64>,
+
+    /// Number of proofs returned by a full node which failed local verification, per chain
+    invalid_proofs: Counter<u64>,
+
+    /// The time packets spent between two stages of their lifecycle, per channel,
+    /// i.e. until they were received, acknowledged or timed out. Milliseconds.
+    packet_stage_latency: Histogram<u64>,
+
+    /// The time left before each client refreshed by Hermes expires, per chain hosting
+    /// the client and counterparty chain. Seconds.
+    client_time_to_expiry: ObservableGauge<u64>,
 }
 
 impl TelemetryState {
@@ -277,6 +302,18 @@ impl TelemetryState {
         )
         .unwrap();
 
+        let packet_stage_latency_view = new_view(
+            Instrument::new().name("packet_stage_latency"),
+            Stream::new().aggregation(Aggregation::ExplicitBucketHistogram {
+                boundaries: vec![
+                    1000.0, 2000.0, 5000.0, 10000.0, 20000.0, 30000.0, 60000.0, 120000.0, 300000.0,
+                    600000.0, 1800000.0, 3600000.0,
+                ],
+                record_min_max: true,
+            }),
+        )
+        .unwrap();
+
         let raw_exporter = opentelemetry_prometheus::exporter().with_registry(registry.clone());
 
         // Condition required to avoid prefixing `_` when using empty namespace
@@ -297,6 +334,7 @@ impl TelemetryState {
             .with_view(tx_submitted_view)
             .with_view(tx_confirmed_view)
             .with_view(gas_fees_view)
+            .with_view(packet_stage_latency_view)
             .build();
         global::set_meter_provider(meter_provider.clone());
 
@@ -373,6 +411,16 @@ impl TelemetryState {
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
                 .init(),
 
+            wallet_balance_level: meter
+                .u64_observable_gauge("wallet_balance_level")
+                .with_description("Whether the balance of each wallet Hermes uses per chain is above the configured thresholds (0), low (1) or critical (2)")
+                .init(),
+
+            wallet_top_ups: meter
+                .u64_counter("wallet_top_ups")
+                .with_description("Number of top-up transfers submitted to each wallet Hermes uses per chain")
+                .init(),
+
             send_packet_events: meter
                 .u64_counter("send_packet_events")
                 .with_description("Number of SendPacket events received")
@@ -482,7 +530,12 @@ impl TelemetryState {
 
             filtered_packets: meter
                 .u64_counter("filtered_packets")
-                .with_description("Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits")
+                .with_description("Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits, or because their content was rejected by the ICS-20 packet filter")
+                .init(),
+
+            unprofitable_packets: meter
+                .u64_counter("unprofitable_packets")
+                .with_description("Number of incentivized packets which were not relayed because their fees did not cover the estimated cost of relaying them")
                 .init(),
 
             cross_chain_queries: meter
@@ -499,6 +552,30 @@ impl TelemetryState {
                 .u64_counter("cross_chain_query_error_responses")
                 .with_description("Number of ICS-31 error query responses")
                 .init(),
+
+            endpoint_switches: meter
+                .u64_counter("endpoint_switches")
+                .with_description("Number of times Hermes switched to another full node because the active one was unhealthy or a preferred one recovered")
+                .init(),
+
+            invalid_proofs: meter
+                .u64_counter("invalid_proofs")
+                .with_description("Number of proofs returned by a full node which failed local verification against the consensus state of the counterparty client")
+                .init(),
+
+            packet_stage_latency: meter
+                .u64_histogram("packet_stage_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The time packets spent between two stages of their lifecycle, \
+                    i.e. `receive` from sent to received, `acknowledge` from received to acknowledged, \
+                    `total` from sent to acknowledged and `timeout` from sent to timed out. Milliseconds.")
+                .init(),
+
+            client_time_to_expiry: meter
+                .u64_observable_gauge("client_time_to_expiry")
+                .with_unit(Unit::new("seconds"))
+                .with_description("The time left before each client refreshed by Hermes expires, i.e. until its trusting period elapses since its latest update. Seconds.")
+                .init(),
         }
     }
 
@@ -802,6 +879,30 @@ impl TelemetryState {
         self.wallet_balance.observe(amount, labels);
     }
 
+    /// Whether the balance in each wallet that Hermes is using is above the
+    /// configured thresholds (0), low (1) or critical (2), per account, denom and chain.
+    pub fn wallet_balance_level(&self, chain_id: &ChainId, account: &str, level: u64, denom: &str) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("account", account.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.wallet_balance_level.observe(level, labels);
+    }
+
+    /// Number of top-up transfers submitted to the wallet that Hermes is using,
+    /// per account, denom and chain.
+    pub fn wallet_top_up(&self, chain_id: &ChainId, account: &str, denom: &str) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("account", account.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.wallet_top_ups.add(1, labels);
+    }
+
     pub fn received_event_batch(&self, tracking_id: impl ToString) {
         self.in_flight_events
             .insert(tracking_id.to_string(), Instant::now());
@@ -1216,7 +1317,8 @@ impl TelemetryState {
         self.dynamic_gas_queried_success_fees.record(amount, labels);
     }
 
-    /// Increment number of packets filtered because the memo field is too big
+    /// Increment number of ICS-20 packets filtered, either because their memo or receiver
+    /// fields are too big, or because their content is rejected by the ICS-20 packet filter
     #[allow(clippy::too_many_arguments)]
     pub fn filtered_packets(
         &self,
@@ -1226,6 +1328,7 @@ impl TelemetryState {
         dst_channel: &ChannelId,
         src_port: &PortId,
         dst_port: &PortId,
+        reason: &'static str,
         count: u64,
     ) {
         if count > 0 {
@@ -1236,12 +1339,35 @@ impl TelemetryState {
                 KeyValue::new("dst_channel", dst_channel.to_string()),
                 KeyValue::new("src_port", src_port.to_string()),
                 KeyValue::new("dst_port", dst_port.to_string()),
+                KeyValue::new("reason", reason),
             ];
 
             self.filtered_packets.add(count, labels);
         }
     }
 
+    pub fn unprofitable_packets(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        src_channel: &ChannelId,
+        src_port: &PortId,
+        decision: &'static str,
+        count: u64,
+    ) {
+        if count > 0 {
+            let labels = &[
+                KeyValue::new("src_chain", src_chain.to_string()),
+                KeyValue::new("dst_chain", dst_chain.to_string()),
+                KeyValue::new("src_channel", src_channel.to_string()),
+                KeyValue::new("src_port", src_port.to_string()),
+                KeyValue::new("decision", decision),
+            ];
+
+            self.unprofitable_packets.add(count, labels);
+        }
+    }
+
     pub fn cross_chain_queries(&self, src_chain: &ChainId, dst_chain: &ChainId, count: usize) {
         if count > 0 {
             let labels = &[
@@ -1272,6 +1398,68 @@ impl TelemetryState {
             }
         }
     }
+
+    /// Record a switch from one full node to another
+    pub fn endpoint_switch(&self, chain_id: &ChainId, from_rpc_addr: &str, to_rpc_addr: &str) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("from", from_rpc_addr.to_string()),
+            KeyValue::new("to", to_rpc_addr.to_string()),
+        ];
+
+        self.endpoint_switches.add(1, labels);
+    }
+
+    /// Record that a full node of `chain_id` returned a proof which failed local verification,
+    /// with `counterparty_chain_id` being the chain the proof was meant for
+    pub fn invalid_proof(&self, chain_id: &ChainId, counterparty_chain_id: &ChainId) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+        ];
+
+        self.invalid_proofs.add(1, labels);
+    }
+
+    /// Record the time a packet sent on the given channel spent between two stages of its lifecycle
+    pub fn packet_stage_latency(
+        &self,
+        stage: &'static str,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        latency: Duration,
+    ) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("stage", stage),
+        ];
+
+        self.packet_stage_latency
+            .record(latency.as_millis() as u64, labels);
+    }
+
+    /// Record the time left before the given client, hosted on `chain_id`, expires
+    pub fn client_time_to_expiry(
+        &self,
+        chain_id: &ChainId,
+        client_id: &ClientId,
+        counterparty_chain_id: &ChainId,
+        time_to_expiry: Duration,
+    ) {
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("client", client_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+        ];
+
+        self.client_time_to_expiry
+            .observe(time_to_expiry.as_secs(), labels);
+    }
 }
 
 fn build_histogram_buckets(start: u64, end: u64, buckets: u64) -> Vec<f64> {
@@ -53,6 +53,8 @@ impl TestOverrides for Test {
                     chain_config.key_store_type = Store::Test;
                 }
                 ChainConfig::Penumbra(_) => { /* no-op */ }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => { /* no-op */ }
             }
         }
     }
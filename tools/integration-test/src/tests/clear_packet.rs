@@ -346,6 +346,8 @@ impl TestOverrides for ClearPacketOverrideTest {
                     chain_config.clear_interval = Some(10)
                 }
                 ChainConfig::Penumbra(_) => { /* no-op */ }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => { /* no-op */ }
             }
         }
     }
@@ -459,6 +461,7 @@ impl TestOverrides for ClearPacketSequencesTest {
     }
 }
 
+use ibc_relayer::config::filter::Ics20Filter;
 use ibc_relayer::link::{Link, LinkParameters};
 
 impl BinaryChannelTest for ClearPacketSequencesTest {
@@ -516,6 +519,8 @@ impl BinaryChannelTest for ClearPacketSequencesTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let rev_opts = LinkParameters {
@@ -524,6 +529,8 @@ impl BinaryChannelTest for ClearPacketSequencesTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         // Clear all even packets
@@ -118,6 +118,8 @@ impl TestOverrides for ExpirationTestOverrides {
                     chain_config.trusting_period = Some(CLIENT_EXPIRY);
                 }
                 ChainConfig::Penumbra(_) => { /* no-op */ }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => { /* no-op */ }
             }
         }
     }
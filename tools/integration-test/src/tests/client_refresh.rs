@@ -31,6 +31,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -39,6 +40,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -103,6 +105,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -111,6 +114,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -135,6 +139,8 @@ impl BinaryChainTest for ClientFailsTest {
                             config_chain_a.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
                         }
                         ChainConfig::Penumbra(_) => { /* no-op */ }
+                        #[cfg(feature = "mock-chain")]
+                        ChainConfig::Mock(_) => { /* no-op */ }
                     }
                 }
 
@@ -146,6 +152,8 @@ impl BinaryChainTest for ClientFailsTest {
                         config_chain_b.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
                     }
                     ChainConfig::Penumbra(_) => { /* no-op */ }
+                    #[cfg(feature = "mock-chain")]
+                    ChainConfig::Mock(_) => { /* no-op */ }
                 }
             },
             config,
@@ -33,6 +33,8 @@ impl TestOverrides for ClientDefaultsTest {
                 chain_config_a.trust_threshold = TrustThreshold::new(13, 23).unwrap();
             }
             ChainConfig::Penumbra(_) => { /* no-op */ }
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => { /* no-op */ }
         }
 
         match &mut config.chains[1] {
@@ -43,6 +45,8 @@ impl TestOverrides for ClientDefaultsTest {
                 chain_config_b.trust_threshold = TrustThreshold::TWO_THIRDS;
             }
             ChainConfig::Penumbra(_) => { /* no-op */ }
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => { /* no-op */ }
         }
     }
 }
@@ -75,6 +79,7 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(120_000)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -83,6 +88,7 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(340_000)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -173,6 +173,7 @@ impl BinaryChainTest for ClientUpgradeTest {
                 assert_eq!(client_state.chain_id, upgraded_chain_id);
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -226,6 +227,7 @@ impl BinaryChainTest for InvalidClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -326,6 +328,7 @@ impl BinaryChainTest for HeightTooHighClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -429,6 +432,7 @@ impl BinaryChainTest for HeightTooLowClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -54,6 +54,8 @@ impl TestOverrides for DynamicGasTest {
             }
             ChainConfig::Namada(_) => {}
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
 
         match &mut config.chains[1] {
@@ -65,6 +67,8 @@ impl TestOverrides for DynamicGasTest {
             }
             ChainConfig::Namada(_) => {}
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
     }
 
@@ -104,6 +108,8 @@ impl BinaryChannelTest for DynamicGasTest {
                 chain_config.gas_price.denom.clone()
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         };
 
         let gas_denom_str_b: String = match relayer
@@ -116,6 +122,8 @@ impl BinaryChannelTest for DynamicGasTest {
                 chain_config.gas_price.denom.clone()
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         };
 
         let gas_denom_a: MonoTagged<ChainA, Denom> =
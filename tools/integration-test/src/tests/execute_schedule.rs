@@ -15,6 +15,7 @@
 use ibc_test_framework::prelude::*;
 use ibc_test_framework::util::random::random_u128_range;
 
+use ibc_relayer::config::filter::Ics20Filter;
 use ibc_relayer::link::{Link, LinkParameters};
 
 /// The number of messages to be sent in a batch contained in a piece of operational data.
@@ -50,6 +51,8 @@ impl BinaryChannelTest for ExecuteScheduleTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let chain_a_link = Link::new_from_opts(
@@ -38,6 +38,10 @@ impl TestOverrides for FilterIncentivizedFeesRelayerTest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
@@ -193,6 +197,10 @@ impl TestOverrides for FilterByChannelIncentivizedFeesRelayerTest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
@@ -89,6 +89,8 @@ impl BinaryChannelTest for FeeGrantTest {
                 chain_config.gas_price.denom.clone()
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> =
@@ -118,6 +120,10 @@ impl BinaryChannelTest for FeeGrantTest {
                         ChainConfig::Penumbra(_) => {
                             panic!("running tests with Penumbra chain not supported")
                         }
+                        #[cfg(feature = "mock-chain")]
+                        ChainConfig::Mock(_) => {
+                            panic!("running tests with a mock chain not supported")
+                        }
                     }
                 }
             });
@@ -243,6 +249,8 @@ impl BinaryChannelTest for NoFeeGrantTest {
                 chain_config.gas_price.denom.clone()
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> =
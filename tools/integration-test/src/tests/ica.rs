@@ -77,6 +77,10 @@ impl TestOverrides for IcaFilterTestAllow {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
@@ -201,6 +205,10 @@ impl TestOverrides for IcaFilterTestDeny {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
@@ -87,6 +87,8 @@ impl TestOverrides for DynamicGasTest {
                 chain_config_a.dynamic_gas_price = DynamicGasPrice::unsafe_new(false, 1.1, 0.6);
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
 
         match &mut config.chains[1] {
@@ -100,6 +102,8 @@ impl TestOverrides for DynamicGasTest {
                     DynamicGasPrice::unsafe_new(self.dynamic_gas_enabled, 1.1, 0.6);
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
     }
 
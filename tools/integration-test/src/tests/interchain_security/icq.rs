@@ -98,6 +98,10 @@ impl TestOverrides for InterchainSecurityIcqTest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
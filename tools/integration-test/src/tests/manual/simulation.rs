@@ -35,6 +35,10 @@ impl TestOverrides for SimulationTest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
     }
@@ -99,6 +103,8 @@ fn tx_raw_ft_transfer<SrcChain: ChainHandle, DstChain: ChainHandle>(
         timeout_duration,
         number_msgs: number_messages,
         memo,
+        additional_tokens: vec![],
+        forwarding: None,
     };
 
     let events_with_heights =
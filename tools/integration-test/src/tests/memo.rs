@@ -40,6 +40,8 @@ impl TestOverrides for MemoTest {
                     chain_config.memo_prefix = self.memo.clone();
                 }
                 ChainConfig::Penumbra(_) => { /* no-op */ }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => { /* no-op */ }
             }
         }
     }
@@ -101,6 +103,8 @@ impl TestOverrides for MemoOverwriteTest {
                     chain_config.memo_overwrite = Some(Memo::new(OVERWRITE_MEMO).unwrap())
                 }
                 ChainConfig::Penumbra(_) => { /* no-op */ }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => { /* no-op */ }
             }
         }
     }
@@ -1,3 +1,4 @@
+use ibc_relayer::config::filter::Ics20Filter;
 use ibc_relayer::config::{types::MaxMsgNum, ChainConfig};
 use ibc_relayer::link::{Link, LinkParameters};
 use ibc_relayer::transfer::{build_and_send_transfer_messages, TransferOptions};
@@ -57,6 +58,10 @@ impl TestOverrides for OrderedChannelClearTest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
 
@@ -66,6 +71,8 @@ impl TestOverrides for OrderedChannelClearTest {
                 chain_config.sequential_batch_tx = self.sequential_batch_tx;
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
     }
 
@@ -126,6 +133,8 @@ impl BinaryChannelTest for OrderedChannelClearTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let chain_a_link = Link::new_from_opts(
@@ -142,6 +151,8 @@ impl BinaryChannelTest for OrderedChannelClearTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let chain_b_link = Link::new_from_opts(
@@ -208,6 +219,10 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                 ChainConfig::Penumbra(_) => {
                     panic!("running tests with Penumbra chain not supported")
                 }
+                #[cfg(feature = "mock-chain")]
+                ChainConfig::Mock(_) => {
+                    panic!("running tests with a mock chain not supported")
+                }
             }
         }
 
@@ -218,6 +233,8 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                 chain_config.max_msg_num = MaxMsgNum::new(3).unwrap();
             }
             ChainConfig::Penumbra(_) => panic!("running tests with Penumbra chain not supported"),
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => panic!("running tests with a mock chain not supported"),
         }
     }
 
@@ -264,6 +281,8 @@ impl BinaryChannelTest for OrderedChannelClearEqualCLITest {
             timeout_duration: Duration::from_secs(0),
             number_msgs: num_msgs,
             memo: None,
+            additional_tokens: vec![],
+            forwarding: None,
         };
 
         let events_with_heights = build_and_send_transfer_messages(
@@ -286,6 +305,8 @@ impl BinaryChannelTest for OrderedChannelClearEqualCLITest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let chain_a_link = Link::new_from_opts(
@@ -1,5 +1,6 @@
 use ibc_relayer::chain::counterparty::{channel_on_destination, pending_packet_summary};
 use ibc_relayer::chain::requests::Paginate;
+use ibc_relayer::config::filter::Ics20Filter;
 use ibc_relayer::link::{Link, LinkParameters};
 
 use ibc_test_framework::prelude::*;
@@ -64,6 +65,8 @@ impl BinaryChannelTest for QueryPacketPendingTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let rev_opts = LinkParameters {
@@ -72,6 +75,8 @@ impl BinaryChannelTest for QueryPacketPendingTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Ics20Filter::default(),
+            verify_proofs: packet_config.verify_proofs,
         };
 
         let link = Link::new_from_opts(
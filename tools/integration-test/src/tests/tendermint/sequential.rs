@@ -49,6 +49,8 @@ impl TestOverrides for SequentialCommitTest {
                 chain_config_a.sequential_batch_tx = true;
             }
             ChainConfig::Penumbra(_) => { /* no-op */ }
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => { /* no-op */ }
         };
 
         match &mut config.chains[1] {
@@ -57,6 +59,8 @@ impl TestOverrides for SequentialCommitTest {
                 chain_config_b.sequential_batch_tx = false;
             }
             ChainConfig::Penumbra(_) => { /* no-op */ }
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => { /* no-op */ }
         };
     }
 
@@ -32,7 +32,7 @@ use ibc_proto::ibc::apps::fee::v1::{
 };
 use ibc_relayer::account::Balance;
 use ibc_relayer::chain::client::ClientSettings;
-use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck};
+use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck, NodeStatus};
 use ibc_relayer::chain::handle::{ChainHandle, ChainRequest, Subscription};
 use ibc_relayer::chain::requests::*;
 use ibc_relayer::chain::tracking::TrackedMsgs;
@@ -45,7 +45,9 @@ use ibc_relayer::error::Error;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::keyring::AnySigningKeyPair;
 use ibc_relayer::misbehaviour::MisbehaviourEvidence;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
@@ -91,6 +93,10 @@ where
         self.value().health_check()
     }
 
+    fn query_node_status(&self) -> Result<Vec<NodeStatus>, Error> {
+        self.value().query_node_status()
+    }
+
     fn subscribe(&self) -> Result<Subscription, Error> {
         self.value().subscribe()
     }
@@ -109,6 +115,10 @@ where
         self.value().send_messages_and_wait_check_tx(tracked_msgs)
     }
 
+    fn estimate_fees(&self, tracked_msgs: TrackedMsgs) -> Result<Vec<RawCoin>, Error> {
+        self.value().estimate_fees(tracked_msgs)
+    }
+
     fn get_signer(&self) -> Result<Signer, Error> {
         self.value().get_signer()
     }
@@ -421,6 +431,14 @@ where
         self.value().query_denom_trace(hash)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<Signer, Error> {
+        self.value().query_interchain_account(owner, connection_id)
+    }
+
     fn cross_chain_query(
         &self,
         request: Vec<CrossChainQueryRequest>,
@@ -435,6 +453,14 @@ where
         self.value().query_incentivized_packet(request)
     }
 
+    fn query_incentivized_packets(
+        &self,
+        channel_id: ChannelId,
+        port_id: PortId,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.value().query_incentivized_packets(channel_id, port_id)
+    }
+
     fn query_consumer_chains(&self) -> Result<Vec<ConsumerChain>, Error> {
         self.value().query_consumer_chains()
     }
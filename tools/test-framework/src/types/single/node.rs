@@ -178,11 +178,14 @@ impl FullNode {
                 },
                 rpc_timeout: config::default::rpc_timeout(),
                 trusted_node: false,
+                witnesses: Vec::new(),
                 genesis_restart: None,
                 account_prefix: self.chain_driver.account_prefix.clone(),
                 key_name: self.wallets.relayer.id.0.clone(),
+                key_names: Vec::new(),
                 key_store_type: Store::Test,
                 key_store_folder: Some(hermes_keystore_dir.into()),
+                remote_signer: None,
                 store_prefix: "ibc".to_string(),
                 default_gas: None,
                 max_gas: Some(3000000),
@@ -212,6 +215,10 @@ impl FullNode {
                 clear_interval: None,
                 excluded_sequences: ExcludedSequences::new(BTreeMap::new()),
                 allow_ccq: true,
+                backup_endpoints: Vec::new(),
+                failover: Default::default(),
+                wallet: Default::default(),
+                verified_queries: None,
             }),
             TestedChainType::Namada => config::ChainConfig::Namada(CosmosSdkConfig {
                 id: self.chain_driver.chain_id.clone(),
@@ -223,11 +230,14 @@ impl FullNode {
                 },
                 rpc_timeout: config::default::rpc_timeout(),
                 trusted_node: false,
+                witnesses: Vec::new(),
                 genesis_restart: None,
                 account_prefix: "".to_owned(),
                 key_name: self.wallets.relayer.id.0.clone(),
+                key_names: Vec::new(),
                 key_store_type: Store::Test,
                 key_store_folder: Some(hermes_keystore_dir.into()),
+                remote_signer: None,
                 store_prefix: "ibc".to_string(),
                 default_gas: None,
                 max_gas: Some(4000000),
@@ -257,6 +267,10 @@ impl FullNode {
                 clear_interval: None,
                 excluded_sequences: ExcludedSequences::new(BTreeMap::new()),
                 allow_ccq: false,
+                backup_endpoints: Vec::new(),
+                failover: Default::default(),
+                wallet: Default::default(),
+                verified_queries: None,
             }),
         };
 
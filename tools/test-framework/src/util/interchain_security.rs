@@ -37,6 +37,8 @@ pub fn update_relayer_config_for_consumer_chain(config: &mut Config) {
             }
             ChainConfig::CosmosSdk(_) | ChainConfig::Namada(_) => {}
             ChainConfig::Penumbra(_) => { /* no-op Penumbra does not support CCV */ }
+            #[cfg(feature = "mock-chain")]
+            ChainConfig::Mock(_) => { /* no-op Penumbra does not support CCV */ }
         }
     }
 }